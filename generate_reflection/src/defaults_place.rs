@@ -58,6 +58,7 @@ fn apply_defaults_from_fixture_place(database: &mut ReflectionDatabase, tree: &W
         found_classes.insert(instance.class.clone());
 
         for (prop_name, prop_value) in &instance.properties {
+            let prop_name: &str = prop_name;
             let descriptors = match find_descriptors(database, &instance.class, prop_name) {
                 Some(descriptor) => descriptor,
                 None => {
@@ -74,7 +75,7 @@ fn apply_defaults_from_fixture_place(database: &mut ReflectionDatabase, tree: &W
             match &descriptors.canonical.kind {
                 PropertyKind::Canonical { serialization } => match serialization {
                     PropertySerialization::Serializes => {
-                        if &descriptors.canonical.name != prop_name {
+                        if descriptors.canonical.name != prop_name {
                             log::error!("Property {}.{} is supposed to serialize as {}, but was actually serialized as {}",
                                 instance.class,
                                 descriptors.canonical.name,
@@ -93,7 +94,7 @@ fn apply_defaults_from_fixture_place(database: &mut ReflectionDatabase, tree: &W
                     }
 
                     PropertySerialization::SerializesAs(serialized_name) => {
-                        if serialized_name != prop_name {
+                        if *serialized_name != prop_name {
                             log::error!("Property {}.{} is supposed to serialize as {}, but was actually serialized as {}",
                                 instance.class,
                                 descriptors.canonical.name,
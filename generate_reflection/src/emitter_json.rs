@@ -0,0 +1,226 @@
+//! Emits the reflection database as JSON with a documented, stable schema, for
+//! tools that aren't written in Rust.
+//!
+//! Unlike the `--json` output, which is whatever serde produces for
+//! `ReflectionDatabase` and exists for rbx_dom_lua, this format resolves
+//! aliases and serialized names ahead of time so that consumers don't need to
+//! understand how rbx_reflection models properties.
+//!
+//! ## Schema
+//! ```text
+//! {
+//!   "version": "0.484.0.425477",
+//!   "classes": {
+//!     "<class name>": {
+//!       "name": string,
+//!       "superclass": string | null,
+//!       "tags": [string],
+//!       "properties": {
+//!         "<property name>": {
+//!           "name": string,
+//!           "canonicalName": string,
+//!           "serializedName": string | null,
+//!           "type": { "kind": "Value" | "Enum", "name": string },
+//!           "tags": [string],
+//!           "default": Variant | null
+//!         }
+//!       },
+//!       "defaults": { "<property name>": Variant }
+//!     }
+//!   },
+//!   "enums": {
+//!     "<enum name>": {
+//!       "name": string,
+//!       "items": { "<item name>": number }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! `canonicalName` is the property that this property is an alias for, or its
+//! own name if it isn't an alias. `serializedName` is the name the property is
+//! written to files with, or null if it isn't written to files at all.
+//!
+//! `type.name` is the name of a `VariantType` for values, or the name of an
+//! entry in `enums` for enums. Types that rbx_reflection adds in the future
+//! will have a `kind` of `"Unknown"` until this emitter learns about them.
+//!
+//! `default` is the property's default value in the format that `Variant` is
+//! serialized to with serde, like `{ "Vector3": [4.0, 1.2, 2.0] }`. It's null
+//! when the default is unknown.
+//!
+//! `properties` only lists the properties that a class declares itself, but
+//! classes often have different defaults for properties they inherit, like
+//! `Part.Size`. `defaults` lists the default value of every property the class
+//! has, including inherited ones, by canonical name.
+//!
+//! Every map is sorted by key.
+
+use std::collections::BTreeMap;
+
+use rbx_dom_weak::types::Variant;
+use rbx_reflection::{
+    ClassDescriptor, DataType, PropertyDescriptor, PropertyKind, PropertySerialization,
+    ReflectionDatabase,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Database<'a> {
+    version: String,
+    classes: BTreeMap<&'a str, Class<'a>>,
+    enums: BTreeMap<&'a str, Enum<'a>>,
+}
+
+#[derive(Serialize)]
+struct Class<'a> {
+    name: &'a str,
+    superclass: Option<&'a str>,
+    tags: Vec<String>,
+    properties: BTreeMap<&'a str, Property<'a>>,
+    defaults: BTreeMap<&'a str, &'a Variant>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Property<'a> {
+    name: &'a str,
+    canonical_name: &'a str,
+    serialized_name: Option<&'a str>,
+    #[serde(rename = "type")]
+    ty: PropertyType,
+    tags: Vec<String>,
+    default: Option<&'a Variant>,
+}
+
+#[derive(Serialize)]
+struct PropertyType {
+    kind: &'static str,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct Enum<'a> {
+    name: &'a str,
+    items: BTreeMap<&'a str, u32>,
+}
+
+pub fn emit(database: &ReflectionDatabase) -> anyhow::Result<String> {
+    let version = database
+        .version
+        .iter()
+        .map(|part| part.to_string())
+        .collect::<Vec<_>>()
+        .join(".");
+
+    let classes = database
+        .classes
+        .values()
+        .map(|class| (class.name.as_ref(), convert_class(class)))
+        .collect();
+
+    let enums = database
+        .enums
+        .values()
+        .map(|descriptor| {
+            let items = descriptor
+                .items
+                .iter()
+                .map(|(name, value)| (name.as_ref(), *value))
+                .collect();
+
+            (
+                descriptor.name.as_ref(),
+                Enum {
+                    name: &descriptor.name,
+                    items,
+                },
+            )
+        })
+        .collect();
+
+    let output = Database {
+        version,
+        classes,
+        enums,
+    };
+
+    Ok(serde_json::to_string_pretty(&output)?)
+}
+
+fn convert_class<'a>(class: &'a ClassDescriptor<'a>) -> Class<'a> {
+    let mut tags: Vec<String> = class.tags.iter().map(|tag| format!("{:?}", tag)).collect();
+    tags.sort();
+
+    let properties = class
+        .properties
+        .values()
+        .map(|property| (property.name.as_ref(), convert_property(class, property)))
+        .collect();
+
+    let defaults = class
+        .default_properties
+        .iter()
+        .map(|(name, value)| (name.as_ref(), value))
+        .collect();
+
+    Class {
+        name: &class.name,
+        superclass: class.superclass.as_deref(),
+        tags,
+        properties,
+        defaults,
+    }
+}
+
+fn convert_property<'a>(
+    class: &'a ClassDescriptor<'a>,
+    property: &'a PropertyDescriptor<'a>,
+) -> Property<'a> {
+    let canonical = match &property.kind {
+        PropertyKind::Alias { alias_for } => {
+            class.properties.get(alias_for.as_ref()).unwrap_or(property)
+        }
+        _ => property,
+    };
+
+    let serialized_name = match &canonical.kind {
+        PropertyKind::Canonical { serialization } => match serialization {
+            PropertySerialization::Serializes => Some(canonical.name.as_ref()),
+            PropertySerialization::SerializesAs(name) => Some(name.as_ref()),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let ty = match &property.data_type {
+        DataType::Value(ty) => PropertyType {
+            kind: "Value",
+            name: format!("{:?}", ty),
+        },
+        DataType::Enum(name) => PropertyType {
+            kind: "Enum",
+            name: name.to_string(),
+        },
+        _ => PropertyType {
+            kind: "Unknown",
+            name: format!("{:?}", property.data_type),
+        },
+    };
+
+    let mut tags: Vec<String> = property
+        .tags
+        .iter()
+        .map(|tag| format!("{:?}", tag))
+        .collect();
+    tags.sort();
+
+    Property {
+        name: &property.name,
+        canonical_name: &canonical.name,
+        serialized_name,
+        ty,
+        tags,
+        default: class.default_properties.get(canonical.name.as_ref()),
+    }
+}
@@ -0,0 +1,416 @@
+//! Emits a Rust source file containing the parts of the reflection database
+//! that rbx_reflection_database needs, so that it can be generated straight
+//! from the API dump.
+//!
+//! The generated file refers to types through the `rbx_types` crate, which must
+//! be a dependency of whatever crate includes it.
+
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt::{self, Write},
+};
+
+use rbx_dom_weak::types::{CFrame, PhysicalProperties, Variant, Vector3};
+use rbx_reflection::{ClassDescriptor, PropertyKind, PropertySerialization, ReflectionDatabase};
+
+/// The classes in the database, sorted by name so that output is stable.
+type Classes<'a> = BTreeMap<&'a Cow<'a, str>, &'a ClassDescriptor<'a>>;
+
+pub fn emit(database: &ReflectionDatabase) -> anyhow::Result<String> {
+    let mut output = String::new();
+
+    write_header(&mut output, database)?;
+    writeln!(output, "use std::collections::HashMap;")?;
+    writeln!(output)?;
+
+    let classes: Classes = database.classes.iter().collect();
+
+    emit_classes(&mut output, database, &classes)?;
+    writeln!(output)?;
+    emit_serializations(&mut output, &classes)?;
+    writeln!(output)?;
+    emit_defaults(&mut output, &classes)?;
+
+    Ok(output)
+}
+
+/// Writes the comment that starts every generated Rust file, which says which
+/// version of Roblox the file was generated from.
+pub fn write_header(output: &mut String, database: &ReflectionDatabase) -> fmt::Result {
+    writeln!(
+        output,
+        "// This file was @generated by generate_reflection from Roblox {}.{}.{}.{}.",
+        database.version[0], database.version[1], database.version[2], database.version[3]
+    )?;
+    writeln!(output, "// Do not edit it by hand!")?;
+    writeln!(output)
+}
+
+/// Emits every class with its superclass chain and tags.
+fn emit_classes(
+    output: &mut String,
+    database: &ReflectionDatabase,
+    classes: &Classes,
+) -> fmt::Result {
+    writeln!(
+        output,
+        "/// Every class as `(name, superclasses, tags)`, where `superclasses` starts"
+    )?;
+    writeln!(output, "/// with the class's direct superclass.")?;
+    writeln!(
+        output,
+        "pub static CLASSES: &[(&str, &[&str], &[&str])] = &["
+    )?;
+
+    for (name, class) in classes {
+        let superclasses: Vec<&str> = database
+            .superclasses(class)
+            .skip(1)
+            .map(|superclass| superclass.name.as_ref())
+            .collect();
+
+        let mut tags: Vec<String> = class.tags.iter().map(|tag| format!("{:?}", tag)).collect();
+        tags.sort();
+
+        writeln!(
+            output,
+            "    ({:?}, &{:?}, &{:?}),",
+            name.as_ref(),
+            superclasses,
+            tags
+        )?;
+    }
+
+    writeln!(output, "];")
+}
+
+/// Emits the name that each canonical property is serialized with. Properties
+/// that don't serialize are left out.
+fn emit_serializations(output: &mut String, classes: &Classes) -> fmt::Result {
+    writeln!(
+        output,
+        "/// Every serialized property as `(class, canonical name, serialized name)`."
+    )?;
+    writeln!(
+        output,
+        "pub static PROPERTY_SERIALIZATIONS: &[(&str, &str, &str)] = &["
+    )?;
+
+    for (class_name, class) in classes {
+        let properties: BTreeMap<_, _> = class.properties.iter().collect();
+
+        for (name, property) in properties {
+            let serialized_name = match &property.kind {
+                PropertyKind::Canonical { serialization } => match serialization {
+                    PropertySerialization::Serializes => name.as_ref(),
+                    PropertySerialization::SerializesAs(serialized) => serialized.as_ref(),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+
+            writeln!(
+                output,
+                "    ({:?}, {:?}, {:?}),",
+                class_name.as_ref(),
+                name.as_ref(),
+                serialized_name
+            )?;
+        }
+    }
+
+    writeln!(output, "];")
+}
+
+/// Emits a function that builds the default properties of every class.
+fn emit_defaults(output: &mut String, classes: &Classes) -> fmt::Result {
+    writeln!(
+        output,
+        "/// Returns the default properties of every class that has any."
+    )?;
+    writeln!(output, "pub fn default_properties(")?;
+    writeln!(
+        output,
+        ") -> HashMap<&'static str, HashMap<&'static str, rbx_types::Variant>> {{"
+    )?;
+    writeln!(output, "    let mut classes = HashMap::new();")?;
+
+    for (class_name, class) in classes {
+        if class.default_properties.is_empty() {
+            continue;
+        }
+
+        let defaults: BTreeMap<_, _> = class.default_properties.iter().collect();
+
+        writeln!(output)?;
+        writeln!(output, "    let mut properties = HashMap::new();")?;
+
+        for (name, value) in defaults {
+            match variant(value) {
+                Some(expression) => writeln!(
+                    output,
+                    "    properties.insert({:?}, {});",
+                    name.as_ref(),
+                    expression
+                )?,
+                None => log::warn!(
+                    "Skipping default value of {}.{} because its type, {:?}, can't be emitted as Rust",
+                    class_name,
+                    name,
+                    value.ty()
+                ),
+            }
+        }
+
+        writeln!(
+            output,
+            "    classes.insert({:?}, properties);",
+            class_name.as_ref()
+        )?;
+    }
+
+    writeln!(output)?;
+    writeln!(output, "    classes")?;
+    writeln!(output, "}}")
+}
+
+/// Returns a Rust expression that constructs the given value, or `None` if we
+/// don't know how to construct values of its type.
+fn variant(value: &Variant) -> Option<String> {
+    let inner = match value {
+        Variant::Axes(value) => format!(
+            "rbx_types::Axes::from_bits({}).unwrap()",
+            value.bits()
+        ),
+        Variant::BinaryString(value) => {
+            let bytes: &[u8] = value.as_ref();
+            format!("rbx_types::BinaryString::from({:?}.to_vec())", bytes)
+        }
+        Variant::Bool(value) => value.to_string(),
+        Variant::BrickColor(value) => format!(
+            "rbx_types::BrickColor::from_number({}).unwrap()",
+            *value as u16
+        ),
+        Variant::CFrame(value) => cframe(value),
+        Variant::Color3(value) => format!(
+            "rbx_types::Color3::new({}, {}, {})",
+            f32_literal(value.r),
+            f32_literal(value.g),
+            f32_literal(value.b)
+        ),
+        Variant::Color3uint8(value) => format!(
+            "rbx_types::Color3uint8::new({}, {}, {})",
+            value.r, value.g, value.b
+        ),
+        Variant::ColorSequence(value) => {
+            let keypoints: Vec<String> = value
+                .keypoints
+                .iter()
+                .map(|keypoint| {
+                    format!(
+                        "rbx_types::ColorSequenceKeypoint {{ time: {}, color: rbx_types::Color3::new({}, {}, {}), envelope: {} }}",
+                        f32_literal(keypoint.time),
+                        f32_literal(keypoint.color.r),
+                        f32_literal(keypoint.color.g),
+                        f32_literal(keypoint.color.b),
+                        f32_literal(keypoint.envelope)
+                    )
+                })
+                .collect();
+
+            format!(
+                "rbx_types::ColorSequence {{ keypoints: vec![{}] }}",
+                keypoints.join(", ")
+            )
+        }
+        Variant::Content(value) => {
+            let value: &str = value.as_ref();
+            format!("rbx_types::Content::from({:?})", value)
+        }
+        Variant::Enum(value) => format!("rbx_types::Enum::from_u32({})", value.to_u32()),
+        Variant::Faces(value) => format!(
+            "rbx_types::Faces::from_bits({}).unwrap()",
+            value.bits()
+        ),
+        Variant::Float32(value) => f32_literal(*value),
+        Variant::Float64(value) => f64_literal(*value),
+        Variant::Int32(value) => format!("{}i32", value),
+        Variant::Int64(value) => format!("{}i64", value),
+        Variant::NumberRange(value) => format!(
+            "rbx_types::NumberRange::new({}, {})",
+            f32_literal(value.min),
+            f32_literal(value.max)
+        ),
+        Variant::NumberSequence(value) => {
+            let keypoints: Vec<String> = value
+                .keypoints
+                .iter()
+                .map(|keypoint| {
+                    format!(
+                        "rbx_types::NumberSequenceKeypoint::new({}, {}, {})",
+                        f32_literal(keypoint.time),
+                        f32_literal(keypoint.value),
+                        f32_literal(keypoint.envelope)
+                    )
+                })
+                .collect();
+
+            format!(
+                "rbx_types::NumberSequence {{ keypoints: vec![{}] }}",
+                keypoints.join(", ")
+            )
+        }
+        Variant::OptionalCFrame(value) => match value {
+            Some(value) => format!("Some({})", cframe(value)),
+            None => "None".to_owned(),
+        },
+        Variant::PhysicalProperties(value) => match value {
+            PhysicalProperties::Default => "rbx_types::PhysicalProperties::Default".to_owned(),
+            PhysicalProperties::Custom(custom) => format!(
+                "rbx_types::PhysicalProperties::Custom(rbx_types::CustomPhysicalProperties {{ density: {}, friction: {}, elasticity: {}, friction_weight: {}, elasticity_weight: {} }})",
+                f32_literal(custom.density),
+                f32_literal(custom.friction),
+                f32_literal(custom.elasticity),
+                f32_literal(custom.friction_weight),
+                f32_literal(custom.elasticity_weight)
+            ),
+        },
+        Variant::ProtectedString(value) => {
+            let value: &str = value.as_ref();
+            format!("rbx_types::ProtectedString::from({:?})", value)
+        }
+        Variant::Ray(value) => format!(
+            "rbx_types::Ray::new({}, {})",
+            vector3(&value.origin),
+            vector3(&value.direction)
+        ),
+        Variant::Rect(value) => format!(
+            "rbx_types::Rect::new(rbx_types::Vector2::new({}, {}), rbx_types::Vector2::new({}, {}))",
+            f32_literal(value.min.x),
+            f32_literal(value.min.y),
+            f32_literal(value.max.x),
+            f32_literal(value.max.y)
+        ),
+        // Referents are only meaningful inside of a single DOM, so the only
+        // one we can write out is the null referent.
+        Variant::Ref(value) if value.is_none() => "rbx_types::Ref::none()".to_owned(),
+        Variant::Region3(value) => format!(
+            "rbx_types::Region3::new({}, {})",
+            vector3(&value.min),
+            vector3(&value.max)
+        ),
+        Variant::Region3int16(value) => format!(
+            "rbx_types::Region3int16::new(rbx_types::Vector3int16::new({}, {}, {}), rbx_types::Vector3int16::new({}, {}, {}))",
+            value.min.x, value.min.y, value.min.z, value.max.x, value.max.y, value.max.z
+        ),
+        Variant::SharedString(value) => format!(
+            "rbx_types::SharedString::new({:?}.to_vec())",
+            value.data()
+        ),
+        Variant::String(value) => format!("String::from({:?})", value),
+        Variant::UDim(value) => format!(
+            "rbx_types::UDim::new({}, {})",
+            f32_literal(value.scale),
+            value.offset
+        ),
+        Variant::UDim2(value) => format!(
+            "rbx_types::UDim2::new(rbx_types::UDim::new({}, {}), rbx_types::UDim::new({}, {}))",
+            f32_literal(value.x.scale),
+            value.x.offset,
+            f32_literal(value.y.scale),
+            value.y.offset
+        ),
+        Variant::Vector2(value) => format!(
+            "rbx_types::Vector2::new({}, {})",
+            f32_literal(value.x),
+            f32_literal(value.y)
+        ),
+        Variant::Vector2int16(value) => format!(
+            "rbx_types::Vector2int16::new({}, {})",
+            value.x, value.y
+        ),
+        Variant::Tags(value) => format!(
+            "rbx_types::Tags::from(&{:?}[..])",
+            value.iter().collect::<Vec<_>>()
+        ),
+        Variant::Vector3(value) => vector3(value),
+        Variant::Attributes(value) => {
+            let entries = value
+                .iter()
+                .map(|(name, value)| Some(format!("({:?}, {})", name, variant(value)?)))
+                .collect::<Option<Vec<_>>>()?;
+
+            format!(
+                "vec![{}].into_iter().collect::<rbx_types::Attributes>()",
+                entries.join(", ")
+            )
+        }
+        Variant::Vector3int16(value) => format!(
+            "rbx_types::Vector3int16::new({}, {}, {})",
+            value.x, value.y, value.z
+        ),
+        _ => return None,
+    };
+
+    Some(format!("rbx_types::Variant::{:?}({})", value.ty(), inner))
+}
+
+fn cframe(value: &CFrame) -> String {
+    format!(
+        "rbx_types::CFrame::new({}, rbx_types::Matrix3::new({}, {}, {}))",
+        vector3(&value.position),
+        vector3(&value.orientation.x),
+        vector3(&value.orientation.y),
+        vector3(&value.orientation.z)
+    )
+}
+
+fn vector3(value: &Vector3) -> String {
+    format!(
+        "rbx_types::Vector3::new({}, {}, {})",
+        f32_literal(value.x),
+        f32_literal(value.y),
+        f32_literal(value.z)
+    )
+}
+
+/// Writes a float so that it parses back to exactly the same value. Rust's
+/// `Debug` output for floats round-trips, but it has no spelling for NaN or
+/// infinity.
+fn f32_literal(value: f32) -> String {
+    if value.is_nan() {
+        "f32::NAN".to_owned()
+    } else if value == f32::INFINITY {
+        "f32::INFINITY".to_owned()
+    } else if value == f32::NEG_INFINITY {
+        "f32::NEG_INFINITY".to_owned()
+    } else {
+        format!("{:?}f32", value)
+    }
+}
+
+fn f64_literal(value: f64) -> String {
+    if value.is_nan() {
+        "f64::NAN".to_owned()
+    } else if value == f64::INFINITY {
+        "f64::INFINITY".to_owned()
+    } else if value == f64::NEG_INFINITY {
+        "f64::NEG_INFINITY".to_owned()
+    } else {
+        format!("{:?}f64", value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_util::assert_parses;
+
+    #[test]
+    fn output_parses() {
+        let output = emit(rbx_reflection_database::get()).unwrap();
+        assert_parses(&output);
+    }
+}
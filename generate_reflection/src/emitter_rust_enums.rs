@@ -0,0 +1,129 @@
+//! Emits a Rust source file containing every enum in the reflection database,
+//! so that enum values like `Enum(256)` on a `Material` property can be turned
+//! back into names like `"SmoothPlastic"` at runtime.
+//!
+//! The generated file only depends on the standard library.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use rbx_reflection::ReflectionDatabase;
+
+use crate::emitter_rust::write_header;
+
+pub fn emit(database: &ReflectionDatabase) -> anyhow::Result<String> {
+    let mut output = String::new();
+
+    write_header(&mut output, database)?;
+    writeln!(output, "use std::collections::HashMap;")?;
+    writeln!(output)?;
+
+    let enums: BTreeMap<_, _> = database.enums.iter().collect();
+
+    writeln!(
+        output,
+        "/// Every enum as `(name, items)`, where `items` is a list of"
+    )?;
+    writeln!(output, "/// `(item name, value)` sorted by value.")?;
+    writeln!(output, "pub static ENUMS: &[(&str, &[(&str, u32)])] = &[")?;
+
+    for (name, descriptor) in enums {
+        let mut items: Vec<(&str, u32)> = descriptor
+            .items
+            .iter()
+            .map(|(item_name, value)| (item_name.as_ref(), *value))
+            .collect();
+        items.sort_by_key(|&(item_name, value)| (value, item_name));
+
+        if items.is_empty() {
+            writeln!(output, "    ({:?}, &[]),", name.as_ref())?;
+            continue;
+        }
+
+        writeln!(output, "    (")?;
+        writeln!(output, "        {:?},", name.as_ref())?;
+        writeln!(output, "        &[")?;
+
+        for (item_name, value) in items {
+            writeln!(output, "            ({:?}, {}),", item_name, value)?;
+        }
+
+        writeln!(output, "        ],")?;
+        writeln!(output, "    ),")?;
+    }
+
+    writeln!(output, "];")?;
+    writeln!(output)?;
+
+    writeln!(
+        output,
+        "/// Returns a map from each enum's name to a map from its values to the"
+    )?;
+    writeln!(output, "/// names of its items.")?;
+    writeln!(
+        output,
+        "pub fn enum_item_names() -> HashMap<&'static str, HashMap<u32, &'static str>> {{"
+    )?;
+    writeln!(output, "    ENUMS")?;
+    writeln!(output, "        .iter()")?;
+    writeln!(output, "        .map(|(name, items)| {{")?;
+    writeln!(
+        output,
+        "            let items = items.iter().map(|&(item, value)| (value, item)).collect();"
+    )?;
+    writeln!(output, "            (*name, items)")?;
+    writeln!(output, "        }})")?;
+    writeln!(output, "        .collect()")?;
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+
+    writeln!(
+        output,
+        "/// Returns a map from each enum's name to a map from the names of its"
+    )?;
+    writeln!(output, "/// items to their values.")?;
+    writeln!(
+        output,
+        "pub fn enum_item_values() -> HashMap<&'static str, HashMap<&'static str, u32>> {{"
+    )?;
+    writeln!(output, "    ENUMS")?;
+    writeln!(output, "        .iter()")?;
+    writeln!(
+        output,
+        "        .map(|(name, items)| (*name, items.iter().copied().collect()))"
+    )?;
+    writeln!(output, "        .collect()")?;
+    writeln!(output, "}}")?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_util::compile_and_run;
+
+    #[test]
+    fn known_values() {
+        let output = emit(rbx_reflection_database::get()).unwrap();
+        let stdout = compile_and_run(
+            &output,
+            r#"
+fn main() {
+    let names = generated::enum_item_names();
+    let values = generated::enum_item_values();
+
+    println!("{}", names["Material"][&256]);
+    println!("{}", names["Material"][&272]);
+    println!("{}", names["NormalId"][&5]);
+    println!("{}", values["PartType"]["Cylinder"]);
+    println!("{}", values["SurfaceType"]["Smooth"]);
+    println!("{}", values["HumanoidStateType"]["Jumping"]);
+    println!("{}", names.len() == generated::ENUMS.len());
+}
+"#,
+        );
+
+        assert_eq!(stdout, "Plastic\nSmoothPlastic\nFront\n2\n0\n3\ntrue\n");
+    }
+}
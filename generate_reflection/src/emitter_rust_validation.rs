@@ -0,0 +1,149 @@
+//! Emits a Rust source file that can tell whether a value of a given type is
+//! valid for a property, so that serializers can check property types without
+//! loading the whole reflection database.
+//!
+//! The output is checked into rbx_reflection as `src/property_types.rs`. The
+//! generated file refers to types through the `rbx_types` crate, which must be
+//! a dependency of whatever crate includes it.
+
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt::{self, Write},
+};
+
+use rbx_dom_weak::types::VariantType;
+use rbx_reflection::{ClassDescriptor, DataType, ReflectionDatabase};
+
+use crate::emitter_rust::write_header;
+
+pub fn emit(database: &ReflectionDatabase) -> anyhow::Result<String> {
+    let mut output = String::new();
+
+    write_header(&mut output, database)?;
+    writeln!(output, "use rbx_types::VariantType;")?;
+    writeln!(output)?;
+
+    let classes: BTreeMap<&Cow<str>, _> = database.classes.iter().collect();
+
+    writeln!(
+        output,
+        "/// Every class that has a superclass as `(class, superclass)`, sorted by"
+    )?;
+    writeln!(output, "/// class.")?;
+    writeln!(output, "static SUPERCLASSES: &[(&str, &str)] = &[")?;
+
+    for (name, class) in &classes {
+        if let Some(superclass) = &class.superclass {
+            writeln!(
+                output,
+                "    ({:?}, {:?}),",
+                name.as_ref(),
+                superclass.as_ref()
+            )?;
+        }
+    }
+
+    writeln!(output, "];")?;
+    writeln!(output)?;
+
+    emit_property_types(&mut output, &classes)?;
+    writeln!(output)?;
+
+    output.push_str(LOOKUP_FUNCTIONS);
+
+    Ok(output)
+}
+
+/// The functions that search the tables above. They don't depend on the
+/// database, so they're written out as-is.
+const LOOKUP_FUNCTIONS: &str = r#"/// Tells whether a value of type `ty` is valid for the given property on the
+/// given class, searching superclasses for the property. Unknown classes and
+/// properties are never valid.
+pub fn validate_property_type(class: &str, property: &str, ty: VariantType) -> bool {
+    superclasses(class)
+        .find_map(|class| {
+            PROPERTY_TYPES
+                .binary_search_by(|&(c, p, _)| (c, p).cmp(&(class, property)))
+                .ok()
+        })
+        .map(|index| PROPERTY_TYPES[index].2 == ty)
+        .unwrap_or(false)
+}
+
+/// Returns the given class followed by each of its superclasses.
+fn superclasses(class: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(class), |&class| {
+        SUPERCLASSES
+            .binary_search_by(|&(c, _)| c.cmp(class))
+            .ok()
+            .map(|index| SUPERCLASSES[index].1)
+    })
+}
+"#;
+
+/// Emits the type of every property that a class defines itself, sorted by
+/// class and then by property. Enum properties all have the type `Enum`.
+fn emit_property_types(
+    output: &mut String,
+    classes: &BTreeMap<&Cow<str>, &ClassDescriptor>,
+) -> fmt::Result {
+    writeln!(
+        output,
+        "/// Every property as `(class, property, type)`, sorted by class and then"
+    )?;
+    writeln!(output, "/// by property.")?;
+    writeln!(
+        output,
+        "static PROPERTY_TYPES: &[(&str, &str, VariantType)] = &["
+    )?;
+
+    for (class_name, class) in classes {
+        let properties: BTreeMap<_, _> = class.properties.iter().collect();
+
+        for (name, property) in properties {
+            let ty = match &property.data_type {
+                DataType::Value(ty) => *ty,
+                DataType::Enum(_) => VariantType::Enum,
+                _ => {
+                    log::warn!(
+                        "Skipping type of {}.{} because it has an unknown data type",
+                        class_name,
+                        name
+                    );
+                    continue;
+                }
+            };
+
+            writeln!(
+                output,
+                "    ({:?}, {:?}, VariantType::{:?}),",
+                class_name.as_ref(),
+                name.as_ref(),
+                ty
+            )?;
+        }
+    }
+
+    writeln!(output, "];")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// rbx_reflection compiles and tests the checked-in output, so it has to
+    /// match what the emitter produces from the bundled database.
+    #[test]
+    fn checked_in_output_is_current() {
+        let output = emit(rbx_reflection_database::get()).unwrap();
+
+        assert!(
+            output == include_str!("../../rbx_reflection/src/property_types.rs"),
+            "rbx_reflection/src/property_types.rs is out of date. Regenerate it with \
+             `cargo run --bin generate_reflection -- --from-msgpack \
+             rbx_reflection_database/database.msgpack \
+             --rust-validation rbx_reflection/src/property_types.rs`"
+        );
+    }
+}
@@ -1,9 +1,16 @@
 mod api_dump;
 mod defaults_place;
+mod emitter_json;
+mod emitter_rust;
+mod emitter_rust_enums;
+mod emitter_rust_validation;
 mod plugin_injector;
 mod property_patches;
 mod values;
 
+#[cfg(test)]
+mod test_util;
+
 use std::fs;
 use std::path::PathBuf;
 
@@ -16,40 +23,71 @@ use crate::property_patches::PropertyPatches;
 
 #[derive(Debug, StructOpt)]
 struct Options {
+    /// Reads the database from a MessagePack file written by `--msgpack`
+    /// instead of building it from Roblox Studio, so that the other outputs
+    /// can be regenerated without Studio installed.
+    #[structopt(long = "from-msgpack")]
+    from_msgpack_path: Option<PathBuf>,
+
     #[structopt(long = "json")]
     json_path: Option<PathBuf>,
 
+    #[structopt(long = "structured-json")]
+    structured_json_path: Option<PathBuf>,
+
     #[structopt(long = "msgpack")]
     msgpack_path: Option<PathBuf>,
 
+    #[structopt(long = "rust")]
+    rust_path: Option<PathBuf>,
+
+    #[structopt(long = "rust-enums")]
+    rust_enums_path: Option<PathBuf>,
+
+    #[structopt(long = "rust-validation")]
+    rust_validation_path: Option<PathBuf>,
+
     #[structopt(long = "values")]
     values_path: Option<PathBuf>,
 }
 
 fn run(options: Options) -> anyhow::Result<()> {
-    let mut database = ReflectionDatabase::new();
-
-    let dump = Dump::read()?;
-    dump.apply(&mut database)?;
-
-    let property_patches = PropertyPatches::load()?;
-    property_patches.apply(&mut database)?;
-
-    measure_default_properties(&mut database)?;
-
-    // TODO
-    // database.validate();
+    match &options.from_msgpack_path {
+        Some(path) => {
+            let contents = fs::read(path)?;
+            write_outputs(&options, &rmp_serde::from_slice(&contents)?)
+        }
+        None => write_outputs(&options, &build_database()?),
+    }
+}
 
+fn write_outputs(options: &Options, database: &ReflectionDatabase) -> anyhow::Result<()> {
     if let Some(path) = &options.msgpack_path {
-        let encoded = rmp_serde::to_vec(&database)?;
+        let encoded = rmp_serde::to_vec(database)?;
         fs::write(path, encoded)?;
     }
 
     if let Some(path) = &options.json_path {
-        let encoded = serde_json::to_string_pretty(&database)?;
+        let encoded = serde_json::to_string_pretty(database)?;
         fs::write(&path, encoded)?;
     }
 
+    if let Some(path) = &options.structured_json_path {
+        fs::write(path, emitter_json::emit(database)?)?;
+    }
+
+    if let Some(path) = &options.rust_path {
+        fs::write(path, emitter_rust::emit(database)?)?;
+    }
+
+    if let Some(path) = &options.rust_enums_path {
+        fs::write(path, emitter_rust_enums::emit(database)?)?;
+    }
+
+    if let Some(path) = &options.rust_validation_path {
+        fs::write(path, emitter_rust_validation::emit(database)?)?;
+    }
+
     if let Some(path) = &options.values_path {
         fs::write(&path, values::encode()?)?;
     }
@@ -57,6 +95,23 @@ fn run(options: Options) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn build_database() -> anyhow::Result<ReflectionDatabase<'static>> {
+    let mut database = ReflectionDatabase::new();
+
+    let dump = Dump::read()?;
+    dump.apply(&mut database)?;
+
+    let property_patches = PropertyPatches::load()?;
+    property_patches.apply(&mut database)?;
+
+    measure_default_properties(&mut database)?;
+
+    // TODO
+    // database.validate();
+
+    Ok(database)
+}
+
 fn main() {
     let options = Options::from_args();
 
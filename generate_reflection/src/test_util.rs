@@ -0,0 +1,64 @@
+use std::{
+    fs,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Runs rustfmt over the given source, panicking with rustfmt's errors if it
+/// isn't valid Rust.
+pub fn assert_parses(source: &str) {
+    let mut rustfmt = Command::new("rustfmt")
+        .arg("--edition=2018")
+        .arg("--emit=stdout")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not run rustfmt");
+
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+
+    let output = rustfmt.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "rustfmt rejected the generated code:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Compiles the given generated source as a module named `generated` next to
+/// `main_source`, runs the resulting binary, and returns what it printed.
+pub fn compile_and_run(generated: &str, main_source: &str) -> String {
+    let dir = tempfile::tempdir().unwrap();
+    let main_path = dir.path().join("main.rs");
+    let binary_path = dir.path().join("main");
+
+    fs::write(dir.path().join("generated.rs"), generated).unwrap();
+    fs::write(&main_path, format!("mod generated;\n{}", main_source)).unwrap();
+
+    let output = Command::new("rustc")
+        .arg("--edition=2018")
+        .arg("--cap-lints=allow")
+        .arg("-o")
+        .arg(&binary_path)
+        .arg(&main_path)
+        .output()
+        .expect("could not run rustc");
+
+    assert!(
+        output.status.success(),
+        "rustc rejected the generated code:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new(&binary_path).output().unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).unwrap()
+}
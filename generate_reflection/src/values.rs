@@ -3,10 +3,10 @@
 use std::collections::BTreeMap;
 
 use rbx_dom_weak::types::{
-    Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence,
+    Attributes, Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence,
     ColorSequenceKeypoint, Content, CustomPhysicalProperties, Enum, Faces, Matrix3, NumberRange,
-    NumberSequence, NumberSequenceKeypoint, PhysicalProperties, Ray, Rect, Region3int16, UDim,
-    UDim2, Variant, VariantType, Vector2, Vector2int16, Vector3, Vector3int16,
+    NumberSequence, NumberSequenceKeypoint, PhysicalProperties, Ray, Rect, Region3int16, Tags,
+    UDim, UDim2, Variant, VariantType, Vector2, Vector2int16, Vector3, Vector3int16,
 };
 use serde::Serialize;
 
@@ -20,6 +20,17 @@ struct TestEntry {
 pub fn encode() -> anyhow::Result<String> {
     let mut values: BTreeMap<&str, Variant> = BTreeMap::new();
 
+    values.insert(
+        "Attributes",
+        vec![
+            ("Health", Variant::Float64(100.0)),
+            ("Team", Variant::String("Red".to_owned())),
+            ("Spawn", Variant::Vector3(Vector3::new(1.0, 2.0, 3.0))),
+        ]
+        .into_iter()
+        .collect::<Attributes>()
+        .into(),
+    );
     values.insert("Axes", Axes::all().into());
     values.insert(
         "BinaryString",
@@ -97,6 +108,7 @@ pub fn encode() -> anyhow::Result<String> {
         Region3int16::new(Vector3int16::new(-10, -5, 0), Vector3int16::new(5, 10, 15)).into(),
     );
     values.insert("String", String::from("Hello, world!").into());
+    values.insert("Tags", Tags::from(&["foo", "con", "bar"][..]).into());
     values.insert("UDim", UDim::new(1.0, 32).into());
     values.insert(
         "UDim2",
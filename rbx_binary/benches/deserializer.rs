@@ -1,5 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
+use rbx_dom_weak::{InstanceBuilder, WeakDom};
+
 pub fn de_folders_100(c: &mut Criterion) {
     static BUFFER: &[u8] = include_bytes!("../bench-files/folders-100.rbxm");
 
@@ -30,6 +32,27 @@ pub fn de_modulescripts_100_lines_100(c: &mut Criterion) {
     });
 }
 
+pub fn de_folders_with_ints_10000(c: &mut Criterion) {
+    let mut tree = WeakDom::with_root_class("Folder", "Container");
+    let root_ref = tree.root_ref();
+
+    for i in 0..9999 {
+        tree.insert(
+            root_ref,
+            InstanceBuilder::new("Folder").with_property("Count", i * 7919 - 5000),
+        );
+    }
+
+    let mut buffer = Vec::new();
+    rbx_binary::to_writer(&mut buffer, &tree, &[root_ref]).unwrap();
+
+    c.bench_function("Deserialize 10,000 Folders with Int32 properties", |b| {
+        b.iter(|| {
+            deserialize_bench(&buffer);
+        });
+    });
+}
+
 #[inline(always)]
 fn deserialize_bench(buffer: &[u8]) {
     rbx_binary::from_reader(buffer).unwrap();
@@ -39,6 +62,7 @@ criterion_group!(
     deserializer,
     de_folders_100,
     de_deep_folders_100,
-    de_modulescripts_100_lines_100
+    de_modulescripts_100_lines_100,
+    de_folders_with_ints_10000
 );
 criterion_main!(deserializer);
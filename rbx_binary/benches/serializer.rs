@@ -3,7 +3,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use rbx_dom_weak::{InstanceBuilder, WeakDom};
 
 pub fn ser_folders_100(c: &mut Criterion) {
-    let mut tree = WeakDom::new(InstanceBuilder::new("Folder").with_name("Container"));
+    let mut tree = WeakDom::with_root_class("Folder", "Container");
     let root_ref = tree.root_ref();
 
     for i in 0..99 {
@@ -27,5 +27,30 @@ pub fn ser_folders_100(c: &mut Criterion) {
     });
 }
 
-criterion_group!(serializer, ser_folders_100);
+pub fn ser_folders_with_ints_10000(c: &mut Criterion) {
+    let mut tree = WeakDom::with_root_class("Folder", "Container");
+    let root_ref = tree.root_ref();
+
+    for i in 0..9999 {
+        tree.insert(
+            root_ref,
+            InstanceBuilder::new("Folder").with_property("Count", i * 7919 - 5000),
+        );
+    }
+
+    let mut buffer = Vec::new();
+
+    // Encode once into the buffer to pre-size it.
+    rbx_binary::to_writer(&mut buffer, &tree, &[root_ref]).unwrap();
+    buffer.clear();
+
+    c.bench_function("Serialize 10,000 Folders with Int32 properties", |b| {
+        b.iter(|| {
+            rbx_binary::to_writer(&mut buffer, &tree, &[root_ref]).unwrap();
+            buffer.clear();
+        });
+    });
+}
+
+criterion_group!(serializer, ser_folders_100, ser_folders_with_ints_10000);
 criterion_main!(serializer);
@@ -0,0 +1,92 @@
+//! Versions of [`from_reader`][crate::from_reader] and
+//! [`to_writer`][crate::to_writer] that work with Tokio's asynchronous I/O
+//! traits, enabled by the `tokio` feature.
+//!
+//! Encoding and decoding are CPU-bound, so they still happen synchronously.
+//! Only reading and writing the bytes of the file is done asynchronously, which
+//! is enough to stream models to and from sockets without blocking a thread on
+//! I/O.
+
+use rbx_dom_weak::{types::Ref, WeakDom};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    deserializer::{error::InnerError as DecodeInnerError, Deserializer},
+    serializer::{error::InnerError as EncodeInnerError, Serializer},
+    DecodeError, EncodeError,
+};
+
+/// Serializes a subset of the given DOM to a binary format model or place,
+/// writing it to an asynchronous writer.
+///
+/// The file is encoded into memory before any of it is written.
+pub async fn encode_async<W: AsyncWrite + Unpin>(
+    dom: &WeakDom,
+    refs: &[Ref],
+    mut writer: W,
+) -> Result<(), EncodeError> {
+    let mut buffer = Vec::new();
+    Serializer::new().serialize(&mut buffer, dom, refs)?;
+
+    writer
+        .write_all(&buffer)
+        .await
+        .map_err(EncodeInnerError::from)?;
+
+    writer.flush().await.map_err(EncodeInnerError::from)?;
+
+    Ok(())
+}
+
+/// Deserializes a Roblox binary model or place from an asynchronous reader.
+///
+/// The whole stream is read into memory before decoding starts.
+pub async fn decode_async<R: AsyncRead + Unpin>(mut reader: R) -> Result<WeakDom, DecodeError> {
+    let mut buffer = Vec::new();
+    reader
+        .read_to_end(&mut buffer)
+        .await
+        .map_err(DecodeInnerError::from)?;
+
+    Deserializer::new().deserialize(buffer.as_slice())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::Cursor;
+
+    use rbx_dom_weak::{types::Vector3, InstanceBuilder};
+    use tokio::io::BufReader;
+
+    #[tokio::test]
+    async fn round_trip() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder").with_child(
+                InstanceBuilder::new("Part")
+                    .with_name("Async Part")
+                    .with_property("Size", Vector3::new(1.0, 2.0, 3.0)),
+            ),
+        );
+
+        let mut encoded = Vec::new();
+        encode_async(&dom, dom.root().children(), &mut encoded)
+            .await
+            .unwrap();
+
+        let mut expected = Vec::new();
+        crate::to_writer(&mut expected, &dom, dom.root().children()).unwrap();
+        assert_eq!(encoded, expected);
+
+        let reader = BufReader::new(Cursor::new(encoded));
+        let decoded = decode_async(reader).await.unwrap();
+
+        let part = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+        assert_eq!(part.name, "Async Part");
+        assert_eq!(
+            part.properties.get("Size"),
+            Some(&Vector3::new(1.0, 2.0, 3.0).into())
+        );
+    }
+}
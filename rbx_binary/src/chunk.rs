@@ -1,45 +1,233 @@
 use std::{
+    cell::{Ref, RefCell},
     fmt,
     io::{self, Read, Write},
     str,
 };
 
-use crate::core::{RbxReadExt, RbxWriteExt};
+use crate::{
+    core::{RbxReadExt, RbxWriteExt},
+    logging,
+};
 
 /// Represents one chunk from a binary model file.
 #[derive(Debug)]
 pub struct Chunk {
+    /// The chunk's name, like `*b"PROP"`.
     pub name: [u8; 4],
+
+    /// The chunk's decompressed contents.
     pub data: Vec<u8>,
 }
 
 impl Chunk {
-    /// Reads and decodes a `Chunk` from the given reader.
+    /// Reads and decodes a `Chunk` from the given reader, which must be
+    /// positioned at the start of a chunk.
     pub fn decode<R: Read>(mut reader: R) -> io::Result<Chunk> {
         let header = decode_chunk_header(&mut reader)?;
 
-        log::trace!("{}", header);
+        logging::trace!("{}", header);
 
         let data = if header.compressed_len == 0 {
-            let mut data = Vec::with_capacity(header.len as usize);
-            reader.take(header.len as u64).read_to_end(&mut data)?;
-            data
+            read_chunk_body(&mut reader, header.len)?
         } else {
-            let mut compressed_data = Vec::with_capacity(header.compressed_len as usize);
-            reader
-                .take(header.compressed_len as u64)
-                .read_to_end(&mut compressed_data)?;
-
-            lz4::block::decompress(&compressed_data, Some(header.len as i32))?
+            let compressed_data = read_chunk_body(&mut reader, header.compressed_len)?;
+            decompress(&compressed_data, header.len)?
         };
 
-        assert_eq!(data.len(), header.len as usize);
-
         Ok(Chunk {
             name: header.name,
             data,
         })
     }
+
+    /// Reads a chunk from the given reader without decompressing its contents.
+    /// The contents are decompressed the first time they're accessed.
+    ///
+    /// ```
+    /// use rbx_binary::Chunk;
+    ///
+    /// // An uncompressed META chunk with no entries: the chunk's name, its
+    /// // compressed length (zero for uncompressed chunks), its length, four
+    /// // reserved bytes, and then its contents.
+    /// let mut input: &[u8] = &[
+    ///     b'M', b'E', b'T', b'A',
+    ///     0, 0, 0, 0,
+    ///     4, 0, 0, 0,
+    ///     0, 0, 0, 0,
+    ///     0, 0, 0, 0,
+    /// ];
+    ///
+    /// let chunk = Chunk::decode_lazy(&mut input)?;
+    /// assert_eq!(&chunk.name, b"META");
+    /// assert_eq!(&*chunk.data()?, &[0, 0, 0, 0]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn decode_lazy<R: Read>(mut reader: R) -> io::Result<LazyChunk> {
+        let header = decode_chunk_header(&mut reader)?;
+
+        logging::trace!("{} (lazy)", header);
+
+        if header.compressed_len == 0 {
+            let data = read_chunk_body(&mut reader, header.len)?;
+
+            Ok(Chunk {
+                name: header.name,
+                data,
+            }
+            .into())
+        } else {
+            Ok(LazyChunk {
+                name: header.name,
+                len: header.len,
+                compressed_data: read_chunk_body(&mut reader, header.compressed_len)?,
+                data: RefCell::new(None),
+            })
+        }
+    }
+}
+
+/// A chunk that rbx_binary doesn't understand, kept around so that it can be
+/// written back out unchanged. See [`Deserializer::preserve_unknown_chunks`].
+///
+/// [`Deserializer::preserve_unknown_chunks`]: crate::Deserializer::preserve_unknown_chunks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpaqueChunk {
+    /// The chunk's name, like `*b"FUTR"`.
+    pub name: [u8; 4],
+
+    /// The chunk's decompressed contents.
+    pub data: Vec<u8>,
+}
+
+/// The size of one chunk written by the serializer. See
+/// [`Serializer::serialize_with_stats`].
+///
+/// [`Serializer::serialize_with_stats`]: crate::Serializer::serialize_with_stats
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkStat {
+    /// The chunk's name, like `*b"PROP"`.
+    pub name: [u8; 4],
+
+    /// The length of the chunk's contents before compression.
+    pub uncompressed_size: usize,
+
+    /// The length of the chunk's contents as written to the file. For chunks
+    /// that aren't compressed, this is the same as `uncompressed_size`.
+    pub compressed_size: usize,
+}
+
+/// A chunk from a binary model file whose contents are only decompressed when
+/// they're first accessed. Decompressed contents are kept around, so each chunk
+/// is decompressed at most once.
+///
+/// Created by [`Chunk::decode_lazy`].
+#[derive(Debug)]
+pub struct LazyChunk {
+    /// The chunk's name, like `*b"PROP"`.
+    pub name: [u8; 4],
+
+    /// The length of the chunk's contents once decompressed.
+    len: u32,
+
+    /// The raw contents of the chunk as read from the file. Empty if the chunk
+    /// was never compressed or was already decompressed when it was created.
+    compressed_data: Vec<u8>,
+
+    data: RefCell<Option<Vec<u8>>>,
+}
+
+impl LazyChunk {
+    /// Returns the decompressed contents of the chunk, decompressing them if
+    /// this is the first time they've been accessed.
+    pub fn data(&self) -> io::Result<Ref<'_, [u8]>> {
+        if self.data.borrow().is_none() {
+            let data = decompress(&self.compressed_data, self.len)?;
+            *self.data.borrow_mut() = Some(data);
+        }
+
+        Ok(Ref::map(self.data.borrow(), |data| {
+            data.as_ref().unwrap().as_slice()
+        }))
+    }
+
+    /// Tells whether the contents of this chunk are available without needing
+    /// to decompress anything.
+    pub fn is_decompressed(&self) -> bool {
+        self.data.borrow().is_some()
+    }
+}
+
+impl From<Chunk> for LazyChunk {
+    fn from(chunk: Chunk) -> Self {
+        LazyChunk {
+            name: chunk.name,
+            len: chunk.data.len() as u32,
+            compressed_data: Vec::new(),
+            data: RefCell::new(Some(chunk.data)),
+        }
+    }
+}
+
+/// The most space that will be set aside for a chunk's contents before any of
+/// them are read. Chunk lengths come from the file, so longer chunks grow
+/// their buffer as they're read instead.
+const MAX_PREALLOCATED_LEN: usize = 1 << 20;
+
+fn read_chunk_body<R: Read>(reader: R, len: u32) -> io::Result<Vec<u8>> {
+    let mut data = Vec::with_capacity((len as usize).min(MAX_PREALLOCATED_LEN));
+    reader.take(len as u64).read_to_end(&mut data)?;
+
+    if data.len() != len as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "chunk should be {} bytes long, but the input ended after {}",
+                len,
+                data.len()
+            ),
+        ));
+    }
+
+    Ok(data)
+}
+
+fn decompress(compressed_data: &[u8], len: u32) -> io::Result<Vec<u8>> {
+    let data = lz4_decompress(compressed_data, len)?;
+
+    if data.len() != len as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "chunk should be {} bytes long when decompressed, but was {}",
+                len,
+                data.len()
+            ),
+        ));
+    }
+
+    Ok(data)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn lz4_decompress(compressed_data: &[u8], len: u32) -> io::Result<Vec<u8>> {
+    lz4::block::decompress(compressed_data, Some(len as i32))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn lz4_decompress(compressed_data: &[u8], len: u32) -> io::Result<Vec<u8>> {
+    lz4_flex::block::decompress(compressed_data, len as usize)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn lz4_compress(data: &[u8]) -> io::Result<Vec<u8>> {
+    lz4::block::compress(data, None, false)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn lz4_compress(data: &[u8]) -> io::Result<Vec<u8>> {
+    Ok(lz4_flex::block::compress(data))
 }
 
 /// The compression format of a chunk in the binary model format.
@@ -59,7 +247,7 @@ pub enum ChunkCompression {
 /// automatically.
 #[must_use]
 pub struct ChunkBuilder {
-    chunk_name: &'static [u8],
+    chunk_name: [u8; 4],
     compression: ChunkCompression,
     buffer: Vec<u8>,
 }
@@ -67,27 +255,29 @@ pub struct ChunkBuilder {
 impl ChunkBuilder {
     /// Creates a new `ChunkBuilder` with the given name and compression
     /// setting.
-    pub fn new(chunk_name: &'static [u8], compression: ChunkCompression) -> Self {
+    pub fn new(chunk_name: &[u8; 4], compression: ChunkCompression) -> Self {
         ChunkBuilder {
-            chunk_name,
+            chunk_name: *chunk_name,
             compression,
             buffer: Vec::new(),
         }
     }
 
-    /// Consume the chunk and write it to the given writer.
-    pub fn dump<W: Write>(self, mut writer: W) -> io::Result<()> {
-        writer.write_all(self.chunk_name)?;
+    /// Consume the chunk and write it to the given writer, returning how large
+    /// the chunk was.
+    pub fn dump<W: Write>(self, mut writer: W) -> io::Result<ChunkStat> {
+        writer.write_all(&self.chunk_name)?;
 
-        match self.compression {
+        let compressed_size = match self.compression {
             ChunkCompression::Compressed => {
-                let compressed = lz4::block::compress(&self.buffer, None, false)?;
+                let compressed = lz4_compress(&self.buffer)?;
 
                 writer.write_le_u32(compressed.len() as u32)?;
                 writer.write_le_u32(self.buffer.len() as u32)?;
                 writer.write_le_u32(0)?;
 
                 writer.write_all(&compressed)?;
+                compressed.len()
             }
             ChunkCompression::Uncompressed => {
                 writer.write_le_u32(0)?;
@@ -95,10 +285,15 @@ impl ChunkBuilder {
                 writer.write_le_u32(0)?;
 
                 writer.write_all(&self.buffer)?;
+                self.buffer.len()
             }
-        }
+        };
 
-        Ok(())
+        Ok(ChunkStat {
+            name: self.chunk_name,
+            uncompressed_size: self.buffer.len(),
+            compressed_size,
+        })
     }
 }
 
@@ -154,10 +349,13 @@ fn decode_chunk_header<R: Read>(source: &mut R) -> io::Result<ChunkHeader> {
     let reserved = source.read_le_u32()?;
 
     if reserved != 0 {
-        panic!(
-            "Chunk reserved space was not zero, it was {}. This chunk may be malformed.",
-            reserved
-        );
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "chunk reserved space was not zero, it was {}. This chunk may be malformed.",
+                reserved
+            ),
+        ));
     }
 
     Ok(ChunkHeader {
@@ -167,3 +365,193 @@ fn decode_chunk_header<R: Read>(source: &mut R) -> io::Result<ChunkHeader> {
         reserved,
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_dom_weak::{types::Vector3, InstanceBuilder, WeakDom};
+
+    use crate::{deserializer::FileHeader, Deserializer, Serializer};
+
+    /// The length of the file header, which comes before any chunks.
+    const HEADER_LEN: usize = 32;
+
+    /// Creates a model with a compressed META chunk at the start, since our
+    /// serializer doesn't write one.
+    fn model_with_metadata() -> Vec<u8> {
+        let dom = WeakDom::new(InstanceBuilder::new("Folder").with_child(
+            InstanceBuilder::new("Part").with_property("Size", Vector3::new(1.0, 2.0, 3.0)),
+        ));
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, dom.root().children())
+            .unwrap();
+
+        let mut meta = ChunkBuilder::new(b"META", ChunkCompression::Compressed);
+        meta.write_le_u32(1).unwrap();
+        meta.write_string("ExplicitAutoJoints").unwrap();
+        meta.write_string("true").unwrap();
+
+        let mut output = model[..HEADER_LEN].to_vec();
+        meta.dump(&mut output).unwrap();
+        output.extend_from_slice(&model[HEADER_LEN..]);
+        output
+    }
+
+    #[test]
+    fn metadata_only() {
+        let model = model_with_metadata();
+        let mut input = model.as_slice();
+
+        FileHeader::decode(&mut input).unwrap();
+
+        let mut chunks = Vec::new();
+        loop {
+            let chunk = Chunk::decode_lazy(&mut input).unwrap();
+            let is_end = &chunk.name == b"END\0";
+            chunks.push(chunk);
+
+            if is_end {
+                break;
+            }
+        }
+
+        let meta = chunks.iter().find(|chunk| &chunk.name == b"META").unwrap();
+        let meta_data = meta.data().unwrap();
+        let mut meta_data: &[u8] = &meta_data;
+        assert_eq!(meta_data.read_le_u32().unwrap(), 1);
+        assert_eq!(meta_data.read_string().unwrap(), "ExplicitAutoJoints");
+        assert_eq!(meta_data.read_string().unwrap(), "true");
+
+        let props: Vec<_> = chunks
+            .iter()
+            .filter(|chunk| &chunk.name == b"PROP")
+            .collect();
+
+        assert!(!props.is_empty());
+        assert!(props.iter().all(|chunk| !chunk.is_decompressed()));
+
+        // Accessing a chunk's contents decompresses it once and keeps the
+        // result around.
+        props[0].data().unwrap();
+        assert!(props[0].is_decompressed());
+    }
+
+    /// An uncompressed META chunk with no entries, like the one in the
+    /// `decode_lazy` example.
+    const EMPTY_META: &[u8] = &[
+        b'M', b'E', b'T', b'A', 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    #[test]
+    fn truncated_chunk() {
+        for len in 0..EMPTY_META.len() {
+            let error = Chunk::decode(&EMPTY_META[..len]).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof, "{} bytes", len);
+
+            let error = Chunk::decode_lazy(&EMPTY_META[..len]).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof, "{} bytes", len);
+        }
+
+        // A compressed chunk that's cut off can't be decompressed.
+        let mut compressed = Vec::new();
+        let mut builder = ChunkBuilder::new(b"META", ChunkCompression::Compressed);
+        builder.write_all(&[0; 64]).unwrap();
+        builder.dump(&mut compressed).unwrap();
+
+        let error = Chunk::decode(&compressed[..compressed.len() - 1]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn wrong_decompressed_len() {
+        let mut chunk = Vec::new();
+        let mut builder = ChunkBuilder::new(b"META", ChunkCompression::Compressed);
+        builder.write_all(&[0; 64]).unwrap();
+        builder.dump(&mut chunk).unwrap();
+
+        // Claim that the chunk decompresses to fewer bytes than it does.
+        chunk[8..12].copy_from_slice(&32u32.to_le_bytes());
+
+        assert!(Chunk::decode(chunk.as_slice()).is_err());
+
+        let lazy = Chunk::decode_lazy(chunk.as_slice()).unwrap();
+        assert!(lazy.data().is_err());
+    }
+
+    #[test]
+    fn nonzero_reserved() {
+        let mut chunk = EMPTY_META.to_vec();
+        chunk[12] = 7;
+
+        let error = Chunk::decode(chunk.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let error = Chunk::decode_lazy(chunk.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn truncated_model() {
+        let model = model_with_metadata();
+
+        for len in 0..model.len() {
+            assert!(
+                crate::from_reader(&model[..len]).is_err(),
+                "model cut to {} bytes should not decode",
+                len
+            );
+            assert!(
+                crate::decode_with_warnings(&model[..len]).is_err(),
+                "model cut to {} bytes should not decode",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn nonzero_reserved_in_model() {
+        let mut model = model_with_metadata();
+        model[HEADER_LEN + 12] = 7;
+
+        assert!(crate::from_reader(model.as_slice()).is_err());
+        assert!(crate::decode_with_warnings(model.as_slice()).is_err());
+    }
+
+    /// Corrupting any single byte of a model should produce a DOM or an error,
+    /// but never a panic.
+    #[test]
+    fn corrupted_model() {
+        let model = model_with_metadata();
+
+        for index in 0..model.len() {
+            let mut corrupted = model.clone();
+            corrupted[index] ^= 0xFF;
+
+            let _ = crate::from_reader(corrupted.as_slice());
+            let _ = crate::decode_with_warnings(corrupted.as_slice());
+            let _ = Deserializer::new()
+                .lazy_chunks(true)
+                .deserialize(corrupted.as_slice());
+        }
+    }
+
+    #[test]
+    fn lazy_deserializer_matches() {
+        let model = model_with_metadata();
+
+        let dom = Deserializer::new()
+            .lazy_chunks(true)
+            .deserialize(model.as_slice())
+            .unwrap();
+
+        let part = dom.get_by_ref(dom.root().children()[0]).unwrap();
+        assert_eq!(part.class, "Part");
+        assert_eq!(
+            part.properties.get("Size"),
+            Some(&Vector3::new(1.0, 2.0, 3.0).into())
+        );
+    }
+}
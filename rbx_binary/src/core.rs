@@ -7,6 +7,8 @@ use rbx_reflection::{
     ClassDescriptor, PropertyDescriptor, PropertyKind, PropertySerialization, ReflectionDatabase,
 };
 
+use crate::logging;
+
 pub static FILE_MAGIC_HEADER: &[u8] = b"<roblox!";
 pub static FILE_SIGNATURE: &[u8] = b"\x89\xff\x0d\x0a\x1a\x0a";
 pub const FILE_VERSION: u16 = 0;
@@ -63,7 +65,9 @@ pub trait RbxReadExt: Read {
     fn read_binary_string(&mut self) -> io::Result<Vec<u8>> {
         let length = self.read_le_u32()?;
 
-        let mut value = Vec::with_capacity(length as usize);
+        // The length comes from the file, so we let the buffer grow as bytes
+        // are actually read instead of trusting it up front.
+        let mut value = Vec::new();
         self.take(length as u64).read_to_end(&mut value)?;
 
         Ok(value)
@@ -74,7 +78,7 @@ pub trait RbxReadExt: Read {
     /// generally aren't dilligent about data being valid Unicode.
     fn read_string(&mut self) -> io::Result<String> {
         let length = self.read_le_u32()?;
-        let mut value = String::with_capacity(length as usize);
+        let mut value = String::new();
         self.take(length as u64).read_to_string(&mut value)?;
 
         Ok(value)
@@ -235,15 +239,22 @@ pub trait RbxWriteExt: Write {
         I: Iterator<Item = i32>,
     {
         let values: Vec<_> = values.collect();
+        let len = values.len();
 
-        for shift in &[24, 16, 8, 0] {
-            for value in values.iter().copied() {
-                let encoded = transform_i32(value) >> shift;
-                self.write_u8(encoded as u8)?;
-            }
+        // Every referent array goes through here, so the bytes are laid out in
+        // memory and written all at once instead of one at a time.
+        let mut buffer = vec![0; len * mem::size_of::<i32>()];
+
+        for (i, value) in values.iter().enumerate() {
+            let bytes = transform_i32(*value).to_be_bytes();
+
+            buffer[i] = bytes[0];
+            buffer[i + len] = bytes[1];
+            buffer[i + len * 2] = bytes[2];
+            buffer[i + len * 3] = bytes[3];
         }
 
-        Ok(())
+        self.write_all(&buffer)
     }
 
     fn write_interleaved_u32_array(&mut self, values: &[u32]) -> io::Result<()> {
@@ -388,7 +399,7 @@ pub fn find_property_descriptors<'a>(
                     } else {
                         // If one property in the database calls itself an alias
                         // of another property, that property must be canonical.
-                        log::error!(
+                        logging::error!(
                             "Property {}.{} is marked as an alias for {}.{}, but the latter is not canonical.",
                             class_descriptor.name,
                             property_descriptor.name,
@@ -0,0 +1,43 @@
+use std::io::{self, Read};
+
+/// Wraps a reader and keeps track of how many bytes have been read from it, so
+/// that errors can point at where in the file they happened.
+pub(crate) struct CountingReader<R> {
+    inner: R,
+    offset: u64,
+}
+
+impl<R: Read> CountingReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self { inner, offset: 0 }
+    }
+
+    /// The number of bytes that have been read so far.
+    pub(crate) fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.offset += read as u64;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts_bytes() {
+        let mut reader = CountingReader::new([1, 2, 3, 4, 5].as_slice());
+
+        reader.read_exact(&mut [0; 2]).unwrap();
+        assert_eq!(reader.offset(), 2);
+
+        reader.read_to_end(&mut Vec::new()).unwrap();
+        assert_eq!(reader.offset(), 5);
+    }
+}
@@ -1,24 +1,123 @@
-use std::io;
+use std::{fmt, io, str};
 
 use thiserror::Error;
 
 use crate::types::InvalidTypeError;
 
 /// Represents an error that occurred during deserialization.
-#[derive(Debug, Error)]
-#[error(transparent)]
+#[derive(Debug)]
 pub struct Error {
     source: Box<InnerError>,
+    byte_offset: Option<u64>,
+    chunk_name: Option<[u8; 4]>,
+    contents_offset: Option<u64>,
+}
+
+impl Error {
+    /// Creates an error for a problem with the field at the given offset in
+    /// the file header.
+    pub(crate) fn at(source: InnerError, byte_offset: u64) -> Self {
+        Self {
+            source: Box::new(source),
+            byte_offset: Some(byte_offset),
+            chunk_name: None,
+            contents_offset: None,
+        }
+    }
+
+    /// Creates an error for a problem with the chunk that starts at the given
+    /// offset. `contents_offset` is how far decoding had gotten into the
+    /// chunk's decompressed contents, if it had started.
+    pub(crate) fn in_chunk(
+        source: InnerError,
+        byte_offset: u64,
+        chunk_name: Option<[u8; 4]>,
+        contents_offset: Option<u64>,
+    ) -> Self {
+        Self {
+            source: Box::new(source),
+            byte_offset: Some(byte_offset),
+            chunk_name,
+            contents_offset,
+        }
+    }
+
+    /// The offset in the input where this error occurred, if it's known.
+    ///
+    /// For errors in the file header, this is the offset of the field that
+    /// couldn't be read or was invalid. For errors in a chunk, this is the
+    /// offset of the start of the chunk; see [`Error::contents_offset`] for
+    /// where in the chunk the error occurred.
+    pub fn byte_offset(&self) -> Option<u64> {
+        self.byte_offset
+    }
+
+    /// The name of the chunk that was being decoded when this error occurred,
+    /// like `*b"PROP"`, or `None` if the error occurred before a chunk's name
+    /// could be read.
+    pub fn chunk_name(&self) -> Option<[u8; 4]> {
+        self.chunk_name
+    }
+
+    /// How many bytes of the chunk's decompressed contents had been read when
+    /// this error occurred, or `None` if the error didn't occur while reading
+    /// a chunk's contents. Errors about a value point just past that value.
+    pub fn contents_offset(&self) -> Option<u64> {
+        self.contents_offset
+    }
+
+    #[cfg(test)]
+    pub(crate) fn inner(&self) -> &InnerError {
+        &self.source
+    }
 }
 
 impl From<InnerError> for Error {
     fn from(inner: InnerError) -> Self {
         Self {
             source: Box::new(inner),
+            byte_offset: None,
+            chunk_name: None,
+            contents_offset: None,
         }
     }
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, output: &mut fmt::Formatter) -> fmt::Result {
+        write!(output, "{}", self.source)?;
+
+        let byte_offset = match self.byte_offset {
+            Some(byte_offset) => byte_offset,
+            None => return Ok(()),
+        };
+
+        let name = match &self.chunk_name {
+            Some(name) => name,
+            None => return write!(output, " (at byte offset {})", byte_offset),
+        };
+
+        match str::from_utf8(name) {
+            Ok(name) => write!(output, " (in chunk {}", name.trim_end_matches('\0'))?,
+            Err(_) => write!(output, " (in chunk {:?}", name)?,
+        }
+
+        write!(output, " at byte offset {}", byte_offset)?;
+
+        if let Some(contents_offset) = self.contents_offset {
+            write!(output, ", contents offset {}", contents_offset)?;
+        }
+
+        write!(output, ")")
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.source()
+    }
+}
+
 #[derive(Debug, Error)]
 pub(crate) enum InnerError {
     #[error(transparent)]
@@ -30,8 +129,12 @@ pub(crate) enum InnerError {
     #[error("Invalid file header")]
     BadHeader,
 
-    #[error("Unknown file version {version}. Known versions are: 0")]
-    UnknownFileVersion { version: u16 },
+    #[error("Unknown file version {version}. Allowed versions are {minimum} through {maximum}")]
+    UnknownFileVersion {
+        version: u16,
+        minimum: u16,
+        maximum: u16,
+    },
 
     #[error("Unknown version {version} for chunk {chunk_name}")]
     UnknownChunkVersion {
@@ -66,6 +169,29 @@ pub(crate) enum InnerError {
     #[error("File referred to type ID {type_id}, which was not declared")]
     InvalidTypeId { type_id: u32 },
 
+    #[error("File referred to instance {referent}, which was not declared")]
+    InvalidReferent { referent: i32 },
+
+    #[error("Type ID {type_id} was declared by more than one INST chunk")]
+    DuplicateTypeId { type_id: u32 },
+
+    #[error("Instance {referent} appeared more than once in {chunk_name} chunks")]
+    DuplicateReferent {
+        referent: i32,
+        chunk_name: &'static str,
+    },
+
+    #[error("File contains {count} instances, which is more than the limit of {limit}")]
+    InstanceLimitExceeded { count: usize, limit: usize },
+
+    #[error("Property {type_name}.{prop_name} contains {count} values, which is more than the limit of {limit}")]
+    PropertyLimitExceeded {
+        type_name: String,
+        prop_name: String,
+        count: usize,
+        limit: usize,
+    },
+
     #[error("Invalid property data: CFrame property {type_name}.{prop_name} had an invalid rotation ID {id:02x}")]
     BadRotationId {
         type_name: String,
@@ -1,12 +1,16 @@
 use std::io::Read;
 
-use crate::core::{RbxReadExt, FILE_MAGIC_HEADER, FILE_SIGNATURE, FILE_VERSION};
+use crate::core::{RbxReadExt, FILE_MAGIC_HEADER, FILE_SIGNATURE};
 
-use super::error::InnerError;
+use super::error::{Error, InnerError};
 
 /// All the information contained in the header before any chunks are read from
 /// the file.
 pub(crate) struct FileHeader {
+    /// The version of the binary format that the file claims to use. It's up
+    /// to the caller to decide whether this is a version they can handle.
+    pub(crate) version: u16,
+
     /// The number of instance types (represented for us as `TypeInfo`) that are
     /// in this file. Generally useful to pre-size some containers before
     /// reading the file.
@@ -16,39 +20,58 @@ pub(crate) struct FileHeader {
     pub(crate) num_instances: u32,
 }
 
+/// The offset of the version field in the file header.
+pub(crate) const VERSION_OFFSET: u64 = 14;
+
+/// The offset of the type count in the file header.
+pub(crate) const NUM_TYPES_OFFSET: u64 = 16;
+
+/// The offset of the instance count in the file header.
+pub(crate) const NUM_INSTANCES_OFFSET: u64 = 20;
+
+/// The offset of the reserved bytes at the end of the file header.
+const RESERVED_OFFSET: u64 = 24;
+
 impl FileHeader {
-    pub(crate) fn decode<R: Read>(mut source: R) -> Result<Self, InnerError> {
+    pub(crate) fn decode<R: Read>(mut source: R) -> Result<Self, Error> {
+        // Each field is read at a fixed offset, which errors point at.
+        let at = |offset| move |source| Error::at(InnerError::from(source), offset);
+
         let mut magic_header = [0; 8];
-        source.read_exact(&mut magic_header)?;
+        source.read_exact(&mut magic_header).map_err(at(0))?;
 
         if magic_header != FILE_MAGIC_HEADER {
-            return Err(InnerError::BadHeader);
+            return Err(Error::at(InnerError::BadHeader, 0));
         }
 
         let mut signature = [0; 6];
-        source.read_exact(&mut signature)?;
+        source
+            .read_exact(&mut signature)
+            .map_err(at(FILE_MAGIC_HEADER.len() as u64))?;
 
         if signature != FILE_SIGNATURE {
-            return Err(InnerError::BadHeader);
+            return Err(Error::at(
+                InnerError::BadHeader,
+                FILE_MAGIC_HEADER.len() as u64,
+            ));
         }
 
-        let version = source.read_le_u16()?;
-
-        if version != FILE_VERSION {
-            return Err(InnerError::UnknownFileVersion { version });
-        }
+        let version = source.read_le_u16().map_err(at(VERSION_OFFSET))?;
 
-        let num_types = source.read_le_u32()?;
-        let num_instances = source.read_le_u32()?;
+        let num_types = source.read_le_u32().map_err(at(NUM_TYPES_OFFSET))?;
+        let num_instances = source.read_le_u32().map_err(at(NUM_INSTANCES_OFFSET))?;
 
         let mut reserved = [0; 8];
-        source.read_exact(&mut reserved)?;
+        source
+            .read_exact(&mut reserved)
+            .map_err(at(RESERVED_OFFSET))?;
 
         if reserved != [0; 8] {
-            return Err(InnerError::BadHeader);
+            return Err(Error::at(InnerError::BadHeader, RESERVED_OFFSET));
         }
 
         Ok(Self {
+            version,
             num_types,
             num_instances,
         })
@@ -1,17 +1,29 @@
-mod error;
+mod counting_reader;
+pub(crate) mod error;
 mod header;
 mod state;
+mod warning;
 
-use std::{io::Read, str};
+use std::{collections::HashMap, io::Read};
 
 use rbx_dom_weak::WeakDom;
 use rbx_reflection::ReflectionDatabase;
 
-use self::state::DeserializerState;
+use crate::{
+    chunk::{Chunk, OpaqueChunk},
+    core::FILE_VERSION,
+    Serializer,
+};
+
+use self::{counting_reader::CountingReader, error::InnerError, state::DeserializerState};
 
 pub(crate) use self::header::FileHeader;
 
-pub use self::error::Error;
+pub use self::{error::Error, warning::DecodeWarning};
+
+/// The default value of [`Deserializer::instance_limit`] and
+/// [`Deserializer::property_limit`].
+const DEFAULT_LIMIT: usize = 1 << 20;
 
 /// A configurable deserializer for Roblox binary models and places.
 ///
@@ -40,6 +52,15 @@ pub use self::error::Error;
 /// ```
 pub struct Deserializer<'a> {
     database: Option<&'a ReflectionDatabase<'a>>,
+    lazy_chunks: bool,
+    recover_from_errors: bool,
+    preserve_unknown_chunks: bool,
+    minimum_version: u16,
+    maximum_version: u16,
+    instance_limit: usize,
+    property_limit: usize,
+    verify_integrity: bool,
+    infer_missing_defaults: bool,
 }
 
 impl<'a> Deserializer<'a> {
@@ -47,40 +68,927 @@ impl<'a> Deserializer<'a> {
     pub fn new() -> Self {
         Self {
             database: Some(rbx_reflection_database::get()),
+            lazy_chunks: false,
+            recover_from_errors: false,
+            preserve_unknown_chunks: false,
+            minimum_version: FILE_VERSION,
+            maximum_version: FILE_VERSION,
+            instance_limit: DEFAULT_LIMIT,
+            property_limit: DEFAULT_LIMIT,
+            verify_integrity: false,
+            infer_missing_defaults: false,
+        }
+    }
+
+    /// Sets whether chunks should only be decompressed when they're needed
+    /// instead of as soon as they're read.
+    ///
+    /// Chunks that the deserializer doesn't understand are skipped without
+    /// ever being decompressed, unless they're being kept with
+    /// [`Deserializer::preserve_unknown_chunks`].
+    pub fn lazy_chunks(self, lazy_chunks: bool) -> Self {
+        Self {
+            lazy_chunks,
+            ..self
+        }
+    }
+
+    /// Sets whether problems that only affect part of the file, like a PROP
+    /// chunk with invalid contents or a PRNT chunk entry that refers to an
+    /// undeclared instance, should be skipped instead of stopping
+    /// deserialization. Skipped problems are reported as warnings in
+    /// [`DecodeOutput::warnings`].
+    ///
+    /// Problems with the structure of the file, like a bad header or missing
+    /// data, are always errors.
+    pub fn recover_from_errors(self, recover_from_errors: bool) -> Self {
+        Self {
+            recover_from_errors,
+            ..self
+        }
+    }
+
+    /// Sets whether chunks that rbx_binary doesn't understand should be kept
+    /// in [`DecodeOutput::unknown_chunks`] instead of being skipped. They can
+    /// be written back out with [`Serializer::unknown_chunks`].
+    ///
+    /// [`Serializer::unknown_chunks`]: crate::Serializer::unknown_chunks
+    pub fn preserve_unknown_chunks(self, preserve_unknown_chunks: bool) -> Self {
+        Self {
+            preserve_unknown_chunks,
+            ..self
+        }
+    }
+
+    /// Sets the oldest version of the binary format that this deserializer will
+    /// accept. Defaults to the version that rbx_binary writes.
+    pub fn minimum_version(self, minimum_version: u16) -> Self {
+        Self {
+            minimum_version,
+            ..self
+        }
+    }
+
+    /// Sets the newest version of the binary format that this deserializer will
+    /// accept. Defaults to the version that rbx_binary writes.
+    ///
+    /// Files using a version other than the one rbx_binary writes are decoded
+    /// on a best-effort basis and produce a
+    /// [`DecodeWarning::UntestedFileVersion`].
+    pub fn maximum_version(self, maximum_version: u16) -> Self {
+        Self {
+            maximum_version,
+            ..self
+        }
+    }
+
+    /// Sets the largest number of instances that a file may contain. Defaults
+    /// to 2^20.
+    ///
+    /// Instance counts are read from the file before any instances are, so
+    /// this stops a small, malicious file from making the deserializer try to
+    /// allocate more memory than it can. Files that go over the limit fail to
+    /// deserialize.
+    pub fn instance_limit(self, instance_limit: usize) -> Self {
+        Self {
+            instance_limit,
+            ..self
+        }
+    }
+
+    /// Sets the largest number of keypoints that a single `NumberSequence` or
+    /// `ColorSequence` may contain. Defaults to 2^20. The number of values in
+    /// a PROP chunk is its class's instance count, which is already limited by
+    /// [`Deserializer::instance_limit`].
+    ///
+    /// PROP chunks that go over the limit are handled like any other invalid
+    /// PROP chunk, so they're skipped if
+    /// [`Deserializer::recover_from_errors`] is set.
+    pub fn property_limit(self, property_limit: usize) -> Self {
+        Self {
+            property_limit,
+            ..self
+        }
+    }
+
+    /// Sets whether the DOM should be serialized again after it's deserialized
+    /// to check that nothing was lost or changed along the way.
+    ///
+    /// The decompressed contents of every chunk in the input and in the
+    /// serialized copy are checksummed with CRC32. If the checksums differ, a
+    /// [`DecodeWarning::IntegrityMismatch`] is reported.
+    ///
+    /// This roughly doubles the time it takes to deserialize a file. Only files
+    /// written by rbx_binary are expected to match exactly; files written by
+    /// other tools, including Roblox Studio, may order their contents
+    /// differently and report a mismatch even when nothing was lost.
+    pub fn verify_integrity(self, verify_integrity: bool) -> Self {
+        Self {
+            verify_integrity,
+            ..self
+        }
+    }
+
+    /// Sets whether properties that the file doesn't contain should be filled
+    /// in with their default values from the reflection database.
+    ///
+    /// Files saved by older versions of Roblox Studio won't contain properties
+    /// that were added to Roblox later. With this set, every known property of
+    /// each instance's class and its superclasses that has a default value is
+    /// present on the decoded instance.
+    pub fn infer_missing_defaults(self, infer_missing_defaults: bool) -> Self {
+        Self {
+            infer_missing_defaults,
+            ..self
         }
     }
 
     /// Deserialize a Roblox binary model or place from the given stream using
     /// this deserializer.
     pub fn deserialize<R: Read>(&self, reader: R) -> Result<WeakDom, Error> {
-        let mut deserializer = DeserializerState::new(self, reader)?;
+        Ok(self.deserialize_full(reader)?.dom)
+    }
+
+    /// Deserialize a Roblox binary model or place from the given stream using
+    /// this deserializer, returning information about the file alongside the
+    /// DOM.
+    pub fn deserialize_full<R: Read>(&self, reader: R) -> Result<DecodeOutput, Error> {
+        let mut deserializer = DeserializerState::new(self, CountingReader::new(reader))?;
+
+        let mut checksum = if self.verify_integrity {
+            Some(crc32fast::Hasher::new())
+        } else {
+            None
+        };
 
         loop {
-            let chunk = deserializer.next_chunk()?;
-
-            match &chunk.name {
-                b"META" => deserializer.decode_meta_chunk(&chunk.data)?,
-                b"SSTR" => deserializer.decode_sstr_chunk(&chunk.data)?,
-                b"INST" => deserializer.decode_inst_chunk(&chunk.data)?,
-                b"PROP" => deserializer.decode_prop_chunk(&chunk.data)?,
-                b"PRNT" => deserializer.decode_prnt_chunk(&chunk.data)?,
-                b"END\0" => {
-                    deserializer.decode_end_chunk(&chunk.data)?;
-                    break;
-                }
-                _ => match str::from_utf8(&chunk.name) {
-                    Ok(name) => log::info!("Unknown binary chunk name {}", name),
-                    Err(_) => log::info!("Unknown binary chunk name {:?}", chunk.name),
-                },
+            let chunk_start = deserializer.byte_offset();
+            let chunk = deserializer
+                .next_chunk()
+                .map_err(|source| Error::in_chunk(source, chunk_start, None, None))?;
+
+            deserializer.decode_chunk(&chunk, chunk_start)?;
+
+            if let Some(hasher) = &mut checksum {
+                let data = chunk.data().map_err(|source| {
+                    Error::in_chunk(
+                        InnerError::from(source),
+                        chunk_start,
+                        Some(chunk.name),
+                        None,
+                    )
+                })?;
+
+                hasher.update(&chunk.name);
+                hasher.update(&data);
+            }
+
+            if &chunk.name == b"END\0" {
+                break;
             }
         }
 
-        Ok(deserializer.finish())
+        let mut output = deserializer.finish();
+
+        if let Some(hasher) = checksum {
+            let expected_hash = hasher.finalize();
+
+            match reserialized_checksum(&output) {
+                Ok(actual_hash) if actual_hash == expected_hash => {}
+                Ok(actual_hash) => output.warnings.push(DecodeWarning::IntegrityMismatch {
+                    expected_hash,
+                    actual_hash,
+                }),
+                Err(message) => output
+                    .warnings
+                    .push(DecodeWarning::IntegrityCheckFailed { message }),
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+/// Serializes a deserialized DOM again, along with its metadata and unknown
+/// chunks, and checksums the decompressed contents of each chunk the same way
+/// that [`Deserializer::verify_integrity`] checksums its input.
+fn reserialized_checksum(output: &DecodeOutput) -> Result<u32, String> {
+    let mut buffer = Vec::new();
+
+    Serializer::new()
+        .unknown_chunks(output.unknown_chunks.clone())
+        .metadata(output.metadata.clone())
+        .serialize(&mut buffer, &output.dom, output.dom.root().children())
+        .map_err(|error| error.to_string())?;
+
+    let mut input = buffer.as_slice();
+    FileHeader::decode(&mut input).map_err(|error| error.to_string())?;
+
+    let mut hasher = crc32fast::Hasher::new();
+
+    loop {
+        let chunk = Chunk::decode(&mut input).map_err(|error| error.to_string())?;
+
+        hasher.update(&chunk.name);
+        hasher.update(&chunk.data);
+
+        if &chunk.name == b"END\0" {
+            return Ok(hasher.finalize());
+        }
     }
 }
 
+/// The result of deserializing a file with [`Deserializer::deserialize_full`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DecodeOutput {
+    /// The deserialized DOM, which has a DataModel at the top level.
+    pub dom: WeakDom,
+
+    /// The version of the binary format that the file used.
+    pub version: u16,
+
+    /// Any non-fatal problems that were found while deserializing the file.
+    pub warnings: Vec<DecodeWarning>,
+
+    /// The chunks in the file that rbx_binary doesn't understand, in the order
+    /// they appeared. Always empty unless
+    /// [`Deserializer::preserve_unknown_chunks`] is enabled.
+    pub unknown_chunks: Vec<OpaqueChunk>,
+
+    /// The file-level metadata stored in the file's META chunk, like
+    /// `ExplicitAutoJoints`. Empty if the file has no META chunk.
+    pub metadata: HashMap<String, String>,
+}
+
 impl<'a> Default for Deserializer<'a> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::convert::TryInto;
+
+    use rbx_dom_weak::{
+        types::{NumberSequence, NumberSequenceKeypoint, Variant, Vector3},
+        InstanceBuilder,
+    };
+
+    use crate::{
+        chunk::{Chunk, ChunkBuilder, ChunkCompression},
+        core::RbxWriteExt,
+        types::Type,
+        Serializer,
+    };
+
+    /// The offset of the version field in the file header, after the magic
+    /// header and signature.
+    const VERSION_OFFSET: usize = 14;
+
+    fn model_with_version(version: u16) -> Vec<u8> {
+        let dom =
+            WeakDom::new(InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Part")));
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, dom.root().children())
+            .unwrap();
+
+        model[VERSION_OFFSET..VERSION_OFFSET + 2].copy_from_slice(&version.to_le_bytes());
+        model
+    }
+
+    #[test]
+    fn current_version() {
+        let output = Deserializer::new()
+            .deserialize_full(model_with_version(FILE_VERSION).as_slice())
+            .unwrap();
+
+        assert_eq!(output.version, FILE_VERSION);
+        assert!(output.warnings.is_empty());
+    }
+
+    #[test]
+    fn untested_version() {
+        let output = Deserializer::new()
+            .maximum_version(1)
+            .deserialize_full(model_with_version(1).as_slice())
+            .unwrap();
+
+        assert_eq!(output.version, 1);
+        assert_eq!(
+            output.warnings,
+            vec![DecodeWarning::UntestedFileVersion { version: 1 }]
+        );
+
+        let part = output
+            .dom
+            .get_by_ref(output.dom.root().children()[0])
+            .unwrap();
+        assert_eq!(part.class, "Part");
+    }
+
+    #[test]
+    fn disallowed_version() {
+        let error = Deserializer::new()
+            .deserialize(model_with_version(1).as_slice())
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Unknown file version 1. Allowed versions are 0 through 0 (at byte offset 14)"
+        );
+        assert_eq!(error.byte_offset(), Some(VERSION_OFFSET as u64));
+        assert_eq!(error.contents_offset(), None);
+        assert_eq!(error.chunk_name(), None);
+    }
+
+    #[test]
+    fn error_location() {
+        let mut model = model_with_version(FILE_VERSION);
+        model.truncate(32);
+
+        let mut meta = ChunkBuilder::new(b"META", ChunkCompression::Uncompressed);
+        meta.write_le_u32(0).unwrap();
+        meta.dump(&mut model).unwrap();
+
+        let prnt_offset = model.len() as u64;
+
+        let mut prnt = ChunkBuilder::new(b"PRNT", ChunkCompression::Compressed);
+        prnt.write_u8(1).unwrap();
+        prnt.dump(&mut model).unwrap();
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert_eq!(error.byte_offset(), Some(prnt_offset));
+        assert_eq!(error.chunk_name(), Some(*b"PRNT"));
+        assert_eq!(error.contents_offset(), Some(1));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Unknown version 1 for chunk PRNT (in chunk PRNT at byte offset {}, contents offset 1)",
+                prnt_offset
+            )
+        );
+    }
+
+    /// Rewrites every chunk in the given model uncompressed, replacing each
+    /// chunk with the chunks that `edit` returns for it.
+    fn rewrite_chunks(model: &[u8], mut edit: impl FnMut(Chunk) -> Vec<Chunk>) -> Vec<u8> {
+        let (header, mut input) = model.split_at(32);
+        let mut output = header.to_vec();
+
+        loop {
+            let chunk = Chunk::decode(&mut input).unwrap();
+            let is_end = &chunk.name == b"END\0";
+
+            for chunk in edit(chunk) {
+                output.extend_from_slice(&chunk.name);
+                output.write_le_u32(0).unwrap();
+                output.write_le_u32(chunk.data.len() as u32).unwrap();
+                output.write_le_u32(0).unwrap();
+                output.extend_from_slice(&chunk.data);
+            }
+
+            if is_end {
+                return output;
+            }
+        }
+    }
+
+    fn serialize_folder_with_part() -> Vec<u8> {
+        let dom =
+            WeakDom::new(InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Part")));
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, &[dom.root_ref()])
+            .unwrap();
+
+        model
+    }
+
+    #[test]
+    fn recover_from_bad_prop_chunk() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Part").with_name("Part"))
+                .with_child(
+                    InstanceBuilder::new("StringValue")
+                        .with_name("Value")
+                        .with_property("Value", "Hello"),
+                ),
+        );
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, &[dom.root_ref()])
+            .unwrap();
+
+        // Claim that StringValue.Value holds bools instead of strings.
+        let model = rewrite_chunks(&model, |mut chunk| {
+            if &chunk.name == b"PROP" && chunk.data[8..13] == *b"Value" {
+                chunk.data[13] = Type::Bool as u8;
+            }
+
+            vec![chunk]
+        });
+
+        assert!(Deserializer::new().deserialize(model.as_slice()).is_err());
+
+        let output = crate::decode_with_warnings(model.as_slice()).unwrap();
+        assert_eq!(output.warnings.len(), 1);
+        assert!(matches!(
+            output.warnings[0],
+            DecodeWarning::InvalidPropChunk { .. }
+        ));
+
+        let folder = output
+            .dom
+            .get_by_ref(output.dom.root().children()[0])
+            .unwrap();
+        let names: Vec<&str> = folder
+            .children()
+            .iter()
+            .map(|&referent| output.dom.get_by_ref(referent).unwrap().name.as_str())
+            .collect();
+        assert_eq!(names, ["Part", "Value"]);
+
+        let value = output.dom.get_by_ref(folder.children()[1]).unwrap();
+        assert!(!value.properties.contains_key("Value"));
+    }
+
+    fn serialize_for_integrity() -> Vec<u8> {
+        let part = InstanceBuilder::new("Part")
+            .with_name("Part")
+            .with_property("Anchored", true);
+        let part_ref = part.referent();
+
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder").with_child(
+                InstanceBuilder::new("Model")
+                    .with_name("Model")
+                    .with_property("PrimaryPart", part_ref)
+                    .with_child(part)
+                    .with_child(
+                        InstanceBuilder::new("StringValue")
+                            .with_name("Value")
+                            .with_property("Value", "Hello"),
+                    ),
+            ),
+        );
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, &[dom.root_ref()])
+            .unwrap();
+
+        model
+    }
+
+    #[test]
+    fn verify_integrity() {
+        let model = serialize_for_integrity();
+
+        let output = Deserializer::new()
+            .verify_integrity(true)
+            .deserialize_full(model.as_slice())
+            .unwrap();
+        assert!(output.warnings.is_empty());
+
+        // Rewriting the chunks without compression doesn't change what they
+        // contain, so it shouldn't be reported either.
+        let uncompressed = rewrite_chunks(&model, |chunk| vec![chunk]);
+        let output = Deserializer::new()
+            .verify_integrity(true)
+            .deserialize_full(uncompressed.as_slice())
+            .unwrap();
+        assert!(output.warnings.is_empty());
+    }
+
+    #[test]
+    fn verify_integrity_mismatch() {
+        // Any nonzero byte decodes as true, but true is always written back
+        // out as 1, so this change is lost in a round trip.
+        let model = rewrite_chunks(&serialize_for_integrity(), |mut chunk| {
+            if &chunk.name == b"PROP" && chunk.data[8..16] == *b"Anchored" {
+                assert_eq!(chunk.data[17..], [1]);
+                chunk.data[17] = 2;
+            }
+
+            vec![chunk]
+        });
+
+        let output = Deserializer::new()
+            .deserialize_full(model.as_slice())
+            .unwrap();
+        assert!(output.warnings.is_empty());
+
+        let output = Deserializer::new()
+            .verify_integrity(true)
+            .deserialize_full(model.as_slice())
+            .unwrap();
+        assert_eq!(output.warnings.len(), 1);
+
+        match output.warnings[0] {
+            DecodeWarning::IntegrityMismatch {
+                expected_hash,
+                actual_hash,
+            } => assert_ne!(expected_hash, actual_hash),
+            ref other => panic!("expected IntegrityMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn recover_from_unknown_parent() {
+        let dom =
+            WeakDom::new(InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Part")));
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, &[dom.root_ref()])
+            .unwrap();
+
+        // The PRNT chunk holds a version byte, a count, and then two
+        // interleaved and delta-encoded referent arrays. Point the Part's
+        // parent, the last referent, somewhere that doesn't exist.
+        let model = rewrite_chunks(&model, |mut chunk| {
+            if &chunk.name == b"PRNT" {
+                let len = chunk.data.len();
+                chunk.data[len - 1] = 50;
+            }
+
+            vec![chunk]
+        });
+
+        assert!(Deserializer::new().deserialize(model.as_slice()).is_err());
+
+        let output = crate::decode_with_warnings(model.as_slice()).unwrap();
+        assert_eq!(output.warnings.len(), 1);
+        assert!(matches!(
+            output.warnings[0],
+            DecodeWarning::UnknownParent { .. }
+        ));
+        assert_eq!(output.dom.root().children().len(), 2);
+    }
+
+    #[test]
+    fn duplicate_type_id() {
+        let model = rewrite_chunks(&serialize_folder_with_part(), |chunk| {
+            if &chunk.name == b"INST" {
+                let copy = Chunk {
+                    name: chunk.name,
+                    data: chunk.data.clone(),
+                };
+
+                vec![chunk, copy]
+            } else {
+                vec![chunk]
+            }
+        });
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("Type ID 0 was declared by more than one INST chunk"));
+    }
+
+    #[test]
+    fn duplicate_inst_referent() {
+        let model = rewrite_chunks(&serialize_folder_with_part(), |chunk| {
+            if &chunk.name == b"INST" && chunk.data[0..4] == [0; 4] {
+                // Declare the same instances again under a new type ID.
+                let mut data = chunk.data.clone();
+                data[0] = 5;
+
+                vec![
+                    chunk,
+                    Chunk {
+                        name: *b"INST",
+                        data,
+                    },
+                ]
+            } else {
+                vec![chunk]
+            }
+        });
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert_eq!(error.chunk_name(), Some(*b"INST"));
+        assert!(error
+            .to_string()
+            .starts_with("Instance 0 appeared more than once in INST chunks"));
+    }
+
+    #[test]
+    fn duplicate_prnt_referent() {
+        let model = rewrite_chunks(&serialize_folder_with_part(), |chunk| {
+            if &chunk.name == b"PRNT" {
+                // Parent the same instances a second time.
+                let copy = Chunk {
+                    name: chunk.name,
+                    data: chunk.data.clone(),
+                };
+
+                vec![chunk, copy]
+            } else {
+                vec![chunk]
+            }
+        });
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert_eq!(error.chunk_name(), Some(*b"PRNT"));
+        assert!(error
+            .to_string()
+            .starts_with("Instance 0 appeared more than once in PRNT chunks"));
+    }
+
+    #[test]
+    fn preserve_unknown_chunks() {
+        let future = OpaqueChunk {
+            name: *b"FUTR",
+            data: b"from the future".to_vec(),
+        };
+
+        let model = rewrite_chunks(&serialize_folder_with_part(), |chunk| {
+            if &chunk.name == b"END\0" {
+                let future = Chunk {
+                    name: future.name,
+                    data: future.data.clone(),
+                };
+
+                vec![future, chunk]
+            } else {
+                vec![chunk]
+            }
+        });
+
+        let skipped = Deserializer::new()
+            .deserialize_full(model.as_slice())
+            .unwrap();
+        assert!(skipped.unknown_chunks.is_empty());
+
+        let decoded = Deserializer::new()
+            .preserve_unknown_chunks(true)
+            .deserialize_full(model.as_slice())
+            .unwrap();
+        assert_eq!(decoded.unknown_chunks, vec![future.clone()]);
+
+        let mut encoded = Vec::new();
+        Serializer::new()
+            .unknown_chunks(decoded.unknown_chunks)
+            .serialize(&mut encoded, &decoded.dom, decoded.dom.root().children())
+            .unwrap();
+
+        let redecoded = Deserializer::new()
+            .preserve_unknown_chunks(true)
+            .lazy_chunks(true)
+            .deserialize_full(encoded.as_slice())
+            .unwrap();
+        assert_eq!(redecoded.unknown_chunks, vec![future]);
+        assert_eq!(redecoded.dom.root().children().len(), 1);
+    }
+
+    /// The offset of the instance count in the file header, after the version
+    /// and type count.
+    const NUM_INSTANCES_OFFSET: usize = 20;
+
+    #[test]
+    fn huge_instance_count_in_header() {
+        let mut model = serialize_folder_with_part();
+        model[NUM_INSTANCES_OFFSET..NUM_INSTANCES_OFFSET + 4]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert!(matches!(
+            error.inner(),
+            InnerError::InstanceLimitExceeded {
+                count: 4294967295,
+                limit: DEFAULT_LIMIT,
+            }
+        ));
+        assert_eq!(error.byte_offset(), Some(NUM_INSTANCES_OFFSET as u64));
+        assert_eq!(error.chunk_name(), None);
+    }
+
+    #[test]
+    fn huge_instance_count_in_inst_chunk() {
+        // INST chunks hold a type ID, a type name, a format byte, and then the
+        // number of instances.
+        let model = rewrite_chunks(&serialize_folder_with_part(), |mut chunk| {
+            if &chunk.name == b"INST" {
+                let name_len = u32::from_le_bytes(chunk.data[4..8].try_into().unwrap()) as usize;
+                let count_offset = 8 + name_len + 1;
+                chunk.data[count_offset..count_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+            }
+
+            vec![chunk]
+        });
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert_eq!(error.chunk_name(), Some(*b"INST"));
+        assert!(matches!(
+            error.inner(),
+            InnerError::InstanceLimitExceeded {
+                count: 4294967295,
+                limit: DEFAULT_LIMIT,
+            }
+        ));
+    }
+
+    #[test]
+    fn instance_limit() {
+        let model = serialize_folder_with_part();
+
+        let error = Deserializer::new()
+            .instance_limit(1)
+            .deserialize(model.as_slice())
+            .unwrap_err();
+        assert!(matches!(
+            error.inner(),
+            InnerError::InstanceLimitExceeded { count: 2, limit: 1 }
+        ));
+
+        let dom = Deserializer::new()
+            .instance_limit(2)
+            .deserialize(model.as_slice())
+            .unwrap();
+        assert_eq!(dom.root().children().len(), 1);
+    }
+
+    #[test]
+    fn huge_keypoint_count() {
+        let dom = WeakDom::new(InstanceBuilder::new("ParticleEmitter").with_property(
+            "Size",
+            NumberSequence {
+                keypoints: vec![
+                    NumberSequenceKeypoint::new(0.0, 1.0, 0.0),
+                    NumberSequenceKeypoint::new(1.0, 2.0, 0.0),
+                ],
+            },
+        ));
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, &[dom.root_ref()])
+            .unwrap();
+
+        // PROP chunks hold a type ID, a property name, and a type byte before
+        // their values. Each NumberSequence starts with its keypoint count.
+        let model = rewrite_chunks(&model, |mut chunk| {
+            if &chunk.name == b"PROP" && chunk.data[8..12] == *b"Size" {
+                chunk.data[13..17].copy_from_slice(&u32::MAX.to_le_bytes());
+            }
+
+            vec![chunk]
+        });
+
+        let error = Deserializer::new()
+            .deserialize(model.as_slice())
+            .unwrap_err();
+
+        assert_eq!(error.chunk_name(), Some(*b"PROP"));
+        assert!(matches!(
+            error.inner(),
+            InnerError::PropertyLimitExceeded {
+                type_name,
+                prop_name,
+                count: 4294967295,
+                limit: DEFAULT_LIMIT,
+            } if type_name == "ParticleEmitter" && prop_name == "Size"
+        ));
+    }
+
+    #[test]
+    fn property_limit() {
+        let sequence = |count: usize| NumberSequence {
+            keypoints: (0..count)
+                .map(|index| NumberSequenceKeypoint::new(index as f32, 1.0, 0.0))
+                .collect(),
+        };
+
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(
+                    InstanceBuilder::new("ParticleEmitter").with_property("Size", sequence(1)),
+                )
+                .with_child(
+                    InstanceBuilder::new("ParticleEmitter").with_property("Size", sequence(2)),
+                ),
+        );
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, dom.root().children())
+            .unwrap();
+
+        let error = Deserializer::new()
+            .property_limit(1)
+            .deserialize(model.as_slice())
+            .unwrap_err();
+        assert_eq!(error.chunk_name(), Some(*b"PROP"));
+        assert!(matches!(
+            error.inner(),
+            InnerError::PropertyLimitExceeded {
+                count: 2,
+                limit: 1,
+                ..
+            }
+        ));
+
+        // The number of instances with a property doesn't count towards the
+        // limit, only the number of keypoints in each value.
+        Deserializer::new()
+            .property_limit(2)
+            .deserialize(model.as_slice())
+            .unwrap();
+
+        // Going over the limit only affects one PROP chunk, so it can be
+        // recovered from like any other invalid PROP chunk.
+        let output = Deserializer::new()
+            .property_limit(1)
+            .recover_from_errors(true)
+            .deserialize_full(model.as_slice())
+            .unwrap();
+        assert!(!output.warnings.is_empty());
+        assert!(output
+            .warnings
+            .iter()
+            .all(|warning| matches!(warning, DecodeWarning::InvalidPropChunk { .. })));
+        assert_eq!(output.dom.root().children().len(), 2);
+    }
+
+    #[test]
+    fn infer_missing_defaults() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder").with_child(
+                InstanceBuilder::new("Part")
+                    .with_name("Part")
+                    .with_property("Anchored", true)
+                    .with_property("Size", Vector3::new(1.0, 2.0, 3.0)),
+            ),
+        );
+
+        let mut model = Vec::new();
+        Serializer::new()
+            .serialize(&mut model, &dom, dom.root().children())
+            .unwrap();
+
+        // Pretend the file came from before Part.size existed.
+        let model = rewrite_chunks(&model, |chunk| {
+            if &chunk.name == b"PROP" && chunk.data[8..12] == *b"size" {
+                Vec::new()
+            } else {
+                vec![chunk]
+            }
+        });
+
+        let decoded = Deserializer::new().deserialize(model.as_slice()).unwrap();
+        let part = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+        assert!(!part.properties.contains_key("Size"));
+        assert!(!part.properties.contains_key("Transparency"));
+
+        let decoded = Deserializer::new()
+            .infer_missing_defaults(true)
+            .deserialize(model.as_slice())
+            .unwrap();
+        let part = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+        let database = rbx_reflection_database::get();
+
+        assert_eq!(part.name, "Part");
+        assert_eq!(part.properties.get("Anchored"), Some(&Variant::Bool(true)));
+        assert_eq!(
+            part.properties.get("Size"),
+            database.find_default_property("Part", "Size")
+        );
+        assert!(part.properties.contains_key("Size"));
+
+        assert_eq!(
+            part.properties.get("Transparency"),
+            database.find_default_property("Part", "Transparency")
+        );
+        assert!(part.properties.contains_key("Transparency"));
+        assert!(!part.properties.contains_key("Name"));
+    }
+}
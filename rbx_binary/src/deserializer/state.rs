@@ -2,14 +2,16 @@ use std::{
     collections::{HashMap, HashSet, VecDeque},
     convert::TryInto,
     io::Read,
+    str,
 };
 
 use rbx_dom_weak::{
     types::{
-        Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence,
+        Attributes, Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence,
         ColorSequenceKeypoint, Content, CustomPhysicalProperties, Enum, Faces, Matrix3,
         NumberRange, NumberSequence, NumberSequenceKeypoint, PhysicalProperties, Ray, Rect, Ref,
-        SharedString, UDim, UDim2, Variant, VariantType, Vector2, Vector3, Vector3int16,
+        SharedString, Tags, UDim, UDim2, Variant, VariantType, Vector2, Vector2int16, Vector3,
+        Vector3int16,
     },
     InstanceBuilder, WeakDom,
 };
@@ -17,19 +19,32 @@ use rbx_reflection::DataType;
 
 use crate::{
     cframe,
-    chunk::Chunk,
-    core::{find_property_descriptors, RbxReadExt},
+    chunk::{Chunk, LazyChunk, OpaqueChunk},
+    core::{find_property_descriptors, RbxReadExt, FILE_VERSION},
+    logging,
     types::Type,
 };
 
-use super::{error::InnerError, header::FileHeader, Deserializer};
+use super::{
+    counting_reader::CountingReader,
+    error::{Error, InnerError},
+    header::{FileHeader, NUM_INSTANCES_OFFSET, VERSION_OFFSET},
+    warning::DecodeWarning,
+    DecodeOutput, Deserializer,
+};
 
 pub(super) struct DeserializerState<'a, R> {
     /// The user-provided configuration that we should use.
     deserializer: &'a Deserializer<'a>,
 
     /// The input data encoded as a binary model.
-    input: R,
+    input: CountingReader<R>,
+
+    /// The version of the binary format that the file says it uses.
+    version: u16,
+
+    /// Non-fatal problems found so far, which are returned to the user.
+    warnings: Vec<DecodeWarning>,
 
     /// The tree that instances should be written into. Eventually returned to
     /// the user.
@@ -53,6 +68,13 @@ pub(super) struct DeserializerState<'a, R> {
     /// in the file.
     root_instance_refs: Vec<i32>,
 
+    /// The chunks we didn't understand, if the user asked us to keep them.
+    unknown_chunks: Vec<OpaqueChunk>,
+
+    /// Referents for all of the instances that have been given a parent by a
+    /// PRNT chunk so far.
+    parented_refs: HashSet<i32>,
+
     /// Contains a set of unknown type IDs that we've encountered so far while
     /// deserializing this file. We use this map in order to ensure we only
     /// print one warning per unknown type ID when deserializing a file.
@@ -87,35 +109,169 @@ struct Instance {
 impl<'a, R: Read> DeserializerState<'a, R> {
     pub(super) fn new(
         deserializer: &'a Deserializer<'a>,
-        mut input: R,
-    ) -> Result<Self, InnerError> {
+        mut input: CountingReader<R>,
+    ) -> Result<Self, Error> {
         let tree = WeakDom::new(InstanceBuilder::new("DataModel"));
 
         let header = FileHeader::decode(&mut input)?;
+        let mut warnings = Vec::new();
+
+        if header.version < deserializer.minimum_version
+            || header.version > deserializer.maximum_version
+        {
+            let source = InnerError::UnknownFileVersion {
+                version: header.version,
+                minimum: deserializer.minimum_version,
+                maximum: deserializer.maximum_version,
+            };
+
+            return Err(Error::at(source, VERSION_OFFSET));
+        }
+
+        if header.version != FILE_VERSION {
+            let warning = DecodeWarning::UntestedFileVersion {
+                version: header.version,
+            };
 
-        let type_infos = HashMap::with_capacity(header.num_types as usize);
+            logging::warn!("{}", warning);
+            warnings.push(warning);
+        }
+
+        check_instance_limit(header.num_instances as usize, deserializer.instance_limit)
+            .map_err(|source| Error::at(source, NUM_INSTANCES_OFFSET))?;
+
+        // Every type declared by a file should have at least one instance, so
+        // the instance count also bounds how many types are worth reserving
+        // space for.
+        let type_infos =
+            HashMap::with_capacity(header.num_types.min(header.num_instances) as usize);
         let instances_by_ref = HashMap::with_capacity(1 + header.num_instances as usize);
 
         Ok(DeserializerState {
             deserializer,
             input,
+            version: header.version,
+            warnings,
             tree,
             metadata: HashMap::new(),
             shared_strings: Vec::new(),
             type_infos,
             instances_by_ref,
             root_instance_refs: Vec::new(),
+            parented_refs: HashSet::new(),
+            unknown_chunks: Vec::new(),
             unknown_type_ids: HashSet::new(),
         })
     }
 
-    pub(super) fn next_chunk(&mut self) -> Result<Chunk, InnerError> {
-        Ok(Chunk::decode(&mut self.input)?)
+    pub(super) fn next_chunk(&mut self) -> Result<LazyChunk, InnerError> {
+        if self.deserializer.lazy_chunks {
+            Ok(Chunk::decode_lazy(&mut self.input)?)
+        } else {
+            Ok(Chunk::decode(&mut self.input)?.into())
+        }
     }
 
-    pub(super) fn decode_meta_chunk(&mut self, mut chunk: &[u8]) -> Result<(), InnerError> {
+    /// The number of bytes read from the input so far, which is the offset of
+    /// the next chunk.
+    pub(super) fn byte_offset(&self) -> u64 {
+        self.input.offset()
+    }
+
+    /// Decodes a chunk that starts at `chunk_start` in the input.
+    pub(super) fn decode_chunk(
+        &mut self,
+        chunk: &LazyChunk,
+        chunk_start: u64,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "decode_chunk",
+            chunk = %String::from_utf8_lossy(&chunk.name),
+        )
+        .entered();
+
+        let decode: fn(&mut Self, &mut &[u8]) -> Result<(), InnerError> = match &chunk.name {
+            b"META" => Self::decode_meta_chunk,
+            b"SSTR" => Self::decode_sstr_chunk,
+            b"INST" => Self::decode_inst_chunk,
+            b"PROP" => Self::decode_prop_chunk,
+            b"PRNT" => Self::decode_prnt_chunk,
+            b"END\0" => Self::decode_end_chunk,
+            _ => {
+                return self
+                    .decode_unknown_chunk(chunk)
+                    .map_err(|source| Error::in_chunk(source, chunk_start, Some(chunk.name), None))
+            }
+        };
+
+        // Chunk contents might not be decompressed yet, so we wait until we
+        // know we need them to access them. Errors from decoding record how
+        // far into the contents they were found.
+        let result = chunk
+            .data()
+            .map_err(|source| (InnerError::from(source), None))
+            .and_then(|data| {
+                let mut contents: &[u8] = &data;
+
+                decode(self, &mut contents).map_err(|source| {
+                    let contents_offset = (data.len() - contents.len()) as u64;
+                    (source, Some(contents_offset))
+                })
+            });
+
+        let (source, contents_offset) = match result {
+            Ok(()) => return Ok(()),
+            Err(error) => error,
+        };
+
+        let result = if &chunk.name == b"PROP" {
+            let warning = DecodeWarning::InvalidPropChunk {
+                message: source.to_string(),
+            };
+
+            self.recover(source, warning)
+        } else {
+            Err(source)
+        };
+
+        result.map_err(|source| {
+            Error::in_chunk(source, chunk_start, Some(chunk.name), contents_offset)
+        })
+    }
+
+    fn decode_unknown_chunk(&mut self, chunk: &LazyChunk) -> Result<(), InnerError> {
+        match str::from_utf8(&chunk.name) {
+            Ok(name) => logging::info!("Unknown binary chunk name {}", name),
+            Err(_) => logging::info!("Unknown binary chunk name {:?}", chunk.name),
+        }
+
+        if self.deserializer.preserve_unknown_chunks {
+            self.unknown_chunks.push(OpaqueChunk {
+                name: chunk.name,
+                data: chunk.data()?.to_vec(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Handles a problem that only affects part of the file. Depending on the
+    /// deserializer's settings, this either stops deserialization with the
+    /// given error or records the given warning and carries on.
+    fn recover(&mut self, error: InnerError, warning: DecodeWarning) -> Result<(), InnerError> {
+        if !self.deserializer.recover_from_errors {
+            return Err(error);
+        }
+
+        logging::warn!("{}", warning);
+        self.warnings.push(warning);
+
+        Ok(())
+    }
+
+    pub(super) fn decode_meta_chunk(&mut self, chunk: &mut &[u8]) -> Result<(), InnerError> {
         let len = chunk.read_le_u32()?;
-        self.metadata.reserve(len as usize);
 
         for _ in 0..len {
             let key = chunk.read_string()?;
@@ -127,7 +283,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
         Ok(())
     }
 
-    pub(super) fn decode_sstr_chunk(&mut self, mut chunk: &[u8]) -> Result<(), InnerError> {
+    pub(super) fn decode_sstr_chunk(&mut self, chunk: &mut &[u8]) -> Result<(), InnerError> {
         let version = chunk.read_le_u32()?;
 
         if version != 0 {
@@ -148,13 +304,13 @@ impl<'a, R: Read> DeserializerState<'a, R> {
         Ok(())
     }
 
-    pub(super) fn decode_inst_chunk(&mut self, mut chunk: &[u8]) -> Result<(), InnerError> {
+    pub(super) fn decode_inst_chunk(&mut self, chunk: &mut &[u8]) -> Result<(), InnerError> {
         let type_id = chunk.read_le_u32()?;
         let type_name = chunk.read_string()?;
         let object_format = chunk.read_u8()?;
         let number_instances = chunk.read_le_u32()?;
 
-        log::trace!(
+        logging::trace!(
             "INST chunk (type ID {}, type name {}, format {}, {} instances)",
             type_id,
             type_name,
@@ -162,12 +318,28 @@ impl<'a, R: Read> DeserializerState<'a, R> {
             number_instances,
         );
 
+        check_instance_limit(
+            self.instances_by_ref.len() + number_instances as usize,
+            self.deserializer.instance_limit,
+        )?;
+
         let mut referents = vec![0; number_instances as usize];
         chunk.read_referent_array(&mut referents)?;
 
         // TODO: Check object_format and check for service markers if it's 1?
 
+        if self.type_infos.contains_key(&type_id) {
+            return Err(InnerError::DuplicateTypeId { type_id });
+        }
+
         for &referent in &referents {
+            if self.instances_by_ref.contains_key(&referent) {
+                return Err(InnerError::DuplicateReferent {
+                    referent,
+                    chunk_name: "INST",
+                });
+            }
+
             self.instances_by_ref.insert(
                 referent,
                 Instance {
@@ -189,7 +361,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
         Ok(())
     }
 
-    pub(super) fn decode_prop_chunk(&mut self, mut chunk: &[u8]) -> Result<(), InnerError> {
+    pub(super) fn decode_prop_chunk(&mut self, chunk: &mut &[u8]) -> Result<(), InnerError> {
         let type_id = chunk.read_le_u32()?;
         let prop_name = chunk.read_string()?;
 
@@ -198,6 +370,16 @@ impl<'a, R: Read> DeserializerState<'a, R> {
             .get(&type_id)
             .ok_or(InnerError::InvalidTypeId { type_id })?;
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "decode_property",
+            type_name = %type_info.type_name,
+            prop_name = %prop_name,
+        )
+        .entered();
+
+        let property_limit = self.deserializer.property_limit;
+
         // PROP chunks that contain no type byte are ignored by Roblox. This can
         // happen when a new type is introduced.
         //
@@ -214,7 +396,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
             Ok(ty) => ty,
             Err(_) => {
                 if self.unknown_type_ids.insert(binary_type_byte) {
-                    log::warn!(
+                    logging::warn!(
                         "Unknown value type ID {byte:#04x} ({byte}) in Roblox \
                          binary model file. Found in property {class}.{prop}.",
                         byte = binary_type_byte,
@@ -227,7 +409,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
             }
         };
 
-        log::trace!(
+        logging::trace!(
             "PROP chunk ({}.{}, instance type {}, prop type {}",
             type_info.type_name,
             prop_name,
@@ -270,7 +452,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                     }
                 };
 
-                log::trace!(
+                logging::trace!(
                     "Known prop, canonical name {} and type {:?}",
                     canonical_name,
                     canonical_type
@@ -282,13 +464,16 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                 match binary_type.to_default_rbx_type() {
                     Some(rbx_type) => canonical_type = rbx_type,
                     None => {
-                        log::warn!("Unsupported prop type {:?}, skipping property", binary_type);
+                        logging::warn!(
+                            "Unsupported prop type {:?}, skipping property",
+                            binary_type
+                        );
 
                         return Ok(());
                     }
                 }
 
-                log::trace!("Unknown prop, using type {:?}", canonical_type);
+                logging::trace!("Unknown prop, using type {:?}", canonical_type);
             }
         }
 
@@ -315,11 +500,40 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                         instance.builder.add_property(&canonical_name, value);
                     }
                 }
+                VariantType::Tags => {
+                    for referent in &type_info.referents {
+                        let instance = self.instances_by_ref.get_mut(referent).unwrap();
+                        let value = Tags::decode(&chunk.read_binary_string()?);
+                        instance.builder.add_property(&canonical_name, value);
+                    }
+                }
+                VariantType::Attributes => {
+                    for referent in &type_info.referents {
+                        let instance = self.instances_by_ref.get_mut(referent).unwrap();
+                        let buffer = chunk.read_binary_string()?;
+
+                        // Attributes of types we don't understand shouldn't
+                        // stop the file from loading, so we keep the raw
+                        // BinaryString around instead.
+                        match Attributes::decode(&buffer) {
+                            Ok(value) => instance.builder.add_property(&canonical_name, value),
+                            Err(err) => {
+                                logging::warn!(
+                                    "Could not decode attributes of instance {}: {}",
+                                    referent,
+                                    err
+                                );
+                                let value: BinaryString = buffer.into();
+                                instance.builder.add_property(&canonical_name, value);
+                            }
+                        }
+                    }
+                }
                 invalid_type => {
                     return Err(InnerError::PropTypeMismatch {
                         type_name: type_info.type_name.clone(),
                         prop_name,
-                        valid_type_names: "String, Content, or BinaryString",
+                        valid_type_names: "String, Content, BinaryString, Tags, or Attributes",
                         actual_type_name: format!("{:?}", invalid_type),
                     });
                 }
@@ -769,6 +983,25 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                     });
                 }
             },
+            Type::Vector2int16 => match canonical_type {
+                VariantType::Vector2int16 => {
+                    for referent in &type_info.referents {
+                        let instance = self.instances_by_ref.get_mut(referent).unwrap();
+                        instance.builder.add_property(
+                            &canonical_name,
+                            Vector2int16::new(chunk.read_le_i16()?, chunk.read_le_i16()?),
+                        )
+                    }
+                }
+                invalid_type => {
+                    return Err(InnerError::PropTypeMismatch {
+                        type_name: type_info.type_name.clone(),
+                        prop_name,
+                        valid_type_names: "Vector2int16",
+                        actual_type_name: format!("{:?}", invalid_type),
+                    });
+                }
+            },
             Type::Vector3int16 => match canonical_type {
                 VariantType::Vector3int16 => {
                     for referent in &type_info.referents {
@@ -797,6 +1030,12 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                     for referent in &type_info.referents {
                         let instance = self.instances_by_ref.get_mut(referent).unwrap();
                         let keypoint_count = chunk.read_le_u32()?;
+                        check_property_limit(
+                            type_info,
+                            &prop_name,
+                            keypoint_count as usize,
+                            property_limit,
+                        )?;
                         let mut keypoints = Vec::with_capacity(keypoint_count as usize);
 
                         for _ in 0..keypoint_count {
@@ -826,20 +1065,24 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                     for referent in &type_info.referents {
                         let instance = self.instances_by_ref.get_mut(referent).unwrap();
                         let keypoint_count = chunk.read_le_u32()? as usize;
+                        check_property_limit(
+                            type_info,
+                            &prop_name,
+                            keypoint_count,
+                            property_limit,
+                        )?;
                         let mut keypoints = Vec::with_capacity(keypoint_count);
 
                         for _ in 0..keypoint_count {
-                            keypoints.push(ColorSequenceKeypoint::new(
-                                chunk.read_le_f32()?,
-                                Color3::new(
+                            keypoints.push(ColorSequenceKeypoint {
+                                time: chunk.read_le_f32()?,
+                                color: Color3::new(
                                     chunk.read_le_f32()?,
                                     chunk.read_le_f32()?,
                                     chunk.read_le_f32()?,
                                 ),
-                            ));
-
-                            // envelope is serialized but doesn't do anything; don't do anything with it
-                            chunk.read_le_f32()?;
+                                envelope: chunk.read_le_f32()?,
+                            });
                         }
 
                         instance
@@ -939,7 +1182,10 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                 }
             },
             Type::Color3uint8 => match canonical_type {
-                VariantType::Color3 => {
+                // Properties like Part.Color are Color3 values that are
+                // serialized as Color3uint8, but unknown properties can also
+                // have the Color3uint8 type.
+                VariantType::Color3 | VariantType::Color3uint8 => {
                     let len = type_info.referents.len();
                     let mut r = vec![0; len];
                     let mut g = vec![0; len];
@@ -964,7 +1210,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
                     return Err(InnerError::PropTypeMismatch {
                         type_name: type_info.type_name.clone(),
                         prop_name,
-                        valid_type_names: "Color3",
+                        valid_type_names: "Color3 or Color3uint8",
                         actual_type_name: format!("{:?}", invalid_type),
                     });
                 }
@@ -1121,7 +1367,7 @@ impl<'a, R: Read> DeserializerState<'a, R> {
         Ok(())
     }
 
-    pub(super) fn decode_prnt_chunk(&mut self, mut chunk: &[u8]) -> Result<(), InnerError> {
+    pub(super) fn decode_prnt_chunk(&mut self, chunk: &mut &[u8]) -> Result<(), InnerError> {
         let version = chunk.read_u8()?;
 
         if version != 0 {
@@ -1133,7 +1379,9 @@ impl<'a, R: Read> DeserializerState<'a, R> {
 
         let number_objects = chunk.read_le_u32()?;
 
-        log::trace!("PRNT chunk ({} instances)", number_objects);
+        logging::trace!("PRNT chunk ({} instances)", number_objects);
+
+        check_instance_limit(number_objects as usize, self.deserializer.instance_limit)?;
 
         let mut subjects = vec![0; number_objects as usize];
         let mut parents = vec![0; number_objects as usize];
@@ -1142,19 +1390,46 @@ impl<'a, R: Read> DeserializerState<'a, R> {
         chunk.read_referent_array(&mut parents)?;
 
         for (id, parent_ref) in subjects.iter().copied().zip(parents.iter().copied()) {
+            if !self.instances_by_ref.contains_key(&id) {
+                self.recover(
+                    InnerError::InvalidReferent { referent: id },
+                    DecodeWarning::UnknownReferent { referent: id },
+                )?;
+
+                continue;
+            }
+
+            if !self.parented_refs.insert(id) {
+                return Err(InnerError::DuplicateReferent {
+                    referent: id,
+                    chunk_name: "PRNT",
+                });
+            }
+
             if parent_ref == -1 {
                 self.root_instance_refs.push(id);
-            } else {
-                let instance = self.instances_by_ref.get_mut(&parent_ref).unwrap();
+            } else if let Some(instance) = self.instances_by_ref.get_mut(&parent_ref) {
                 instance.children.push(id);
+            } else {
+                self.recover(
+                    InnerError::InvalidReferent {
+                        referent: parent_ref,
+                    },
+                    DecodeWarning::UnknownParent {
+                        referent: id,
+                        parent: parent_ref,
+                    },
+                )?;
+
+                self.root_instance_refs.push(id);
             }
         }
 
         Ok(())
     }
 
-    pub(super) fn decode_end_chunk(&mut self, _chunk: &[u8]) -> Result<(), InnerError> {
-        log::trace!("END chunk");
+    pub(super) fn decode_end_chunk(&mut self, _chunk: &mut &[u8]) -> Result<(), InnerError> {
+        logging::trace!("END chunk");
 
         // We don't do any validation on the END chunk. There's no useful
         // information for us here as it just signals that the file hasn't been
@@ -1165,8 +1440,12 @@ impl<'a, R: Read> DeserializerState<'a, R> {
 
     /// Combines together all the decoded information to build and emplace
     /// instances in our tree.
-    pub(super) fn finish(mut self) -> WeakDom {
-        log::trace!("Constructing tree from deserialized data");
+    pub(super) fn finish(mut self) -> DecodeOutput {
+        logging::trace!("Constructing tree from deserialized data");
+
+        if self.deserializer.infer_missing_defaults {
+            self.insert_missing_defaults();
+        }
 
         // Track all the instances we need to construct. Order of construction
         // is important to preserve for both determinism and sometimes
@@ -1190,6 +1469,57 @@ impl<'a, R: Read> DeserializerState<'a, R> {
             }
         }
 
-        self.tree
+        DecodeOutput {
+            dom: self.tree,
+            version: self.version,
+            warnings: self.warnings,
+            unknown_chunks: self.unknown_chunks,
+            metadata: self.metadata,
+        }
+    }
+
+    /// Gives every instance the default value of each property that its class
+    /// or one of its superclasses has a default for, unless the file already
+    /// contained that property.
+    fn insert_missing_defaults(&mut self) {
+        let database = match self.deserializer.database {
+            Some(database) => database,
+            None => return,
+        };
+
+        for instance in self.instances_by_ref.values_mut() {
+            instance.builder.add_defaults_from(database);
+        }
     }
 }
+
+/// Checks a count of instances read from the file against the deserializer's
+/// instance limit before any space is allocated for them.
+fn check_instance_limit(count: usize, limit: usize) -> Result<(), InnerError> {
+    if count > limit {
+        return Err(InnerError::InstanceLimitExceeded { count, limit });
+    }
+
+    Ok(())
+}
+
+/// Checks a count of values in a single property value, like a sequence's
+/// keypoints, against the deserializer's property limit before any space is
+/// allocated for them.
+fn check_property_limit(
+    type_info: &TypeInfo,
+    prop_name: &str,
+    count: usize,
+    limit: usize,
+) -> Result<(), InnerError> {
+    if count > limit {
+        return Err(InnerError::PropertyLimitExceeded {
+            type_name: type_info.type_name.clone(),
+            prop_name: prop_name.to_owned(),
+            count,
+            limit,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::fmt;
+
+use crate::core::FILE_VERSION;
+
+/// A problem found while deserializing a file that wasn't serious enough to
+/// stop deserialization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeWarning {
+    /// The file uses a version of the binary format that was allowed by the
+    /// deserializer's settings, but that rbx_binary hasn't been tested with.
+    UntestedFileVersion {
+        /// The version of the format that the file claims to use.
+        version: u16,
+    },
+
+    /// A PROP chunk couldn't be decoded and was skipped. Instances of the
+    /// chunk's class may be missing that property, or only have it set on
+    /// some of them.
+    InvalidPropChunk {
+        /// A description of what was wrong with the chunk.
+        message: String,
+    },
+
+    /// The PRNT chunk gave an instance a parent that was never declared. The
+    /// instance was placed at the top level of the DOM instead.
+    UnknownParent {
+        /// The file's ID for the instance.
+        referent: i32,
+
+        /// The file's ID for the parent that was never declared.
+        parent: i32,
+    },
+
+    /// The PRNT chunk referred to an instance that was never declared. The
+    /// entry was skipped.
+    UnknownReferent {
+        /// The file's ID for the instance.
+        referent: i32,
+    },
+
+    /// [`Deserializer::verify_integrity`] was set, and serializing the
+    /// deserialized DOM again didn't reproduce the contents of the file. Some
+    /// data may have been lost or changed while deserializing.
+    ///
+    /// [`Deserializer::verify_integrity`]: crate::Deserializer::verify_integrity
+    IntegrityMismatch {
+        /// The CRC32 checksum of the file's chunks.
+        expected_hash: u32,
+
+        /// The CRC32 checksum of the chunks written when serializing the DOM
+        /// again.
+        actual_hash: u32,
+    },
+
+    /// [`Deserializer::verify_integrity`] was set, but the deserialized DOM
+    /// couldn't be serialized again to check it.
+    ///
+    /// [`Deserializer::verify_integrity`]: crate::Deserializer::verify_integrity
+    IntegrityCheckFailed {
+        /// A description of why the DOM couldn't be serialized.
+        message: String,
+    },
+}
+
+impl fmt::Display for DecodeWarning {
+    fn fmt(&self, output: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeWarning::UntestedFileVersion { version } => write!(
+                output,
+                "File version {} has not been tested with rbx_binary, which supports version {}",
+                version, FILE_VERSION
+            ),
+            DecodeWarning::InvalidPropChunk { message } => {
+                write!(output, "Skipped invalid PROP chunk: {}", message)
+            }
+            DecodeWarning::UnknownParent { referent, parent } => write!(
+                output,
+                "Instance {} has undeclared parent {}, so it was placed at the top level",
+                referent, parent
+            ),
+            DecodeWarning::UnknownReferent { referent } => write!(
+                output,
+                "Skipped parent of instance {}, which was not declared",
+                referent
+            ),
+            DecodeWarning::IntegrityMismatch {
+                expected_hash,
+                actual_hash,
+            } => write!(
+                output,
+                "Serializing the file again produced different contents \
+                 (expected checksum {:08x}, got {:08x})",
+                expected_hash, actual_hash
+            ),
+            DecodeWarning::IntegrityCheckFailed { message } => {
+                write!(output, "Could not serialize the file again: {}", message)
+            }
+        }
+    }
+}
@@ -57,16 +57,27 @@ mod cframe;
 mod chunk;
 mod core;
 mod deserializer;
+mod logging;
 mod serializer;
 mod types;
 
+#[cfg(feature = "tokio")]
+pub mod async_io;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 #[cfg(any(test, feature = "unstable_text_format"))]
 mod text_deserializer;
 
 #[cfg(test)]
 mod tests;
 
-use std::io::{Read, Write};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
 
 use rbx_dom_weak::{types::Ref, WeakDom};
 
@@ -77,8 +88,9 @@ pub mod text_format {
 }
 
 pub use crate::{
-    deserializer::{Deserializer, Error as DecodeError},
-    serializer::{Error as EncodeError, Serializer},
+    chunk::{Chunk, ChunkStat, LazyChunk, OpaqueChunk},
+    deserializer::{DecodeOutput, DecodeWarning, Deserializer, Error as DecodeError},
+    serializer::{Error as EncodeError, SerializationStats, Serializer, ServiceMarkerBehavior},
 };
 
 /// Deserialize a Roblox binary model or place from a stream.
@@ -86,8 +98,77 @@ pub fn from_reader<R: Read>(reader: R) -> Result<WeakDom, DecodeError> {
     Deserializer::new().deserialize(reader)
 }
 
+/// Deserialize a Roblox binary model or place from a slice of bytes.
+pub fn decode_from_slice(slice: &[u8]) -> Result<WeakDom, DecodeError> {
+    Deserializer::new().deserialize(slice)
+}
+
+/// Deserialize a Roblox binary model or place from a stream, skipping over
+/// problems that only affect part of the file instead of failing. Skipped
+/// problems are returned as warnings alongside the DOM.
+///
+/// See [`Deserializer::recover_from_errors`] for which problems are skipped.
+pub fn decode_with_warnings<R: Read>(reader: R) -> Result<DecodeOutput, DecodeError> {
+    Deserializer::new()
+        .recover_from_errors(true)
+        .deserialize_full(reader)
+}
+
+/// Deserialize a Roblox binary model or place from the file at the given path,
+/// using the options set on the given [`Deserializer`].
+pub fn from_file<P: AsRef<Path>>(path: P, options: Deserializer) -> Result<WeakDom, DecodeError> {
+    let file = File::open(path).map_err(deserializer::error::InnerError::from)?;
+    options.deserialize(BufReader::new(file))
+}
+
+/// Deserialize a Roblox binary model or place from the file at the given path
+/// using the default deserializer options.
+pub fn from_file_default<P: AsRef<Path>>(path: P) -> Result<WeakDom, DecodeError> {
+    from_file(path, Deserializer::new())
+}
+
 /// Serializes a subset of the given DOM to a binary format model or place,
 /// writing to something that implements the `std::io::Write` trait.
 pub fn to_writer<W: Write>(writer: W, dom: &WeakDom, refs: &[Ref]) -> Result<(), EncodeError> {
     Serializer::new().serialize(writer, dom, refs)
 }
+
+/// Serializes a subset of the given DOM to a binary format model or place,
+/// returning the encoded bytes.
+pub fn encode_to_vec(dom: &WeakDom, refs: &[Ref]) -> Result<Vec<u8>, EncodeError> {
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, dom, refs)?;
+
+    Ok(buffer)
+}
+
+/// Serializes a subset of the given DOM to a binary format model or place,
+/// creating or replacing the file at the given path using the options set on
+/// the given [`Serializer`].
+pub fn to_file<P: AsRef<Path>>(
+    path: P,
+    dom: &WeakDom,
+    refs: &[Ref],
+    options: Serializer,
+) -> Result<(), EncodeError> {
+    let file = File::create(path).map_err(serializer::error::InnerError::from)?;
+
+    let mut writer = BufWriter::new(file);
+    options.serialize(&mut writer, dom, refs)?;
+    writer
+        .flush()
+        .map_err(serializer::error::InnerError::from)?;
+
+    Ok(())
+}
+
+/// Serializes a subset of the given DOM to a binary format model or place,
+/// creating or replacing the file at the given path using the default
+/// serializer options.
+pub fn to_file_default<P: AsRef<Path>>(
+    path: P,
+    dom: &WeakDom,
+    refs: &[Ref],
+) -> Result<(), EncodeError> {
+    to_file(path, dom, refs, Serializer::new())
+}
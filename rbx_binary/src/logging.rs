@@ -0,0 +1,10 @@
+//! Chooses where diagnostics are sent. By default, rbx_binary logs through the
+//! `log` crate. With the `tracing` feature enabled, the same messages are
+//! emitted as `tracing` events instead, and decoding and encoding are wrapped
+//! in spans with structured fields like `chunk`, `type_name`, and `prop_name`.
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use log::{debug, error, info, trace, warn};
+
+#[cfg(feature = "tracing")]
+pub(crate) use tracing::{debug, error, info, trace, warn};
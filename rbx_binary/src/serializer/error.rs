@@ -1,6 +1,6 @@
 use std::io;
 
-use rbx_dom_weak::types::Ref;
+use rbx_dom_weak::types::{AttributeError, Ref};
 use thiserror::Error;
 
 /// Represents an error that occurred during serialization.
@@ -19,7 +19,7 @@ impl From<InnerError> for Error {
 }
 
 #[derive(Debug, Error)]
-pub(super) enum InnerError {
+pub(crate) enum InnerError {
     #[error(transparent)]
     Io {
         #[from]
@@ -45,6 +45,14 @@ pub(super) enum InnerError {
         prop_type: String,
     },
 
+    #[error("Could not encode {type_name}.{prop_name} on instance {instance_full_name}: {source}")]
+    InvalidAttributes {
+        type_name: String,
+        prop_name: String,
+        instance_full_name: String,
+        source: AttributeError,
+    },
+
     #[error("The instance with referent {referent:?} was not present in the dom.")]
     InvalidInstanceId { referent: Ref },
 }
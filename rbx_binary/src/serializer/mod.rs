@@ -1,10 +1,15 @@
-mod error;
+pub(crate) mod error;
 mod state;
 
-use std::io::Write;
+use std::{collections::HashMap, io::Write};
 
 use rbx_dom_weak::{types::Ref, WeakDom};
 
+use crate::{
+    chunk::{ChunkStat, OpaqueChunk},
+    logging,
+};
+
 use self::state::SerializerState;
 
 pub use self::error::Error;
@@ -31,20 +36,152 @@ pub use self::error::Error;
 // * reflection_database: Option<ReflectionDatabase> = default
 // * recursive: bool = true
 #[non_exhaustive]
-pub struct Serializer {}
+pub struct Serializer {
+    skip_default_properties: bool,
+    sort_children_by_name: bool,
+    skip_non_archivable: bool,
+    unknown_chunks: Vec<OpaqueChunk>,
+    metadata: HashMap<String, String>,
+    auto_detect_format: bool,
+    write_service_marker: ServiceMarkerBehavior,
+    all_chunks_uncompressed: bool,
+}
 
 impl Serializer {
     /// Create a new `Serializer` with the default settings.
     pub fn new() -> Self {
-        Serializer {}
+        Serializer {
+            skip_default_properties: false,
+            sort_children_by_name: false,
+            skip_non_archivable: false,
+            unknown_chunks: Vec::new(),
+            metadata: HashMap::new(),
+            auto_detect_format: false,
+            write_service_marker: ServiceMarkerBehavior::Auto,
+            all_chunks_uncompressed: false,
+        }
+    }
+
+    /// Sets whether properties equal to their default value from the
+    /// reflection database should be omitted from the output.
+    ///
+    /// Properties are stored per class in the binary format, so a property is
+    /// only omitted if it has its default value on every instance of that
+    /// class being serialized. Roblox fills in omitted properties with their
+    /// defaults when loading a file.
+    pub fn skip_default_properties(self, skip_default_properties: bool) -> Self {
+        Self {
+            skip_default_properties,
+            ..self
+        }
+    }
+
+    /// Sets whether the children of each instance should be sorted by name
+    /// before being serialized, instead of keeping the order they have in the
+    /// DOM. Children with the same name are ordered by class name, then by
+    /// their order in the DOM.
+    ///
+    /// This makes the output the same no matter what order instances were
+    /// inserted in, which keeps diffs of files in version control small.
+    pub fn sort_children_by_name(self, sort_children_by_name: bool) -> Self {
+        Self {
+            sort_children_by_name,
+            ..self
+        }
+    }
+
+    /// Sets whether instances that Roblox wouldn't save should be left out of
+    /// the output, along with all of their descendants. This matches what
+    /// Roblox does when saving a file.
+    ///
+    /// An instance isn't saved if its `Archivable` property is false. See
+    /// `ReflectionDatabase::is_archivable`.
+    pub fn skip_non_archivable(self, skip_non_archivable: bool) -> Self {
+        Self {
+            skip_non_archivable,
+            ..self
+        }
+    }
+
+    /// Sets chunks that rbx_binary doesn't understand to write into the output
+    /// unchanged, just before the file's END chunk. These usually come from
+    /// [`DecodeOutput::unknown_chunks`].
+    ///
+    /// [`DecodeOutput::unknown_chunks`]: crate::DecodeOutput::unknown_chunks
+    pub fn unknown_chunks(self, unknown_chunks: Vec<OpaqueChunk>) -> Self {
+        Self {
+            unknown_chunks,
+            ..self
+        }
+    }
+
+    /// Sets file-level metadata to write into the output's META chunk, like
+    /// `ExplicitAutoJoints`. These usually come from
+    /// [`DecodeOutput::metadata`]. No META chunk is written if this is empty.
+    ///
+    /// [`DecodeOutput::metadata`]: crate::DecodeOutput::metadata
+    pub fn metadata(self, metadata: HashMap<String, String>) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// Sets whether the serializer should decide between writing a model and
+    /// writing a place based on the instances it's given.
+    ///
+    /// Places don't contain their `DataModel`, only its children. With this
+    /// set, any `DataModel` passed to [`Serializer::serialize`] is replaced by
+    /// its children, so `&[dom.root_ref()]` writes a place if the root is a
+    /// `DataModel` and a model otherwise.
+    pub fn auto_detect_format(self, auto_detect_format: bool) -> Self {
+        Self {
+            auto_detect_format,
+            ..self
+        }
+    }
+
+    /// Sets whether instances should be marked as services in their INST
+    /// chunk. By default, classes tagged `Service` in the reflection database
+    /// are marked and all others aren't.
+    pub fn write_service_marker(self, write_service_marker: ServiceMarkerBehavior) -> Self {
+        Self {
+            write_service_marker,
+            ..self
+        }
+    }
+
+    /// Sets whether every chunk should be written without LZ4 compression.
+    /// Roblox and rbx_binary both read uncompressed chunks, which are larger
+    /// but can be loaded without an LZ4 implementation.
+    pub fn all_chunks_uncompressed(self, all_chunks_uncompressed: bool) -> Self {
+        Self {
+            all_chunks_uncompressed,
+            ..self
+        }
     }
 
     /// Serialize a Roblox binary model or place into the given stream using
     /// this serializer.
     pub fn serialize<W: Write>(&self, writer: W, dom: &WeakDom, refs: &[Ref]) -> Result<(), Error> {
-        let mut serializer = SerializerState::new(dom, writer);
+        self.serialize_with_stats(writer, dom, refs)?;
+
+        Ok(())
+    }
+
+    /// Serialize a Roblox binary model or place into the given stream using
+    /// this serializer, returning statistics about what was written.
+    pub fn serialize_with_stats<W: Write>(
+        &self,
+        writer: W,
+        dom: &WeakDom,
+        refs: &[Ref],
+    ) -> Result<SerializationStats, Error> {
+        let mut serializer = SerializerState::new(self, dom, writer);
+
+        if self.auto_detect_format {
+            serializer.add_instances(&dom.expand_data_models(refs))?;
+        } else {
+            serializer.add_instances(refs)?;
+        }
 
-        serializer.add_instances(refs)?;
         serializer.generate_referents();
         serializer.write_header()?;
         serializer.serialize_metadata()?;
@@ -52,12 +189,45 @@ impl Serializer {
         serializer.serialize_instances()?;
         serializer.serialize_properties()?;
         serializer.serialize_parents()?;
+        serializer.serialize_unknown_chunks()?;
         serializer.serialize_end()?;
 
-        Ok(())
+        let stats = serializer.into_stats();
+        logging::debug!("Serialization stats: {:#?}", stats);
+
+        Ok(stats)
     }
 }
 
+/// Describes when the serializer should mark a class as a service in its INST
+/// chunk. Services have their chunk's service flag set and a `1` byte written
+/// for every instance of the class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ServiceMarkerBehavior {
+    /// Marks classes that are tagged `Service` in the reflection database.
+    ///
+    /// This is the default.
+    Auto,
+
+    /// Marks every class as a service.
+    AlwaysWrite,
+
+    /// Never marks any class as a service.
+    NeverWrite,
+}
+
+/// Statistics about a file written with [`Serializer::serialize_with_stats`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SerializationStats {
+    /// The number of instances written of each class.
+    pub class_counts: HashMap<String, usize>,
+
+    /// The size of every chunk written, in the order they were written.
+    pub chunk_stats: Vec<ChunkStat>,
+}
+
 impl Default for Serializer {
     fn default() -> Self {
         Self::new()
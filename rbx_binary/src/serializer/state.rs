@@ -7,26 +7,22 @@ use std::{
 };
 
 use rbx_dom_weak::{
-    types::{
-        Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence,
-        ColorSequenceKeypoint, Enum, Faces, Matrix3, NumberRange, NumberSequence,
-        NumberSequenceKeypoint, PhysicalProperties, Ray, Rect, Ref, SharedString, UDim, UDim2,
-        Variant, VariantType, Vector2, Vector3, Vector3int16,
-    },
-    WeakDom,
+    types::{Color3, Enum, Matrix3, PhysicalProperties, Ref, SharedString, Variant, VariantType},
+    Instance, WeakDom,
 };
 use rbx_reflection::{ClassDescriptor, ClassTag, DataType};
 
 use crate::{
     cframe,
-    chunk::{ChunkBuilder, ChunkCompression},
+    chunk::{ChunkBuilder, ChunkCompression, ChunkStat},
     core::{
         find_property_descriptors, RbxWriteExt, FILE_MAGIC_HEADER, FILE_SIGNATURE, FILE_VERSION,
     },
+    logging,
     types::Type,
 };
 
-use super::error::InnerError;
+use super::{error::InnerError, SerializationStats, Serializer, ServiceMarkerBehavior};
 
 static FILE_FOOTER: &[u8] = b"</roblox>";
 
@@ -34,6 +30,9 @@ static FILE_FOOTER: &[u8] = b"</roblox>";
 /// `BinarySerializer` object should be created every time we want to serialize
 /// a binary model file.
 pub(super) struct SerializerState<'a, W> {
+    /// The serializer that created this state, which holds its settings.
+    serializer: &'a Serializer,
+
     /// The dom containing all of the instances that we're serializing.
     dom: &'a WeakDom,
 
@@ -59,6 +58,9 @@ pub(super) struct SerializerState<'a, W> {
     /// A map of SharedStrings to where it is in the SSTR chunk. This is used
     /// for writing PROP chunks.
     shared_string_ids: HashMap<SharedString, u32>,
+
+    /// The sizes of every chunk written so far, in order.
+    chunk_stats: Vec<ChunkStat>,
 }
 
 /// An instance class that our serializer knows about. We should have one struct
@@ -169,7 +171,7 @@ impl TypeInfos {
             if let Some(descriptor) = &class_descriptor {
                 is_service = descriptor.tags.contains(&ClassTag::Service);
             } else {
-                log::info!("The class {} is not known to rbx_binary", class);
+                logging::info!("The class {} is not known to rbx_binary", class);
                 is_service = false;
             };
 
@@ -211,8 +213,9 @@ impl TypeInfos {
 }
 
 impl<'a, W: Write> SerializerState<'a, W> {
-    pub fn new(dom: &'a WeakDom, output: W) -> Self {
+    pub fn new(serializer: &'a Serializer, dom: &'a WeakDom, output: W) -> Self {
         SerializerState {
+            serializer,
             dom,
             output,
             relevant_instances: Vec::new(),
@@ -220,6 +223,17 @@ impl<'a, W: Write> SerializerState<'a, W> {
             type_infos: TypeInfos::new(),
             shared_strings: Vec::new(),
             shared_string_ids: HashMap::new(),
+            chunk_stats: Vec::new(),
+        }
+    }
+
+    /// The compression to use for every chunk except END, which is always
+    /// uncompressed.
+    fn chunk_compression(&self) -> ChunkCompression {
+        if self.serializer.all_chunks_uncompressed {
+            ChunkCompression::Uncompressed
+        } else {
+            ChunkCompression::Compressed
         }
     }
 
@@ -230,15 +244,41 @@ impl<'a, W: Write> SerializerState<'a, W> {
         to_visit.extend(referents);
 
         while let Some(referent) = to_visit.pop_front() {
+            let instance = self
+                .dom
+                .get_by_ref(referent)
+                .ok_or(InnerError::InvalidInstanceId { referent })?;
+
+            if self.serializer.skip_non_archivable
+                && !rbx_reflection_database::get()
+                    .is_archivable(&instance.class, instance.properties.get("Archivable"))
+            {
+                logging::debug!(
+                    "Skipping non-archivable instance {} ({})",
+                    instance.name,
+                    instance.class
+                );
+                continue;
+            }
+
             self.relevant_instances.push(referent);
             self.collect_type_info(referent)?;
 
-            // TODO: Turn into error
-            let instance = self.dom.get_by_ref(referent).unwrap();
-            to_visit.extend(instance.children());
+            if self.serializer.sort_children_by_name {
+                let mut children: Vec<&Instance> = instance
+                    .children()
+                    .iter()
+                    .map(|child| self.dom.get_by_ref(*child).unwrap())
+                    .collect();
+
+                children.sort_by(|a, b| (&a.name, &a.class).cmp(&(&b.name, &b.class)));
+                to_visit.extend(children.iter().map(|child| child.referent()));
+            } else {
+                to_visit.extend(instance.children());
+            }
         }
 
-        log::debug!("Type info discovered: {:#?}", self.type_infos);
+        logging::debug!("Type info discovered: {:#?}", self.type_infos);
 
         Ok(())
     }
@@ -258,6 +298,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
         type_info.object_refs.push(referent);
 
         for (prop_name, prop_value) in &instance.properties {
+            let prop_name: &str = prop_name;
             let canonical_name;
             let serialized_name;
             let serialized_ty;
@@ -284,7 +325,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
                             // of property, whatever it is.
                             return Err(InnerError::UnsupportedPropType {
                                 type_name: instance.class.clone(),
-                                prop_name: prop_name.clone(),
+                                prop_name: prop_name.to_owned(),
                                 prop_type: format!("{:?}", unknown_ty),
                             });
                         }
@@ -292,8 +333,8 @@ impl<'a, W: Write> SerializerState<'a, W> {
                 }
 
                 None => {
-                    canonical_name = Cow::Owned(prop_name.clone());
-                    serialized_name = Cow::Owned(prop_name.clone());
+                    canonical_name = Cow::Owned(prop_name.to_owned());
+                    serialized_name = Cow::Owned(prop_name.to_owned());
                     serialized_ty = prop_value.ty();
                 }
             }
@@ -301,7 +342,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
             // In order to prevent cloning canonical_name in a rare branch,
             // we conditionally clone here if we'll need canonical_name after
             // it's inserted into type_info.properties.
-            let canonical_name_if_different = if prop_name != &canonical_name {
+            let canonical_name_if_different = if prop_name != canonical_name {
                 Some(canonical_name.clone())
             } else {
                 None
@@ -356,7 +397,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
                 let prop_info = type_info.properties.get_mut(&canonical_name).unwrap();
 
                 if !prop_info.aliases.contains(prop_name) {
-                    prop_info.aliases.insert(prop_name.clone());
+                    prop_info.aliases.insert(prop_name.to_owned());
                 }
             }
 
@@ -382,11 +423,11 @@ impl<'a, W: Write> SerializerState<'a, W> {
                 .insert(*id, next_referent.try_into().unwrap());
         }
 
-        log::trace!("Referents constructed: {:#?}", self.id_to_referent);
+        logging::trace!("Referents constructed: {:#?}", self.id_to_referent);
     }
 
     pub fn write_header(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing header");
+        logging::trace!("Writing header");
 
         self.output.write_all(FILE_MAGIC_HEADER)?;
         self.output.write_all(FILE_SIGNATURE)?;
@@ -403,21 +444,42 @@ impl<'a, W: Write> SerializerState<'a, W> {
 
     /// Write out any metadata about this file, stored in a chunk named META.
     pub fn serialize_metadata(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing metadata (currently no-op)");
-        // TODO: There is no concept of metadata in a dom yet.
+        logging::trace!("Writing metadata");
+
+        let metadata = &self.serializer.metadata;
+        if metadata.is_empty() {
+            return Ok(());
+        }
+
+        // Entries are sorted so that the output doesn't depend on the order
+        // of the HashMap.
+        let mut entries: Vec<_> = metadata.iter().collect();
+        entries.sort_unstable();
+
+        let mut chunk = ChunkBuilder::new(b"META", self.chunk_compression());
+        chunk.write_le_u32(entries.len() as u32)?;
+
+        for (key, value) in entries {
+            chunk.write_string(key)?;
+            chunk.write_string(value)?;
+        }
+
+        let stat = chunk.dump(&mut self.output)?;
+        self.chunk_stats.push(stat);
+
         Ok(())
     }
 
     /// Write out all of the SharedStrings in this file, if any exist,
     /// stored in a chunk named SSTR.
     pub fn serialize_shared_strings(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing shared string chunk");
+        logging::trace!("Writing shared string chunk");
 
         if self.shared_strings.is_empty() {
             return Ok(());
         }
 
-        let mut chunk = ChunkBuilder::new(b"SSTR", ChunkCompression::Compressed);
+        let mut chunk = ChunkBuilder::new(b"SSTR", self.chunk_compression());
 
         chunk.write_le_u32(0)?; // SSTR version number
         chunk.write_le_u32(self.shared_strings.len() as u32)?;
@@ -428,7 +490,8 @@ impl<'a, W: Write> SerializerState<'a, W> {
             chunk.write_binary_string(shared_string.data())?;
         }
 
-        chunk.dump(&mut self.output)?;
+        let stat = chunk.dump(&mut self.output)?;
+        self.chunk_stats.push(stat);
 
         Ok(())
     }
@@ -436,16 +499,21 @@ impl<'a, W: Write> SerializerState<'a, W> {
     /// Write out the declarations of all instances, stored in a series of
     /// chunks named INST.
     pub fn serialize_instances(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing instance chunks");
+        logging::trace!("Writing instance chunks");
 
         for (type_name, type_info) in &self.type_infos.values {
-            log::trace!(
+            #[cfg(feature = "tracing")]
+            let _span =
+                tracing::debug_span!("encode_chunk", chunk = "INST", type_name = %type_name)
+                    .entered();
+
+            logging::trace!(
                 "Writing chunk for {} ({} instances)",
                 type_name,
                 type_info.object_refs.len()
             );
 
-            let mut chunk = ChunkBuilder::new(b"INST", ChunkCompression::Compressed);
+            let mut chunk = ChunkBuilder::new(b"INST", self.chunk_compression());
 
             chunk.write_le_u32(type_info.type_id)?;
             chunk.write_string(type_name)?;
@@ -456,7 +524,13 @@ impl<'a, W: Write> SerializerState<'a, W> {
             //
             // At that point, we'll start thinking about it like it's a u8
             // instead of a bool.
-            chunk.write_bool(type_info.is_service)?;
+            let is_service = match self.serializer.write_service_marker {
+                ServiceMarkerBehavior::Auto => type_info.is_service,
+                ServiceMarkerBehavior::AlwaysWrite => true,
+                ServiceMarkerBehavior::NeverWrite => false,
+            };
+
+            chunk.write_bool(is_service)?;
 
             chunk.write_le_u32(type_info.object_refs.len() as u32)?;
 
@@ -467,7 +541,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
                     .map(|id| self.id_to_referent[id]),
             )?;
 
-            if type_info.is_service {
+            if is_service {
                 // It's unclear what this byte is used for, but when the type is
                 // a service (like Workspace, Lighting, etc), we need to write
                 // the value `1` for every instance in our file of that type.
@@ -480,7 +554,8 @@ impl<'a, W: Write> SerializerState<'a, W> {
                 }
             }
 
-            chunk.dump(&mut self.output)?;
+            let stat = chunk.dump(&mut self.output)?;
+            self.chunk_stats.push(stat);
         }
 
         Ok(())
@@ -490,18 +565,47 @@ impl<'a, W: Write> SerializerState<'a, W> {
     /// previously defined in the INST chunks. Property data is contained in
     /// chunks named PROP.
     pub fn serialize_properties(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing properties");
+        logging::trace!("Writing properties");
+
+        let database = rbx_reflection_database::get();
 
         for (type_name, type_info) in &self.type_infos.values {
             for (prop_name, prop_info) in &type_info.properties {
-                log::trace!(
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!(
+                    "encode_chunk",
+                    chunk = "PROP",
+                    type_name = %type_name,
+                    prop_name = %prop_name,
+                )
+                .entered();
+
+                if self.serializer.skip_default_properties && prop_name != "Name" {
+                    let all_default = type_info.object_refs.iter().all(|id| {
+                        let instance = self.dom.get_by_ref(*id).unwrap();
+                        let value = property_value(instance, prop_name, prop_info);
+
+                        database.is_default(type_name, prop_name, &value)
+                    });
+
+                    if all_default {
+                        logging::trace!(
+                            "Skipping property {}.{} because it has its default value",
+                            type_name,
+                            prop_name
+                        );
+                        continue;
+                    }
+                }
+
+                logging::trace!(
                     "Writing property {}.{} (type {:?})",
                     type_name,
                     prop_name,
                     prop_info.prop_type
                 );
 
-                let mut chunk = ChunkBuilder::new(b"PROP", ChunkCompression::Compressed);
+                let mut chunk = ChunkBuilder::new(b"PROP", self.chunk_compression());
 
                 chunk.write_le_u32(type_info.type_id)?;
                 chunk.write_string(&prop_info.serialized_name)?;
@@ -517,32 +621,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
                         // this dom.
                         let instance = dom.get_by_ref(*id).unwrap();
 
-                        // We store the Name property in a different field for
-                        // convenience, but when serializing to the binary model
-                        // format we need to handle it just like other properties.
-                        if prop_name == "Name" {
-                            return Cow::Owned(Variant::String(instance.name.clone()));
-                        }
-
-                        // Most properties will be stored on instances using the
-                        // property's canonical name, so we'll try that first.
-                        if let Some(property) = instance.properties.get(prop_name.as_ref()) {
-                            return Cow::Borrowed(property);
-                        }
-
-                        // If there were any known aliases for this property
-                        // used as part of this file, we can check those next.
-                        for alias in &prop_info.aliases {
-                            if let Some(property) = instance.properties.get(alias) {
-                                return Cow::Borrowed(property);
-                            }
-                        }
-
-                        // Finally, we can fall back to the default value we
-                        // computed for this PropInfo. This is sourced from the
-                        // reflection database if available, or falls back to a
-                        // reasonable default.
-                        Cow::Borrowed(prop_info.default_value.borrow())
+                        property_value(instance, prop_name, prop_info)
                     })
                     .enumerate();
 
@@ -555,7 +634,10 @@ impl<'a, W: Write> SerializerState<'a, W> {
                             prop_name: prop_name.to_string(),
                             valid_type_names,
                             actual_type_name: format!("{:?}", bad_value.ty()),
-                            instance_full_name: self.full_name_for(type_info.object_refs[i]),
+                            instance_full_name: self
+                                .dom
+                                .full_name_of(type_info.object_refs[i])
+                                .unwrap_or_default(),
                         })
                     };
 
@@ -569,14 +651,34 @@ impl<'a, W: Write> SerializerState<'a, W> {
                                 Variant::Content(value) => {
                                     chunk.write_string(value.as_ref())?;
                                 }
+                                Variant::ProtectedString(value) => {
+                                    chunk.write_string(value.as_ref())?;
+                                }
                                 Variant::BinaryString(value) => {
                                     chunk.write_binary_string(value.as_ref())?;
                                 }
+                                Variant::Tags(value) => {
+                                    chunk.write_binary_string(&value.encode())?;
+                                }
+                                Variant::Attributes(value) => {
+                                    let buffer = value.encode().map_err(|source| {
+                                        InnerError::InvalidAttributes {
+                                            type_name: type_name.clone(),
+                                            prop_name: prop_name.to_string(),
+                                            instance_full_name: self
+                                                .dom
+                                                .full_name_of(type_info.object_refs[i])
+                                                .unwrap_or_default(),
+                                            source,
+                                        }
+                                    })?;
+                                    chunk.write_binary_string(&buffer)?;
+                                }
                                 _ => {
                                     return type_mismatch(
                                         i,
                                         &rbx_value,
-                                        "String, Content, or BinaryString",
+                                        "String, Content, ProtectedString, BinaryString, Tags, or Attributes",
                                     );
                                 }
                             }
@@ -676,7 +778,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
                                 chunk.write_le_f32(value.origin.z)?;
                                 chunk.write_le_f32(value.direction.x)?;
                                 chunk.write_le_f32(value.direction.y)?;
-                                chunk.write_le_f32(value.direction.x)?;
+                                chunk.write_le_f32(value.direction.z)?;
                             } else {
                                 return type_mismatch(i, &rbx_value, "Ray");
                             }
@@ -721,13 +823,15 @@ impl<'a, W: Write> SerializerState<'a, W> {
                         let mut b = Vec::with_capacity(values.len());
 
                         for (i, rbx_value) in values {
-                            if let Variant::Color3(value) = rbx_value.as_ref() {
-                                r.push(value.r);
-                                g.push(value.g);
-                                b.push(value.b);
-                            } else {
-                                return type_mismatch(i, &rbx_value, "Color3");
-                            }
+                            let value = match rbx_value.as_ref() {
+                                Variant::Color3(value) => *value,
+                                Variant::Color3uint8(value) => Color3::from(*value),
+                                _ => return type_mismatch(i, &rbx_value, "Color3 or Color3uint8"),
+                            };
+
+                            r.push(value.r);
+                            g.push(value.g);
+                            b.push(value.b);
                         }
 
                         chunk.write_interleaved_f32_array(r.into_iter())?;
@@ -840,6 +944,16 @@ impl<'a, W: Write> SerializerState<'a, W> {
 
                         chunk.write_referent_array(buf.into_iter())?;
                     }
+                    Type::Vector2int16 => {
+                        for (i, rbx_value) in values {
+                            if let Variant::Vector2int16(value) = rbx_value.as_ref() {
+                                chunk.write_le_i16(value.x)?;
+                                chunk.write_le_i16(value.y)?;
+                            } else {
+                                return type_mismatch(i, &rbx_value, "Vector2int16");
+                            }
+                        }
+                    }
                     Type::Vector3int16 => {
                         for (i, rbx_value) in values {
                             if let Variant::Vector3int16(value) = rbx_value.as_ref() {
@@ -876,9 +990,7 @@ impl<'a, W: Write> SerializerState<'a, W> {
                                     chunk.write_le_f32(keypoint.color.r)?;
                                     chunk.write_le_f32(keypoint.color.g)?;
                                     chunk.write_le_f32(keypoint.color.b)?;
-
-                                    // write out a dummy value for envelope, which is serialized but doesn't do anything
-                                    chunk.write_le_f32(0.0)?;
+                                    chunk.write_le_f32(keypoint.envelope)?;
                                 }
                             } else {
                                 return type_mismatch(i, &rbx_value, "ColorSequence");
@@ -1049,7 +1161,8 @@ impl<'a, W: Write> SerializerState<'a, W> {
                     }
                 }
 
-                chunk.dump(&mut self.output)?;
+                let stat = chunk.dump(&mut self.output)?;
+                self.chunk_stats.push(stat);
             }
         }
 
@@ -1059,9 +1172,9 @@ impl<'a, W: Write> SerializerState<'a, W> {
     /// Write out the hierarchical relations between instances, stored in a
     /// chunk named PRNT.
     pub fn serialize_parents(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing parent relationships");
+        logging::trace!("Writing parent relationships");
 
-        let mut chunk = ChunkBuilder::new(b"PRNT", ChunkCompression::Compressed);
+        let mut chunk = ChunkBuilder::new(b"PRNT", self.chunk_compression());
 
         chunk.write_u8(0)?; // PRNT version 0
         chunk.write_le_u32(self.relevant_instances.len() as u32)?;
@@ -1090,7 +1203,22 @@ impl<'a, W: Write> SerializerState<'a, W> {
         chunk.write_referent_array(object_referents)?;
         chunk.write_referent_array(parent_referents)?;
 
-        chunk.dump(&mut self.output)?;
+        let stat = chunk.dump(&mut self.output)?;
+        self.chunk_stats.push(stat);
+
+        Ok(())
+    }
+
+    /// Write out the chunks that the user asked us to pass through unchanged.
+    pub fn serialize_unknown_chunks(&mut self) -> Result<(), InnerError> {
+        for unknown_chunk in &self.serializer.unknown_chunks {
+            logging::trace!("Writing unknown chunk {:?}", unknown_chunk.name);
+
+            let mut chunk = ChunkBuilder::new(&unknown_chunk.name, self.chunk_compression());
+            chunk.write_all(&unknown_chunk.data)?;
+            let stat = chunk.dump(&mut self.output)?;
+            self.chunk_stats.push(stat);
+        }
 
         Ok(())
     }
@@ -1099,89 +1227,73 @@ impl<'a, W: Write> SerializerState<'a, W> {
     /// hasn't been truncated mistakenly. This chunk is named END\0, with a zero
     /// byte at the end.
     pub fn serialize_end(&mut self) -> Result<(), InnerError> {
-        log::trace!("Writing file end");
+        logging::trace!("Writing file end");
 
         let mut end = ChunkBuilder::new(b"END\0", ChunkCompression::Uncompressed);
         end.write_all(FILE_FOOTER)?;
-        end.dump(&mut self.output)?;
+        let stat = end.dump(&mut self.output)?;
+        self.chunk_stats.push(stat);
 
         Ok(())
     }
 
-    /// Equivalent to Instance:GetFullName() from Roblox.
-    fn full_name_for(&self, subject_ref: Ref) -> String {
-        let mut components = Vec::new();
-        let mut current_id = subject_ref;
-
-        while current_id.is_some() {
-            let instance = self.dom.get_by_ref(current_id).unwrap();
-            components.push(instance.name.as_str());
-            current_id = instance.parent();
-        }
+    /// Consumes the state, returning statistics about what was written.
+    pub fn into_stats(self) -> SerializationStats {
+        let class_counts = self
+            .type_infos
+            .values
+            .iter()
+            .map(|(class, type_info)| (class.clone(), type_info.object_refs.len()))
+            .collect();
 
-        let mut name = String::new();
-        for component in components.iter().rev() {
-            name.push_str(component);
-            name.push('.');
+        SerializationStats {
+            class_counts,
+            chunk_stats: self.chunk_stats,
         }
-        name.pop();
-
-        name
     }
 
     fn fallback_default_value(rbx_type: VariantType) -> Option<Variant> {
-        Some(match rbx_type {
-            VariantType::String => Variant::String(String::new()),
-            VariantType::BinaryString => Variant::BinaryString(BinaryString::new()),
-            VariantType::Bool => Variant::Bool(false),
-            VariantType::Int32 => Variant::Int32(0),
-            VariantType::Float32 => Variant::Float32(0.0),
-            VariantType::Float64 => Variant::Float64(0.0),
-            VariantType::UDim => Variant::UDim(UDim::new(0.0, 0)),
-            VariantType::UDim2 => Variant::UDim2(UDim2::new(UDim::new(0.0, 0), UDim::new(0.0, 0))),
-            VariantType::Ray => Variant::Ray(Ray::new(
-                Vector3::new(0.0, 0.0, 0.0),
-                Vector3::new(0.0, 0.0, 0.0),
-            )),
-            VariantType::Faces => Variant::Faces(Faces::from_bits(0)?),
-            VariantType::Axes => Variant::Axes(Axes::from_bits(0)?),
-            VariantType::BrickColor => Variant::BrickColor(BrickColor::MediumStoneGrey),
-            VariantType::CFrame => Variant::CFrame(CFrame::new(
-                Vector3::new(0.0, 0.0, 0.0),
-                Matrix3::identity(),
-            )),
-            VariantType::Enum => Variant::Enum(Enum::from_u32(u32::MAX)),
-            VariantType::Color3 => Variant::Color3(Color3::new(0.0, 0.0, 0.0)),
-            VariantType::Vector2 => Variant::Vector2(Vector2::new(0.0, 0.0)),
-            VariantType::Vector3 => Variant::Vector3(Vector3::new(0.0, 0.0, 0.0)),
-            VariantType::Ref => Variant::Ref(Ref::none()),
-            VariantType::Vector3int16 => Variant::Vector3int16(Vector3int16::new(0, 0, 0)),
-            VariantType::NumberSequence => Variant::NumberSequence(NumberSequence {
-                keypoints: [
-                    NumberSequenceKeypoint::new(0.0, 0.0, 0.0),
-                    NumberSequenceKeypoint::new(0.0, 0.0, 0.0),
-                ]
-                .to_vec(),
-            }),
-            VariantType::ColorSequence => Variant::ColorSequence(ColorSequence {
-                keypoints: [
-                    ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0)),
-                    ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0)),
-                ]
-                .to_vec(),
-            }),
-            VariantType::NumberRange => Variant::NumberRange(NumberRange::new(0.0, 0.0)),
-            VariantType::Rect => {
-                Variant::Rect(Rect::new(Vector2::new(0.0, 0.0), Vector2::new(0.0, 0.0)))
-            }
-            VariantType::PhysicalProperties => {
-                Variant::PhysicalProperties(PhysicalProperties::Default)
-            }
-            VariantType::Color3uint8 => Variant::Color3uint8(Color3uint8::new(0, 0, 0)),
-            VariantType::Int64 => Variant::Int64(0),
-            VariantType::SharedString => Variant::SharedString(SharedString::new(Vec::new())),
-            VariantType::OptionalCFrame => Variant::OptionalCFrame(None),
-            _ => return None,
+        // The binary format needs a value for every instance, so types without
+        // a natural zero fall back to the same empty values Roblox writes.
+        Variant::default_for_type(rbx_type).or_else(|| match rbx_type {
+            VariantType::Enum => Some(Variant::Enum(Enum::from_u32(u32::MAX))),
+            VariantType::Ref => Some(Variant::Ref(Ref::none())),
+            VariantType::SharedString => Some(Variant::SharedString(SharedString::new(Vec::new()))),
+            _ => None,
         })
     }
 }
+
+/// Finds the value of the given property on an instance, falling back to any
+/// aliases and then the default value from `prop_info`.
+fn property_value<'a>(
+    instance: &'a Instance,
+    prop_name: &str,
+    prop_info: &'a PropInfo,
+) -> Cow<'a, Variant> {
+    // We store the Name property in a different field for convenience, but
+    // when serializing to the binary model format we need to handle it just
+    // like other properties.
+    if prop_name == "Name" {
+        return Cow::Owned(Variant::String(instance.name.clone()));
+    }
+
+    // Most properties will be stored on instances using the property's
+    // canonical name, so we'll try that first.
+    if let Some(property) = instance.properties.get(prop_name) {
+        return Cow::Borrowed(property);
+    }
+
+    // If there were any known aliases for this property used as part of this
+    // file, we can check those next.
+    for alias in &prop_info.aliases {
+        if let Some(property) = instance.properties.get(alias.as_str()) {
+            return Cow::Borrowed(property);
+        }
+    }
+
+    // Finally, we can fall back to the default value we computed for this
+    // PropInfo. This is sourced from the reflection database if available, or
+    // falls back to a reasonable default.
+    Cow::Borrowed(prop_info.default_value.borrow())
+}
@@ -1,3 +1,7 @@
 mod models;
 mod serializer;
+#[cfg(feature = "tracing")]
+mod tracing_spans;
 mod util;
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+mod wasm;
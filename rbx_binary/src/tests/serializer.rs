@@ -1,9 +1,24 @@
+use std::{collections::HashMap, env, fs, path::PathBuf, process};
+
 use rbx_dom_weak::{
-    types::{Color3, Color3uint8, Ref, Region3, Vector3},
+    types::{
+        Attributes, BinaryString, CFrame, Color3, Color3uint8, ColorSequence,
+        ColorSequenceKeypoint, Content, Matrix3, NumberSequence, NumberSequenceKeypoint,
+        ProtectedString, Ray, Rect, Ref, Region3, Tags, Variant, Vector2, Vector2int16, Vector3,
+        Vector3int16,
+    },
     InstanceBuilder, WeakDom,
 };
+use rbx_test_util::assert_dom_eq;
 
-use crate::{text_deserializer::DecodedModel, to_writer};
+use crate::{
+    chunk::Chunk,
+    decode_from_slice, encode_to_vec, from_file, from_file_default, from_reader,
+    text_deserializer::{DecodedChunk, DecodedModel, DecodedPropType, DecodedValues},
+    to_file, to_file_default, to_writer,
+    types::Type,
+    Deserializer, Serializer, ServiceMarkerBehavior,
+};
 
 /// A basic test to make sure we can serialize the simplest instance: a Folder.
 #[test]
@@ -153,3 +168,1032 @@ fn part_color() {
     let decoded = DecodedModel::from_reader(buf.as_slice());
     insta::assert_yaml_snapshot!(decoded);
 }
+
+/// Ensures that properties with their default value on every instance of a
+/// class are omitted when `skip_default_properties` is enabled.
+#[test]
+fn skip_default_properties() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(
+                InstanceBuilder::new("Part")
+                    .with_property("Size", Vector3::new(4.0, 1.2, 2.0))
+                    .with_property("Anchored", true),
+            )
+            .with_child(
+                InstanceBuilder::new("Part")
+                    .with_property("Size", Vector3::new(4.0, 1.2, 2.0))
+                    .with_property("Anchored", false),
+            ),
+    );
+
+    let mut buffer = Vec::new();
+    Serializer::new()
+        .skip_default_properties(true)
+        .serialize(&mut buffer, &tree, tree.root().children())
+        .expect("failed to encode model");
+
+    let decoded = DecodedModel::from_reader(buffer.as_slice());
+    insta::assert_yaml_snapshot!(decoded);
+}
+
+/// Ensures that Ray values round-trip through the binary format, and that
+/// instances missing a Ray property fall back to the zero ray.
+#[test]
+fn ray_round_trip() {
+    let ray = Ray::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0));
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder").with_property("HypotheticalRay", ray))
+            .with_child(InstanceBuilder::new("Folder")),
+    );
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, tree.root().children()).expect("failed to encode model");
+
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+    let children = decoded.root().children();
+
+    let first = decoded.get_by_ref(children[0]).unwrap();
+    assert_eq!(
+        first.properties.get("HypotheticalRay"),
+        Some(&Variant::Ray(ray))
+    );
+
+    let zero = Vector3::new(0.0, 0.0, 0.0);
+    let second = decoded.get_by_ref(children[1]).unwrap();
+    assert_eq!(
+        second.properties.get("HypotheticalRay"),
+        Some(&Variant::Ray(Ray::new(zero, zero)))
+    );
+}
+
+/// Ensures that Vector2int16 and Vector3int16 values, including negative and
+/// extreme components, survive a trip through the binary format, and that
+/// instances missing the property get the type's default value.
+#[test]
+fn int16_vectors_round_trip() {
+    let v2 = Vector2int16::new(i16::MIN, -1);
+    let v3 = Vector3int16::new(1, i16::MAX, -300);
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(
+                InstanceBuilder::new("Folder")
+                    .with_property("HypotheticalVector2int16", v2)
+                    .with_property("HypotheticalVector3int16", v3),
+            )
+            .with_child(InstanceBuilder::new("Folder")),
+    );
+
+    let encoded = encode_to_vec(&tree, tree.root().children()).unwrap();
+    let decoded = decode_from_slice(&encoded).unwrap();
+    let children = decoded.root().children();
+
+    let first = decoded.get_by_ref(children[0]).unwrap();
+    assert_eq!(
+        first.properties.get("HypotheticalVector2int16"),
+        Some(&Variant::Vector2int16(v2))
+    );
+    assert_eq!(
+        first.properties.get("HypotheticalVector3int16"),
+        Some(&Variant::Vector3int16(v3))
+    );
+
+    let second = decoded.get_by_ref(children[1]).unwrap();
+    assert_eq!(
+        second.properties.get("HypotheticalVector2int16"),
+        Some(&Variant::Vector2int16(Vector2int16::new(0, 0)))
+    );
+    assert_eq!(
+        second.properties.get("HypotheticalVector3int16"),
+        Some(&Variant::Vector3int16(Vector3int16::new(0, 0, 0)))
+    );
+}
+
+/// Ensures that tags are written as null-delimited strings and read back as
+/// `Tags`, whether an instance has zero, one, or several of them.
+#[test]
+fn tags_round_trip() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder"))
+            .with_child(
+                InstanceBuilder::new("Folder").with_property("Tags", Tags::from(&["Enemy"][..])),
+            )
+            .with_child(
+                InstanceBuilder::new("Folder")
+                    .with_property("Tags", Tags::from(&["Enemy", "Boss", "Flying"][..])),
+            ),
+    );
+
+    let encoded = encode_to_vec(&tree, tree.root().children()).unwrap();
+    let decoded = decode_from_slice(&encoded).unwrap();
+    let tags: Vec<_> = decoded
+        .root()
+        .children()
+        .iter()
+        .map(|&id| decoded.get_by_ref(id).unwrap().properties.get("Tags"))
+        .collect();
+
+    assert_eq!(tags[0], Some(&Variant::Tags(Tags::new())));
+    assert_eq!(tags[1], Some(&Variant::Tags(Tags::from(&["Enemy"][..]))));
+    assert_eq!(
+        tags[2],
+        Some(&Variant::Tags(Tags::from(&["Enemy", "Boss", "Flying"][..])))
+    );
+
+    let mut input = &encoded[32..];
+    let prop = loop {
+        let chunk = Chunk::decode(&mut input).unwrap();
+
+        if &chunk.name == b"PROP" && chunk.data[8..12] == *b"Tags" {
+            break chunk;
+        }
+    };
+    assert_eq!(prop.data[12], Type::String as u8);
+    assert!(prop
+        .data
+        .windows(17)
+        .any(|window| window == b"Enemy\0Boss\0Flying"));
+}
+
+/// Ensures that the service flag and the marker bytes that follow it in INST
+/// chunks are written according to `Serializer::write_service_marker`.
+#[test]
+fn write_service_marker() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("DataModel")
+            .with_child(InstanceBuilder::new("Workspace"))
+            .with_child(InstanceBuilder::new("Folder")),
+    );
+
+    // Encodes the tree, checks that it decodes back to the same DOM, and
+    // returns whether each class's INST chunk is marked as a service, along
+    // with the marker bytes after its referents.
+    let markers = |behavior: ServiceMarkerBehavior| {
+        let mut encoded = Vec::new();
+        Serializer::new()
+            .write_service_marker(behavior)
+            .serialize(&mut encoded, &tree, tree.root().children())
+            .unwrap();
+
+        let decoded = decode_from_slice(&encoded).unwrap();
+        let names: Vec<_> = decoded
+            .root()
+            .children()
+            .iter()
+            .map(|&referent| decoded.get_by_ref(referent).unwrap().class.as_str())
+            .collect();
+        assert_eq!(names, ["Workspace", "Folder"]);
+
+        let model = DecodedModel::from_reader(encoded.as_slice());
+        let mut markers = HashMap::new();
+
+        for chunk in model.chunks {
+            if let DecodedChunk::Inst {
+                type_name,
+                object_format,
+                remaining,
+                ..
+            } = chunk
+            {
+                markers.insert(type_name, (object_format == 1, remaining));
+            }
+        }
+
+        markers
+    };
+
+    // Workspace is tagged as a service in the reflection database and Folder
+    // isn't.
+    let auto = markers(ServiceMarkerBehavior::Auto);
+    assert_eq!(auto["Workspace"], (true, vec![1]));
+    assert_eq!(auto["Folder"], (false, vec![]));
+
+    let always = markers(ServiceMarkerBehavior::AlwaysWrite);
+    assert_eq!(always["Workspace"], (true, vec![1]));
+    assert_eq!(always["Folder"], (true, vec![1]));
+
+    let never = markers(ServiceMarkerBehavior::NeverWrite);
+    assert_eq!(never["Workspace"], (false, vec![]));
+    assert_eq!(never["Folder"], (false, vec![]));
+}
+
+/// Ensures that files written with `Serializer::all_chunks_uncompressed` use
+/// uncompressed chunk framing everywhere and decode to the same DOM.
+#[test]
+fn all_chunks_uncompressed() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(
+                InstanceBuilder::new("Part")
+                    .with_name("Some Part")
+                    .with_property("Anchored", true)
+                    .with_property("Size", Vector3::new(1.0, 2.0, 3.0)),
+            )
+            .with_child(InstanceBuilder::new("StringValue").with_property("Value", "hello")),
+    );
+    let refs = tree.root().children();
+
+    let mut uncompressed = Vec::new();
+    Serializer::new()
+        .all_chunks_uncompressed(true)
+        .metadata(
+            [("ExplicitAutoJoints".to_owned(), "true".to_owned())]
+                .iter()
+                .cloned()
+                .collect(),
+        )
+        .serialize(&mut uncompressed, &tree, refs)
+        .unwrap();
+
+    // Every chunk should be stored uncompressed, which LazyChunk reports as
+    // already decompressed.
+    let mut input = &uncompressed[32..];
+    let mut names = Vec::new();
+
+    while !input.is_empty() {
+        let chunk = Chunk::decode_lazy(&mut input).unwrap();
+        assert!(
+            chunk.is_decompressed(),
+            "{:?} chunk was compressed",
+            chunk.name
+        );
+        names.push(chunk.name);
+    }
+
+    assert_eq!(names.first(), Some(b"META"));
+    assert_eq!(names.last(), Some(b"END\0"));
+
+    let compressed = encode_to_vec(&tree, refs).unwrap();
+    assert!(compressed.len() < uncompressed.len());
+
+    let expected = decode_from_slice(&compressed).unwrap();
+    let decoded = decode_from_slice(&uncompressed).unwrap();
+    assert_dom_eq!(expected, decoded);
+}
+
+/// Ensures that attributes are written as `AttributesSerialize` and read back
+/// as `Attributes`, and that blobs that can't be decoded are kept as-is.
+#[test]
+fn attributes_round_trip() {
+    let attributes: Attributes = vec![
+        ("Health", Variant::Float64(100.0)),
+        ("Team", Variant::String("Red".to_owned())),
+    ]
+    .into_iter()
+    .collect();
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder"))
+            .with_child(
+                InstanceBuilder::new("Folder").with_property("Attributes", attributes.clone()),
+            )
+            .with_child(
+                InstanceBuilder::new("Folder")
+                    .with_property("Attributes", BinaryString::from(&b"\xff"[..])),
+            ),
+    );
+
+    let encoded = encode_to_vec(&tree, tree.root().children()).unwrap();
+    let decoded = decode_from_slice(&encoded).unwrap();
+    let values: Vec<_> = decoded
+        .root()
+        .children()
+        .iter()
+        .map(|&id| decoded.get_by_ref(id).unwrap().properties.get("Attributes"))
+        .collect();
+
+    assert_eq!(values[0], Some(&Variant::Attributes(Attributes::new())));
+    assert_eq!(values[1], Some(&Variant::Attributes(attributes)));
+    assert_eq!(
+        values[2],
+        Some(&Variant::BinaryString(BinaryString::from(&b"\xff"[..])))
+    );
+
+    let mut input = &encoded[32..];
+    let prop = loop {
+        let chunk = Chunk::decode(&mut input).unwrap();
+
+        if &chunk.name == b"PROP" && chunk.data[8..27] == *b"AttributesSerialize" {
+            break chunk;
+        }
+    };
+    assert_eq!(prop.data[27], Type::String as u8);
+}
+
+/// Ensures that NaN and infinite floats survive a trip through the binary
+/// format, both through the interleaved encoding used for Float32 and the
+/// plain encoding used for Float64.
+#[test]
+fn float_inf_and_nan() {
+    let values = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder").with_children(values.iter().map(|&value| {
+            InstanceBuilder::new("Folder")
+                .with_property("HypotheticalFloat32", value)
+                .with_property("HypotheticalFloat64", value as f64)
+        })),
+    );
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, tree.root().children()).expect("failed to encode model");
+
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+    let children = decoded.root().children();
+    assert_eq!(children.len(), values.len());
+
+    for (referent, expected) in children.iter().zip(values.iter()) {
+        let instance = decoded.get_by_ref(*referent).unwrap();
+
+        let float32 = match instance.properties.get("HypotheticalFloat32") {
+            Some(Variant::Float32(value)) => *value,
+            other => panic!("expected a Float32, got {:?}", other),
+        };
+
+        let float64 = match instance.properties.get("HypotheticalFloat64") {
+            Some(Variant::Float64(value)) => *value,
+            other => panic!("expected a Float64, got {:?}", other),
+        };
+
+        if expected.is_nan() {
+            assert!(float32.is_nan());
+            assert!(float64.is_nan());
+        } else {
+            assert_eq!(float32, *expected);
+            assert_eq!(float64, *expected as f64);
+        }
+    }
+}
+
+/// Runs rbx_xml's file full of NaN and infinite values through the binary
+/// format and makes sure every property comes out the way it went in.
+#[test]
+fn xml_inf_and_nan() {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    assert!(path.pop());
+    path.push("rbx_xml/test-files/inf-and-nan.rbxmx");
+
+    let contents = fs::read_to_string(path).unwrap();
+    let tree = rbx_xml::from_str_default(&contents).expect("failed to decode XML model");
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, tree.root().children()).expect("failed to encode model");
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+
+    assert_dom_eq!(tree, decoded);
+}
+
+/// Ensures that a `Rect` read from XML survives a trip through the binary
+/// format and comes back out as the same XML.
+#[test]
+fn xml_rect_round_trip() {
+    let document = r#"
+        <roblox version="4">
+            <Item class="ImageLabel" referent="RBX1">
+                <Properties>
+                    <Rect2D name="SliceCenter">
+                        <min>
+                            <X>12.5</X>
+                            <Y>-30.5</Y>
+                        </min>
+                        <max>
+                            <X>23</X>
+                            <Y>9</Y>
+                        </max>
+                    </Rect2D>
+                </Properties>
+            </Item>
+            <Item class="ImageLabel" referent="RBX2">
+                <Properties>
+                    <Rect2D name="SliceCenter">
+                        <min>
+                            <X>-1</X>
+                            <Y>0</Y>
+                        </min>
+                        <max>
+                            <X>1024</X>
+                            <Y>0.25</Y>
+                        </max>
+                    </Rect2D>
+                </Properties>
+            </Item>
+        </roblox>
+    "#;
+
+    let tree = rbx_xml::from_str_default(document).expect("failed to decode XML model");
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, tree.root().children()).expect("failed to encode model");
+
+    // Rects are written as four interleaved f32 arrays: min X, min Y, max X,
+    // and max Y. Nothing should be left over after reading them back.
+    let model = DecodedModel::from_reader(buffer.as_slice());
+    let slice_centers = model
+        .chunks
+        .into_iter()
+        .find_map(|chunk| match chunk {
+            DecodedChunk::Prop {
+                prop_name,
+                prop_type: DecodedPropType::Known(Type::Rect),
+                values: Some(DecodedValues::Rect(values)),
+                remaining,
+                ..
+            } if prop_name == "SliceCenter" && remaining.is_empty() => Some(values),
+            _ => None,
+        })
+        .expect("missing SliceCenter PROP chunk");
+
+    assert_eq!(
+        slice_centers,
+        [
+            Rect::new(Vector2::new(12.5, -30.5), Vector2::new(23.0, 9.0)),
+            Rect::new(Vector2::new(-1.0, 0.0), Vector2::new(1024.0, 0.25)),
+        ]
+    );
+
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+
+    let mut expected = Vec::new();
+    rbx_xml::to_writer_default(&mut expected, &tree, tree.root().children())
+        .expect("failed to encode XML model");
+
+    let mut actual = Vec::new();
+    rbx_xml::to_writer_default(&mut actual, &decoded, decoded.root().children())
+        .expect("failed to encode XML model");
+
+    let actual = String::from_utf8(actual).unwrap();
+    assert_eq!(String::from_utf8(expected).unwrap(), actual);
+
+    let compact: String = actual.split_whitespace().collect();
+    assert!(compact.contains("<min><X>12.5</X><Y>-30.5</Y></min><max><X>23</X><Y>9</Y></max>"));
+    assert!(compact.contains("<min><X>-1</X><Y>0</Y></min><max><X>1024</X><Y>0.25</Y></max>"));
+}
+
+/// Ensures that two trees containing the same instances inserted in different
+/// orders serialize to the same bytes when children are sorted by name.
+#[test]
+fn sort_children_by_name() {
+    fn build(names: &[&str]) -> WeakDom {
+        WeakDom::new(InstanceBuilder::new("Folder").with_child(
+            InstanceBuilder::new("Folder").with_children(names.iter().map(|name| {
+                InstanceBuilder::new("StringValue")
+                    .with_name(*name)
+                    .with_property("Value", *name)
+                    .with_child(InstanceBuilder::new("Folder").with_name(format!("{} Child", name)))
+            })),
+        ))
+    }
+
+    let encode = |tree: &WeakDom| {
+        let mut buffer = Vec::new();
+        Serializer::new()
+            .sort_children_by_name(true)
+            .serialize(&mut buffer, tree, tree.root().children())
+            .expect("failed to encode model");
+        buffer
+    };
+
+    let first = build(&["Charlie", "Alpha", "Bravo"]);
+    let second = build(&["Bravo", "Charlie", "Alpha"]);
+
+    assert_eq!(encode(&first), encode(&second));
+}
+
+/// Ensures that instances Roblox wouldn't save are left out, along with their
+/// descendants, when `skip_non_archivable` is enabled.
+#[test]
+fn skip_non_archivable() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder").with_name("Kept"))
+            .with_child(
+                InstanceBuilder::new("Folder")
+                    .with_name("Skipped")
+                    .with_property("Archivable", false)
+                    .with_child(InstanceBuilder::new("Folder").with_name("Skipped Child")),
+            ),
+    );
+
+    let encode = |skip_non_archivable| {
+        let mut buffer = Vec::new();
+        Serializer::new()
+            .skip_non_archivable(skip_non_archivable)
+            .serialize(&mut buffer, &tree, &[tree.root_ref()])
+            .expect("failed to encode model");
+
+        from_reader(buffer.as_slice()).expect("failed to decode model")
+    };
+
+    let names = |dom: &WeakDom| -> Vec<String> {
+        let mut names = Vec::new();
+        let mut stack = vec![dom.root_ref()];
+
+        while let Some(referent) = stack.pop() {
+            let instance = dom.get_by_ref(referent).unwrap();
+            names.push(instance.name.clone());
+            stack.extend(instance.children().iter().rev().copied());
+        }
+
+        names
+    };
+
+    let skipped = encode(true);
+    assert_eq!(names(&skipped), ["DataModel", "Folder", "Kept"]);
+
+    let kept = encode(false);
+    assert_eq!(names(&kept).len(), 5);
+}
+
+/// Ensures that a DataModel is written as a place, and anything else as a
+/// model, when the format is detected automatically.
+#[test]
+fn auto_detect_format() {
+    let place = WeakDom::new(
+        InstanceBuilder::new("DataModel")
+            .with_child(
+                InstanceBuilder::new("Workspace")
+                    .with_child(InstanceBuilder::new("Part").with_name("Part")),
+            )
+            .with_child(InstanceBuilder::new("Lighting")),
+    );
+    let model =
+        WeakDom::new(InstanceBuilder::new("Model").with_child(InstanceBuilder::new("Part")));
+
+    let top_level = |dom: &WeakDom, auto_detect_format| -> Vec<String> {
+        let mut buffer = Vec::new();
+        Serializer::new()
+            .auto_detect_format(auto_detect_format)
+            .serialize(&mut buffer, dom, &[dom.root_ref()])
+            .expect("failed to encode model");
+
+        let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+        decoded
+            .root()
+            .children()
+            .iter()
+            .map(|&referent| decoded.get_by_ref(referent).unwrap().class.clone())
+            .collect()
+    };
+
+    assert_eq!(top_level(&place, true), ["Workspace", "Lighting"]);
+    assert_eq!(top_level(&place, false), ["DataModel"]);
+    assert_eq!(top_level(&model, true), ["Model"]);
+}
+
+/// Ensures that the envelope of ColorSequence keypoints survives a round trip,
+/// even though Roblox always sets it to zero.
+#[test]
+fn color_sequence_envelope() {
+    let sequence = ColorSequence {
+        keypoints: vec![
+            ColorSequenceKeypoint {
+                time: 0.0,
+                color: Color3::new(0.0, 0.5, 1.0),
+                envelope: 0.25,
+            },
+            ColorSequenceKeypoint::new(1.0, Color3::new(1.0, 0.5, 0.0)),
+        ],
+    };
+
+    let tree = WeakDom::new(InstanceBuilder::new("Folder").with_child(
+        InstanceBuilder::new("Folder").with_property("HypotheticalSequence", sequence.clone()),
+    ));
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, tree.root().children()).expect("failed to encode model");
+
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+    let folder = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+
+    assert_eq!(
+        folder.properties.get("HypotheticalSequence"),
+        Some(&Variant::ColorSequence(sequence))
+    );
+}
+
+/// Ensures that instances missing a property that has no default in the
+/// reflection database get the value from `Variant::default_for_type`.
+#[test]
+fn fallback_default_values() {
+    let number_sequence = NumberSequence {
+        keypoints: vec![
+            NumberSequenceKeypoint::new(0.0, 1.0, 0.0),
+            NumberSequenceKeypoint::new(1.0, 2.0, 0.0),
+        ],
+    };
+    let color_sequence = ColorSequence {
+        keypoints: vec![
+            ColorSequenceKeypoint::new(0.0, Color3::new(1.0, 1.0, 1.0)),
+            ColorSequenceKeypoint::new(1.0, Color3::new(1.0, 1.0, 1.0)),
+        ],
+    };
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(
+                InstanceBuilder::new("Folder")
+                    .with_property("HypotheticalNumbers", number_sequence)
+                    .with_property("HypotheticalColors", color_sequence)
+                    .with_property("HypotheticalContent", Content::from("rbxasset://a.png"))
+                    .with_property("HypotheticalSource", ProtectedString::from("print(1)")),
+            )
+            .with_child(InstanceBuilder::new("Folder")),
+    );
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, tree.root().children()).expect("failed to encode model");
+
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+    let missing = decoded.get_by_ref(decoded.root().children()[1]).unwrap();
+
+    assert_eq!(
+        missing.properties.get("HypotheticalNumbers"),
+        Some(&Variant::NumberSequence(NumberSequence {
+            keypoints: vec![
+                NumberSequenceKeypoint::new(0.0, 0.0, 0.0),
+                NumberSequenceKeypoint::new(1.0, 0.0, 0.0),
+            ],
+        }))
+    );
+    assert_eq!(
+        missing.properties.get("HypotheticalColors"),
+        Some(&Variant::ColorSequence(ColorSequence {
+            keypoints: vec![
+                ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0)),
+                ColorSequenceKeypoint::new(1.0, Color3::new(0.0, 0.0, 0.0)),
+            ],
+        }))
+    );
+
+    // Both are written as strings, which decode as BinaryString when the
+    // property isn't in the reflection database.
+    assert_eq!(
+        missing.properties.get("HypotheticalContent"),
+        Some(&Variant::BinaryString(BinaryString::new()))
+    );
+    assert_eq!(
+        missing.properties.get("HypotheticalSource"),
+        Some(&Variant::BinaryString(BinaryString::new()))
+    );
+}
+
+/// Ensures that protected strings, like script sources, are written as regular
+/// strings, since the binary format doesn't distinguish between the two.
+#[test]
+fn protected_string() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Script").with_property("Source", ProtectedString::from("print(1)")),
+    );
+
+    let mut buffer = Vec::new();
+    to_writer(&mut buffer, &tree, &[tree.root_ref()]).expect("failed to encode model");
+
+    let decoded = from_reader(buffer.as_slice()).expect("failed to decode model");
+    let script = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+
+    assert_eq!(
+        script.properties.get("Source"),
+        Some(&Variant::String("print(1)".to_owned()))
+    );
+}
+
+#[test]
+fn file_round_trip() {
+    let path = env::temp_dir().join(format!("rbx_binary-file-round-trip-{}.rbxm", process::id()));
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+
+    to_file_default(&path, &tree, &[tree.root_ref()]).unwrap();
+    let decoded = from_file_default(&path);
+    fs::remove_file(&path).unwrap();
+
+    let decoded = decoded.unwrap();
+    let folder = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    let value = decoded.get_by_ref(folder.children()[0]).unwrap();
+
+    assert_eq!(folder.class, "Folder");
+    assert_eq!(value.properties.get("Value"), Some(&Variant::Bool(true)));
+
+    assert!(from_file_default(&path).is_err());
+}
+
+#[test]
+fn file_round_trip_with_options() {
+    let path = env::temp_dir().join(format!(
+        "rbx_binary-file-round-trip-with-options-{}.rbxm",
+        process::id()
+    ));
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+
+    to_file(
+        &path,
+        &tree,
+        &[tree.root_ref()],
+        Serializer::new().all_chunks_uncompressed(true),
+    )
+    .unwrap();
+    let bytes = fs::read(&path).unwrap();
+    let limited = from_file(&path, Deserializer::new().instance_limit(1));
+    let decoded = from_file(&path, Deserializer::new());
+    fs::remove_file(&path).unwrap();
+
+    let mut input = &bytes[32..];
+    while !input.is_empty() {
+        assert!(Chunk::decode_lazy(&mut input).unwrap().is_decompressed());
+    }
+
+    assert!(limited.is_err());
+    assert_eq!(decoded.unwrap().root().children().len(), 1);
+}
+
+#[test]
+fn vec_round_trip() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+
+    let encoded = encode_to_vec(&tree, &[tree.root_ref()]).unwrap();
+
+    let mut expected = Vec::new();
+    to_writer(&mut expected, &tree, &[tree.root_ref()]).unwrap();
+    assert_eq!(encoded, expected);
+
+    let decoded = decode_from_slice(&encoded).unwrap();
+    let folder = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    let value = decoded.get_by_ref(folder.children()[0]).unwrap();
+
+    assert_eq!(folder.class, "Folder");
+    assert_eq!(value.properties.get("Value"), Some(&Variant::Bool(true)));
+
+    assert!(decode_from_slice(&encoded[..encoded.len() / 2]).is_err());
+}
+
+/// Ensures that Color3uint8 values are kept distinct from Color3 values, both
+/// for unknown properties and for known properties of type Color3.
+#[test]
+fn color3uint8_round_trip() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(
+                InstanceBuilder::new("Folder")
+                    .with_property("UnknownColor", Color3uint8::new(1, 128, 255)),
+            )
+            .with_child(
+                InstanceBuilder::new("PointLight")
+                    .with_property("Color", Color3uint8::new(0, 255, 0)),
+            ),
+    );
+
+    let encoded = encode_to_vec(&tree, tree.root().children()).unwrap();
+    let decoded = decode_from_slice(&encoded).unwrap();
+
+    let children = decoded.root().children();
+    let folder = decoded.get_by_ref(children[0]).unwrap();
+    let light = decoded.get_by_ref(children[1]).unwrap();
+
+    assert_eq!(
+        folder.properties.get("UnknownColor"),
+        Some(&Variant::Color3uint8(Color3uint8::new(1, 128, 255)))
+    );
+    assert_eq!(
+        light.properties.get("Color"),
+        Some(&Variant::Color3(Color3::new(0.0, 1.0, 0.0)))
+    );
+}
+
+#[test]
+fn serialization_stats() {
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Part"))
+            .with_child(InstanceBuilder::new("Part"))
+            .with_child(InstanceBuilder::new("Model").with_child(InstanceBuilder::new("Part"))),
+    );
+
+    let mut buffer = Vec::new();
+    let stats = Serializer::new()
+        .serialize_with_stats(&mut buffer, &tree, &[tree.root_ref()])
+        .unwrap();
+
+    let expected_counts = [("Folder", 1), ("Model", 1), ("Part", 3)]
+        .iter()
+        .map(|(class, count)| (class.to_string(), *count))
+        .collect();
+    assert_eq!(stats.class_counts, expected_counts);
+
+    let names: Vec<&[u8; 4]> = stats.chunk_stats.iter().map(|stat| &stat.name).collect();
+    assert_eq!(
+        names,
+        [b"INST", b"INST", b"INST", b"PROP", b"PROP", b"PROP", b"PRNT", b"END\0"]
+    );
+
+    // Each chunk has a 16 byte header, and the file has a 32 byte header.
+    let chunk_total: usize = stats
+        .chunk_stats
+        .iter()
+        .map(|stat| stat.compressed_size + 16)
+        .sum();
+    assert_eq!(chunk_total + 32, buffer.len());
+
+    let end = stats.chunk_stats.last().unwrap();
+    assert_eq!(end.compressed_size, end.uncompressed_size);
+}
+
+/// Ensures that both present and missing OptionalCFrame values survive a round
+/// trip, including when they're mixed in the same PROP chunk.
+#[test]
+fn optional_cframe_round_trip() {
+    let cframe = CFrame::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        Matrix3 {
+            x: Vector3::new(0.0, 1.0, 0.0),
+            y: Vector3::new(1.0, 0.0, 0.0),
+            z: Vector3::new(0.0, 0.0, -1.0),
+        },
+    );
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder").with_property("Pivot", Some(cframe)))
+            .with_child(
+                InstanceBuilder::new("Folder").with_property("Pivot", Option::<CFrame>::None),
+            ),
+    );
+
+    let encoded = encode_to_vec(&tree, tree.root().children()).unwrap();
+    let decoded = decode_from_slice(&encoded).unwrap();
+
+    let children = decoded.root().children();
+    let some = decoded.get_by_ref(children[0]).unwrap();
+    let none = decoded.get_by_ref(children[1]).unwrap();
+
+    assert_eq!(
+        some.properties.get("Pivot"),
+        Some(&Variant::OptionalCFrame(Some(cframe)))
+    );
+    assert_eq!(
+        none.properties.get("Pivot"),
+        Some(&Variant::OptionalCFrame(None))
+    );
+}
+
+#[test]
+fn ref_round_trip() {
+    let part = InstanceBuilder::new("Part").with_name("Part");
+    let part_ref = part.referent();
+    let outside = InstanceBuilder::new("Part").with_name("Outside");
+    let outside_ref = outside.referent();
+
+    let model = InstanceBuilder::new("Model")
+        .with_name("Model")
+        .with_property("PrimaryPart", part_ref)
+        .with_child(part);
+    let model_ref = model.referent();
+
+    let empty_model = InstanceBuilder::new("Model")
+        .with_name("EmptyModel")
+        .with_property("PrimaryPart", Ref::none());
+    let empty_model_ref = empty_model.referent();
+
+    let selection_box = InstanceBuilder::new("SelectionBox")
+        .with_name("SelectionBox")
+        .with_property("Adornee", outside_ref);
+    let selection_box_ref = selection_box.referent();
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(model)
+            .with_child(empty_model)
+            .with_child(selection_box)
+            .with_child(outside),
+    );
+
+    // Leave out the instance that the SelectionBox points to, which should
+    // make its Adornee null.
+    let encoded = encode_to_vec(&tree, &[model_ref, empty_model_ref, selection_box_ref]).unwrap();
+    let decoded = decode_from_slice(&encoded).unwrap();
+
+    let children = decoded.root().children();
+    assert_eq!(children.len(), 3);
+
+    let model = decoded.get_by_ref(children[0]).unwrap();
+    let empty_model = decoded.get_by_ref(children[1]).unwrap();
+    let selection_box = decoded.get_by_ref(children[2]).unwrap();
+
+    assert_eq!(
+        model.properties.get("PrimaryPart"),
+        Some(&Variant::Ref(model.children()[0]))
+    );
+    assert_eq!(
+        empty_model.properties.get("PrimaryPart"),
+        Some(&Variant::Ref(Ref::none()))
+    );
+    assert_eq!(
+        selection_box.properties.get("Adornee"),
+        Some(&Variant::Ref(Ref::none()))
+    );
+}
+
+/// Unlike most number types in the binary format, Float64 values are stored as
+/// plain little-endian doubles instead of being byte-interleaved.
+#[test]
+fn float64_round_trip() {
+    let values = [
+        f64::MAX,
+        f64::MIN,
+        f64::MIN_POSITIVE,
+        -0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+
+    let tree = WeakDom::new(
+        InstanceBuilder::new("Folder").with_children(
+            values
+                .iter()
+                .map(|&value| InstanceBuilder::new("NumberValue").with_property("Value", value)),
+        ),
+    );
+
+    let encoded = encode_to_vec(&tree, tree.root().children()).unwrap();
+
+    // PROP chunks hold a type ID, a property name, and a type byte before
+    // their values.
+    let expected: Vec<u8> = values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect();
+    let mut input = &encoded[32..];
+    let prop = loop {
+        let chunk = Chunk::decode(&mut input).unwrap();
+
+        if &chunk.name == b"PROP" && chunk.data[8..13] == *b"Value" {
+            break chunk;
+        }
+    };
+    assert_eq!(prop.data[13], Type::Float64 as u8);
+    assert_eq!(prop.data[14..], expected[..]);
+
+    let decoded = decode_from_slice(&encoded).unwrap();
+    let decoded_values: Vec<u64> = decoded
+        .root()
+        .children()
+        .iter()
+        .map(|&referent| {
+            match decoded
+                .get_by_ref(referent)
+                .unwrap()
+                .properties
+                .get("Value")
+            {
+                Some(Variant::Float64(value)) => value.to_bits(),
+                other => panic!("expected a Float64, got {:?}", other),
+            }
+        })
+        .collect();
+    let expected_values: Vec<u64> = values.iter().map(|value| value.to_bits()).collect();
+
+    assert_eq!(decoded_values, expected_values);
+}
+
+#[test]
+fn metadata_round_trip() {
+    let tree = WeakDom::new(InstanceBuilder::new("Folder"));
+
+    let mut metadata = HashMap::new();
+    metadata.insert("ExplicitAutoJoints".to_owned(), "true".to_owned());
+    metadata.insert("CreatedBy".to_owned(), "rbx_binary".to_owned());
+    metadata.insert("Empty".to_owned(), String::new());
+
+    let mut encoded = Vec::new();
+    Serializer::new()
+        .metadata(metadata.clone())
+        .serialize(&mut encoded, &tree, &[tree.root_ref()])
+        .unwrap();
+
+    let decoded = Deserializer::new()
+        .deserialize_full(encoded.as_slice())
+        .unwrap();
+    assert_eq!(decoded.metadata, metadata);
+
+    // Files written without metadata decode with none.
+    let encoded = encode_to_vec(&tree, &[tree.root_ref()]).unwrap();
+    let decoded = Deserializer::new()
+        .deserialize_full(encoded.as_slice())
+        .unwrap();
+    assert!(decoded.metadata.is_empty());
+}
@@ -0,0 +1,33 @@
+use rbx_dom_weak::{InstanceBuilder, WeakDom};
+use rbx_test_util::SpanRecorder;
+
+use crate::{from_reader, to_writer};
+
+#[test]
+fn spans_have_fields() {
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Part").with_property("Transparency", 0.5f32)),
+    );
+
+    let recorder = SpanRecorder::record(|| {
+        let mut buffer = Vec::new();
+        to_writer(&mut buffer, &dom, dom.root().children()).unwrap();
+        from_reader(buffer.as_slice()).unwrap();
+    });
+
+    assert!(recorder.has_span("encode_chunk", &[("chunk", "INST"), ("type_name", "Part")]));
+    assert!(recorder.has_span(
+        "encode_chunk",
+        &[
+            ("chunk", "PROP"),
+            ("type_name", "Part"),
+            ("prop_name", "Transparency")
+        ]
+    ));
+    assert!(recorder.has_span("decode_chunk", &[("chunk", "PRNT")]));
+    assert!(recorder.has_span(
+        "decode_property",
+        &[("type_name", "Part"), ("prop_name", "Transparency")]
+    ));
+}
@@ -10,7 +10,7 @@ use rbx_dom_weak::types::{
     Axes, BrickColor, CFrame, Color3, Color3uint8, ColorSequence, ColorSequenceKeypoint,
     CustomPhysicalProperties, Enum, Faces, Matrix3, NumberRange, NumberSequence,
     NumberSequenceKeypoint, PhysicalProperties, Ray, Rect, SharedString, UDim, UDim2, Vector2,
-    Vector3, Vector3int16,
+    Vector2int16, Vector3, Vector3int16,
 };
 use serde::{ser::SerializeSeq, Serialize, Serializer};
 
@@ -212,6 +212,7 @@ pub enum DecodedValues {
     CFrame(Vec<CFrame>),
     Enum(Vec<Enum>),
     Ref(Vec<i32>),
+    Vector2int16(Vec<Vector2int16>),
     Vector3int16(Vec<Vector3int16>),
     NumberSequence(Vec<NumberSequence>),
     ColorSequence(Vec<ColorSequence>),
@@ -477,17 +478,15 @@ impl DecodedValues {
                     let mut keypoints = Vec::with_capacity(keypoint_count);
 
                     for _ in 0..keypoint_count {
-                        keypoints.push(ColorSequenceKeypoint::new(
-                            reader.read_le_f32().unwrap(),
-                            Color3::new(
+                        keypoints.push(ColorSequenceKeypoint {
+                            time: reader.read_le_f32().unwrap(),
+                            color: Color3::new(
                                 reader.read_le_f32().unwrap(),
                                 reader.read_le_f32().unwrap(),
                                 reader.read_le_f32().unwrap(),
                             ),
-                        ));
-
-                        // envelope is serialized but doesn't do anything; don't do anything with it
-                        reader.read_le_f32().unwrap();
+                            envelope: reader.read_le_f32().unwrap(),
+                        });
                     }
 
                     values.push(ColorSequence { keypoints })
@@ -495,6 +494,18 @@ impl DecodedValues {
 
                 Some(DecodedValues::ColorSequence(values))
             }
+            Type::Vector2int16 => {
+                let mut values = Vec::with_capacity(prop_count);
+
+                for _ in 0..prop_count {
+                    values.push(Vector2int16::new(
+                        reader.read_le_i16().unwrap(),
+                        reader.read_le_i16().unwrap(),
+                    ));
+                }
+
+                Some(DecodedValues::Vector2int16(values))
+            }
             Type::Vector3int16 => {
                 let mut values = Vec::with_capacity(prop_count);
 
@@ -26,6 +26,7 @@ pub enum Type {
     Color3 = 0x0C,
     Vector2 = 0x0D,
     Vector3 = 0x0E,
+    Vector2int16 = 0x0F,
     CFrame = 0x10,
     Enum = 0x12,
     Ref = 0x13,
@@ -48,6 +49,9 @@ impl Type {
             VariantType::String => Type::String,
             VariantType::BinaryString => Type::String,
             VariantType::Content => Type::String,
+            VariantType::ProtectedString => Type::String,
+            VariantType::Tags => Type::String,
+            VariantType::Attributes => Type::String,
 
             VariantType::Bool => Type::Bool,
             VariantType::Int32 => Type::Int32,
@@ -62,6 +66,7 @@ impl Type {
             VariantType::Color3 => Type::Color3,
             VariantType::Vector2 => Type::Vector2,
             VariantType::Vector3 => Type::Vector3,
+            VariantType::Vector2int16 => Type::Vector2int16,
             VariantType::CFrame => Type::CFrame,
             VariantType::Enum => Type::Enum,
             VariantType::Ref => Type::Ref,
@@ -97,6 +102,7 @@ impl Type {
             Type::Color3 => VariantType::Color3,
             Type::Vector2 => VariantType::Vector2,
             Type::Vector3 => VariantType::Vector3,
+            Type::Vector2int16 => VariantType::Vector2int16,
             Type::CFrame => VariantType::CFrame,
             Type::Enum => VariantType::Enum,
             Type::Ref => VariantType::Ref,
@@ -135,6 +141,7 @@ impl TryFrom<u8> for Type {
             0x0C => Color3,
             0x0D => Vector2,
             0x0E => Vector3,
+            0x0F => Vector2int16,
             0x10 => CFrame,
             0x12 => Enum,
             0x13 => Ref,
@@ -23,8 +23,41 @@ end
 local ALL_AXES = {"X", "Y", "Z"}
 local ALL_FACES = {"Right", "Top", "Back", "Left", "Bottom", "Front"}
 
+-- Attribute values that don't share a name with their encoded type.
+local ATTRIBUTE_TYPES = {
+	boolean = "Bool",
+	number = "Float64",
+	string = "String",
+}
+
 local types
 types = {
+	Attributes = {
+		fromPod = function(pod)
+			local attributes = {}
+
+			for name, encoded in pairs(pod) do
+				local ty, value = next(encoded)
+				attributes[name] = types[ty].fromPod(value)
+			end
+
+			return attributes
+		end,
+
+		toPod = function(roblox)
+			local pod = {}
+
+			for name, value in pairs(roblox) do
+				local ty = ATTRIBUTE_TYPES[typeof(value)] or typeof(value)
+				pod[name] = {
+					[ty] = types[ty].toPod(value),
+				}
+			end
+
+			return pod
+		end,
+	},
+
 	Axes = {
 		fromPod = function(pod)
 			local axes = {}
@@ -364,6 +397,11 @@ types = {
 		toPod = identity,
 	},
 
+	Tags = {
+		fromPod = identity,
+		toPod = identity,
+	},
+
 	UDim = {
 		fromPod = unpackDecoder(UDim.new),
 
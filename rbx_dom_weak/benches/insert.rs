@@ -0,0 +1,92 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use rbx_dom_weak::{InstanceBuilder, WeakDom};
+
+pub fn insert_10000(c: &mut Criterion) {
+    c.bench_function("Insert 10,000 instances one at a time", |b| {
+        b.iter_batched(
+            || (0..100).map(|_| (0..99).map(part).collect()).collect(),
+            |branches: Vec<Vec<InstanceBuilder>>| {
+                let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+                let root_ref = dom.root_ref();
+
+                for parts in branches {
+                    let branch = dom.insert(root_ref, InstanceBuilder::new("Folder"));
+
+                    for part in parts {
+                        dom.insert(branch, part);
+                    }
+                }
+
+                dom
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    c.bench_function("Insert 10,000 instances with build_into_dom", |b| {
+        b.iter_batched(
+            || {
+                (0..100)
+                    .map(|_| InstanceBuilder::new("Folder").with_children((0..99).map(part)))
+                    .collect()
+            },
+            |branches: Vec<InstanceBuilder>| {
+                let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+                let root_ref = dom.root_ref();
+
+                for branch in branches {
+                    branch.build_into_dom(&mut dom, root_ref);
+                }
+
+                dom
+            },
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+pub fn reserve_100000(c: &mut Criterion) {
+    let insert_parts = |reserve: bool| {
+        move |parts: Vec<InstanceBuilder>| {
+            let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+            let root_ref = dom.root_ref();
+
+            if reserve {
+                dom.reserve(parts.len());
+            }
+
+            for part in parts {
+                dom.insert(root_ref, part);
+            }
+
+            dom
+        }
+    };
+
+    c.bench_function("Insert 100,000 instances without reserving", |b| {
+        b.iter_batched(
+            || (0..100_000).map(part).collect(),
+            insert_parts(false),
+            BatchSize::LargeInput,
+        );
+    });
+
+    c.bench_function("Insert 100,000 instances after reserving", |b| {
+        b.iter_batched(
+            || (0..100_000).map(part).collect(),
+            insert_parts(true),
+            BatchSize::LargeInput,
+        );
+    });
+}
+
+fn part(i: usize) -> InstanceBuilder {
+    InstanceBuilder::new("Part")
+        .with_name(format!("Part{}", i))
+        .with_property("Anchored", true)
+        .with_property("Transparency", 0.5f32)
+}
+
+criterion_group!(insert, insert_10000, reserve_100000);
+criterion_main!(insert);
@@ -0,0 +1,50 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use rbx_dom_weak::{types::Ref, InstanceBuilder, WeakDom};
+
+pub fn dfs_50000(c: &mut Criterion) {
+    let dom = wide_tree(50, 999);
+
+    c.bench_function("Depth-first traversal of 50,000 instances", |b| {
+        b.iter(|| dfs(&dom));
+    });
+}
+
+/// Builds a tree with `branches` Folders under the root, each containing
+/// `leaves` Parts with a couple of properties.
+fn wide_tree(branches: usize, leaves: usize) -> WeakDom {
+    let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+    let root_ref = dom.root_ref();
+
+    for _ in 0..branches {
+        let branch = dom.insert(root_ref, InstanceBuilder::new("Folder"));
+
+        for i in 0..leaves {
+            dom.insert(
+                branch,
+                InstanceBuilder::new("Part")
+                    .with_name(format!("Part{}", i))
+                    .with_property("Anchored", true)
+                    .with_property("Transparency", 0.5f32),
+            );
+        }
+    }
+
+    dom
+}
+
+fn dfs(dom: &WeakDom) -> usize {
+    let mut total = 0;
+    let mut stack: Vec<Ref> = vec![dom.root_ref()];
+
+    while let Some(referent) = stack.pop() {
+        let instance = dom.get_by_ref(referent).unwrap();
+        total += instance.name.len() + instance.properties.len();
+        stack.extend(instance.children().iter().rev().copied());
+    }
+
+    total
+}
+
+criterion_group!(traversal, dfs_50000);
+criterion_main!(traversal);
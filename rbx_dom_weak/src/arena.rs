@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use rbx_types::Ref;
+
+use crate::instance::Instance;
+
+/// Storage for the instances in a `WeakDom`.
+///
+/// Instances live next to each other in a single `Vec`, which keeps traversals
+/// that touch many instances friendly to the cache. Refs stay the public
+/// identity of an instance, so we keep an index from each Ref to the slot its
+/// instance lives in.
+#[derive(Debug, Default)]
+pub(crate) struct InstanceArena {
+    instances: Vec<Instance>,
+    slots: HashMap<Ref, usize>,
+}
+
+impl InstanceArena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, referent: Ref) -> Option<&Instance> {
+        let slot = *self.slots.get(&referent)?;
+        Some(&self.instances[slot])
+    }
+
+    pub fn get_mut(&mut self, referent: Ref) -> Option<&mut Instance> {
+        let slot = *self.slots.get(&referent)?;
+        Some(&mut self.instances[slot])
+    }
+
+    /// Reserves space for at least `additional` more instances.
+    pub fn reserve(&mut self, additional: usize) {
+        self.instances.reserve(additional);
+        self.slots.reserve(additional);
+    }
+
+    /// Adds an instance to the arena, replacing any existing instance with the
+    /// same referent.
+    pub fn insert(&mut self, instance: Instance) {
+        match self.slots.get(&instance.referent) {
+            Some(&slot) => self.instances[slot] = instance,
+            None => {
+                self.slots.insert(instance.referent, self.instances.len());
+                self.instances.push(instance);
+            }
+        }
+    }
+
+    /// Removes an instance from the arena. To keep instances contiguous, the
+    /// last instance in the arena is moved into the slot that was freed.
+    pub fn remove(&mut self, referent: Ref) -> Option<Instance> {
+        let slot = self.slots.remove(&referent)?;
+        let instance = self.instances.swap_remove(slot);
+
+        if let Some(moved) = self.instances.get(slot) {
+            self.slots.insert(moved.referent, slot);
+        }
+
+        Some(instance)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Instance> {
+        self.instances.iter()
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Instance> {
+        self.instances.iter_mut()
+    }
+}
+
+/// The arena is serialized as a map from referent to instance, which doesn't
+/// expose how instances are laid out in memory.
+#[cfg(feature = "serde")]
+impl serde::Serialize for InstanceArena {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter().map(|instance| (instance.referent, instance)))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for InstanceArena {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let instances: HashMap<Ref, Instance> = serde::Deserialize::deserialize(deserializer)?;
+
+        let mut arena = InstanceArena::new();
+        for (referent, instance) in instances {
+            if referent != instance.referent {
+                return Err(serde::de::Error::custom(format!(
+                    "instance stored under referent {} has referent {}",
+                    referent, instance.referent
+                )));
+            }
+
+            arena.insert(instance);
+        }
+
+        Ok(arena)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn instance(name: &str) -> Instance {
+        Instance {
+            referent: Ref::new(),
+            children: Vec::new(),
+            parent: Ref::none(),
+            name: name.to_owned(),
+            class: "Folder".to_owned(),
+            properties: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn remove_keeps_slots_valid() {
+        let mut arena = InstanceArena::new();
+
+        let refs: Vec<Ref> = ["A", "B", "C", "D"]
+            .iter()
+            .map(|name| {
+                let instance = instance(name);
+                let referent = instance.referent;
+                arena.insert(instance);
+                referent
+            })
+            .collect();
+
+        assert_eq!(arena.remove(refs[1]).unwrap().name, "B");
+        assert_eq!(arena.remove(refs[0]).unwrap().name, "A");
+        assert!(arena.remove(refs[0]).is_none());
+
+        assert_eq!(arena.iter().count(), 2);
+        assert_eq!(arena.get(refs[2]).unwrap().name, "C");
+        assert_eq!(arena.get(refs[3]).unwrap().name, "D");
+        assert!(arena.get(refs[1]).is_none());
+    }
+
+    #[test]
+    fn insert_replaces() {
+        let mut arena = InstanceArena::new();
+
+        let first = instance("First");
+        let referent = first.referent;
+        arena.insert(first);
+
+        let mut second = instance("Second");
+        second.referent = referent;
+        arena.insert(second);
+
+        assert_eq!(arena.iter().count(), 1);
+        assert_eq!(arena.get(referent).unwrap().name, "Second");
+    }
+}
@@ -0,0 +1,237 @@
+use std::{convert::TryFrom, sync::Arc};
+
+use rbx_reflection::{DataType, PropertyKind};
+use rbx_types::{BrickColor, Color3, Ref, Variant, VariantType};
+
+use crate::{validation::find_property, WeakDom};
+
+/// A property that needs to be renamed, converted, or both to be canonical.
+struct Change {
+    name: Arc<str>,
+    canonical_name: String,
+    expected_type: Option<VariantType>,
+}
+
+/// Renames every property in the DOM to its canonical name according to the
+/// reflection database, converting values to the canonical property's type
+/// when a conversion is known.
+///
+/// This is useful after deserializing a file with reflection turned off, which
+/// leaves properties with their serialized names, like `formFactorRaw` instead
+/// of `FormFactor` and `Color3uint8` instead of `Color`.
+///
+/// Properties that aren't in the reflection database are left alone. If a
+/// property can't be converted to the canonical type, it keeps its original
+/// name and value. If an instance has both a property and its canonical form,
+/// the canonical property is kept.
+///
+/// Renames and conversions are logged at the `info` level.
+pub fn canonicalize_properties(dom: &mut WeakDom) {
+    let database = rbx_reflection_database::get();
+
+    let mut stack: Vec<Ref> = vec![dom.root_ref()];
+
+    while let Some(referent) = stack.pop() {
+        let instance = dom.get_by_ref(referent).unwrap();
+        stack.extend(instance.children().iter().copied());
+
+        let mut changes = Vec::new();
+
+        for (name, value) in &instance.properties {
+            let mut descriptor = match find_property(database, &instance.class, name) {
+                Some(descriptor) => descriptor,
+                None => continue,
+            };
+
+            if let PropertyKind::Alias { alias_for } = &descriptor.kind {
+                descriptor = match find_property(database, &instance.class, alias_for) {
+                    Some(canonical) => canonical,
+                    None => continue,
+                };
+            }
+
+            let expected_type = match &descriptor.data_type {
+                DataType::Value(ty) => Some(*ty),
+                DataType::Enum(_) => Some(VariantType::Enum),
+                _ => None,
+            };
+
+            let needs_rename = descriptor.name != name.as_ref();
+            // The reflection database describes protected strings as strings,
+            // but we want to keep them distinct.
+            let needs_conversion = match expected_type {
+                Some(ty) => {
+                    ty != value.ty()
+                        && !(ty == VariantType::String
+                            && value.ty() == VariantType::ProtectedString)
+                }
+                None => false,
+            };
+
+            if needs_rename || needs_conversion {
+                changes.push(Change {
+                    name: Arc::clone(name),
+                    canonical_name: descriptor.name.to_string(),
+                    expected_type,
+                });
+            }
+        }
+
+        for change in changes {
+            let canonical_name = dom.intern_property_name(&change.canonical_name);
+            let instance = dom.get_by_ref_mut(referent).unwrap();
+
+            if change.name != canonical_name && instance.properties.contains_key(&canonical_name) {
+                log::info!(
+                    "Removed {}.{} because {} was already set",
+                    instance.class,
+                    change.name,
+                    canonical_name
+                );
+                instance.properties.remove(&change.name);
+                continue;
+            }
+
+            let value = instance.properties.remove(&change.name).unwrap();
+            let original_type = value.ty();
+
+            let value = match change.expected_type {
+                Some(ty) if ty != original_type => match convert(value, ty) {
+                    Ok(converted) => {
+                        log::info!(
+                            "Converted {}.{} from {:?} to {:?}",
+                            instance.class,
+                            change.name,
+                            original_type,
+                            ty
+                        );
+                        converted
+                    }
+                    Err(original) => {
+                        log::info!(
+                            "Could not convert {}.{} from {:?} to {:?}, leaving it alone",
+                            instance.class,
+                            change.name,
+                            original_type,
+                            ty
+                        );
+                        instance.properties.insert(change.name, original);
+                        continue;
+                    }
+                },
+                _ => value,
+            };
+
+            if change.name != canonical_name {
+                log::info!(
+                    "Renamed {}.{} to {}",
+                    instance.class,
+                    change.name,
+                    canonical_name
+                );
+            }
+
+            instance.properties.insert(canonical_name, value);
+        }
+    }
+}
+
+/// Converts a value to the given type if we know how, handing the value back
+/// otherwise.
+fn convert(value: Variant, target: VariantType) -> Result<Variant, Variant> {
+    match (value, target) {
+        (Variant::Color3uint8(color), VariantType::Color3) => Ok(Color3::from(color).into()),
+        (Variant::BrickColor(color), VariantType::Color3) => {
+            Ok(Color3::from(color.to_color3uint8()).into())
+        }
+        (Variant::BrickColor(color), VariantType::Color3uint8) => Ok(color.to_color3uint8().into()),
+        (Variant::Int32(number), VariantType::BrickColor) => u16::try_from(number)
+            .ok()
+            .and_then(BrickColor::from_number)
+            .map(Variant::BrickColor)
+            .ok_or(Variant::Int32(number)),
+        (value, _) => Err(value),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_types::{Color3uint8, Enum, Vector3};
+
+    use crate::InstanceBuilder;
+
+    #[test]
+    fn serialized_names() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder").with_child(
+                InstanceBuilder::new("Part")
+                    .with_property("formFactorRaw", Enum::from_u32(1))
+                    .with_property("Color3uint8", Color3uint8::new(255, 0, 51))
+                    .with_property("size", Vector3::new(1.0, 2.0, 3.0))
+                    .with_property("NotARealProperty", true),
+            ),
+        );
+
+        canonicalize_properties(&mut dom);
+
+        let part = dom.get_by_ref(dom.root().children()[0]).unwrap();
+        let mut names: Vec<&str> = part.properties.keys().map(|key| key.as_ref()).collect();
+        names.sort_unstable();
+
+        assert_eq!(names, ["Color", "FormFactor", "NotARealProperty", "Size"]);
+        assert_eq!(
+            part.properties.get("FormFactor"),
+            Some(&Variant::Enum(Enum::from_u32(1)))
+        );
+        assert_eq!(
+            part.properties.get("Color"),
+            Some(&Variant::Color3(Color3::new(1.0, 0.0, 0.2)))
+        );
+    }
+
+    #[test]
+    fn brick_color() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Part").with_property("BrickColor", BrickColor::ReallyRed),
+        );
+
+        canonicalize_properties(&mut dom);
+
+        assert_eq!(
+            dom.root().properties.get("Color"),
+            Some(&Variant::Color3(Color3::new(1.0, 0.0, 0.0)))
+        );
+        assert!(!dom.root().properties.contains_key("BrickColor"));
+    }
+
+    #[test]
+    fn canonical_wins() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Part")
+                .with_property("Color", Color3::new(0.0, 1.0, 0.0))
+                .with_property("Color3uint8", Color3uint8::new(255, 0, 0)),
+        );
+
+        canonicalize_properties(&mut dom);
+
+        assert_eq!(dom.root().properties.len(), 1);
+        assert_eq!(
+            dom.root().properties.get("Color"),
+            Some(&Variant::Color3(Color3::new(0.0, 1.0, 0.0)))
+        );
+    }
+
+    #[test]
+    fn unconvertible() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Part").with_property("Color3uint8", 5));
+
+        canonicalize_properties(&mut dom);
+
+        assert_eq!(
+            dom.root().properties.get("Color3uint8"),
+            Some(&Variant::Int32(5))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Aliases for the names that rbx_dom_weak 1.x used, so that code written
+//! against them still compiles, with deprecation warnings that point at their
+//! replacements.
+//!
+//! There's no alias for `RbxInstanceProperties`. It was a struct that was built
+//! with its fields, which [`InstanceBuilder`][crate::InstanceBuilder] doesn't
+//! have, so code using it needs to be changed by hand either way.
+
+use rbx_types::{Ref, Variant, VariantType};
+
+use crate::{Instance, WeakDom};
+
+/// The old name for [`WeakDom`].
+#[deprecated(since = "2.0.0", note = "RbxTree was replaced with WeakDom")]
+pub type RbxTree = WeakDom;
+
+/// The old name for [`Instance`].
+#[deprecated(since = "2.0.0", note = "RbxInstance was replaced with Instance")]
+pub type RbxInstance = Instance;
+
+/// The old name for [`Ref`][crate::types::Ref].
+#[deprecated(since = "2.0.0", note = "RbxId was replaced with types::Ref")]
+pub type RbxId = Ref;
+
+/// The old name for [`Variant`][crate::types::Variant].
+#[deprecated(since = "2.0.0", note = "RbxValue was replaced with types::Variant")]
+pub type RbxValue = Variant;
+
+/// The old name for [`VariantType`][crate::types::VariantType].
+#[deprecated(
+    since = "2.0.0",
+    note = "RbxValueType was replaced with types::VariantType"
+)]
+pub type RbxValueType = VariantType;
+
+#[cfg(test)]
+mod test {
+    #[allow(deprecated)]
+    use crate::{InstanceBuilder, RbxId, RbxTree, RbxValue};
+
+    #[test]
+    #[allow(deprecated)]
+    fn old_names() {
+        let tree: RbxTree = RbxTree::new(InstanceBuilder::new("Folder"));
+        let id: RbxId = tree.root_ref();
+        let value: RbxValue = RbxValue::Ref(id);
+
+        assert_eq!(tree.root().class, "Folder");
+        assert_eq!(value, crate::types::Variant::Ref(tree.root_ref()));
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use rbx_types::Variant;
+
+use crate::{Instance, WeakDom};
+
+/// A property whose value is different between two DOMs. Returned by
+/// [`diff_properties`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct PropertyChange<'a> {
+    /// The full name of the instance that changed, as returned by
+    /// [`WeakDom::full_name_of`].
+    pub path: String,
+
+    /// The name of the property that changed.
+    pub property: &'a str,
+
+    /// The property's value in the base DOM, or `None` if the property was
+    /// added.
+    pub old_value: Option<&'a Variant>,
+
+    /// The property's value in the changed DOM, or `None` if the property was
+    /// removed.
+    pub new_value: Option<&'a Variant>,
+}
+
+/// Finds every property that was added, removed, or changed between `base` and
+/// `changed`.
+///
+/// Referents are different in every DOM, so instances are matched up by their
+/// full name instead. If several instances share a full name, they're matched
+/// up in the order they appear in each DOM. Instances that only exist in one
+/// of the DOMs are ignored.
+///
+/// `Ref` properties are considered equal if they point to instances with the
+/// same full name in each DOM.
+///
+/// Changes are sorted by path, then by property name.
+pub fn diff_properties<'a>(base: &'a WeakDom, changed: &'a WeakDom) -> Vec<PropertyChange<'a>> {
+    let base_paths = instances_by_path(base);
+    let mut changed_paths = instances_by_path(changed);
+
+    let mut changes = Vec::new();
+
+    for (path, base_instances) in base_paths {
+        let changed_instances = match changed_paths.remove(&path) {
+            Some(instances) => instances,
+            None => continue,
+        };
+
+        for (old, new) in base_instances.into_iter().zip(changed_instances) {
+            let names: BTreeSet<&str> = old
+                .properties
+                .keys()
+                .chain(new.properties.keys())
+                .map(|name| name.as_ref())
+                .collect();
+
+            for name in names {
+                let old_value = old.properties.get(name);
+                let new_value = new.properties.get(name);
+
+                let same = match (old_value, new_value) {
+                    (Some(Variant::Ref(old_ref)), Some(Variant::Ref(new_ref))) => {
+                        base.full_name_of(*old_ref) == changed.full_name_of(*new_ref)
+                    }
+                    _ => old_value == new_value,
+                };
+
+                if !same {
+                    changes.push(PropertyChange {
+                        path: path.clone(),
+                        property: name,
+                        old_value,
+                        new_value,
+                    });
+                }
+            }
+        }
+    }
+
+    changes
+}
+
+/// Groups every instance in the DOM by its full name, keeping instances with
+/// the same name in the order they were found.
+fn instances_by_path(dom: &WeakDom) -> BTreeMap<String, Vec<&Instance>> {
+    let mut paths: BTreeMap<String, Vec<&Instance>> = BTreeMap::new();
+    let mut to_visit = VecDeque::new();
+    to_visit.push_back((dom.root_ref(), dom.root().name.clone()));
+
+    while let Some((referent, path)) = to_visit.pop_front() {
+        let instance = dom.get_by_ref(referent).unwrap();
+
+        for &child_ref in instance.children() {
+            let child = dom.get_by_ref(child_ref).unwrap();
+            to_visit.push_back((child_ref, format!("{}.{}", path, child.name)));
+        }
+
+        paths.entry(path).or_default().push(instance);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::InstanceBuilder;
+
+    fn make_dom(transparency: f32, child_name: &str) -> WeakDom {
+        let part = InstanceBuilder::new("Part")
+            .with_name("Part")
+            .with_property("Transparency", transparency)
+            .with_property("Anchored", true);
+        let part_ref = part.referent();
+
+        WeakDom::new(
+            InstanceBuilder::new("DataModel").with_child(
+                InstanceBuilder::new("Workspace")
+                    .with_name("Workspace")
+                    .with_child(
+                        InstanceBuilder::new("Model")
+                            .with_name("Model")
+                            .with_property("PrimaryPart", part_ref)
+                            .with_child(part),
+                    )
+                    .with_child(InstanceBuilder::new("Folder").with_name(child_name)),
+            ),
+        )
+    }
+
+    #[test]
+    fn unchanged() {
+        let base = make_dom(0.5, "Folder");
+        let changed = make_dom(0.5, "Folder");
+
+        assert_eq!(diff_properties(&base, &changed), Vec::new());
+    }
+
+    #[test]
+    fn changed_property() {
+        let base = make_dom(0.5, "Folder");
+        let changed = make_dom(1.0, "Folder");
+
+        assert_eq!(
+            diff_properties(&base, &changed),
+            vec![PropertyChange {
+                path: "DataModel.Workspace.Model.Part".to_owned(),
+                property: "Transparency",
+                old_value: Some(&Variant::Float32(0.5)),
+                new_value: Some(&Variant::Float32(1.0)),
+            }]
+        );
+    }
+
+    #[test]
+    fn added_and_removed_properties() {
+        let base = make_dom(0.5, "Folder");
+        let mut changed = make_dom(0.5, "Folder");
+
+        let model_ref = changed.root().children()[0];
+        let model_ref = changed.get_by_ref(model_ref).unwrap().children()[0];
+        let model = changed.get_by_ref_mut(model_ref).unwrap();
+        model.properties.remove("PrimaryPart");
+        model
+            .properties
+            .insert("LevelOfDetail".into(), Variant::Int32(1));
+
+        let changes = diff_properties(&base, &changed);
+        assert_eq!(changes.len(), 2);
+
+        assert_eq!(changes[0].path, "DataModel.Workspace.Model");
+        assert_eq!(changes[0].property, "LevelOfDetail");
+        assert_eq!(changes[0].old_value, None);
+        assert_eq!(changes[0].new_value, Some(&Variant::Int32(1)));
+
+        assert_eq!(changes[1].property, "PrimaryPart");
+        assert!(changes[1].old_value.is_some());
+        assert_eq!(changes[1].new_value, None);
+    }
+
+    #[test]
+    fn unmatched_instances_are_ignored() {
+        let base = make_dom(0.5, "Folder");
+        let changed = make_dom(0.5, "Renamed");
+
+        assert_eq!(diff_properties(&base, &changed), Vec::new());
+    }
+}
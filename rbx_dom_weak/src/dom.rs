@@ -1,8 +1,16 @@
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
 
-use rbx_types::Ref;
+use rbx_types::{AttributeError, Attributes, Ref, Variant};
 
-use crate::instance::{Instance, InstanceBuilder};
+use crate::{
+    arena::InstanceArena,
+    error::DomError,
+    instance::{Instance, InstanceBuilder},
+    interner::StringInterner,
+};
 
 /// Represents a DOM containing one or more Roblox instances.
 ///
@@ -11,33 +19,58 @@ use crate::instance::{Instance, InstanceBuilder};
 ///
 /// When constructing instances, you'll want to create [`InstanceBuilder`]
 /// objects and insert them into the tree.
+///
+/// When the `serde` feature is enabled, `WeakDom` can be serialized. The root
+/// referent is stored as `root_ref` and every instance is stored in a flat map
+/// named `instances`, keyed by referent. Deserializing checks that the parent
+/// and child links between instances form a single tree under the root.
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(crate = "serde_crate")
+)]
 pub struct WeakDom {
-    instances: HashMap<Ref, Instance>,
+    instances: InstanceArena,
     root_ref: Ref,
+
+    /// Property names are shared between every instance in the DOM to save
+    /// memory. The interner is rebuilt as instances are inserted, so it doesn't
+    /// need to be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    property_names: StringInterner,
 }
 
 impl WeakDom {
     /// Construct a new `WeakDom` described by the given [`InstanceBuilder`].
     pub fn new(builder: InstanceBuilder) -> WeakDom {
+        WeakDom::new_with_capacity(builder, 0)
+    }
+
+    /// Construct a new `WeakDom` described by the given [`InstanceBuilder`],
+    /// with room for at least `capacity` instances, including the ones in
+    /// `builder`, before it needs to grow.
+    pub fn new_with_capacity(builder: InstanceBuilder, capacity: usize) -> WeakDom {
         let root_ref = builder.referent;
 
-        let mut instances = HashMap::new();
-        instances.insert(
-            root_ref,
-            Instance {
-                referent: root_ref,
-                children: Vec::with_capacity(builder.children.len()),
-                parent: Ref::none(),
-                name: builder.name,
-                class: builder.class,
-                properties: builder.properties,
-            },
-        );
+        let mut property_names = StringInterner::new();
+        let properties = intern_properties(&mut property_names, builder.properties);
+
+        let mut instances = InstanceArena::new();
+        instances.reserve(capacity);
+        instances.insert(Instance {
+            referent: root_ref,
+            children: Vec::with_capacity(builder.children.len()),
+            parent: Ref::none(),
+            name: builder.name,
+            class: builder.class,
+            properties,
+        });
 
         let mut dom = WeakDom {
             instances,
             root_ref,
+            property_names,
         };
 
         for child in builder.children {
@@ -47,6 +80,16 @@ impl WeakDom {
         dom
     }
 
+    /// Construct a new `WeakDom` whose root is an instance with the given class
+    /// and name and no properties.
+    pub fn with_root_class<C, N>(class: C, name: N) -> WeakDom
+    where
+        C: Into<String>,
+        N: Into<String>,
+    {
+        WeakDom::new(InstanceBuilder::new(class).with_name(name))
+    }
+
     /// Returns the referent of the root instance of the `WeakDom`.
     pub fn root_ref(&self) -> Ref {
         self.root_ref
@@ -54,24 +97,202 @@ impl WeakDom {
 
     /// Returns a reference to the root instance of the `WeakDom`.
     pub fn root(&self) -> &Instance {
-        self.instances.get(&self.root_ref).unwrap()
+        self.instances.get(self.root_ref).unwrap()
     }
 
     /// Returns a _mutable_ reference to the root instance of the `WeakDom`.
     pub fn root_mut(&mut self) -> &mut Instance {
-        self.instances.get_mut(&self.root_ref).unwrap()
+        self.instances.get_mut(self.root_ref).unwrap()
+    }
+
+    /// Returns the class name of the root instance, like `"DataModel"` for a
+    /// place or `"Model"` for a model.
+    pub fn root_class(&self) -> &str {
+        &self.root().class
+    }
+
+    /// Changes the class name of the root instance.
+    pub fn set_root_class(&mut self, class: &str) {
+        self.root_mut().class = class.to_owned();
+    }
+
+    /// Returns the name of the root instance.
+    pub fn root_name(&self) -> &str {
+        &self.root().name
+    }
+
+    /// Changes the name of the root instance.
+    pub fn set_root_name(&mut self, name: &str) {
+        self.root_mut().name = name.to_owned();
     }
 
     /// Returns a reference to an instance by referent, or `None` if it is not
     /// found.
     pub fn get_by_ref(&self, referent: Ref) -> Option<&Instance> {
-        self.instances.get(&referent)
+        self.instances.get(referent)
     }
 
     /// Returns a _mutable_ reference to an instance by referent, or `None` if
     /// it is not found.
     pub fn get_by_ref_mut(&mut self, referent: Ref) -> Option<&mut Instance> {
-        self.instances.get_mut(&referent)
+        self.instances.get_mut(referent)
+    }
+
+    /// Changes the name of the instance with the given referent.
+    pub fn rename_instance(&mut self, referent: Ref, new_name: String) -> Result<(), DomError> {
+        let instance = self
+            .instances
+            .get_mut(referent)
+            .ok_or(DomError::InvalidRef { referent })?;
+
+        instance.name = new_name;
+
+        Ok(())
+    }
+
+    /// Changes the class name of the instance with the given referent. Its
+    /// properties are left alone, even if they don't belong to the new class.
+    pub fn reclassify_instance(
+        &mut self,
+        referent: Ref,
+        new_class: String,
+    ) -> Result<(), DomError> {
+        let instance = self
+            .instances
+            .get_mut(referent)
+            .ok_or(DomError::InvalidRef { referent })?;
+
+        instance.class = new_class;
+
+        Ok(())
+    }
+
+    /// Returns an iterator over every instance in the DOM that has no
+    /// children, in no particular order.
+    pub fn iter_leaves(&self) -> impl Iterator<Item = &Instance> {
+        self.instances
+            .iter()
+            .filter(|instance| instance.children.is_empty())
+    }
+
+    /// Returns an iterator over every instance in the DOM that has no children
+    /// and has exactly the given ClassName, in no particular order.
+    pub fn iter_leaves_of_class<'a>(
+        &'a self,
+        class: &'a str,
+    ) -> impl Iterator<Item = &'a Instance> {
+        self.iter_leaves()
+            .filter(move |instance| instance.class == class)
+    }
+
+    /// Returns the first child of `parent` with the given name, like Roblox's
+    /// `FindFirstChild`. If `recursive` is true, all descendants of `parent`
+    /// are searched in depth-first order instead of only its children.
+    ///
+    /// Returns `None` if there's no match or if `parent` does not refer to an
+    /// instance in the DOM.
+    pub fn find_first_child(&self, parent: Ref, name: &str, recursive: bool) -> Option<&Instance> {
+        self.find_first(parent, recursive, |instance| instance.name == name)
+    }
+
+    /// Returns the first child of `parent` with the given ClassName, like
+    /// Roblox's `FindFirstChildOfClass`. If `recursive` is true, all
+    /// descendants of `parent` are searched in depth-first order instead of
+    /// only its children.
+    ///
+    /// Returns `None` if there's no match or if `parent` does not refer to an
+    /// instance in the DOM.
+    pub fn find_first_child_of_class(
+        &self,
+        parent: Ref,
+        class: &str,
+        recursive: bool,
+    ) -> Option<&Instance> {
+        self.find_first(parent, recursive, |instance| instance.class == class)
+    }
+
+    /// Returns the names of the given instance and all of its ancestors,
+    /// starting from the root and separated by periods, like
+    /// `DataModel.Workspace.Part`. This is similar to Roblox's `GetFullName`,
+    /// except that the root's name is included.
+    ///
+    /// Returns `None` if `referent` does not refer to an instance in the DOM.
+    pub fn full_name_of(&self, referent: Ref) -> Option<String> {
+        let mut components = Vec::new();
+        let mut current = self.get_by_ref(referent)?;
+
+        loop {
+            components.push(current.name.as_str());
+
+            match self.get_by_ref(current.parent) {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        components.reverse();
+        Some(components.join("."))
+    }
+
+    /// Replaces every `DataModel` in `refs` with its children, keeping
+    /// everything else in order. Serializers use this to write the contents
+    /// of a place instead of the `DataModel` itself.
+    ///
+    /// Referents that don't refer to an instance in the DOM are kept as-is.
+    pub fn expand_data_models(&self, refs: &[Ref]) -> Vec<Ref> {
+        let mut expanded = Vec::with_capacity(refs.len());
+
+        for &referent in refs {
+            match self.get_by_ref(referent) {
+                Some(instance) if instance.class == "DataModel" => {
+                    expanded.extend_from_slice(instance.children())
+                }
+                _ => expanded.push(referent),
+            }
+        }
+
+        expanded
+    }
+
+    /// Returns a shared copy of the given property name. Using this when adding
+    /// properties to instances directly lets them share memory with the
+    /// property names already in the DOM.
+    pub fn intern_property_name(&mut self, name: &str) -> Arc<str> {
+        self.property_names.intern(name)
+    }
+
+    /// Sets an attribute on the instance with the given referent, returning
+    /// its old value if it had one.
+    ///
+    /// Encoded attributes are decoded first and stored back in the `Attributes`
+    /// property as a `Variant::Attributes`, so they're only decoded once. This
+    /// fails if that decoding fails, in which case the instance is left alone.
+    ///
+    /// ## Panics
+    /// Panics if `referent` does not refer to an instance in the DOM.
+    pub fn set_attribute<K: Into<String>, V: Into<Variant>>(
+        &mut self,
+        referent: Ref,
+        name: K,
+        value: V,
+    ) -> Result<Option<Variant>, AttributeError> {
+        Ok(self
+            .attributes_mut(referent)?
+            .insert(name.into(), value.into()))
+    }
+
+    /// Removes an attribute from the instance with the given referent,
+    /// returning its value if it had one. Decodes attributes the same way as
+    /// [`set_attribute`][WeakDom::set_attribute].
+    ///
+    /// ## Panics
+    /// Panics if `referent` does not refer to an instance in the DOM.
+    pub fn remove_attribute(
+        &mut self,
+        referent: Ref,
+        name: &str,
+    ) -> Result<Option<Variant>, AttributeError> {
+        Ok(self.attributes_mut(referent)?.remove(name))
     }
 
     /// Insert a new instance into the DOM with the given parent.
@@ -80,22 +301,20 @@ impl WeakDom {
     /// Panics if `parent_ref` does not refer to an instance in the DOM.
     pub fn insert(&mut self, parent_ref: Ref, builder: InstanceBuilder) -> Ref {
         let referent = builder.referent;
+        let properties = intern_properties(&mut self.property_names, builder.properties);
 
-        self.instances.insert(
+        self.instances.insert(Instance {
             referent,
-            Instance {
-                referent,
-                children: Vec::with_capacity(builder.children.len()),
-                parent: parent_ref,
-                name: builder.name,
-                class: builder.class,
-                properties: builder.properties,
-            },
-        );
+            children: Vec::with_capacity(builder.children.len()),
+            parent: parent_ref,
+            name: builder.name,
+            class: builder.class,
+            properties,
+        });
 
         let parent = self
             .instances
-            .get_mut(&parent_ref)
+            .get_mut(parent_ref)
             .unwrap_or_else(|| panic!("cannot insert into parent that does not exist"));
 
         parent.children.push(referent);
@@ -107,6 +326,261 @@ impl WeakDom {
         referent
     }
 
+    /// Reserves space for at least `additional` more instances to be inserted
+    /// into the DOM without it needing to grow.
+    ///
+    /// Inserting many instances one at a time can resize the DOM's storage
+    /// several times along the way, which this avoids.
+    pub fn reserve(&mut self, additional: usize) {
+        self.instances.reserve(additional);
+    }
+
+    /// Insert a new instance into the DOM with the given parent, placing it at
+    /// `index` in the parent's children instead of at the end.
+    ///
+    /// ## Panics
+    /// Panics if `parent_ref` does not refer to an instance in the DOM, or if
+    /// `index` is greater than the number of children the parent has.
+    pub fn insert_at_index(
+        &mut self,
+        parent_ref: Ref,
+        builder: InstanceBuilder,
+        index: usize,
+    ) -> Ref {
+        let parent = self
+            .instances
+            .get(parent_ref)
+            .unwrap_or_else(|| panic!("cannot insert into parent that does not exist"));
+
+        assert!(
+            index <= parent.children.len(),
+            "cannot insert at index {} of a parent with {} children",
+            index,
+            parent.children.len()
+        );
+
+        let referent = self.insert(parent_ref, builder);
+
+        let parent = self.instances.get_mut(parent_ref).unwrap();
+        parent.children.pop();
+        parent.children.insert(index, referent);
+
+        referent
+    }
+
+    /// Insert a new instance into the DOM as a sibling of `sibling_ref`,
+    /// placed just before it in their parent's children.
+    ///
+    /// ## Panics
+    /// Panics if `sibling_ref` does not refer to an instance in the DOM, or if
+    /// it refers to the root instance.
+    pub fn insert_before(&mut self, sibling_ref: Ref, builder: InstanceBuilder) -> Ref {
+        let (parent_ref, index) = self.sibling_position(sibling_ref);
+        self.insert_at_index(parent_ref, builder, index)
+    }
+
+    /// Insert a new instance into the DOM as a sibling of `sibling_ref`,
+    /// placed just after it in their parent's children.
+    ///
+    /// ## Panics
+    /// Panics if `sibling_ref` does not refer to an instance in the DOM, or if
+    /// it refers to the root instance.
+    pub fn insert_after(&mut self, sibling_ref: Ref, builder: InstanceBuilder) -> Ref {
+        let (parent_ref, index) = self.sibling_position(sibling_ref);
+        self.insert_at_index(parent_ref, builder, index + 1)
+    }
+
+    /// Insert a shallow copy of the instance at `source_ref` into the DOM as
+    /// the last child of `parent_ref`, returning the copy's referent.
+    ///
+    /// The copy has the same class, name, and properties as the source, but a
+    /// new referent and no children. `Ref` properties are copied as-is, so they
+    /// still point to the same instances as the source's do.
+    ///
+    /// ## Panics
+    /// Panics if `source_ref` or `parent_ref` does not refer to an instance in
+    /// the DOM.
+    pub fn insert_copy(&mut self, source_ref: Ref, parent_ref: Ref) -> Ref {
+        let source = self
+            .instances
+            .get(source_ref)
+            .unwrap_or_else(|| panic!("cannot copy an instance that does not exist"));
+
+        let referent = Ref::new();
+        let copy = Instance {
+            referent,
+            children: Vec::new(),
+            parent: parent_ref,
+            name: source.name.clone(),
+            class: source.class.clone(),
+            properties: source.properties.clone(),
+        };
+
+        let parent = self
+            .instances
+            .get_mut(parent_ref)
+            .unwrap_or_else(|| panic!("cannot insert into parent that does not exist"));
+
+        parent.children.push(referent);
+        self.instances.insert(copy);
+
+        referent
+    }
+
+    /// Returns the parent of the given instance and its index in the parent's
+    /// children.
+    fn sibling_position(&self, sibling_ref: Ref) -> (Ref, usize) {
+        let sibling = self
+            .instances
+            .get(sibling_ref)
+            .unwrap_or_else(|| panic!("cannot insert next to an instance that does not exist"));
+
+        let parent_ref = sibling.parent;
+        let parent = self
+            .instances
+            .get(parent_ref)
+            .unwrap_or_else(|| panic!("cannot insert next to the root instance"));
+
+        let index = parent
+            .children
+            .iter()
+            .position(|&child| child == sibling_ref)
+            .unwrap();
+
+        (parent_ref, index)
+    }
+
+    /// Swaps the children at indices `a` and `b` in the children of
+    /// `parent_ref`.
+    pub fn swap_children(&mut self, parent_ref: Ref, a: usize, b: usize) -> Result<(), DomError> {
+        let parent = self
+            .instances
+            .get_mut(parent_ref)
+            .ok_or(DomError::InvalidRef {
+                referent: parent_ref,
+            })?;
+
+        let len = parent.children.len();
+        for &index in &[a, b] {
+            if index >= len {
+                return Err(DomError::IndexOutOfBounds { index, len });
+            }
+        }
+
+        parent.children.swap(a, b);
+
+        Ok(())
+    }
+
+    /// Moves `child_ref` so that it's at `new_index` in the children of
+    /// `parent_ref`, shifting the children between its old and new position
+    /// over to make room.
+    pub fn move_child_to_index(
+        &mut self,
+        parent_ref: Ref,
+        child_ref: Ref,
+        new_index: usize,
+    ) -> Result<(), DomError> {
+        let parent = self
+            .instances
+            .get_mut(parent_ref)
+            .ok_or(DomError::InvalidRef {
+                referent: parent_ref,
+            })?;
+
+        let len = parent.children.len();
+        if new_index >= len {
+            return Err(DomError::IndexOutOfBounds {
+                index: new_index,
+                len,
+            });
+        }
+
+        let old_index = parent
+            .children
+            .iter()
+            .position(|&child| child == child_ref)
+            .ok_or(DomError::NotAChild {
+                parent: parent_ref,
+                child: child_ref,
+            })?;
+
+        parent.children.remove(old_index);
+        parent.children.insert(new_index, child_ref);
+
+        Ok(())
+    }
+
+    /// Replaces the children of `parent_ref` with `new_order`, which must list
+    /// every child of `parent_ref` exactly once. Nothing is changed if
+    /// `new_order` is invalid.
+    ///
+    /// This is useful for sorting children by something other than their
+    /// position, like a property value.
+    pub fn reorder_children(&mut self, parent_ref: Ref, new_order: &[Ref]) -> Result<(), DomError> {
+        let parent = self
+            .instances
+            .get_mut(parent_ref)
+            .ok_or(DomError::InvalidRef {
+                referent: parent_ref,
+            })?;
+
+        let current: HashSet<Ref> = parent.children.iter().copied().collect();
+        let mut seen = HashSet::with_capacity(new_order.len());
+
+        for &child in new_order {
+            if !current.contains(&child) {
+                return Err(DomError::NotAChild {
+                    parent: parent_ref,
+                    child,
+                });
+            }
+
+            if !seen.insert(child) {
+                return Err(DomError::DuplicateRef { referent: child });
+            }
+        }
+
+        if let Some(&child) = parent.children.iter().find(|child| !seen.contains(child)) {
+            return Err(DomError::MissingChild {
+                parent: parent_ref,
+                child,
+            });
+        }
+
+        parent.children.clear();
+        parent.children.extend_from_slice(new_order);
+
+        Ok(())
+    }
+
+    /// Returns the referent of the first child of `parent` with the given
+    /// ClassName and name. If there isn't one, a new instance with that class
+    /// and name is inserted into `parent` and its referent is returned.
+    ///
+    /// Returns an error if `parent` does not refer to an instance in the DOM.
+    pub fn get_or_create_child(
+        &mut self,
+        parent: Ref,
+        class: &str,
+        name: &str,
+    ) -> Result<Ref, DomError> {
+        let parent_instance = self
+            .instances
+            .get(parent)
+            .ok_or(DomError::InvalidRef { referent: parent })?;
+
+        let existing = parent_instance.children.iter().copied().find(|child| {
+            let child = self.instances.get(*child).unwrap();
+            child.class == class && child.name == name
+        });
+
+        Ok(match existing {
+            Some(referent) => referent,
+            None => self.insert(parent, InstanceBuilder::new(class).with_name(name)),
+        })
+    }
+
     /// Destroy the instance with the given referent.
     ///
     /// ## Panics
@@ -121,22 +595,49 @@ impl WeakDom {
 
         let instance = self
             .instances
-            .get(&referent)
+            .get(referent)
             .unwrap_or_else(|| panic!("cannot destroy an instance that does not exist"));
 
         let parent_ref = instance.parent;
-        let parent = self.instances.get_mut(&parent_ref).unwrap();
+        let parent = self.instances.get_mut(parent_ref).unwrap();
         parent.children.retain(|&child| child != referent);
 
         let mut to_remove = VecDeque::new();
         to_remove.push_back(referent);
 
         while let Some(referent) = to_remove.pop_front() {
-            let instance = self.instances.remove(&referent).unwrap();
+            let instance = self.instances.remove(referent).unwrap();
             to_remove.extend(instance.children);
         }
     }
 
+    /// Calls `f` on every instance in the DOM, letting it change the instance
+    /// in place. If `f` returns `false`, the instance and all of its
+    /// descendants are removed from the DOM.
+    ///
+    /// Instances are visited from the top of the tree down, so `f` is never
+    /// called on the descendants of an instance it removed.
+    ///
+    /// ## Panics
+    /// Panics if `f` returns `false` for the root instance.
+    pub fn filter_map<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut Instance) -> bool,
+    {
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(self.root_ref);
+
+        while let Some(referent) = to_visit.pop_front() {
+            let instance = self.instances.get_mut(referent).unwrap();
+
+            if f(instance) {
+                to_visit.extend(instance.children.iter().copied());
+            } else {
+                self.destroy(referent);
+            }
+        }
+    }
+
     /// Move the instance with the given referent to a new `WeakDom`, parenting
     /// it to the given ref. To move to within the same DOM, use
     /// [`WeakDom::transfer_within`].
@@ -156,13 +657,13 @@ impl WeakDom {
 
         let mut instance = self
             .instances
-            .remove(&referent)
+            .remove(referent)
             .unwrap_or_else(|| panic!("cannot move an instance that does not exist"));
 
         // Remove the instance being moved from its parent's list of children.
         // If we care about panic tolerance in the future, doing this first is
         // important to ensure this link is the one severed first.
-        let parent = self.instances.get_mut(&instance.parent).unwrap();
+        let parent = self.instances.get_mut(instance.parent).unwrap();
         parent.children.retain(|&child| child != referent);
 
         // We'll start tracking all of the instances that we're moving in a
@@ -174,18 +675,20 @@ impl WeakDom {
         // Instance was released.
         // Bye-bye, instance!
         instance.parent = dest_parent_ref;
-        dest.instances.insert(referent, instance);
+        dest.reintern_properties(&mut instance);
+        dest.instances.insert(instance);
 
         // Transfer all of the descendants of the moving instance breadth-first.
         while let Some(referent) = to_move.pop_front() {
-            let instance = self.instances.remove(&referent).unwrap();
+            let mut instance = self.instances.remove(referent).unwrap();
             to_move.extend(instance.children.iter().copied());
-            dest.instances.insert(referent, instance);
+            dest.reintern_properties(&mut instance);
+            dest.instances.insert(instance);
         }
 
         // Finally, notify the new parent instance that their adoption is
         // complete. Enjoy!
-        let dest_parent = dest.instances.get_mut(&dest_parent_ref).unwrap_or_else(|| {
+        let dest_parent = dest.instances.get_mut(dest_parent_ref).unwrap_or_else(|| {
             panic!("cannot move an instance into an instance that does not exist")
         });
         dest_parent.children.push(referent);
@@ -210,7 +713,7 @@ impl WeakDom {
 
         let mut instance = self
             .instances
-            .get_mut(&referent)
+            .get_mut(referent)
             .unwrap_or_else(|| panic!("cannot move an instance that does not exist"));
 
         // Tell the instance who its new parent is.
@@ -218,78 +721,1586 @@ impl WeakDom {
         instance.parent = dest_parent_ref;
 
         // Remove the instance's referent from its parent's list of children.
-        let parent = self.instances.get_mut(&parent_ref).unwrap();
+        let parent = self.instances.get_mut(parent_ref).unwrap();
         parent.children.retain(|&child| child != referent);
 
         // Add the instance's referent to its new parent's list of children.
         let dest_parent = self
             .instances
-            .get_mut(&dest_parent_ref)
+            .get_mut(dest_parent_ref)
             .unwrap_or_else(|| panic!("cannot move into an instance that does not exist"));
         dest_parent.children.push(referent);
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Copy the instance with the given referent and all of its descendants
+    /// into another `WeakDom`, under the given parent. Neither DOM is consumed
+    /// and the copies are given new referents. Returns the referent of the
+    /// copy of the instance.
+    ///
+    /// `Ref` properties that point to instances being copied are changed to
+    /// point to their copies. `Ref` properties that point anywhere else are
+    /// set to `Ref::none()`, since the instances they point to won't exist in
+    /// the other DOM.
+    ///
+    /// ## Panics
+    /// Panics if `referent` does not refer to an instance in this `WeakDom`,
+    /// or if `dest_parent_ref` does not refer to an instance in `dest`.
+    pub fn copy_instance_across(
+        &self,
+        referent: Ref,
+        dest: &mut WeakDom,
+        dest_parent_ref: Ref,
+    ) -> Ref {
+        if dest.instances.get(dest_parent_ref).is_none() {
+            panic!("cannot copy an instance into an instance that does not exist");
+        }
 
-    use crate::DomViewer;
+        // Every copy needs its new referent before any of them are built, so
+        // that Ref properties can point forwards to instances deeper in the
+        // tree.
+        let mut new_refs = HashMap::new();
+        let mut to_copy = Vec::new();
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(referent);
 
-    #[test]
-    fn transfer() {
-        let target = InstanceBuilder::new("Folder")
-            .with_name("Target")
-            .with_child(InstanceBuilder::new("Part").with_name("Some Child"));
-        let target_ref = target.referent;
+        while let Some(referent) = to_visit.pop_front() {
+            let instance = self
+                .instances
+                .get(referent)
+                .unwrap_or_else(|| panic!("cannot copy an instance that does not exist"));
 
-        let mut source = WeakDom::new(InstanceBuilder::new("Folder").with_child(target));
-        let mut dest = WeakDom::new(InstanceBuilder::new("DataModel"));
+            new_refs.insert(referent, Ref::new());
+            to_copy.push(instance);
+            to_visit.extend(instance.children.iter().copied());
+        }
 
-        let mut viewer = DomViewer::new();
+        for instance in to_copy {
+            let parent = if instance.referent == referent {
+                dest_parent_ref
+            } else {
+                new_refs[&instance.parent]
+            };
 
-        // This snapshot should contain Target and Some Child
-        insta::assert_yaml_snapshot!(viewer.view_children(&source));
+            let properties = instance
+                .properties
+                .iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Variant::Ref(target) => {
+                            Variant::Ref(new_refs.get(target).copied().unwrap_or_else(Ref::none))
+                        }
+                        other => other.clone(),
+                    };
 
-        let dest_root = dest.root_ref();
-        source.transfer(target_ref, &mut dest, dest_root);
+                    (dest.property_names.intern(key), value)
+                })
+                .collect();
 
-        // This snapshot should be empty
-        insta::assert_yaml_snapshot!(viewer.view_children(&source));
+            dest.instances.insert(Instance {
+                referent: new_refs[&instance.referent],
+                children: instance
+                    .children
+                    .iter()
+                    .map(|child| new_refs[child])
+                    .collect(),
+                parent,
+                name: instance.name.clone(),
+                class: instance.class.clone(),
+                properties,
+            });
+        }
 
-        // This snapshot should be exactly the same as the first snapshot,
-        // containing Target and Child.
-        insta::assert_yaml_snapshot!(viewer.view_children(&dest));
+        let new_ref = new_refs[&referent];
+        let dest_parent = dest.instances.get_mut(dest_parent_ref).unwrap();
+        dest_parent.children.push(new_ref);
+
+        new_ref
     }
 
-    #[test]
-    fn transfer_within() {
-        let subject = InstanceBuilder::new("Folder")
-            .with_name("Root")
-            .with_child(InstanceBuilder::new("SpawnLocation"));
-        let subject_ref = subject.referent;
+    /// Consumes the `WeakDom`, turning every instance into an
+    /// [`InstanceBuilder`] paired with its referent and its parent's referent.
+    ///
+    /// The list is in breadth-first order starting from the root, so parents
+    /// always come before their children. The root is the only instance with
+    /// no parent. The returned builders keep their original referents but have
+    /// no children of their own.
+    pub fn into_flat_list(mut self) -> Vec<(Ref, Option<Ref>, InstanceBuilder)> {
+        let mut output = Vec::new();
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(self.root_ref);
 
-        let source_parent = InstanceBuilder::new("Folder")
-            .with_name("Source")
-            .with_child(subject);
+        while let Some(referent) = to_visit.pop_front() {
+            let instance = self.instances.remove(referent).unwrap();
+            to_visit.extend(instance.children.iter().copied());
 
-        let dest_parent = InstanceBuilder::new("Folder").with_name("Dest");
-        let dest_parent_ref = dest_parent.referent;
+            let parent = if instance.parent.is_some() {
+                Some(instance.parent)
+            } else {
+                None
+            };
 
-        let mut dom = WeakDom::new(
-            InstanceBuilder::new("Folder")
-                .with_child(source_parent)
-                .with_child(dest_parent),
-        );
+            let builder = InstanceBuilder {
+                referent,
+                name: instance.name,
+                class: instance.class,
+                properties: instance
+                    .properties
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value))
+                    .collect(),
+                children: Vec::new(),
+            };
 
-        let mut viewer = DomViewer::new();
+            output.push((referent, parent, builder));
+        }
 
-        // This snapshot should have Root and SpawnLocation contained in Source.
-        insta::assert_yaml_snapshot!(viewer.view_children(&dom));
+        output
+    }
 
-        dom.transfer_within(subject_ref, dest_parent_ref);
+    /// Returns the referent of every instance in the DOM, ordered so that each
+    /// instance comes after its parent and after every instance its `Ref`
+    /// properties point to.
+    ///
+    /// `Ref` properties that are `Ref::none()` or that point outside of the DOM
+    /// are ignored. So are `Ref` properties that point to the instance itself,
+    /// one of its ancestors, or one of its descendants, like a `Model`'s
+    /// `PrimaryPart`: the parent order already decides where those go. If the
+    /// remaining `Ref` properties form a cycle, [`DomError::RefCycle`] is
+    /// returned.
+    pub fn topological_sort(&self) -> Result<Vec<Ref>, DomError> {
+        // Visiting instances breadth-first gives ties a stable order.
+        let mut breadth_first = Vec::new();
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(self.root_ref);
 
-        // This snapshot should have Root and SpawnLocation contained in Dest.
-        insta::assert_yaml_snapshot!(viewer.view_children(&dom));
+        while let Some(referent) = to_visit.pop_front() {
+            let instance = self.instances.get(referent).unwrap();
+            to_visit.extend(instance.children.iter().copied());
+            breadth_first.push(referent);
+        }
+
+        let mut in_degree: HashMap<Ref, usize> = HashMap::new();
+        let mut dependents: HashMap<Ref, Vec<Ref>> = HashMap::new();
+
+        for &referent in &breadth_first {
+            let instance = self.instances.get(referent).unwrap();
+            let targets = instance
+                .properties
+                .values()
+                .filter_map(|value| match value {
+                    Variant::Ref(target) if self.instances.get(*target).is_some() => Some(*target),
+                    _ => None,
+                })
+                .filter(|&target| {
+                    !self.is_lineal(referent, target) && !self.is_lineal(target, referent)
+                });
+
+            let mut count = 0;
+            for dependency in Some(instance.parent)
+                .filter(|parent| parent.is_some())
+                .into_iter()
+                .chain(targets)
+            {
+                dependents.entry(dependency).or_default().push(referent);
+                count += 1;
+            }
+
+            in_degree.insert(referent, count);
+        }
+
+        let mut output = Vec::with_capacity(breadth_first.len());
+        let mut ready: VecDeque<Ref> = breadth_first
+            .iter()
+            .copied()
+            .filter(|referent| in_degree[referent] == 0)
+            .collect();
+
+        while let Some(referent) = ready.pop_front() {
+            output.push(referent);
+
+            for dependent in dependents.get(&referent).into_iter().flatten() {
+                let count = in_degree.get_mut(dependent).unwrap();
+                *count -= 1;
+
+                if *count == 0 {
+                    ready.push_back(*dependent);
+                }
+            }
+        }
+
+        if let Some(&referent) = breadth_first
+            .iter()
+            .find(|referent| in_degree[*referent] > 0)
+        {
+            return Err(DomError::RefCycle { referent });
+        }
+
+        Ok(output)
+    }
+
+    /// Tells whether `ancestor` is `referent` or one of its ancestors.
+    fn is_lineal(&self, ancestor: Ref, referent: Ref) -> bool {
+        let mut current = referent;
+
+        while current.is_some() {
+            if current == ancestor {
+                return true;
+            }
+
+            current = self.instances.get(current).unwrap().parent;
+        }
+
+        false
+    }
+
+    /// Inserts a flat list of instances, like the one returned by
+    /// [`into_flat_list`][WeakDom::into_flat_list], into this `WeakDom`.
+    ///
+    /// Each entry is the instance's original referent, its original parent's
+    /// referent, and a builder for the instance. Parents must come before
+    /// their children in the list. Instances with no parent are inserted as
+    /// children of `parent_ref`.
+    ///
+    /// Every instance is given a new referent. `Ref` properties that point to
+    /// instances in the list are updated to point to the new instances, and
+    /// all other `Ref` properties are set to `Ref::none()`. Returns a map from
+    /// each original referent to its new referent.
+    ///
+    /// If an error is returned, the `WeakDom` is left unchanged.
+    pub fn import_instances<I>(
+        &mut self,
+        instances: I,
+        parent_ref: Ref,
+    ) -> Result<HashMap<Ref, Ref>, DomError>
+    where
+        I: IntoIterator<Item = (Ref, Option<Ref>, InstanceBuilder)>,
+    {
+        if self.instances.get(parent_ref).is_none() {
+            return Err(DomError::InvalidRef {
+                referent: parent_ref,
+            });
+        }
+
+        let instances: Vec<_> = instances.into_iter().collect();
+
+        // Every instance needs its new referent before any of them are
+        // inserted, so that Ref properties can point forwards in the list.
+        let mut new_refs = HashMap::with_capacity(instances.len());
+
+        for (referent, parent, _) in &instances {
+            if let Some(parent) = parent {
+                if !new_refs.contains_key(parent) {
+                    return Err(DomError::UnknownParent {
+                        referent: *referent,
+                        parent: *parent,
+                    });
+                }
+            }
+
+            if new_refs.insert(*referent, Ref::new()).is_some() {
+                return Err(DomError::DuplicateRef {
+                    referent: *referent,
+                });
+            }
+        }
+
+        for (referent, parent, mut builder) in instances {
+            let parent = match parent {
+                Some(parent) => new_refs[&parent],
+                None => parent_ref,
+            };
+
+            builder.referent = new_refs[&referent];
+
+            for value in builder.properties.values_mut() {
+                if let Variant::Ref(target) = value {
+                    *target = new_refs.get(target).copied().unwrap_or_else(Ref::none);
+                }
+            }
+
+            self.insert(parent, builder);
+        }
+
+        Ok(new_refs)
+    }
+
+    fn find_first<F>(&self, parent: Ref, recursive: bool, predicate: F) -> Option<&Instance>
+    where
+        F: Fn(&Instance) -> bool,
+    {
+        let parent = self.get_by_ref(parent)?;
+        let mut stack: Vec<Ref> = parent.children.iter().rev().copied().collect();
+
+        while let Some(referent) = stack.pop() {
+            let instance = self.get_by_ref(referent).unwrap();
+
+            if predicate(instance) {
+                return Some(instance);
+            }
+
+            if recursive {
+                stack.extend(instance.children.iter().rev().copied());
+            }
+        }
+
+        None
+    }
+
+    /// Replaces the property names of an instance coming from another DOM with
+    /// the copies shared by this DOM.
+    fn attributes_mut(&mut self, referent: Ref) -> Result<&mut Attributes, AttributeError> {
+        let instance = self
+            .instances
+            .get_mut(referent)
+            .unwrap_or_else(|| panic!("cannot modify an instance that does not exist"));
+
+        // Like Instance::add_tag, values of any other type are replaced.
+        let replacement = match instance.properties.get("Attributes") {
+            Some(Variant::Attributes(_)) => None,
+            _ => match instance
+                .properties
+                .get("Attributes")
+                .or_else(|| instance.properties.get("AttributesSerialize"))
+            {
+                Some(Variant::Attributes(attributes)) => Some(attributes.clone()),
+                Some(Variant::BinaryString(buffer)) => Some(Attributes::decode(buffer.as_ref())?),
+                _ => Some(Attributes::new()),
+            },
+        };
+
+        if let Some(attributes) = replacement {
+            instance.properties.remove("AttributesSerialize");
+            instance
+                .properties
+                .insert(self.property_names.intern("Attributes"), attributes.into());
+        }
+
+        match instance.properties.get_mut("Attributes") {
+            Some(Variant::Attributes(attributes)) => Ok(attributes),
+            _ => unreachable!(),
+        }
+    }
+
+    fn reintern_properties(&mut self, instance: &mut Instance) {
+        let properties = std::mem::take(&mut instance.properties);
+
+        instance.properties = properties
+            .into_iter()
+            .map(|(key, value)| (self.property_names.intern(&key), value))
+            .collect();
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for WeakDom {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(crate = "serde_crate")]
+        struct SerializedDom {
+            instances: InstanceArena,
+            root_ref: Ref,
+        }
+
+        let serialized = SerializedDom::deserialize(deserializer)?;
+        let mut dom = WeakDom {
+            instances: serialized.instances,
+            root_ref: serialized.root_ref,
+            property_names: StringInterner::new(),
+        };
+
+        dom.check_links().map_err(serde::de::Error::custom)?;
+
+        let property_names = &mut dom.property_names;
+        for instance in dom.instances.iter_mut() {
+            let properties = std::mem::take(&mut instance.properties);
+            instance.properties = properties
+                .into_iter()
+                .map(|(key, value)| (property_names.intern(&key), value))
+                .collect();
+        }
+
+        Ok(dom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl WeakDom {
+    /// Checks that every instance is reachable from the root exactly once and
+    /// that every instance's parent lists it as a child.
+    fn check_links(&self) -> Result<(), String> {
+        let root = self
+            .instances
+            .get(self.root_ref)
+            .ok_or_else(|| format!("root instance {} does not exist", self.root_ref))?;
+
+        if root.parent.is_some() {
+            return Err(format!("root instance {} has a parent", self.root_ref));
+        }
+
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![self.root_ref];
+        visited.insert(self.root_ref);
+
+        while let Some(referent) = to_visit.pop() {
+            let instance = self.instances.get(referent).unwrap();
+
+            for &child_ref in &instance.children {
+                let child = self.instances.get(child_ref).ok_or_else(|| {
+                    format!("instance {} has missing child {}", referent, child_ref)
+                })?;
+
+                if child.parent != referent {
+                    return Err(format!(
+                        "instance {} is a child of {}, but its parent is {}",
+                        child_ref, referent, child.parent
+                    ));
+                }
+
+                if !visited.insert(child_ref) {
+                    return Err(format!("instance {} appears more than once", child_ref));
+                }
+
+                to_visit.push(child_ref);
+            }
+        }
+
+        if let Some(orphan) = self
+            .instances
+            .iter()
+            .find(|instance| !visited.contains(&instance.referent))
+        {
+            return Err(format!(
+                "instance {} is not a descendant of the root",
+                orphan.referent
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn intern_properties(
+    interner: &mut StringInterner,
+    properties: HashMap<String, Variant>,
+) -> HashMap<Arc<str>, Variant> {
+    properties
+        .into_iter()
+        .map(|(key, value)| (interner.intern(&key), value))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use rbx_types::BinaryString;
+
+    use crate::DomViewer;
+
+    #[test]
+    fn transfer() {
+        let target = InstanceBuilder::new("Folder")
+            .with_name("Target")
+            .with_child(InstanceBuilder::new("Part").with_name("Some Child"));
+        let target_ref = target.referent;
+
+        let mut source = WeakDom::new(InstanceBuilder::new("Folder").with_child(target));
+        let mut dest = WeakDom::new(InstanceBuilder::new("DataModel"));
+
+        let mut viewer = DomViewer::new();
+
+        // This snapshot should contain Target and Some Child
+        insta::assert_yaml_snapshot!(viewer.view_children(&source));
+
+        let dest_root = dest.root_ref();
+        source.transfer(target_ref, &mut dest, dest_root);
+
+        // This snapshot should be empty
+        insta::assert_yaml_snapshot!(viewer.view_children(&source));
+
+        // This snapshot should be exactly the same as the first snapshot,
+        // containing Target and Child.
+        insta::assert_yaml_snapshot!(viewer.view_children(&dest));
+    }
+
+    #[test]
+    fn transfer_within() {
+        let subject = InstanceBuilder::new("Folder")
+            .with_name("Root")
+            .with_child(InstanceBuilder::new("SpawnLocation"));
+        let subject_ref = subject.referent;
+
+        let source_parent = InstanceBuilder::new("Folder")
+            .with_name("Source")
+            .with_child(subject);
+
+        let dest_parent = InstanceBuilder::new("Folder").with_name("Dest");
+        let dest_parent_ref = dest_parent.referent;
+
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(source_parent)
+                .with_child(dest_parent),
+        );
+
+        let mut viewer = DomViewer::new();
+
+        // This snapshot should have Root and SpawnLocation contained in Source.
+        insta::assert_yaml_snapshot!(viewer.view_children(&dom));
+
+        dom.transfer_within(subject_ref, dest_parent_ref);
+
+        // This snapshot should have Root and SpawnLocation contained in Dest.
+        insta::assert_yaml_snapshot!(viewer.view_children(&dom));
+    }
+
+    #[test]
+    fn find_first_child() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_name("Car")
+                        .with_child(InstanceBuilder::new("Part").with_name("Wheel")),
+                )
+                .with_child(InstanceBuilder::new("Part").with_name("Wheel"))
+                .with_child(InstanceBuilder::new("Folder").with_name("Empty")),
+        );
+        let root_ref = dom.root_ref();
+        let root_children = dom.root().children();
+
+        let empty = dom.find_first_child(root_ref, "Empty", false).unwrap();
+        assert_eq!(empty.referent(), root_children[2]);
+        assert!(dom.find_first_child(root_ref, "Missing", false).is_none());
+        assert!(dom.find_first_child(root_ref, "Missing", true).is_none());
+
+        // Descendants come before later siblings when searching recursively.
+        let wheel = dom.find_first_child(root_ref, "Wheel", false).unwrap();
+        assert_eq!(wheel.referent(), root_children[1]);
+        let wheel = dom.find_first_child(root_ref, "Wheel", true).unwrap();
+        assert_eq!(wheel.parent(), root_children[0]);
+
+        assert!(dom.find_first_child(Ref::new(), "Wheel", true).is_none());
+    }
+
+    #[test]
+    fn find_first_child_of_class() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_child(InstanceBuilder::new("Part").with_name("Deep")),
+                )
+                .with_child(InstanceBuilder::new("Folder")),
+        );
+        let root_ref = dom.root_ref();
+
+        let model = dom
+            .find_first_child_of_class(root_ref, "Model", false)
+            .unwrap();
+        assert_eq!(model.referent(), dom.root().children()[0]);
+
+        assert!(dom
+            .find_first_child_of_class(root_ref, "Part", false)
+            .is_none());
+
+        let part = dom
+            .find_first_child_of_class(root_ref, "Part", true)
+            .unwrap();
+        assert_eq!(part.name, "Deep");
+        assert_eq!(part.parent(), model.referent());
+    }
+
+    #[test]
+    fn full_name_of() {
+        let part = InstanceBuilder::new("Part").with_name("Part");
+        let part_ref = part.referent();
+
+        let dom = WeakDom::new(
+            InstanceBuilder::new("DataModel")
+                .with_child(InstanceBuilder::new("Workspace").with_child(part)),
+        );
+
+        assert_eq!(
+            dom.full_name_of(part_ref).as_deref(),
+            Some("DataModel.Workspace.Part")
+        );
+        assert_eq!(
+            dom.full_name_of(dom.root_ref()).as_deref(),
+            Some("DataModel")
+        );
+        assert_eq!(dom.full_name_of(Ref::new()), None);
+        assert_eq!(dom.full_name_of(Ref::none()), None);
+    }
+
+    #[test]
+    fn expand_data_models() {
+        let workspace = InstanceBuilder::new("Workspace");
+        let workspace_ref = workspace.referent();
+        let lighting = InstanceBuilder::new("Lighting");
+        let lighting_ref = lighting.referent();
+        let part = InstanceBuilder::new("Part");
+        let part_ref = part.referent();
+
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(part)
+                .with_child(
+                    InstanceBuilder::new("DataModel")
+                        .with_child(workspace)
+                        .with_child(lighting),
+                )
+                .with_child(InstanceBuilder::new("DataModel")),
+        );
+        let children = dom.root().children().to_vec();
+        let missing_ref = Ref::new();
+
+        assert_eq!(
+            dom.expand_data_models(&children),
+            vec![part_ref, workspace_ref, lighting_ref]
+        );
+        assert_eq!(
+            dom.expand_data_models(&[missing_ref, part_ref]),
+            vec![missing_ref, part_ref]
+        );
+        assert_eq!(dom.expand_data_models(&[]), Vec::<Ref>::new());
+    }
+
+    #[test]
+    fn get_or_create_child_found() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("Shared"))
+                .with_child(InstanceBuilder::new("Model").with_name("Shared")),
+        );
+        let root_ref = dom.root_ref();
+        let model_ref = dom.root().children()[1];
+
+        let found = dom
+            .get_or_create_child(root_ref, "Model", "Shared")
+            .unwrap();
+        assert_eq!(found, model_ref);
+        assert_eq!(dom.root().children().len(), 2);
+    }
+
+    #[test]
+    fn get_or_create_child_created() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("Shared")),
+        );
+        let root_ref = dom.root_ref();
+
+        let created = dom
+            .get_or_create_child(root_ref, "Model", "Shared")
+            .unwrap();
+        assert_eq!(dom.root().children(), &[dom.root().children()[0], created]);
+
+        let instance = dom.get_by_ref(created).unwrap();
+        assert_eq!(instance.class, "Model");
+        assert_eq!(instance.name, "Shared");
+        assert_eq!(instance.parent(), root_ref);
+        assert!(instance.properties.is_empty());
+
+        let again = dom
+            .get_or_create_child(root_ref, "Model", "Shared")
+            .unwrap();
+        assert_eq!(again, created);
+        assert_eq!(dom.root().children().len(), 2);
+    }
+
+    #[test]
+    fn get_or_create_child_invalid_parent() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let missing = Ref::new();
+
+        assert_eq!(
+            dom.get_or_create_child(missing, "Folder", "Child"),
+            Err(DomError::InvalidRef { referent: missing })
+        );
+        assert!(dom.root().children().is_empty());
+    }
+
+    fn child_names(dom: &WeakDom, parent: Ref) -> Vec<&str> {
+        dom.get_by_ref(parent)
+            .unwrap()
+            .children()
+            .iter()
+            .map(|&child| dom.get_by_ref(child).unwrap().name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn insert_at_index() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("A"))
+                .with_child(InstanceBuilder::new("Folder").with_name("C")),
+        );
+        let root_ref = dom.root_ref();
+
+        let b = dom.insert_at_index(root_ref, InstanceBuilder::new("Part").with_name("B"), 1);
+        assert_eq!(dom.get_by_ref(b).unwrap().parent(), root_ref);
+        assert_eq!(child_names(&dom, root_ref), ["A", "B", "C"]);
+
+        dom.insert_at_index(root_ref, InstanceBuilder::new("Part").with_name("Start"), 0);
+        dom.insert_at_index(root_ref, InstanceBuilder::new("Part").with_name("End"), 4);
+        assert_eq!(child_names(&dom, root_ref), ["Start", "A", "B", "C", "End"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_at_index_out_of_bounds() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+        dom.insert_at_index(root_ref, InstanceBuilder::new("Folder"), 1);
+    }
+
+    #[test]
+    fn insert_before_and_after() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("A"))
+                .with_child(InstanceBuilder::new("Folder").with_name("C")),
+        );
+        let root_ref = dom.root_ref();
+        let a = dom.root().children()[0];
+        let c = dom.root().children()[1];
+
+        dom.insert_before(c, InstanceBuilder::new("Part").with_name("B"));
+        dom.insert_after(c, InstanceBuilder::new("Part").with_name("D"));
+        dom.insert_before(a, InstanceBuilder::new("Part").with_name("Start"));
+
+        assert_eq!(child_names(&dom, root_ref), ["Start", "A", "B", "C", "D"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_before_root() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+        dom.insert_before(root_ref, InstanceBuilder::new("Folder"));
+    }
+
+    #[test]
+    fn swap_children() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("A"))
+                .with_child(InstanceBuilder::new("Folder").with_name("B"))
+                .with_child(InstanceBuilder::new("Folder").with_name("C")),
+        );
+        let root_ref = dom.root_ref();
+
+        dom.swap_children(root_ref, 0, 2).unwrap();
+        assert_eq!(child_names(&dom, root_ref), ["C", "B", "A"]);
+
+        dom.swap_children(root_ref, 1, 1).unwrap();
+        assert_eq!(child_names(&dom, root_ref), ["C", "B", "A"]);
+
+        assert_eq!(
+            dom.swap_children(root_ref, 1, 3),
+            Err(DomError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+
+        let missing = Ref::new();
+        assert_eq!(
+            dom.swap_children(missing, 0, 1),
+            Err(DomError::InvalidRef { referent: missing })
+        );
+    }
+
+    #[test]
+    fn rename_and_reclassify_instance() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Part").with_property("Anchored", true)),
+        );
+        let child_ref = dom.root().children()[0];
+
+        dom.rename_instance(child_ref, "Renamed".to_owned())
+            .unwrap();
+        dom.reclassify_instance(child_ref, "MeshPart".to_owned())
+            .unwrap();
+
+        let child = dom.get_by_ref(child_ref).unwrap();
+        assert_eq!(child.name, "Renamed");
+        assert_eq!(child.class, "MeshPart");
+        assert_eq!(child.properties.get("Anchored"), Some(&true.into()));
+
+        let missing = Ref::new();
+        assert_eq!(
+            dom.rename_instance(missing, "Missing".to_owned()),
+            Err(DomError::InvalidRef { referent: missing })
+        );
+        assert_eq!(
+            dom.reclassify_instance(missing, "Folder".to_owned()),
+            Err(DomError::InvalidRef { referent: missing })
+        );
+    }
+
+    #[test]
+    fn set_and_remove_attribute() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+
+        let part_ref = dom.insert(root_ref, InstanceBuilder::new("Part"));
+
+        assert_eq!(dom.set_attribute(part_ref, "Team", "Red").unwrap(), None);
+        assert_eq!(
+            dom.set_attribute(part_ref, "Team", "Blue").unwrap(),
+            Some(Variant::String("Red".to_owned()))
+        );
+        assert_eq!(
+            dom.get_by_ref(part_ref).unwrap().get_attribute("Team"),
+            Some(Variant::String("Blue".to_owned()))
+        );
+
+        assert_eq!(
+            dom.remove_attribute(part_ref, "Team").unwrap(),
+            Some(Variant::String("Blue".to_owned()))
+        );
+        assert_eq!(dom.remove_attribute(part_ref, "Team").unwrap(), None);
+
+        // The new Attributes property shares its name with the rest of the DOM.
+        let interned = dom.intern_property_name("Attributes");
+        let part = dom.get_by_ref(part_ref).unwrap();
+        let (name, _) = part.properties.iter().next().unwrap();
+        assert!(Arc::ptr_eq(name, &interned));
+    }
+
+    #[test]
+    fn set_attribute_decodes_binary_string() {
+        let encoded = vec![("Health", 100.0f64)]
+            .into_iter()
+            .collect::<Attributes>()
+            .encode()
+            .unwrap();
+
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+
+        let encoded_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_property("AttributesSerialize", BinaryString::from(encoded)),
+        );
+        let invalid_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_property("Attributes", BinaryString::from(&b"\x01"[..])),
+        );
+
+        dom.set_attribute(encoded_ref, "Speed", 16.0f64).unwrap();
+
+        let encoded = dom.get_by_ref(encoded_ref).unwrap();
+        assert_eq!(
+            encoded.properties.get("Attributes"),
+            Some(&Variant::Attributes(
+                vec![("Health", 100.0f64), ("Speed", 16.0)]
+                    .into_iter()
+                    .collect()
+            ))
+        );
+        assert!(!encoded.has_property("AttributesSerialize"));
+
+        assert!(dom.remove_attribute(invalid_ref, "Health").is_err());
+        assert!(matches!(
+            dom.get_by_ref(invalid_ref)
+                .unwrap()
+                .properties
+                .get("Attributes"),
+            Some(Variant::BinaryString(_))
+        ));
+    }
+
+    #[test]
+    fn topological_sort() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+        let value_ref = dom.insert(root_ref, InstanceBuilder::new("ObjectValue"));
+        let part_ref = dom.insert(root_ref, InstanceBuilder::new("Part"));
+        let child_ref = dom.insert(part_ref, InstanceBuilder::new("Attachment"));
+
+        // The ObjectValue points at the Part's child, so it has to come after
+        // it even though it's closer to the root.
+        dom.get_by_ref_mut(value_ref)
+            .unwrap()
+            .properties
+            .insert("Value".into(), Variant::Ref(child_ref));
+        dom.get_by_ref_mut(part_ref)
+            .unwrap()
+            .properties
+            .insert("Dangling".into(), Variant::Ref(Ref::new()));
+
+        assert_eq!(
+            dom.topological_sort().unwrap(),
+            [root_ref, part_ref, child_ref, value_ref]
+        );
+    }
+
+    #[test]
+    fn topological_sort_lineal_refs() {
+        // A Model's PrimaryPart is usually one of its descendants, which the
+        // parent order already puts after it.
+        let mut dom = WeakDom::new(InstanceBuilder::new("Model"));
+        let root_ref = dom.root_ref();
+        let part_ref = dom.insert(root_ref, InstanceBuilder::new("Part"));
+        let weld_ref = dom.insert(part_ref, InstanceBuilder::new("WeldConstraint"));
+
+        dom.root_mut()
+            .properties
+            .insert("PrimaryPart".into(), Variant::Ref(part_ref));
+        dom.get_by_ref_mut(part_ref)
+            .unwrap()
+            .properties
+            .insert("Self".into(), Variant::Ref(part_ref));
+        dom.get_by_ref_mut(weld_ref)
+            .unwrap()
+            .properties
+            .insert("Part0".into(), Variant::Ref(root_ref));
+
+        assert_eq!(
+            dom.topological_sort().unwrap(),
+            [root_ref, part_ref, weld_ref]
+        );
+    }
+
+    #[test]
+    fn topological_sort_cycle() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+        let first_ref = dom.insert(root_ref, InstanceBuilder::new("ObjectValue"));
+        let second_ref = dom.insert(root_ref, InstanceBuilder::new("ObjectValue"));
+
+        dom.get_by_ref_mut(first_ref)
+            .unwrap()
+            .properties
+            .insert("Value".into(), Variant::Ref(second_ref));
+        dom.get_by_ref_mut(second_ref)
+            .unwrap()
+            .properties
+            .insert("Value".into(), Variant::Ref(first_ref));
+
+        assert_eq!(
+            dom.topological_sort(),
+            Err(DomError::RefCycle {
+                referent: first_ref
+            })
+        );
+    }
+
+    #[test]
+    fn move_child_to_index() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("A"))
+                .with_child(InstanceBuilder::new("Folder").with_name("B"))
+                .with_child(InstanceBuilder::new("Folder").with_name("C")),
+        );
+        let root_ref = dom.root_ref();
+        let a = dom.root().children()[0];
+        let c = dom.root().children()[2];
+
+        dom.move_child_to_index(root_ref, a, 2).unwrap();
+        assert_eq!(child_names(&dom, root_ref), ["B", "C", "A"]);
+
+        dom.move_child_to_index(root_ref, c, 0).unwrap();
+        assert_eq!(child_names(&dom, root_ref), ["C", "B", "A"]);
+
+        assert_eq!(
+            dom.move_child_to_index(root_ref, a, 3),
+            Err(DomError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+        assert_eq!(
+            dom.move_child_to_index(a, c, 0),
+            Err(DomError::IndexOutOfBounds { index: 0, len: 0 })
+        );
+
+        dom.insert(a, InstanceBuilder::new("Folder"));
+        assert_eq!(
+            dom.move_child_to_index(a, c, 0),
+            Err(DomError::NotAChild {
+                parent: a,
+                child: c
+            })
+        );
+    }
+
+    #[test]
+    fn reorder_children() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("A"))
+                .with_child(InstanceBuilder::new("Folder").with_name("B"))
+                .with_child(InstanceBuilder::new("Folder").with_name("C")),
+        );
+        let root_ref = dom.root_ref();
+        let (a, b, c) = match *dom.root().children() {
+            [a, b, c] => (a, b, c),
+            _ => unreachable!(),
+        };
+
+        dom.reorder_children(root_ref, &[c, a, b]).unwrap();
+        assert_eq!(child_names(&dom, root_ref), ["C", "A", "B"]);
+
+        let missing = Ref::new();
+        assert_eq!(
+            dom.reorder_children(missing, &[]),
+            Err(DomError::InvalidRef { referent: missing })
+        );
+    }
+
+    #[test]
+    fn reorder_children_errors() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("A"))
+                .with_child(
+                    InstanceBuilder::new("Folder")
+                        .with_name("B")
+                        .with_child(InstanceBuilder::new("Folder").with_name("Grandchild")),
+                ),
+        );
+        let root_ref = dom.root_ref();
+        let a = dom.root().children()[0];
+        let b = dom.root().children()[1];
+        let grandchild = dom.get_by_ref(b).unwrap().children()[0];
+
+        assert_eq!(
+            dom.reorder_children(root_ref, &[b]),
+            Err(DomError::MissingChild {
+                parent: root_ref,
+                child: a
+            })
+        );
+        assert_eq!(
+            dom.reorder_children(root_ref, &[b, a, grandchild]),
+            Err(DomError::NotAChild {
+                parent: root_ref,
+                child: grandchild
+            })
+        );
+        assert_eq!(
+            dom.reorder_children(root_ref, &[b, a, b]),
+            Err(DomError::DuplicateRef { referent: b })
+        );
+
+        // Failed reorders leave the children alone.
+        assert_eq!(child_names(&dom, root_ref), ["A", "B"]);
+    }
+
+    #[test]
+    fn copy_instance_across() {
+        let outside = InstanceBuilder::new("Folder").with_name("Outside");
+        let outside_ref = outside.referent();
+
+        let target = InstanceBuilder::new("Part").with_name("Target");
+        let target_ref = target.referent();
+
+        let source = InstanceBuilder::new("Model")
+            .with_name("Source")
+            .with_property("PrimaryPart", target_ref)
+            .with_child(target)
+            .with_child(
+                InstanceBuilder::new("ObjectValue")
+                    .with_name("Pointer")
+                    .with_property("Value", outside_ref),
+            );
+        let source_ref = source.referent();
+
+        let src = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(source)
+                .with_child(outside),
+        );
+        let mut dest = WeakDom::new(InstanceBuilder::new("Folder"));
+        let dest_root = dest.root_ref();
+
+        let copy_ref = src.copy_instance_across(source_ref, &mut dest, dest_root);
+
+        // The source DOM is left alone.
+        assert_eq!(src.root().children(), &[source_ref, outside_ref]);
+        assert_eq!(src.get_by_ref(source_ref).unwrap().children().len(), 2);
+
+        assert_ne!(copy_ref, source_ref);
+        assert_eq!(dest.root().children(), &[copy_ref]);
+
+        let copy = dest.get_by_ref(copy_ref).unwrap();
+        assert_eq!(copy.name, "Source");
+        assert_eq!(copy.parent(), dest_root);
+
+        let target_copy_ref = copy.children()[0];
+        let target_copy = dest.get_by_ref(target_copy_ref).unwrap();
+        assert_ne!(target_copy_ref, target_ref);
+        assert_eq!(target_copy.name, "Target");
+        assert_eq!(target_copy.parent(), copy_ref);
+        assert_eq!(
+            copy.properties.get("PrimaryPart"),
+            Some(&Variant::Ref(target_copy_ref))
+        );
+
+        let pointer = dest.get_by_ref(copy.children()[1]).unwrap();
+        assert_eq!(pointer.parent(), copy_ref);
+        assert_eq!(
+            pointer.properties.get("Value"),
+            Some(&Variant::Ref(Ref::none()))
+        );
+
+        assert_eq!(
+            src.get_by_ref(target_ref).unwrap().parent(),
+            source_ref,
+            "source instances should keep their parents"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_instance_across_invalid_parent() {
+        let src = WeakDom::new(InstanceBuilder::new("Folder"));
+        let mut dest = WeakDom::new(InstanceBuilder::new("Folder"));
+        src.copy_instance_across(src.root_ref(), &mut dest, Ref::new());
+    }
+
+    #[test]
+    fn filter_map_transform() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_name("Root")
+                .with_child(
+                    InstanceBuilder::new("Folder")
+                        .with_name("A")
+                        .with_child(InstanceBuilder::new("Folder").with_name("B")),
+                )
+                .with_child(InstanceBuilder::new("Part").with_name("C")),
+        );
+        let root_ref = dom.root_ref();
+        let a = dom.root().children()[0];
+
+        let mut visited = Vec::new();
+        dom.filter_map(|instance| {
+            visited.push(instance.name.clone());
+
+            if instance.class == "Folder" {
+                instance.class = "Model".to_owned();
+                instance.name.push('!');
+            }
+
+            true
+        });
+
+        assert_eq!(visited, ["Root", "A", "C", "B"]);
+        assert_eq!(child_names(&dom, root_ref), ["A!", "C"]);
+        assert_eq!(child_names(&dom, a), ["B!"]);
+        assert_eq!(dom.root().class, "Model");
+        assert_eq!(
+            dom.get_by_ref(dom.root().children()[1]).unwrap().class,
+            "Part"
+        );
+    }
+
+    #[test]
+    fn filter_map_remove() {
+        let mut dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(
+                    InstanceBuilder::new("Folder")
+                        .with_name("Removed")
+                        .with_child(InstanceBuilder::new("Folder").with_name("Descendant")),
+                )
+                .with_child(InstanceBuilder::new("Folder").with_name("Kept")),
+        );
+        let root_ref = dom.root_ref();
+        let removed = dom.root().children()[0];
+        let descendant = dom.get_by_ref(removed).unwrap().children()[0];
+
+        let mut visited = Vec::new();
+        dom.filter_map(|instance| {
+            visited.push(instance.name.clone());
+            instance.name != "Removed"
+        });
+
+        assert!(!visited.contains(&"Descendant".to_owned()));
+        assert_eq!(child_names(&dom, root_ref), ["Kept"]);
+        assert!(dom.get_by_ref(removed).is_none());
+        assert!(dom.get_by_ref(descendant).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn filter_map_remove_root() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        dom.filter_map(|_| false);
+    }
+
+    #[test]
+    fn with_root_class() {
+        let dom = WeakDom::with_root_class("DataModel", "Game");
+        let root = dom.root();
+
+        assert_eq!(root.class, "DataModel");
+        assert_eq!(root.name, "Game");
+        assert_eq!(root.parent(), Ref::none());
+        assert!(root.properties.is_empty());
+        assert!(root.children().is_empty());
+    }
+
+    #[test]
+    fn insert_copy() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+
+        let target_ref = dom.insert(root_ref, InstanceBuilder::new("Part"));
+        let source_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("ObjectValue")
+                .with_name("Source")
+                .with_property("Value", target_ref)
+                .with_child(InstanceBuilder::new("Folder")),
+        );
+        let folder_ref = dom.insert(root_ref, InstanceBuilder::new("Folder"));
+
+        let copy_ref = dom.insert_copy(source_ref, folder_ref);
+        assert_ne!(copy_ref, source_ref);
+        assert_eq!(dom.get_by_ref(folder_ref).unwrap().children(), &[copy_ref]);
+
+        let copy = dom.get_by_ref(copy_ref).unwrap();
+        assert_eq!(copy.class, "ObjectValue");
+        assert_eq!(copy.name, "Source");
+        assert_eq!(copy.parent(), folder_ref);
+        assert!(copy.children().is_empty());
+        assert_eq!(
+            copy.properties.get("Value"),
+            Some(&Variant::Ref(target_ref))
+        );
+
+        let copy = dom.get_by_ref_mut(copy_ref).unwrap();
+        copy.name = "Copy".to_owned();
+        copy.properties
+            .insert("Value".into(), Variant::Ref(Ref::none()));
+
+        let source = dom.get_by_ref(source_ref).unwrap();
+        assert_eq!(source.name, "Source");
+        assert_eq!(source.children().len(), 1);
+        assert_eq!(
+            source.properties.get("Value"),
+            Some(&Variant::Ref(target_ref))
+        );
+    }
+
+    #[test]
+    fn iter_leaves() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_name("Model")
+                        .with_child(InstanceBuilder::new("Part").with_name("A"))
+                        .with_child(
+                            InstanceBuilder::new("Part")
+                                .with_name("B")
+                                .with_child(InstanceBuilder::new("Decal").with_name("C")),
+                        ),
+                )
+                .with_child(InstanceBuilder::new("Script").with_name("D"))
+                .with_child(InstanceBuilder::new("Folder").with_name("E")),
+        );
+
+        let mut leaves: Vec<&str> = dom
+            .iter_leaves()
+            .map(|instance| instance.name.as_str())
+            .collect();
+        leaves.sort_unstable();
+        assert_eq!(leaves, ["A", "C", "D", "E"]);
+
+        let parts: Vec<&str> = dom
+            .iter_leaves_of_class("Part")
+            .map(|instance| instance.name.as_str())
+            .collect();
+        assert_eq!(parts, ["A"]);
+
+        assert_eq!(dom.iter_leaves_of_class("Model").count(), 0);
+
+        let lonely = WeakDom::new(InstanceBuilder::new("Folder"));
+        assert_eq!(lonely.iter_leaves().count(), 1);
+    }
+
+    #[test]
+    fn reserve() {
+        let mut dom = WeakDom::new_with_capacity(InstanceBuilder::new("Folder"), 10);
+        let root_ref = dom.root_ref();
+        dom.reserve(100);
+
+        for _ in 0..100 {
+            dom.insert(root_ref, InstanceBuilder::new("Part"));
+        }
+
+        assert_eq!(dom.root().children().len(), 100);
+
+        let nested = WeakDom::new_with_capacity(
+            InstanceBuilder::new("Folder").with_child(
+                InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Part")),
+            ),
+            0,
+        );
+        assert_eq!(nested.instances.iter().count(), 3);
+    }
+
+    #[test]
+    fn root_class_and_name() {
+        let mut dom = WeakDom::with_root_class("DataModel", "Game");
+        assert_eq!(dom.root_class(), "DataModel");
+        assert_eq!(dom.root_name(), "Game");
+
+        dom.set_root_class("Model");
+        dom.set_root_name("MyModel");
+
+        assert_eq!(dom.root_class(), "Model");
+        assert_eq!(dom.root_name(), "MyModel");
+        assert_eq!(dom.root().class, "Model");
+        assert_eq!(dom.root().name, "MyModel");
+    }
+
+    #[test]
+    fn into_flat_list() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_child(InstanceBuilder::new("Part").with_property("Anchored", true)),
+                )
+                .with_child(
+                    InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Part")),
+                ),
+        );
+
+        let mut expected_refs = vec![dom.root_ref()];
+        let mut expected_parents = HashMap::new();
+        let mut stack = vec![dom.root_ref()];
+        while let Some(referent) = stack.pop() {
+            for &child in dom.get_by_ref(referent).unwrap().children() {
+                expected_refs.push(child);
+                expected_parents.insert(child, referent);
+                stack.push(child);
+            }
+        }
+
+        let root_ref = dom.root_ref();
+        let list = dom.into_flat_list();
+
+        let actual_refs: HashSet<Ref> = list.iter().map(|(referent, _, _)| *referent).collect();
+        assert_eq!(list.len(), expected_refs.len());
+        assert_eq!(actual_refs, expected_refs.into_iter().collect());
+
+        let positions: HashMap<Ref, usize> = list
+            .iter()
+            .enumerate()
+            .map(|(position, (referent, _, _))| (*referent, position))
+            .collect();
+
+        for (position, (referent, parent, builder)) in list.iter().enumerate() {
+            assert_eq!(builder.referent(), *referent);
+
+            match parent {
+                Some(parent) => {
+                    assert_eq!(expected_parents[referent], *parent);
+                    assert!(positions[parent] < position);
+                }
+                None => assert_eq!(*referent, root_ref),
+            }
+        }
+
+        let (_, _, model) = &list[1];
+        assert_eq!(model.class, "Model");
+        let (_, _, part) = &list[3];
+        assert_eq!(part.properties.get("Anchored"), Some(&Variant::Bool(true)));
+    }
+
+    #[test]
+    fn import_instances() {
+        let part = InstanceBuilder::new("Part").with_property("Anchored", true);
+        let part_ref = part.referent();
+
+        let src = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_name("Root")
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_property("PrimaryPart", part_ref)
+                        .with_child(part),
+                )
+                .with_child(
+                    InstanceBuilder::new("ObjectValue").with_property("Value", Ref::none()),
+                ),
+        );
+        let src_root = src.root_ref();
+        let expected = serde_json::to_value(DomViewer::new().view(&src)).unwrap();
+
+        let mut dest = WeakDom::new(InstanceBuilder::new("DataModel"));
+        let dest_root = dest.root_ref();
+        let mapping = dest
+            .import_instances(src.into_flat_list(), dest_root)
+            .unwrap();
+
+        assert_eq!(mapping.len(), 4);
+        assert_eq!(dest.root().children(), &[mapping[&src_root]]);
+
+        let part_copy = dest.get_by_ref(mapping[&part_ref]).unwrap();
+        assert_eq!(part_copy.class, "Part");
+
+        let imported = DomViewer::new().view_children(&dest);
+        assert_eq!(serde_json::to_value(&imported[0]).unwrap(), expected);
+    }
+
+    #[test]
+    fn import_instances_outside_refs() {
+        let pointer = InstanceBuilder::new("ObjectValue").with_property("Value", Ref::new());
+        let pointer_ref = pointer.referent();
+
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+        let mapping = dom
+            .import_instances(vec![(pointer_ref, None, pointer)], root_ref)
+            .unwrap();
+
+        let pointer = dom.get_by_ref(mapping[&pointer_ref]).unwrap();
+        assert_eq!(pointer.parent(), root_ref);
+        assert_eq!(
+            pointer.properties.get("Value"),
+            Some(&Variant::Ref(Ref::none()))
+        );
+    }
+
+    #[test]
+    fn import_instances_errors() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+
+        let missing = Ref::new();
+        assert_eq!(
+            dom.import_instances(Vec::new(), missing),
+            Err(DomError::InvalidRef { referent: missing })
+        );
+
+        let parent_ref = Ref::new();
+        let child_ref = Ref::new();
+        let folder = |referent| InstanceBuilder::new("Folder").with_referent(referent);
+
+        assert_eq!(
+            dom.import_instances(
+                vec![
+                    (child_ref, Some(parent_ref), folder(child_ref)),
+                    (parent_ref, None, folder(parent_ref)),
+                ],
+                root_ref,
+            ),
+            Err(DomError::UnknownParent {
+                referent: child_ref,
+                parent: parent_ref,
+            })
+        );
+
+        assert_eq!(
+            dom.import_instances(
+                vec![
+                    (parent_ref, None, folder(parent_ref)),
+                    (parent_ref, None, folder(parent_ref)),
+                ],
+                root_ref,
+            ),
+            Err(DomError::DuplicateRef {
+                referent: parent_ref
+            })
+        );
+
+        assert!(dom.root().children().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_test {
+    use super::*;
+
+    use rbx_types::Vector3;
+
+    #[test]
+    fn round_trip_json() {
+        let child = InstanceBuilder::new("Part")
+            .with_name("Some Part")
+            .with_property("Size", Vector3::new(1.0, 2.0, 3.0))
+            .with_property("Anchored", true);
+
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_property("Tags", "hello")
+                .with_child(child)
+                .with_child(InstanceBuilder::new("Model")),
+        );
+
+        let encoded = serde_json::to_string(&dom).unwrap();
+        let decoded: WeakDom = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(decoded.root_ref(), dom.root_ref());
+        assert_eq!(
+            decoded.instances.iter().count(),
+            dom.instances.iter().count()
+        );
+
+        for instance in dom.instances.iter() {
+            let other = decoded.get_by_ref(instance.referent).unwrap();
+
+            assert_eq!(other.referent(), instance.referent());
+            assert_eq!(other.parent(), instance.parent());
+            assert_eq!(other.children(), instance.children());
+            assert_eq!(other.name, instance.name);
+            assert_eq!(other.class, instance.class);
+            assert_eq!(other.properties, instance.properties);
+        }
+    }
+
+    #[test]
+    fn reject_broken_links() {
+        let dom =
+            WeakDom::new(InstanceBuilder::new("Folder").with_child(
+                InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Part")),
+            ));
+        let root_ref = dom.root_ref();
+        let child_ref = dom.root().children()[0];
+        let grandchild_ref = dom.get_by_ref(child_ref).unwrap().children()[0];
+
+        let valid = serde_json::to_value(&dom).unwrap();
+        assert!(serde_json::from_value::<WeakDom>(valid.clone()).is_ok());
+
+        let edit = |edit: &dyn Fn(&mut serde_json::Value)| {
+            let mut value = valid.clone();
+            edit(&mut value);
+            serde_json::from_value::<WeakDom>(value)
+                .unwrap_err()
+                .to_string()
+        };
+        // The root doesn't exist.
+        let error = edit(&|value| value["root_ref"] = serde_json::json!(Ref::new()));
+        assert!(error.contains("root instance"), "{}", error);
+
+        // A child's parent doesn't point back at the instance listing it.
+        let error = edit(&|value| {
+            value["instances"][grandchild_ref.to_string()]["parent"] = serde_json::json!(root_ref);
+        });
+        assert!(error.contains("but its parent is"), "{}", error);
+
+        // An instance isn't reachable from the root.
+        let error = edit(&|value| {
+            value["instances"][child_ref.to_string()]["children"] = serde_json::json!([]);
+        });
+        assert!(error.contains("not a descendant"), "{}", error);
+
+        // An instance is stored under the wrong referent.
+        let error = edit(&|value| {
+            let moved = value["instances"][grandchild_ref.to_string()].clone();
+            value["instances"][Ref::new().to_string()] = moved;
+        });
+        assert!(error.contains("stored under referent"), "{}", error);
     }
 }
@@ -0,0 +1,143 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use rbx_types::{Ref, Variant};
+
+use crate::WeakDom;
+
+impl WeakDom {
+    /// Writes a [Graphviz] DOT representation of the DOM to the given writer,
+    /// which is useful for debugging the structure of complicated trees.
+    ///
+    /// Each instance becomes a node labeled with its class and name. Solid
+    /// edges point from parents to their children, and dashed edges point from
+    /// `Ref` properties to the instances they refer to. Refs pointing outside
+    /// of the DOM are left out.
+    ///
+    /// Nodes are numbered in depth-first order, so the output for the same tree
+    /// is stable.
+    ///
+    /// [Graphviz]: https://graphviz.org/
+    pub fn to_dot(&self, writer: &mut impl Write) -> io::Result<()> {
+        let mut order = Vec::new();
+        let mut stack = vec![self.root_ref()];
+
+        while let Some(referent) = stack.pop() {
+            order.push(referent);
+
+            let instance = self.get_by_ref(referent).unwrap();
+            stack.extend(instance.children().iter().rev().copied());
+        }
+
+        let ids: HashMap<Ref, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(id, referent)| (*referent, id))
+            .collect();
+
+        writeln!(writer, "digraph {{")?;
+        writeln!(writer, "    node [shape=box];")?;
+
+        for (id, referent) in order.iter().enumerate() {
+            let instance = self.get_by_ref(*referent).unwrap();
+
+            writeln!(
+                writer,
+                "    i{} [label=\"{}\\n{}\"];",
+                id,
+                escape(&instance.class),
+                escape(&instance.name)
+            )?;
+        }
+
+        for (id, referent) in order.iter().enumerate() {
+            let instance = self.get_by_ref(*referent).unwrap();
+
+            for child in instance.children() {
+                writeln!(writer, "    i{} -> i{};", id, ids[child])?;
+            }
+
+            let mut ref_properties: Vec<_> = instance
+                .properties
+                .iter()
+                .filter_map(|(name, value)| match value {
+                    Variant::Ref(target) => Some((name, ids.get(target)?)),
+                    _ => None,
+                })
+                .collect();
+
+            ref_properties.sort();
+
+            for (name, target_id) in ref_properties {
+                writeln!(
+                    writer,
+                    "    i{} -> i{} [style=dashed, label=\"{}\"];",
+                    id,
+                    target_id,
+                    escape(name)
+                )?;
+            }
+        }
+
+        writeln!(writer, "}}")
+    }
+}
+
+/// Escapes a string so that it can be placed inside of a quoted DOT string.
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => {}
+            _ => output.push(char),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::InstanceBuilder;
+
+    #[test]
+    fn five_instances() {
+        let target = InstanceBuilder::new("Part").with_name("Target");
+        let target_ref = target.referent();
+
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_name("Root")
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_name("Model")
+                        .with_property("PrimaryPart", target_ref)
+                        .with_child(target),
+                )
+                .with_child(
+                    InstanceBuilder::new("ObjectValue")
+                        .with_name("Quoted \"Value\"")
+                        .with_property("Value", target_ref),
+                )
+                .with_child(
+                    InstanceBuilder::new("ObjectValue")
+                        .with_name("Dangling")
+                        .with_property("Value", Ref::new()),
+                ),
+        );
+
+        let mut output = Vec::new();
+        dom.to_dot(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        insta::assert_snapshot!(output);
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use rbx_types::Ref;
+
+/// An error returned by a [`WeakDom`][crate::WeakDom] operation that couldn't
+/// be carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DomError {
+    /// The given referent does not refer to an instance in the DOM.
+    InvalidRef {
+        /// The referent that wasn't found.
+        referent: Ref,
+    },
+
+    /// The given instance is not a child of the given parent.
+    NotAChild {
+        /// The referent of the parent.
+        parent: Ref,
+
+        /// The referent of the instance that isn't one of its children.
+        child: Ref,
+    },
+
+    /// A child of the given parent was left out of a list that needed to
+    /// contain all of its children.
+    MissingChild {
+        /// The referent of the parent.
+        parent: Ref,
+
+        /// The referent of the child that was left out.
+        child: Ref,
+    },
+
+    /// A referent appeared more than once in a list of instances.
+    DuplicateRef {
+        /// The referent that was repeated.
+        referent: Ref,
+    },
+
+    /// An instance in a list named a parent that did not come before it in
+    /// the list.
+    UnknownParent {
+        /// The referent of the instance.
+        referent: Ref,
+
+        /// The referent of the parent that wasn't found.
+        parent: Ref,
+    },
+
+    /// An index into an instance's children was past the end of the list.
+    IndexOutOfBounds {
+        /// The index that was given.
+        index: usize,
+
+        /// The number of children the instance has.
+        len: usize,
+    },
+
+    /// Instances couldn't be put in order because their `Ref` properties and
+    /// parents depend on each other in a cycle.
+    RefCycle {
+        /// The referent of an instance that is in the cycle or depends on an
+        /// instance in it.
+        referent: Ref,
+    },
+}
+
+impl fmt::Display for DomError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DomError::InvalidRef { referent } => {
+                write!(out, "instance {} does not exist in the DOM", referent)
+            }
+            DomError::NotAChild { parent, child } => {
+                write!(out, "instance {} is not a child of {}", child, parent)
+            }
+            DomError::MissingChild { parent, child } => write!(
+                out,
+                "instance {} is a child of {}, but was not listed",
+                child, parent
+            ),
+            DomError::DuplicateRef { referent } => {
+                write!(out, "instance {} appears more than once", referent)
+            }
+            DomError::UnknownParent { referent, parent } => write!(
+                out,
+                "instance {} has parent {}, which does not come before it",
+                referent, parent
+            ),
+            DomError::IndexOutOfBounds { index, len } => write!(
+                out,
+                "index {} is out of bounds for an instance with {} children",
+                index, len
+            ),
+            DomError::RefCycle { referent } => write!(
+                out,
+                "instance {} is part of or depends on a cycle of Ref properties",
+                referent
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DomError {}
+
+/// An error returned by
+/// [`InstanceBuilder::from_json`][crate::InstanceBuilder::from_json] when the
+/// JSON doesn't describe a valid instance, like when a required field is
+/// missing or a property value has the wrong shape.
+#[cfg(feature = "json")]
+#[derive(Debug)]
+pub struct JsonError {
+    source: serde_json::Error,
+}
+
+#[cfg(feature = "json")]
+impl JsonError {
+    pub(crate) fn new(source: serde_json::Error) -> Self {
+        Self { source }
+    }
+}
+
+#[cfg(feature = "json")]
+impl fmt::Display for JsonError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "invalid instance JSON: {}", self.source)
+    }
+}
+
+#[cfg(feature = "json")]
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    collections::HashMap,
+    convert::TryInto,
+    hash::{Hash, Hasher},
+};
+
+use rbx_types::{Ref, Variant};
+
+use crate::WeakDom;
+
+/// How a `Ref` property is represented when hashing, since the referents
+/// themselves are different every time a file is loaded.
+#[derive(Hash)]
+enum HashedRef {
+    /// The property is a null ref.
+    None,
+
+    /// The property points to an instance in the subtree being hashed,
+    /// identified by its position in a depth-first traversal of the subtree.
+    Internal(usize),
+
+    /// The property points to an instance outside of the subtree being hashed.
+    External,
+}
+
+/// A `Hasher` backed by BLAKE3. Unlike `DefaultHasher`, its output doesn't
+/// change between Rust releases, and integers are always fed to it as
+/// little-endian bytes, so hashes can be stored and compared later.
+struct StableHasher(blake3::Hasher);
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        let hash = self.0.finalize();
+        u64::from_le_bytes(hash.as_bytes()[..8].try_into().unwrap())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+}
+
+impl WeakDom {
+    /// Computes a hash of the subtree rooted at `root` that depends only on its
+    /// structure and contents: class names, names, property values, and the
+    /// order of children. The values of referents are not included, so two
+    /// DOMs loaded from the same file produce the same hash.
+    ///
+    /// `Ref` properties are hashed by where they point in the subtree.
+    ///
+    /// The hash is computed with BLAKE3, so it's the same on every platform
+    /// and with every Rust release.
+    ///
+    /// ## Panics
+    /// Panics if `root` does not refer to an instance in the DOM.
+    pub fn structural_hash(&self, root: Ref) -> u64 {
+        let mut order = Vec::new();
+        let mut stack = vec![root];
+
+        while let Some(referent) = stack.pop() {
+            let instance = self
+                .get_by_ref(referent)
+                .unwrap_or_else(|| panic!("cannot hash an instance that does not exist"));
+
+            order.push(instance);
+            stack.extend(instance.children().iter().rev().copied());
+        }
+
+        let positions: HashMap<Ref, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(position, instance)| (instance.referent(), position))
+            .collect();
+
+        let mut hasher = StableHasher(blake3::Hasher::new());
+
+        for instance in order {
+            instance.class.hash(&mut hasher);
+            instance.name.hash(&mut hasher);
+            instance.children().len().hash(&mut hasher);
+
+            let mut properties: Vec<_> = instance.properties.iter().collect();
+            properties.sort_by_key(|(name, _)| *name);
+
+            properties.len().hash(&mut hasher);
+
+            for (name, value) in properties {
+                name.hash(&mut hasher);
+
+                match value {
+                    Variant::Ref(referent) => {
+                        value.ty().hash(&mut hasher);
+
+                        let hashed = if referent.is_none() {
+                            HashedRef::None
+                        } else {
+                            match positions.get(referent) {
+                                Some(&position) => HashedRef::Internal(position),
+                                None => HashedRef::External,
+                            }
+                        };
+
+                        hashed.hash(&mut hasher);
+                    }
+                    _ => value.hash(&mut hasher),
+                }
+            }
+        }
+
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_types::Vector3;
+
+    use crate::InstanceBuilder;
+
+    /// Builds the same small tree every time it's called, but with brand new
+    /// referents.
+    fn build(first: &str, second: &str, deep_name: &str) -> WeakDom {
+        let target = InstanceBuilder::new("Part")
+            .with_name(deep_name)
+            .with_property("Size", Vector3::new(1.0, 2.0, 3.0));
+        let target_ref = target.referent();
+
+        WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name(first))
+                .with_child(InstanceBuilder::new("Folder").with_name(second))
+                .with_child(
+                    InstanceBuilder::new("Model")
+                        .with_property("PrimaryPart", target_ref)
+                        .with_child(InstanceBuilder::new("Model").with_child(target)),
+                ),
+        )
+    }
+
+    #[test]
+    fn independent_of_referents() {
+        let a = build("A", "B", "Deep");
+        let b = build("A", "B", "Deep");
+
+        assert_eq!(
+            a.structural_hash(a.root_ref()),
+            b.structural_hash(b.root_ref())
+        );
+    }
+
+    #[test]
+    fn child_order() {
+        let a = build("A", "B", "Deep");
+        let b = build("B", "A", "Deep");
+
+        assert_ne!(
+            a.structural_hash(a.root_ref()),
+            b.structural_hash(b.root_ref())
+        );
+    }
+
+    #[test]
+    fn deep_rename() {
+        let a = build("A", "B", "Deep");
+        let b = build("A", "B", "Renamed");
+
+        assert_ne!(
+            a.structural_hash(a.root_ref()),
+            b.structural_hash(b.root_ref())
+        );
+    }
+
+    #[test]
+    fn ref_target() {
+        let a = build("A", "B", "Deep");
+        let mut b = build("A", "B", "Deep");
+
+        let model_ref = b.root().children()[2];
+        let first_ref = b.root().children()[0];
+        b.get_by_ref_mut(model_ref)
+            .unwrap()
+            .properties
+            .insert("PrimaryPart".into(), Variant::Ref(first_ref));
+
+        assert_ne!(
+            a.structural_hash(a.root_ref()),
+            b.structural_hash(b.root_ref())
+        );
+    }
+
+    #[test]
+    fn stable_value() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Part").with_property("Anchored", true)),
+        );
+
+        // This value should only change if the way instances are hashed
+        // changes, not when Rust or the platform does.
+        assert_eq!(dom.structural_hash(dom.root_ref()), 11900607123257136390);
+    }
+}
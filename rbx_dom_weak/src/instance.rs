@@ -1,6 +1,12 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap, convert::TryFrom, sync::Arc};
 
-use rbx_types::{Ref, Variant};
+use rbx_reflection::ReflectionDatabase;
+use rbx_types::{Attributes, Ref, Tags, Variant, VariantTypeMismatch};
+
+use crate::{
+    validation::{validate_builder, ValidationError},
+    WeakDom,
+};
 
 /**
 Represents an instance that can be turned into a new
@@ -30,16 +36,48 @@ let data_model = InstanceBuilder::new("DataModel")
 
 let dom = WeakDom::new(data_model);
 ```
+
+When the `serde` feature is enabled, `InstanceBuilder` can be serialized. Only
+`name` and `class` are required when deserializing; a missing `referent` is
+generated with [`Ref::new`], and missing `properties` and `children` are empty.
 */
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct InstanceBuilder {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "Ref::new", deserialize_with = "deserialize_referent")
+    )]
     pub(crate) referent: Ref,
     pub(crate) name: String,
     pub(crate) class: String,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) properties: HashMap<String, Variant>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub(crate) children: Vec<InstanceBuilder>,
 }
 
+/// Builders always have a referent, so reject null ones instead of building
+/// an instance that can't be inserted into a DOM.
+#[cfg(feature = "serde")]
+fn deserialize_referent<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Ref, D::Error> {
+    let referent: Ref = serde::Deserialize::deserialize(deserializer)?;
+
+    if referent.is_none() {
+        Err(serde::de::Error::custom(
+            "instance referents must not be null",
+        ))
+    } else {
+        Ok(referent)
+    }
+}
+
 impl InstanceBuilder {
     /// Create a new `InstanceBuilder` with the given ClassName. This is also
     /// used as the instance's Name, unless overwritten later.
@@ -61,6 +99,31 @@ impl InstanceBuilder {
         self.referent
     }
 
+    /// Change the referent of the `InstanceBuilder`. This is useful for keeping
+    /// referents stable when the same instance is loaded more than once.
+    ///
+    /// Inserting two instances with the same referent into one
+    /// [`WeakDom`][crate::WeakDom] will leave the DOM in an invalid state.
+    pub fn with_referent(self, referent: Ref) -> Self {
+        Self { referent, ..self }
+    }
+
+    /// Converts this builder and all of its descendants to JSON, in the same
+    /// form used by its `Serialize` implementation.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Value {
+        // Every field serializes to a string, number, array, or map with string
+        // keys, none of which serde_json can fail on.
+        serde_json::to_value(self).expect("InstanceBuilder should always serialize to JSON")
+    }
+
+    /// Creates a builder from JSON produced by
+    /// [`InstanceBuilder::to_json`], or written by hand in the same form.
+    #[cfg(feature = "json")]
+    pub fn from_json(value: serde_json::Value) -> Result<Self, crate::JsonError> {
+        serde_json::from_value(value).map_err(crate::JsonError::new)
+    }
+
     /// Change the name of the `InstanceBuilder`.
     pub fn with_name<S: Into<String>>(self, name: S) -> Self {
         Self {
@@ -142,6 +205,78 @@ impl InstanceBuilder {
     {
         self.children.extend(children.into_iter());
     }
+
+    /// Fills in every property that isn't already set with its default value
+    /// from the reflection database, including properties inherited from
+    /// superclasses. Properties with no known default are left unset, as are
+    /// the properties of any children.
+    pub fn inherit_defaults(mut self) -> Self {
+        self.add_defaults_from(rbx_reflection_database::get());
+        self
+    }
+
+    /// Like [`InstanceBuilder::inherit_defaults`], but takes the defaults from
+    /// the given reflection database instead of the bundled one.
+    pub fn add_defaults_from(&mut self, database: &ReflectionDatabase) {
+        let class = match database.classes.get(self.class.as_str()) {
+            Some(class) => class,
+            None => return,
+        };
+
+        for class in database.superclasses(class) {
+            for (name, value) in &class.default_properties {
+                if !self.properties.contains_key(name.as_ref()) {
+                    self.properties.insert(name.to_string(), value.clone());
+                }
+            }
+        }
+    }
+
+    /// Checks this instance against the reflection database, without checking
+    /// its children.
+    ///
+    /// The instance's class must be known, each of its properties must be a
+    /// canonical property of its class or one of its superclasses, and each
+    /// property's value must have the type that the property expects. All
+    /// problems that are found are returned together.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        validate_builder(self)
+    }
+
+    /// Inserts this instance and all of its descendants into `dom` as the last
+    /// child of `parent`, like [`WeakDom::insert`].
+    ///
+    /// Space for the whole tree is reserved in the DOM before anything is
+    /// inserted. Instances keep the referents of the builders they came from,
+    /// so [`InstanceBuilder::referent`] can be used to find them afterwards.
+    ///
+    /// ## Panics
+    /// Panics if `parent` does not refer to an instance in `dom`.
+    pub fn build_into_dom(self, dom: &mut WeakDom, parent: Ref) -> BuildResult {
+        dom.reserve(self.count());
+        let root = dom.insert(parent, self);
+
+        BuildResult { root }
+    }
+
+    /// Returns the number of instances in this builder, including itself and
+    /// all of its descendants.
+    pub(crate) fn count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(InstanceBuilder::count)
+            .sum::<usize>()
+    }
+}
+
+/// The result of inserting a tree of instances with
+/// [`InstanceBuilder::build_into_dom`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct BuildResult {
+    /// The referent of the topmost instance that was inserted.
+    pub root: Ref,
 }
 
 /// An instance contained inside of a [`WeakDom`][crate::WeakDom].
@@ -149,6 +284,11 @@ impl InstanceBuilder {
 /// Operations that could affect other instances contained in the
 /// [`WeakDom`][crate::WeakDom] cannot be performed on an `Instance` correctly.
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct Instance {
     pub(crate) referent: Ref,
     pub(crate) children: Vec<Ref>,
@@ -161,7 +301,12 @@ pub struct Instance {
     pub class: String,
 
     /// Any properties stored on the object that are not `Name` or `ClassName`.
-    pub properties: HashMap<String, Variant>,
+    ///
+    /// Property names are shared between all instances in the same
+    /// [`WeakDom`][crate::WeakDom]. New names can be created with
+    /// [`WeakDom::intern_property_name`][crate::WeakDom::intern_property_name]
+    /// or converted from any string with `into()`.
+    pub properties: HashMap<Arc<str>, Variant>,
 }
 
 impl Instance {
@@ -186,4 +331,541 @@ impl Instance {
     pub fn parent(&self) -> Ref {
         self.parent
     }
+
+    /// Tells whether this instance's class is `class` or inherits from it
+    /// according to the reflection database, like Roblox's `IsA`.
+    ///
+    /// If this instance's class isn't in the reflection database, this only
+    /// returns true if `class` is exactly the instance's class.
+    pub fn is_a(&self, class: &str) -> bool {
+        if self.class == class {
+            return true;
+        }
+
+        let database = rbx_reflection_database::get();
+
+        match database.classes.get(self.class.as_str()) {
+            Some(descriptor) => database
+                .superclasses(descriptor)
+                .any(|superclass| superclass.name == class),
+            None => false,
+        }
+    }
+
+    /// Returns the value of the property with the given name converted to the
+    /// type `T`.
+    ///
+    /// Returns `None` if the property is not present, or `Some(Err(_))` if the
+    /// property is present but holds a different type.
+    ///
+    /// ```
+    /// use rbx_dom_weak::{InstanceBuilder, WeakDom};
+    /// use rbx_dom_weak::types::Vector3;
+    ///
+    /// let part = InstanceBuilder::new("Part")
+    ///     .with_property("Size", Vector3::new(4.0, 1.0, 2.0));
+    /// let dom = WeakDom::new(part);
+    ///
+    /// let size = dom.root().get_property_as::<Vector3>("Size");
+    /// assert_eq!(size, Some(Ok(Vector3::new(4.0, 1.0, 2.0))));
+    /// ```
+    pub fn get_property_as<'a, T>(&'a self, name: &str) -> Option<Result<T, VariantTypeMismatch>>
+    where
+        T: TryFrom<&'a Variant, Error = VariantTypeMismatch>,
+    {
+        self.properties.get(name).map(T::try_from)
+    }
+
+    /// Returns the number of properties set on this instance, not counting
+    /// `Name` or `ClassName`.
+    #[inline]
+    pub fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
+    /// Tells whether this instance has a property with the given name.
+    #[inline]
+    pub fn has_property(&self, name: &str) -> bool {
+        self.properties.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of every property set on this
+    /// instance, in no particular order.
+    pub fn property_names(&self) -> impl Iterator<Item = &str> {
+        self.properties.keys().map(|name| name.as_ref())
+    }
+
+    /// Returns an iterator over every property set on this instance, sorted by
+    /// name. Unlike iterating over `properties` directly, the order is the same
+    /// every time, which is useful for diffing and snapshots.
+    pub fn iter_properties_sorted(&self) -> impl Iterator<Item = (&str, &Variant)> {
+        let mut properties: Vec<(&str, &Variant)> = self
+            .properties
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value))
+            .collect();
+
+        properties.sort_unstable_by_key(|&(name, _)| name);
+        properties.into_iter()
+    }
+
+    /// Returns the `CollectionService` tags on this instance.
+    ///
+    /// Tags are read from the `Tags` property, which can also be a
+    /// null-delimited `BinaryString` if the instance was loaded without
+    /// reflection. Instances without tags return an empty list.
+    pub fn get_tags(&self) -> Tags {
+        match self.properties.get("Tags") {
+            Some(Variant::Tags(tags)) => tags.clone(),
+            Some(Variant::BinaryString(value)) => Tags::decode(value.as_ref()),
+            _ => Tags::new(),
+        }
+    }
+
+    /// Tells whether this instance has the given `CollectionService` tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        match self.properties.get("Tags") {
+            Some(Variant::Tags(tags)) => tags.contains(tag),
+            _ => self.get_tags().contains(tag),
+        }
+    }
+
+    /// Adds a `CollectionService` tag to this instance if it doesn't already
+    /// have it. The `Tags` property is always left as a `Variant::Tags`.
+    pub fn add_tag(&mut self, tag: &str) {
+        let mut tags = self.get_tags();
+        tags.push(tag);
+
+        match self.properties.get_mut("Tags") {
+            Some(value) => *value = tags.into(),
+            None => {
+                self.properties.insert(Arc::from("Tags"), tags.into());
+            }
+        }
+    }
+
+    /// Returns the value of the attribute with the given name, if this
+    /// instance has it.
+    ///
+    /// Attributes are read from the `Attributes` property, or from
+    /// `AttributesSerialize` if the instance was loaded without reflection. If
+    /// that property is still an encoded `BinaryString`, it's decoded for this
+    /// call and left as it is. Attributes that can't be decoded are treated as
+    /// missing.
+    pub fn get_attribute(&self, name: &str) -> Option<Variant> {
+        match self.attributes()? {
+            Cow::Borrowed(attributes) => attributes.get(name).cloned(),
+            Cow::Owned(mut attributes) => attributes.remove(name),
+        }
+    }
+
+    /// Returns an iterator over the attributes on this instance, sorted by
+    /// name. Encoded attributes are decoded the same way as
+    /// [`get_attribute`][Instance::get_attribute].
+    pub fn iter_attributes(&self) -> impl Iterator<Item = (String, Variant)> {
+        self.attributes().map(Cow::into_owned).into_iter().flatten()
+    }
+
+    fn attributes(&self) -> Option<Cow<'_, Attributes>> {
+        let value = self
+            .properties
+            .get("Attributes")
+            .or_else(|| self.properties.get("AttributesSerialize"))?;
+
+        match value {
+            Variant::Attributes(attributes) => Some(Cow::Borrowed(attributes)),
+            Variant::BinaryString(buffer) => {
+                Attributes::decode(buffer.as_ref()).ok().map(Cow::Owned)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use rbx_types::{BinaryString, Color3, VariantType, Vector3};
+
+    use crate::WeakDom;
+
+    #[test]
+    fn get_property_as() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Part")
+                .with_property("Size", Vector3::new(1.0, 2.0, 3.0))
+                .with_property("Anchored", true),
+        );
+        let part = dom.root();
+
+        assert_eq!(
+            part.get_property_as::<Vector3>("Size"),
+            Some(Ok(Vector3::new(1.0, 2.0, 3.0)))
+        );
+        assert_eq!(part.get_property_as::<bool>("Anchored"), Some(Ok(true)));
+
+        assert_eq!(
+            part.get_property_as::<String>("Size"),
+            Some(Err(VariantTypeMismatch {
+                expected: VariantType::String,
+                actual: VariantType::Vector3,
+            }))
+        );
+
+        assert_eq!(part.get_property_as::<Vector3>("Position"), None);
+    }
+
+    #[test]
+    fn build_into_dom() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+        let existing_ref = dom.insert(root_ref, InstanceBuilder::new("Folder"));
+
+        let part = InstanceBuilder::new("Part").with_property("Anchored", true);
+        let part_ref = part.referent();
+        let model = InstanceBuilder::new("Model")
+            .with_child(part)
+            .with_child(InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Script")));
+        let model_ref = model.referent();
+
+        let result = model.build_into_dom(&mut dom, root_ref);
+
+        assert_eq!(result.root, model_ref);
+
+        assert_eq!(dom.root().children(), &[existing_ref, model_ref]);
+
+        let model = dom.get_by_ref(model_ref).unwrap();
+        assert_eq!(model.parent(), root_ref);
+        assert_eq!(model.children().len(), 2);
+        assert_eq!(model.children()[0], part_ref);
+
+        let part = dom.get_by_ref(part_ref).unwrap();
+        assert_eq!(part.parent(), model_ref);
+        assert_eq!(part.properties.get("Anchored"), Some(&Variant::Bool(true)));
+
+        let folder = dom.get_by_ref(model.children()[1]).unwrap();
+        let script = dom.get_by_ref(folder.children()[0]).unwrap();
+        assert_eq!(script.class, "Script");
+        assert_eq!(script.parent(), folder.referent());
+    }
+
+    #[test]
+    fn property_helpers() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Part")
+                .with_property("Size", Vector3::new(1.0, 2.0, 3.0))
+                .with_property("Anchored", true),
+        );
+        let part = dom.root();
+
+        assert_eq!(part.property_count(), part.properties.len());
+        assert_eq!(part.property_count(), 2);
+
+        assert!(part.has_property("Size"));
+        assert!(part.has_property("Anchored"));
+        assert!(!part.has_property("Position"));
+        assert!(!part.has_property("Name"));
+
+        let mut names: Vec<&str> = part.property_names().collect();
+        names.sort_unstable();
+        assert_eq!(names, ["Anchored", "Size"]);
+    }
+
+    #[test]
+    fn iter_properties_sorted() {
+        let dom = WeakDom::new(
+            InstanceBuilder::new("Part")
+                .with_property("Size", Vector3::new(1.0, 2.0, 3.0))
+                .with_property("Anchored", true)
+                .with_property("Transparency", 0.5f32)
+                .with_property("CanCollide", false)
+                .with_property("Color", Color3::new(1.0, 0.0, 0.0)),
+        );
+        let part = dom.root();
+
+        let expected: BTreeMap<&str, &Variant> = part
+            .properties
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value))
+            .collect();
+        let expected: Vec<(&str, &Variant)> = expected.into_iter().collect();
+
+        let sorted: Vec<(&str, &Variant)> = part.iter_properties_sorted().collect();
+        assert_eq!(sorted, expected);
+        assert_eq!(
+            sorted.iter().map(|&(name, _)| name).collect::<Vec<_>>(),
+            ["Anchored", "CanCollide", "Color", "Size", "Transparency"]
+        );
+
+        for _ in 0..10 {
+            assert!(part.iter_properties_sorted().eq(sorted.iter().copied()));
+        }
+    }
+
+    #[test]
+    fn tags() {
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+
+        let none_ref = dom.insert(root_ref, InstanceBuilder::new("Part"));
+        let one_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part").with_property("Tags", Tags::from(&["Enemy"][..])),
+        );
+        let many_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_property("Tags", BinaryString::from(&b"Enemy\0Boss\0Flying"[..])),
+        );
+
+        let none = dom.get_by_ref(none_ref).unwrap();
+        assert!(none.get_tags().is_empty());
+        assert!(!none.has_tag("Enemy"));
+
+        let one = dom.get_by_ref(one_ref).unwrap();
+        assert_eq!(one.get_tags().iter().collect::<Vec<_>>(), ["Enemy"]);
+        assert!(one.has_tag("Enemy"));
+        assert!(!one.has_tag("Boss"));
+
+        let many = dom.get_by_ref(many_ref).unwrap();
+        assert_eq!(
+            many.get_tags().iter().collect::<Vec<_>>(),
+            ["Enemy", "Boss", "Flying"]
+        );
+        assert!(many.has_tag("Flying"));
+
+        let none = dom.get_by_ref_mut(none_ref).unwrap();
+        none.add_tag("Enemy");
+        none.add_tag("Enemy");
+        assert_eq!(
+            none.properties.get("Tags"),
+            Some(&Variant::Tags(Tags::from(&["Enemy"][..])))
+        );
+
+        let many = dom.get_by_ref_mut(many_ref).unwrap();
+        many.add_tag("Boss");
+        many.add_tag("Loot");
+        assert_eq!(
+            many.properties.get("Tags"),
+            Some(&Variant::Tags(Tags::from(
+                &["Enemy", "Boss", "Flying", "Loot"][..]
+            )))
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        let encoded = vec![("Health", 100.0f64)]
+            .into_iter()
+            .collect::<Attributes>()
+            .encode()
+            .unwrap();
+
+        let mut dom = WeakDom::new(InstanceBuilder::new("Folder"));
+        let root_ref = dom.root_ref();
+
+        let none_ref = dom.insert(root_ref, InstanceBuilder::new("Part"));
+        let decoded_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part").with_property(
+                "Attributes",
+                vec![("Health", 100.0f64), ("Speed", 16.0)]
+                    .into_iter()
+                    .collect::<Attributes>(),
+            ),
+        );
+        let encoded_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_property("AttributesSerialize", BinaryString::from(encoded)),
+        );
+        let invalid_ref = dom.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_property("Attributes", BinaryString::from(&b"\x01"[..])),
+        );
+
+        let none = dom.get_by_ref(none_ref).unwrap();
+        assert_eq!(none.get_attribute("Health"), None);
+        assert_eq!(none.iter_attributes().count(), 0);
+
+        let decoded = dom.get_by_ref(decoded_ref).unwrap();
+        assert_eq!(
+            decoded.get_attribute("Health"),
+            Some(Variant::Float64(100.0))
+        );
+        assert_eq!(decoded.get_attribute("Team"), None);
+        assert_eq!(
+            decoded.iter_attributes().collect::<Vec<_>>(),
+            [
+                ("Health".to_owned(), Variant::Float64(100.0)),
+                ("Speed".to_owned(), Variant::Float64(16.0))
+            ]
+        );
+
+        // Encoded attributes are decoded when read, but not stored.
+        let encoded = dom.get_by_ref(encoded_ref).unwrap();
+        assert_eq!(
+            encoded.get_attribute("Health"),
+            Some(Variant::Float64(100.0))
+        );
+        assert_eq!(
+            encoded.iter_attributes().collect::<Vec<_>>(),
+            [("Health".to_owned(), Variant::Float64(100.0))]
+        );
+        assert!(matches!(
+            encoded.properties.get("AttributesSerialize"),
+            Some(Variant::BinaryString(_))
+        ));
+
+        let invalid = dom.get_by_ref(invalid_ref).unwrap();
+        assert_eq!(invalid.get_attribute("Health"), None);
+        assert_eq!(invalid.iter_attributes().count(), 0);
+    }
+
+    #[test]
+    fn is_a() {
+        let dom = WeakDom::new(InstanceBuilder::new("Part"));
+        let part = dom.root();
+
+        assert!(part.is_a("Part"));
+        assert!(part.is_a("BasePart"));
+        assert!(part.is_a("Instance"));
+        assert!(!part.is_a("Decal"));
+        assert!(!part.is_a("NotARealClass"));
+
+        let dom = WeakDom::new(InstanceBuilder::new("NotARealClass"));
+        assert!(dom.root().is_a("NotARealClass"));
+        assert!(!dom.root().is_a("Instance"));
+    }
+
+    #[test]
+    fn inherit_defaults() {
+        let builder = InstanceBuilder::new("Part")
+            .with_property("Anchored", true)
+            .inherit_defaults();
+
+        assert!(builder.properties.contains_key("Size"));
+        assert!(builder.properties.contains_key("Color"));
+        assert!(builder.properties.contains_key("Material"));
+
+        // Properties that were already set should not be overwritten.
+        assert_eq!(
+            builder.properties.get("Anchored"),
+            Some(&Variant::Bool(true))
+        );
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json_test {
+    use super::*;
+
+    use rbx_types::{
+        BinaryString, CFrame, Color3, Enum, Matrix3, UDim, UDim2, Vector2, Vector3, Vector3int16,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn round_trip() {
+        let part = InstanceBuilder::new("Part")
+            .with_name("Some Part")
+            .with_property("Anchored", true)
+            .with_property("Int32", 5i32)
+            .with_property("Int64", -7i64)
+            .with_property("Float32", 0.5f32)
+            .with_property("Float64", 1.25f64)
+            .with_property("String", "hello")
+            .with_property("BinaryString", BinaryString::from(&b"\x00\x01"[..]))
+            .with_property("Color3", Color3::new(1.0, 0.5, 0.0))
+            .with_property("Vector2", Vector2::new(1.0, 2.0))
+            .with_property("Vector3", Vector3::new(1.0, 2.0, 3.0))
+            .with_property("Vector3int16", Vector3int16::new(1, -2, 3))
+            .with_property("UDim2", UDim2::new(UDim::new(0.5, 10), UDim::new(1.0, -10)))
+            .with_property(
+                "CFrame",
+                CFrame::new(Vector3::new(1.0, 2.0, 3.0), Matrix3::identity()),
+            )
+            .with_property("Material", Enum::from_u32(256));
+        let builder = InstanceBuilder::new("Model")
+            .with_child(part)
+            .with_child(InstanceBuilder::new("Folder").with_child(InstanceBuilder::new("Script")));
+
+        let encoded = builder.to_json();
+        let decoded = InstanceBuilder::from_json(encoded.clone()).unwrap();
+
+        assert_eq!(decoded.to_json(), encoded);
+        assert_eq!(decoded.referent(), builder.referent());
+        assert_eq!(decoded.children.len(), 2);
+        assert_eq!(
+            decoded.children[0].properties,
+            builder.children[0].properties
+        );
+        assert_eq!(decoded.children[1].children[0].class, "Script");
+    }
+
+    #[test]
+    fn optional_fields() {
+        let builder = InstanceBuilder::from_json(json!({
+            "name": "Stuff",
+            "class": "Folder",
+            "children": [
+                {
+                    "name": "Flag",
+                    "class": "BoolValue",
+                    "properties": { "Value": { "Bool": true } },
+                },
+            ],
+        }))
+        .unwrap();
+
+        assert!(builder.referent().is_some());
+        assert_eq!(builder.name, "Stuff");
+        assert!(builder.properties.is_empty());
+
+        let flag = &builder.children[0];
+        assert!(flag.referent().is_some());
+        assert_ne!(flag.referent(), builder.referent());
+        assert_eq!(flag.properties.get("Value"), Some(&Variant::Bool(true)));
+    }
+
+    #[test]
+    fn invalid() {
+        let missing_class = json!({ "name": "Folder" });
+        let missing_name = json!({ "class": "Folder" });
+        let nested_missing_class = json!({
+            "name": "Folder",
+            "class": "Folder",
+            "children": [{ "name": "Part" }],
+        });
+        let null_referent = json!({
+            "referent": "00000000000000000000000000000000",
+            "name": "Folder",
+            "class": "Folder",
+        });
+        let bad_property = json!({
+            "name": "Part",
+            "class": "Part",
+            "properties": { "Size": { "Vector3": "big" } },
+        });
+
+        for value in &[
+            missing_class,
+            missing_name,
+            nested_missing_class,
+            null_referent,
+            bad_property,
+        ] {
+            let err = InstanceBuilder::from_json(value.clone()).unwrap_err();
+            assert!(
+                err.to_string().starts_with("invalid instance JSON"),
+                "{} gave the wrong error: {}",
+                value,
+                err
+            );
+        }
+    }
 }
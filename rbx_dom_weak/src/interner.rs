@@ -0,0 +1,49 @@
+use std::{collections::HashSet, sync::Arc};
+
+/// Deduplicates strings, handing out shared references to a single copy of
+/// each unique string.
+///
+/// `WeakDom` uses this for property names, which are repeated on almost every
+/// instance in large places.
+#[derive(Debug, Default)]
+pub(crate) struct StringInterner {
+    strings: HashSet<Arc<str>>,
+}
+
+impl StringInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared copy of the given string, creating it if this is the
+    /// first time we've seen it.
+    pub fn intern(&mut self, value: &str) -> Arc<str> {
+        if let Some(existing) = self.strings.get(value) {
+            return Arc::clone(existing);
+        }
+
+        let interned: Arc<str> = Arc::from(value);
+        self.strings.insert(Arc::clone(&interned));
+        interned
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deduplicates() {
+        let mut interner = StringInterner::new();
+
+        let separate_allocation = String::from("Size");
+
+        let first = interner.intern("Size");
+        let second = interner.intern(&separate_allocation);
+        let other = interner.intern("Color");
+
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(&*other, "Color");
+    }
+}
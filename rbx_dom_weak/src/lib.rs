@@ -41,14 +41,42 @@
 
 #![deny(missing_docs)]
 
+#[cfg(feature = "serde")]
+extern crate serde_crate as serde;
+
+mod arena;
+mod canonicalize;
+mod compat;
+mod diff;
 mod dom;
+mod dot;
+mod error;
+mod hash;
 mod instance;
+mod interner;
+mod validation;
+#[cfg(feature = "serde")]
 mod viewer;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub use rbx_types as types;
 
 pub use crate::{
+    canonicalize::canonicalize_properties,
+    diff::{diff_properties, PropertyChange},
     dom::WeakDom,
-    instance::{Instance, InstanceBuilder},
-    viewer::{DomViewer, ViewedInstance},
+    error::DomError,
+    instance::{BuildResult, Instance, InstanceBuilder},
+    validation::ValidationError,
 };
+
+#[cfg(feature = "serde")]
+pub use crate::viewer::{DomViewer, ViewedInstance};
+
+#[cfg(feature = "json")]
+pub use crate::error::JsonError;
+
+#[allow(deprecated)]
+pub use crate::compat::{RbxId, RbxInstance, RbxTree, RbxValue, RbxValueType};
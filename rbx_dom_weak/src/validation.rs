@@ -0,0 +1,255 @@
+use std::fmt;
+
+use rbx_reflection::{DataType, PropertyDescriptor, PropertyKind, ReflectionDatabase};
+use rbx_types::{Variant, VariantType};
+
+use crate::InstanceBuilder;
+
+/// A problem found by [`InstanceBuilder::validate`] when checking an instance
+/// against the reflection database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The instance's class is not known to the reflection database.
+    UnknownClass {
+        /// The ClassName of the instance.
+        class: String,
+    },
+
+    /// The instance has a property that isn't defined on its class or any of
+    /// its superclasses.
+    UnknownProperty {
+        /// The ClassName of the instance.
+        class: String,
+
+        /// The name of the unknown property.
+        property: String,
+    },
+
+    /// The instance has a property that is an alias of another property,
+    /// instead of using the property's canonical name.
+    NonCanonicalProperty {
+        /// The ClassName of the instance.
+        class: String,
+
+        /// The name of the property as it was set on the instance.
+        property: String,
+
+        /// The canonical name of the property.
+        canonical: String,
+    },
+
+    /// The instance has a property whose value is a different type than the
+    /// reflection database expects.
+    TypeMismatch {
+        /// The ClassName of the instance.
+        class: String,
+
+        /// The name of the property.
+        property: String,
+
+        /// The type that the reflection database expects.
+        expected: VariantType,
+
+        /// The type of the value set on the instance.
+        actual: VariantType,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::UnknownClass { class } => write!(out, "unknown class {}", class),
+            ValidationError::UnknownProperty { class, property } => {
+                write!(out, "unknown property {}.{}", class, property)
+            }
+            ValidationError::NonCanonicalProperty {
+                class,
+                property,
+                canonical,
+            } => write!(
+                out,
+                "property {}.{} should use its canonical name, {}",
+                class, property, canonical
+            ),
+            ValidationError::TypeMismatch {
+                class,
+                property,
+                expected,
+                actual,
+            } => write!(
+                out,
+                "property {}.{} should be of type {:?}, but was {:?}",
+                class, property, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Finds the descriptor for the given property on the given class or any of its
+/// superclasses.
+pub(crate) fn find_property<'db>(
+    database: &'db ReflectionDatabase<'db>,
+    class_name: &str,
+    property_name: &str,
+) -> Option<&'db PropertyDescriptor<'db>> {
+    let class = database.classes.get(class_name)?;
+
+    database
+        .superclasses(class)
+        .find_map(|class| class.properties.get(property_name))
+}
+
+fn validate_property(
+    database: &ReflectionDatabase,
+    class: &str,
+    property: &str,
+    value: &Variant,
+    errors: &mut Vec<ValidationError>,
+) {
+    let descriptor = match find_property(database, class, property) {
+        Some(descriptor) => descriptor,
+        None => {
+            errors.push(ValidationError::UnknownProperty {
+                class: class.to_owned(),
+                property: property.to_owned(),
+            });
+            return;
+        }
+    };
+
+    if let PropertyKind::Alias { alias_for } = &descriptor.kind {
+        errors.push(ValidationError::NonCanonicalProperty {
+            class: class.to_owned(),
+            property: property.to_owned(),
+            canonical: alias_for.to_string(),
+        });
+        return;
+    }
+
+    let expected = match &descriptor.data_type {
+        DataType::Value(ty) => *ty,
+        DataType::Enum(_) => VariantType::Enum,
+
+        // If a newer reflection database has a kind of type we don't know
+        // about, we can't say whether the value is wrong.
+        _ => return,
+    };
+
+    if value.ty() != expected {
+        errors.push(ValidationError::TypeMismatch {
+            class: class.to_owned(),
+            property: property.to_owned(),
+            expected,
+            actual: value.ty(),
+        });
+    }
+}
+
+pub(crate) fn validate_builder(builder: &InstanceBuilder) -> Result<(), Vec<ValidationError>> {
+    let database = rbx_reflection_database::get();
+    let mut errors = Vec::new();
+
+    if database.classes.contains_key(builder.class.as_str()) {
+        // Sort properties so that errors are reported in a stable order.
+        let mut properties: Vec<_> = builder.properties.iter().collect();
+        properties.sort_by_key(|(name, _)| *name);
+
+        for (name, value) in properties {
+            validate_property(database, &builder.class, name, value, &mut errors);
+        }
+    } else {
+        errors.push(ValidationError::UnknownClass {
+            class: builder.class.clone(),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_types::Vector3;
+
+    #[test]
+    fn valid() {
+        let builder = InstanceBuilder::new("Part")
+            .with_property("Size", Vector3::new(1.0, 2.0, 3.0))
+            .with_property("Anchored", true);
+
+        assert_eq!(builder.validate(), Ok(()));
+    }
+
+    #[test]
+    fn unknown_class() {
+        let builder = InstanceBuilder::new("NotARealClass");
+
+        assert_eq!(
+            builder.validate(),
+            Err(vec![ValidationError::UnknownClass {
+                class: "NotARealClass".to_owned(),
+            }])
+        );
+    }
+
+    #[test]
+    fn unknown_property() {
+        let builder = InstanceBuilder::new("Folder").with_property("NotARealProperty", true);
+
+        assert_eq!(
+            builder.validate(),
+            Err(vec![ValidationError::UnknownProperty {
+                class: "Folder".to_owned(),
+                property: "NotARealProperty".to_owned(),
+            }])
+        );
+    }
+
+    #[test]
+    fn mismatched_type() {
+        let builder = InstanceBuilder::new("Part")
+            .with_property("Anchored", "yes")
+            .with_property("Size", true);
+
+        assert_eq!(
+            builder.validate(),
+            Err(vec![
+                ValidationError::TypeMismatch {
+                    class: "Part".to_owned(),
+                    property: "Anchored".to_owned(),
+                    expected: VariantType::Bool,
+                    actual: VariantType::String,
+                },
+                ValidationError::TypeMismatch {
+                    class: "Part".to_owned(),
+                    property: "Size".to_owned(),
+                    expected: VariantType::Vector3,
+                    actual: VariantType::Bool,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn non_canonical_property() {
+        let builder =
+            InstanceBuilder::new("Part").with_property("size", Vector3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(
+            builder.validate(),
+            Err(vec![ValidationError::NonCanonicalProperty {
+                class: "Part".to_owned(),
+                property: "size".to_owned(),
+                canonical: "Size".to_owned(),
+            }])
+        );
+    }
+}
@@ -81,7 +81,7 @@ impl DomViewer {
             .properties
             .iter()
             .map(|(key, value)| {
-                let key = key.clone();
+                let key = key.to_string();
                 let new_value = match value {
                     Variant::Ref(referent) => {
                         if referent.is_some() {
@@ -134,6 +134,7 @@ impl Default for DomViewer {
 /// A transformed view into a `WeakDom` or `Instance` that has been redacted and
 /// transformed to be more readable.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "serde_crate")]
 pub struct ViewedInstance {
     referent: String,
     name: String,
@@ -145,6 +146,7 @@ pub struct ViewedInstance {
 /// Wrapper around Variant with refs replaced to be redacted, stable versions of
 /// their original IDs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(crate = "serde_crate")]
 #[serde(untagged)]
 enum ViewedValue {
     Ref(String),
@@ -161,7 +163,7 @@ mod test {
 
     #[test]
     fn redact_single() {
-        let dom = WeakDom::new(InstanceBuilder::new("Folder").with_name("Root"));
+        let dom = WeakDom::with_root_class("Folder", "Root");
 
         insta::assert_yaml_snapshot!(DomViewer::new().view(&dom));
     }
@@ -0,0 +1,67 @@
+//! A [`WeakDom`] handle for passing DOMs to and from JavaScript through
+//! wasm-bindgen, enabled by the `wasm` feature. rbx_binary and rbx_xml use it
+//! for the values they hand to JavaScript, so a DOM decoded by one can be
+//! encoded by the other.
+//!
+//! These bindings only run in a WebAssembly host, which CI doesn't test. Their
+//! tests can be run locally with
+//! `wasm-pack test --headless --firefox -- --features wasm`.
+
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::{types::Ref, WeakDom};
+
+/// A decoded model or place, handed to JavaScript as an opaque object.
+#[wasm_bindgen]
+pub struct Dom {
+    dom: WeakDom,
+}
+
+#[wasm_bindgen]
+impl Dom {
+    /// Returns the instances in the DOM as plain JavaScript objects with
+    /// `name`, `className`, and `children` fields, starting from the root.
+    #[wasm_bindgen(js_name = toObject)]
+    pub fn to_object(&self) -> Object {
+        instance_to_object(&self.dom, self.dom.root_ref())
+    }
+}
+
+impl Dom {
+    /// Returns a reference to the `WeakDom` held by this handle.
+    pub fn inner(&self) -> &WeakDom {
+        &self.dom
+    }
+
+    /// Returns the `WeakDom` held by this handle.
+    pub fn into_inner(self) -> WeakDom {
+        self.dom
+    }
+}
+
+impl From<WeakDom> for Dom {
+    fn from(dom: WeakDom) -> Self {
+        Self { dom }
+    }
+}
+
+fn instance_to_object(dom: &WeakDom, referent: Ref) -> Object {
+    let instance = dom.get_by_ref(referent).unwrap();
+    let children: Array = instance
+        .children()
+        .iter()
+        .map(|&child| instance_to_object(dom, child))
+        .collect();
+
+    let object = Object::new();
+    set_field(&object, "name", &instance.name.as_str().into());
+    set_field(&object, "className", &instance.class.as_str().into());
+    set_field(&object, "children", &children);
+    object
+}
+
+fn set_field(object: &Object, key: &str, value: &JsValue) {
+    // Setting a field on a plain object we just created can't fail.
+    Reflect::set(object, &key.into(), value).unwrap();
+}
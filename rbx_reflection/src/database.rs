@@ -5,6 +5,7 @@
 use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
+    iter,
 };
 
 use rbx_types::{Variant, VariantType};
@@ -39,6 +40,60 @@ impl<'a> ReflectionDatabase<'a> {
             enums: HashMap::new(),
         }
     }
+
+    /// Returns an iterator over the given class followed by each of its
+    /// superclasses, ending at the root of the hierarchy. Superclasses missing
+    /// from the database end the iterator early.
+    pub fn superclasses<'d>(
+        &'d self,
+        descriptor: &'d ClassDescriptor<'a>,
+    ) -> impl Iterator<Item = &'d ClassDescriptor<'a>> + 'd {
+        iter::successors(Some(descriptor), move |class| {
+            class
+                .superclass
+                .as_ref()
+                .and_then(|superclass| self.classes.get(superclass))
+        })
+    }
+
+    /// Tells whether Roblox would save an instance of the given class to a
+    /// file, given the value of the instance's `Archivable` property if it has
+    /// one. Instances without the property use their class's default, and are
+    /// saved if there isn't one.
+    pub fn is_archivable(&self, class_name: &str, archivable: Option<&Variant>) -> bool {
+        let archivable =
+            archivable.or_else(|| self.find_default_property(class_name, "Archivable"));
+
+        match archivable {
+            Some(Variant::Bool(archivable)) => *archivable,
+            _ => true,
+        }
+    }
+
+    /// Finds the default value of the given property on the given class,
+    /// searching superclasses if the class itself doesn't define one.
+    pub fn find_default_property(&self, class_name: &str, property_name: &str) -> Option<&Variant> {
+        let class = self.classes.get(class_name)?;
+
+        self.superclasses(class)
+            .find_map(|class| class.default_properties.get(property_name))
+    }
+
+    /// Tells whether the given value is the default value for the given
+    /// property on the given class. If no default value is known, returns
+    /// `false`.
+    ///
+    /// Values are compared structurally, using `Variant`'s `Eq` impl.
+    pub fn is_default(&self, class_name: &str, property_name: &str, value: &Variant) -> bool {
+        self.find_default_property(class_name, property_name)
+            .map(|default| default == value)
+            .unwrap_or(false)
+    }
+
+    /// Finds the enum with the given name, like "Material", if it exists.
+    pub fn get_enum(&self, name: &str) -> Option<&EnumDescriptor<'a>> {
+        self.enums.get(name)
+    }
 }
 
 /// Describes a class of Instance, its properties, and its relation to other
@@ -117,6 +172,11 @@ impl<'a> PropertyDescriptor<'a> {
             },
         }
     }
+
+    /// Tells whether Roblox has marked this property as deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.tags.contains(&PropertyTag::Deprecated)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -200,4 +260,18 @@ impl<'a> EnumDescriptor<'a> {
             items: HashMap::new(),
         }
     }
+
+    /// Returns the value of the item with the given name, like
+    /// "SmoothPlastic", if it exists.
+    pub fn by_name(&self, item_name: &str) -> Option<u32> {
+        self.items.get(item_name).copied()
+    }
+
+    /// Returns the name of the item with the given value, if it exists.
+    pub fn by_value(&self, value: u32) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|(_, &item_value)| item_value == value)
+            .map(|(name, _)| name.as_ref())
+    }
 }
@@ -1,8 +1,81 @@
 mod class_tag;
 mod database;
 mod property_tag;
+// Generated by generate_reflection, which checks that it's up to date.
+#[rustfmt::skip]
+mod property_types;
 mod serde_util;
 
 pub use class_tag::*;
 pub use database::*;
 pub use property_tag::*;
+pub use property_types::validate_property_type;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_types::VariantType;
+
+    #[test]
+    fn valid_property_types() {
+        assert!(validate_property_type("Part", "Shape", VariantType::Enum));
+        assert!(validate_property_type("Part", "Size", VariantType::Vector3));
+        assert!(validate_property_type("Part", "Name", VariantType::String));
+        assert!(validate_property_type(
+            "Model",
+            "PrimaryPart",
+            VariantType::Ref
+        ));
+        assert!(validate_property_type(
+            "StringValue",
+            "Value",
+            VariantType::String
+        ));
+    }
+
+    #[test]
+    fn invalid_property_types() {
+        assert!(!validate_property_type(
+            "Part",
+            "Size",
+            VariantType::Vector2
+        ));
+        assert!(!validate_property_type(
+            "Part",
+            "Name",
+            VariantType::BinaryString
+        ));
+        assert!(!validate_property_type(
+            "StringValue",
+            "Value",
+            VariantType::Bool
+        ));
+
+        // Properties only belong to their class and its subclasses.
+        assert!(!validate_property_type(
+            "Instance",
+            "Size",
+            VariantType::Vector3
+        ));
+        assert!(!validate_property_type(
+            "Folder",
+            "PrimaryPart",
+            VariantType::Ref
+        ));
+    }
+
+    #[test]
+    fn unknown_properties() {
+        assert!(!validate_property_type(
+            "Part",
+            "NotAProperty",
+            VariantType::String
+        ));
+        assert!(!validate_property_type(
+            "NotAClass",
+            "Name",
+            VariantType::String
+        ));
+    }
+}
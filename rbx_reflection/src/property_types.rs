@@ -0,0 +1,2279 @@
+// This file was @generated by generate_reflection from Roblox 0.484.0.425477.
+// Do not edit it by hand!
+
+use rbx_types::VariantType;
+
+/// Every class that has a superclass as `(class, superclass)`, sorted by
+/// class.
+static SUPERCLASSES: &[(&str, &str)] = &[
+    ("ABTestService", "Instance"),
+    ("Accessory", "Accoutrement"),
+    ("Accoutrement", "Instance"),
+    ("Actor", "Model"),
+    ("AdService", "Instance"),
+    ("AdvancedDragger", "Instance"),
+    ("AlignOrientation", "Constraint"),
+    ("AlignPosition", "Constraint"),
+    ("AnalysticsSettings", "GenericSettings"),
+    ("AnalyticsService", "Instance"),
+    ("AngularVelocity", "Constraint"),
+    ("Animation", "Instance"),
+    ("AnimationController", "Instance"),
+    ("AnimationTrack", "Instance"),
+    ("Animator", "Instance"),
+    ("AppStorageService", "LocalStorageService"),
+    ("AppUpdateService", "Instance"),
+    ("ArcHandles", "HandlesBase"),
+    ("AssetCounterService", "Instance"),
+    ("AssetDeliveryProxy", "Instance"),
+    ("AssetImportService", "Instance"),
+    ("AssetManagerService", "Instance"),
+    ("AssetService", "Instance"),
+    ("Atmosphere", "Instance"),
+    ("Attachment", "Instance"),
+    ("AvatarEditorService", "Instance"),
+    ("AvatarImportService", "Instance"),
+    ("Backpack", "Instance"),
+    ("BackpackItem", "Instance"),
+    ("BadgeService", "Instance"),
+    ("BallSocketConstraint", "Constraint"),
+    ("BasePart", "PVInstance"),
+    ("BasePlayerGui", "Instance"),
+    ("BaseScript", "LuaSourceContainer"),
+    ("BaseWrap", "Instance"),
+    ("Beam", "Instance"),
+    ("BevelMesh", "DataModelMesh"),
+    ("BillboardGui", "LayerCollector"),
+    ("BinaryStringValue", "ValueBase"),
+    ("BindableEvent", "Instance"),
+    ("BindableFunction", "Instance"),
+    ("BlockMesh", "BevelMesh"),
+    ("BloomEffect", "PostEffect"),
+    ("BlurEffect", "PostEffect"),
+    ("BodyAngularVelocity", "BodyMover"),
+    ("BodyColors", "CharacterAppearance"),
+    ("BodyForce", "BodyMover"),
+    ("BodyGyro", "BodyMover"),
+    ("BodyMover", "Instance"),
+    ("BodyPosition", "BodyMover"),
+    ("BodyThrust", "BodyMover"),
+    ("BodyVelocity", "BodyMover"),
+    ("Bone", "Attachment"),
+    ("BoolValue", "ValueBase"),
+    ("BoxHandleAdornment", "HandleAdornment"),
+    ("BrickColorValue", "ValueBase"),
+    ("BrowserService", "Instance"),
+    ("BulkImportService", "Instance"),
+    ("CFrameValue", "ValueBase"),
+    ("CSGDictionaryService", "FlyweightService"),
+    ("CacheableContentProvider", "Instance"),
+    ("CalloutService", "Instance"),
+    ("Camera", "Instance"),
+    ("CatalogPages", "Pages"),
+    ("ChangeHistoryService", "Instance"),
+    ("CharacterAppearance", "Instance"),
+    ("CharacterMesh", "CharacterAppearance"),
+    ("Chat", "Instance"),
+    ("ChorusSoundEffect", "SoundEffect"),
+    ("ClickDetector", "Instance"),
+    ("ClientReplicator", "NetworkReplicator"),
+    ("Clothing", "CharacterAppearance"),
+    ("Clouds", "Instance"),
+    ("ClusterPacketCache", "Instance"),
+    ("CollectionService", "Instance"),
+    ("Color3Value", "ValueBase"),
+    ("ColorCorrectionEffect", "PostEffect"),
+    ("CommandInstance", "Instance"),
+    ("CommandService", "Instance"),
+    ("CompressorSoundEffect", "SoundEffect"),
+    ("ConeHandleAdornment", "HandleAdornment"),
+    ("Configuration", "Instance"),
+    ("Constraint", "Instance"),
+    ("ContentProvider", "Instance"),
+    ("ContextActionService", "Instance"),
+    ("Controller", "Instance"),
+    ("ControllerService", "Instance"),
+    ("CookiesService", "Instance"),
+    ("CoreGui", "BasePlayerGui"),
+    ("CorePackages", "Instance"),
+    ("CoreScript", "BaseScript"),
+    ("CoreScriptSyncService", "Instance"),
+    ("CornerWedgePart", "BasePart"),
+    ("CustomEvent", "Instance"),
+    ("CustomEventReceiver", "Instance"),
+    ("CylinderHandleAdornment", "HandleAdornment"),
+    ("CylinderMesh", "BevelMesh"),
+    ("CylindricalConstraint", "SlidingBallConstraint"),
+    ("DataModel", "ServiceProvider"),
+    ("DataModelMesh", "Instance"),
+    ("DataModelSession", "Instance"),
+    ("DataStore", "GlobalDataStore"),
+    ("DataStoreEnumerationPages", "Pages"),
+    ("DataStoreIncrementOptions", "Instance"),
+    ("DataStoreInfo", "Instance"),
+    ("DataStoreKeyInfo", "Instance"),
+    ("DataStoreKeyPages", "Pages"),
+    ("DataStoreKeyVersionInfo", "Instance"),
+    ("DataStorePages", "Pages"),
+    ("DataStoreService", "Instance"),
+    ("DataStoreSetOptions", "Instance"),
+    ("DataStoreVersionPages", "Pages"),
+    ("Debris", "Instance"),
+    ("DebugSettings", "Instance"),
+    ("DebuggerBreakpoint", "Instance"),
+    ("DebuggerManager", "Instance"),
+    ("DebuggerWatch", "Instance"),
+    ("Decal", "FaceInstance"),
+    ("DepthOfFieldEffect", "PostEffect"),
+    ("Dialog", "Instance"),
+    ("DialogChoice", "Instance"),
+    ("DistortionSoundEffect", "SoundEffect"),
+    ("DockWidgetPluginGui", "PluginGui"),
+    ("DoubleConstrainedValue", "ValueBase"),
+    ("DraftsService", "Instance"),
+    ("Dragger", "Instance"),
+    ("DraggerService", "Instance"),
+    ("DynamicRotate", "JointInstance"),
+    ("EchoSoundEffect", "SoundEffect"),
+    ("EmotesPages", "InventoryPages"),
+    ("EqualizerSoundEffect", "SoundEffect"),
+    ("EventIngestService", "Instance"),
+    ("Explosion", "Instance"),
+    ("FaceInstance", "Instance"),
+    ("Feature", "Instance"),
+    ("File", "Instance"),
+    ("FileMesh", "DataModelMesh"),
+    ("Fire", "Instance"),
+    ("Flag", "Tool"),
+    ("FlagStand", "Part"),
+    ("FlagStandService", "Instance"),
+    ("FlangeSoundEffect", "SoundEffect"),
+    ("FloorWire", "GuiBase3d"),
+    ("FlyweightService", "Instance"),
+    ("Folder", "Instance"),
+    ("ForceField", "Instance"),
+    ("FormFactorPart", "BasePart"),
+    ("Frame", "GuiObject"),
+    ("FriendPages", "Pages"),
+    ("FriendService", "Instance"),
+    ("FunctionalTest", "Instance"),
+    ("GamePassService", "Instance"),
+    ("GameSettings", "Instance"),
+    ("GamepadService", "Instance"),
+    ("GenericSettings", "ServiceProvider"),
+    ("Geometry", "Instance"),
+    ("GetDataStoreOptions", "Instance"),
+    ("GlobalDataStore", "Instance"),
+    ("GlobalSettings", "GenericSettings"),
+    ("Glue", "JointInstance"),
+    ("GoogleAnalyticsConfiguration", "Instance"),
+    ("GroupService", "Instance"),
+    ("GuiBase", "Instance"),
+    ("GuiBase2d", "GuiBase"),
+    ("GuiBase3d", "GuiBase"),
+    ("GuiButton", "GuiObject"),
+    ("GuiLabel", "GuiObject"),
+    ("GuiMain", "ScreenGui"),
+    ("GuiObject", "GuiBase2d"),
+    ("GuiService", "Instance"),
+    ("GuidRegistryService", "Instance"),
+    ("HandleAdornment", "PVAdornment"),
+    ("Handles", "HandlesBase"),
+    ("HandlesBase", "PartAdornment"),
+    ("HapticService", "Instance"),
+    ("Hat", "Accoutrement"),
+    ("HeightmapImporterService", "Instance"),
+    ("HingeConstraint", "Constraint"),
+    ("Hint", "Message"),
+    ("Hole", "Feature"),
+    ("Hopper", "Instance"),
+    ("HopperBin", "BackpackItem"),
+    ("HttpRbxApiService", "Instance"),
+    ("HttpRequest", "Instance"),
+    ("HttpService", "Instance"),
+    ("Humanoid", "Instance"),
+    ("HumanoidController", "Controller"),
+    ("HumanoidDescription", "Instance"),
+    ("ILegacyStudioBridge", "Instance"),
+    ("IXPService", "Instance"),
+    ("ImageButton", "GuiButton"),
+    ("ImageHandleAdornment", "HandleAdornment"),
+    ("ImageLabel", "GuiLabel"),
+    ("IncrementalPatchBuilder", "Instance"),
+    ("InputObject", "Instance"),
+    ("InsertService", "Instance"),
+    ("InstanceAdornment", "GuiBase3d"),
+    ("IntConstrainedValue", "ValueBase"),
+    ("IntValue", "ValueBase"),
+    ("InternalContainer", "Instance"),
+    ("InventoryPages", "Pages"),
+    ("JointInstance", "Instance"),
+    ("JointsService", "Instance"),
+    ("KeyboardService", "Instance"),
+    ("Keyframe", "Instance"),
+    ("KeyframeMarker", "Instance"),
+    ("KeyframeSequence", "Instance"),
+    ("KeyframeSequenceProvider", "Instance"),
+    ("LanguageService", "Instance"),
+    ("LayerCollector", "GuiBase2d"),
+    ("LegacyStudioBridge", "ILegacyStudioBridge"),
+    ("Light", "Instance"),
+    ("Lighting", "Instance"),
+    ("LineForce", "Constraint"),
+    ("LineHandleAdornment", "HandleAdornment"),
+    ("LocalScript", "Script"),
+    ("LocalStorageService", "Instance"),
+    ("LocalizationService", "Instance"),
+    ("LocalizationTable", "Instance"),
+    ("LogService", "Instance"),
+    ("LoginService", "Instance"),
+    ("LuaSettings", "Instance"),
+    ("LuaSourceContainer", "Instance"),
+    ("LuaWebService", "Instance"),
+    ("ManualGlue", "ManualSurfaceJointInstance"),
+    ("ManualSurfaceJointInstance", "JointInstance"),
+    ("ManualWeld", "ManualSurfaceJointInstance"),
+    ("MarketplaceService", "Instance"),
+    ("MemStorageConnection", "Instance"),
+    ("MemStorageService", "Instance"),
+    ("MemoryStoreQueue", "Instance"),
+    ("MemoryStoreService", "Instance"),
+    ("MemoryStoreSortedMap", "Instance"),
+    ("MeshContentProvider", "CacheableContentProvider"),
+    ("MeshPart", "TriangleMeshPart"),
+    ("Message", "Instance"),
+    ("MessagingService", "Instance"),
+    ("Model", "PVInstance"),
+    ("ModuleScript", "LuaSourceContainer"),
+    ("Motor", "JointInstance"),
+    ("Motor6D", "Motor"),
+    ("MotorFeature", "Feature"),
+    ("Mouse", "Instance"),
+    ("MouseService", "Instance"),
+    ("MultipleDocumentInterfaceInstance", "Instance"),
+    ("NegateOperation", "PartOperation"),
+    ("NetworkClient", "NetworkPeer"),
+    ("NetworkMarker", "Instance"),
+    ("NetworkPeer", "Instance"),
+    ("NetworkReplicator", "Instance"),
+    ("NetworkServer", "NetworkPeer"),
+    ("NetworkSettings", "Instance"),
+    ("NoCollisionConstraint", "Instance"),
+    ("NonReplicatedCSGDictionaryService", "FlyweightService"),
+    ("NotificationService", "Instance"),
+    ("NumberPose", "PoseBase"),
+    ("NumberValue", "ValueBase"),
+    ("ObjectValue", "ValueBase"),
+    ("ObjectVersionInfo", "Instance"),
+    ("OrderedDataStore", "GlobalDataStore"),
+    ("OutfitPages", "Pages"),
+    ("PVAdornment", "GuiBase3d"),
+    ("PVInstance", "Instance"),
+    ("PackageLink", "Instance"),
+    ("PackageService", "Instance"),
+    ("Pages", "Instance"),
+    ("Pants", "Clothing"),
+    ("ParabolaAdornment", "PVAdornment"),
+    ("Part", "FormFactorPart"),
+    ("PartAdornment", "GuiBase3d"),
+    ("PartOperation", "TriangleMeshPart"),
+    ("PartOperationAsset", "Instance"),
+    ("ParticleEmitter", "Instance"),
+    ("Path", "Instance"),
+    ("PathfindingService", "Instance"),
+    ("PermissionsService", "Instance"),
+    ("PhysicsService", "Instance"),
+    ("PhysicsSettings", "Instance"),
+    ("PitchShiftSoundEffect", "SoundEffect"),
+    ("Platform", "Part"),
+    ("Player", "Instance"),
+    ("PlayerEmulatorService", "Instance"),
+    ("PlayerGui", "BasePlayerGui"),
+    ("PlayerMouse", "Mouse"),
+    ("PlayerScripts", "Instance"),
+    ("Players", "Instance"),
+    ("Plugin", "Instance"),
+    ("PluginAction", "Instance"),
+    ("PluginDebugService", "Instance"),
+    ("PluginDragEvent", "Instance"),
+    ("PluginGui", "LayerCollector"),
+    ("PluginGuiService", "Instance"),
+    ("PluginManager", "Instance"),
+    ("PluginManagerInterface", "Instance"),
+    ("PluginMenu", "Instance"),
+    ("PluginMouse", "Mouse"),
+    ("PluginPolicyService", "Instance"),
+    ("PluginToolbar", "Instance"),
+    ("PluginToolbarButton", "Instance"),
+    ("PointLight", "Light"),
+    ("PointsService", "Instance"),
+    ("PolicyService", "Instance"),
+    ("Pose", "PoseBase"),
+    ("PoseBase", "Instance"),
+    ("PostEffect", "Instance"),
+    ("PrismaticConstraint", "SlidingBallConstraint"),
+    ("ProximityPrompt", "Instance"),
+    ("ProximityPromptService", "Instance"),
+    ("PublishService", "Instance"),
+    ("QWidgetPluginGui", "PluginGui"),
+    ("RayValue", "ValueBase"),
+    ("RbxAnalyticsService", "Instance"),
+    ("ReflectionMetadata", "Instance"),
+    ("ReflectionMetadataCallbacks", "Instance"),
+    ("ReflectionMetadataClass", "ReflectionMetadataItem"),
+    ("ReflectionMetadataClasses", "Instance"),
+    ("ReflectionMetadataEnum", "ReflectionMetadataItem"),
+    ("ReflectionMetadataEnumItem", "ReflectionMetadataItem"),
+    ("ReflectionMetadataEnums", "Instance"),
+    ("ReflectionMetadataEvents", "Instance"),
+    ("ReflectionMetadataFunctions", "Instance"),
+    ("ReflectionMetadataItem", "Instance"),
+    ("ReflectionMetadataMember", "ReflectionMetadataItem"),
+    ("ReflectionMetadataProperties", "Instance"),
+    ("ReflectionMetadataYieldFunctions", "Instance"),
+    ("RemoteEvent", "Instance"),
+    ("RemoteFunction", "Instance"),
+    ("RenderSettings", "Instance"),
+    ("RenderingTest", "Instance"),
+    ("ReplicatedFirst", "Instance"),
+    ("ReplicatedScriptService", "Instance"),
+    ("ReplicatedStorage", "Instance"),
+    ("ReverbSoundEffect", "SoundEffect"),
+    ("RobloxPluginGuiService", "Instance"),
+    ("RobloxReplicatedStorage", "Instance"),
+    ("RocketPropulsion", "BodyMover"),
+    ("RodConstraint", "Constraint"),
+    ("RopeConstraint", "Constraint"),
+    ("Rotate", "JointInstance"),
+    ("RotateP", "DynamicRotate"),
+    ("RotateV", "DynamicRotate"),
+    ("RunService", "Instance"),
+    ("RunningAverageItemDouble", "StatsItem"),
+    ("RunningAverageItemInt", "StatsItem"),
+    ("RunningAverageTimeIntervalItem", "StatsItem"),
+    ("RuntimeScriptService", "Instance"),
+    ("ScreenGui", "LayerCollector"),
+    ("Script", "BaseScript"),
+    ("ScriptContext", "Instance"),
+    ("ScriptDebugger", "Instance"),
+    ("ScriptService", "Instance"),
+    ("ScrollingFrame", "GuiObject"),
+    ("Seat", "Part"),
+    ("Selection", "Instance"),
+    ("SelectionBox", "InstanceAdornment"),
+    ("SelectionLasso", "GuiBase3d"),
+    ("SelectionPartLasso", "SelectionLasso"),
+    ("SelectionPointLasso", "SelectionLasso"),
+    ("SelectionSphere", "PVAdornment"),
+    ("ServerReplicator", "NetworkReplicator"),
+    ("ServerScriptService", "Instance"),
+    ("ServerStorage", "Instance"),
+    ("ServiceProvider", "Instance"),
+    ("SessionService", "Instance"),
+    ("Shirt", "Clothing"),
+    ("ShirtGraphic", "CharacterAppearance"),
+    ("SkateboardController", "Controller"),
+    ("SkateboardPlatform", "Part"),
+    ("Skin", "CharacterAppearance"),
+    ("Sky", "Instance"),
+    ("SlidingBallConstraint", "Constraint"),
+    ("Smoke", "Instance"),
+    ("Snap", "JointInstance"),
+    ("SocialService", "Instance"),
+    ("SolidModelContentProvider", "CacheableContentProvider"),
+    ("Sound", "Instance"),
+    ("SoundEffect", "Instance"),
+    ("SoundGroup", "Instance"),
+    ("SoundService", "Instance"),
+    ("Sparkles", "Instance"),
+    ("SpawnLocation", "Part"),
+    ("SpawnerService", "Instance"),
+    ("Speaker", "Instance"),
+    ("SpecialMesh", "FileMesh"),
+    ("SphereHandleAdornment", "HandleAdornment"),
+    ("SpotLight", "Light"),
+    ("SpringConstraint", "Constraint"),
+    ("StandalonePluginScripts", "Instance"),
+    ("StandardPages", "Pages"),
+    ("StarterCharacterScripts", "StarterPlayerScripts"),
+    ("StarterGear", "Instance"),
+    ("StarterGui", "BasePlayerGui"),
+    ("StarterPack", "Instance"),
+    ("StarterPlayer", "Instance"),
+    ("StarterPlayerScripts", "Instance"),
+    ("Stats", "Instance"),
+    ("StatsItem", "Instance"),
+    ("Status", "Model"),
+    ("StopWatchReporter", "Instance"),
+    ("StringValue", "ValueBase"),
+    ("Studio", "Instance"),
+    ("StudioData", "Instance"),
+    ("StudioDeviceEmulatorService", "Instance"),
+    ("StudioService", "Instance"),
+    ("StudioTheme", "Instance"),
+    ("SunRaysEffect", "PostEffect"),
+    ("SurfaceAppearance", "Instance"),
+    ("SurfaceGui", "LayerCollector"),
+    ("SurfaceLight", "Light"),
+    ("SurfaceSelection", "PartAdornment"),
+    ("TaskScheduler", "Instance"),
+    ("Team", "Instance"),
+    ("Teams", "Instance"),
+    ("TeleportAsyncResult", "Instance"),
+    ("TeleportOptions", "Instance"),
+    ("TeleportService", "Instance"),
+    ("Terrain", "BasePart"),
+    ("TerrainRegion", "Instance"),
+    ("TestService", "Instance"),
+    ("TextBox", "GuiObject"),
+    ("TextButton", "GuiButton"),
+    ("TextFilterResult", "Instance"),
+    ("TextLabel", "GuiLabel"),
+    ("TextService", "Instance"),
+    ("Texture", "Decal"),
+    ("ThirdPartyUserService", "Instance"),
+    ("TimerService", "Instance"),
+    ("ToastNotificationService", "Instance"),
+    ("Tool", "BackpackItem"),
+    ("Torque", "Constraint"),
+    ("TorsionSpringConstraint", "Constraint"),
+    ("TotalCountTimeIntervalItem", "StatsItem"),
+    ("TouchInputService", "Instance"),
+    ("TouchTransmitter", "Instance"),
+    ("TracerService", "Instance"),
+    ("Trail", "Instance"),
+    ("Translator", "Instance"),
+    ("TremoloSoundEffect", "SoundEffect"),
+    ("TriangleMeshPart", "BasePart"),
+    ("TrussPart", "BasePart"),
+    ("Tween", "TweenBase"),
+    ("TweenBase", "Instance"),
+    ("TweenService", "Instance"),
+    ("UGCValidationService", "Instance"),
+    ("UIAspectRatioConstraint", "UIConstraint"),
+    ("UIBase", "Instance"),
+    ("UIComponent", "UIBase"),
+    ("UIConstraint", "UIComponent"),
+    ("UICorner", "UIComponent"),
+    ("UIGradient", "UIComponent"),
+    ("UIGridLayout", "UIGridStyleLayout"),
+    ("UIGridStyleLayout", "UILayout"),
+    ("UILayout", "UIComponent"),
+    ("UIListLayout", "UIGridStyleLayout"),
+    ("UIPadding", "UIComponent"),
+    ("UIPageLayout", "UIGridStyleLayout"),
+    ("UIScale", "UIComponent"),
+    ("UISizeConstraint", "UIConstraint"),
+    ("UIStroke", "UIComponent"),
+    ("UITableLayout", "UIGridStyleLayout"),
+    ("UITextSizeConstraint", "UIConstraint"),
+    ("UnionOperation", "PartOperation"),
+    ("UniversalConstraint", "Constraint"),
+    ("UnvalidatedAssetService", "Instance"),
+    ("UserGameSettings", "Instance"),
+    ("UserInputService", "Instance"),
+    ("UserService", "Instance"),
+    ("UserSettings", "GenericSettings"),
+    ("UserStorageService", "LocalStorageService"),
+    ("VRService", "Instance"),
+    ("ValueBase", "Instance"),
+    ("Vector3Value", "ValueBase"),
+    ("VectorForce", "Constraint"),
+    ("VehicleController", "Controller"),
+    ("VehicleSeat", "BasePart"),
+    ("VelocityMotor", "JointInstance"),
+    ("VersionControlService", "Instance"),
+    ("VideoFrame", "GuiObject"),
+    ("ViewportFrame", "GuiObject"),
+    ("VirtualInputManager", "Instance"),
+    ("VirtualUser", "Instance"),
+    ("Visit", "Instance"),
+    ("VoiceSource", "Instance"),
+    ("WedgePart", "FormFactorPart"),
+    ("Weld", "JointInstance"),
+    ("WeldConstraint", "Instance"),
+    ("Workspace", "WorldRoot"),
+    ("WorldModel", "WorldRoot"),
+    ("WorldRoot", "Model"),
+    ("WrapLayer", "BaseWrap"),
+    ("WrapTarget", "BaseWrap"),
+];
+
+/// Every property as `(class, property, type)`, sorted by class and then
+/// by property.
+static PROPERTY_TYPES: &[(&str, &str, VariantType)] = &[
+    ("Accoutrement", "AttachmentForward", VariantType::Vector3),
+    ("Accoutrement", "AttachmentPoint", VariantType::CFrame),
+    ("Accoutrement", "AttachmentPos", VariantType::Vector3),
+    ("Accoutrement", "AttachmentRight", VariantType::Vector3),
+    ("Accoutrement", "AttachmentUp", VariantType::Vector3),
+    ("AlignOrientation", "AlignType", VariantType::Enum),
+    ("AlignOrientation", "MaxAngularVelocity", VariantType::Float32),
+    ("AlignOrientation", "MaxTorque", VariantType::Float32),
+    ("AlignOrientation", "PrimaryAxisOnly", VariantType::Bool),
+    ("AlignOrientation", "ReactionTorqueEnabled", VariantType::Bool),
+    ("AlignOrientation", "Responsiveness", VariantType::Float32),
+    ("AlignOrientation", "RigidityEnabled", VariantType::Bool),
+    ("AlignPosition", "ApplyAtCenterOfMass", VariantType::Bool),
+    ("AlignPosition", "MaxForce", VariantType::Float32),
+    ("AlignPosition", "MaxVelocity", VariantType::Float32),
+    ("AlignPosition", "ReactionForceEnabled", VariantType::Bool),
+    ("AlignPosition", "Responsiveness", VariantType::Float32),
+    ("AlignPosition", "RigidityEnabled", VariantType::Bool),
+    ("AnalyticsService", "ApiKey", VariantType::String),
+    ("AngularVelocity", "AngularVelocity", VariantType::Vector3),
+    ("AngularVelocity", "MaxTorque", VariantType::Float32),
+    ("AngularVelocity", "ReactionTorqueEnabled", VariantType::Bool),
+    ("AngularVelocity", "RelativeTo", VariantType::Enum),
+    ("Animation", "AnimationId", VariantType::Content),
+    ("AnimationTrack", "Animation", VariantType::Ref),
+    ("AnimationTrack", "IsPlaying", VariantType::Bool),
+    ("AnimationTrack", "Length", VariantType::Float32),
+    ("AnimationTrack", "Looped", VariantType::Bool),
+    ("AnimationTrack", "Priority", VariantType::Enum),
+    ("AnimationTrack", "Speed", VariantType::Float32),
+    ("AnimationTrack", "TimePosition", VariantType::Float32),
+    ("AnimationTrack", "WeightCurrent", VariantType::Float32),
+    ("AnimationTrack", "WeightTarget", VariantType::Float32),
+    ("ArcHandles", "Axes", VariantType::Axes),
+    ("Atmosphere", "Color", VariantType::Color3),
+    ("Atmosphere", "Decay", VariantType::Color3),
+    ("Atmosphere", "Density", VariantType::Float32),
+    ("Atmosphere", "Glare", VariantType::Float32),
+    ("Atmosphere", "Haze", VariantType::Float32),
+    ("Atmosphere", "Offset", VariantType::Float32),
+    ("Attachment", "Axis", VariantType::Vector3),
+    ("Attachment", "CFrame", VariantType::CFrame),
+    ("Attachment", "Orientation", VariantType::Vector3),
+    ("Attachment", "Position", VariantType::Vector3),
+    ("Attachment", "Rotation", VariantType::Vector3),
+    ("Attachment", "SecondaryAxis", VariantType::Vector3),
+    ("Attachment", "Visible", VariantType::Bool),
+    ("Attachment", "WorldAxis", VariantType::Vector3),
+    ("Attachment", "WorldCFrame", VariantType::CFrame),
+    ("Attachment", "WorldOrientation", VariantType::Vector3),
+    ("Attachment", "WorldPosition", VariantType::Vector3),
+    ("Attachment", "WorldRotation", VariantType::Vector3),
+    ("Attachment", "WorldSecondaryAxis", VariantType::Vector3),
+    ("BackpackItem", "TextureId", VariantType::Content),
+    ("BallSocketConstraint", "LimitsEnabled", VariantType::Bool),
+    ("BallSocketConstraint", "MaxFrictionTorque", VariantType::Float32),
+    ("BallSocketConstraint", "Radius", VariantType::Float32),
+    ("BallSocketConstraint", "Restitution", VariantType::Float32),
+    ("BallSocketConstraint", "TwistLimitsEnabled", VariantType::Bool),
+    ("BallSocketConstraint", "TwistLowerAngle", VariantType::Float32),
+    ("BallSocketConstraint", "TwistUpperAngle", VariantType::Float32),
+    ("BallSocketConstraint", "UpperAngle", VariantType::Float32),
+    ("BasePart", "Anchored", VariantType::Bool),
+    ("BasePart", "AssemblyAngularVelocity", VariantType::Vector3),
+    ("BasePart", "AssemblyCenterOfMass", VariantType::Vector3),
+    ("BasePart", "AssemblyLinearVelocity", VariantType::Vector3),
+    ("BasePart", "AssemblyMass", VariantType::Float32),
+    ("BasePart", "AssemblyRootPart", VariantType::Ref),
+    ("BasePart", "BackParamA", VariantType::Float32),
+    ("BasePart", "BackParamB", VariantType::Float32),
+    ("BasePart", "BackSurface", VariantType::Enum),
+    ("BasePart", "BackSurfaceInput", VariantType::Enum),
+    ("BasePart", "BottomParamA", VariantType::Float32),
+    ("BasePart", "BottomParamB", VariantType::Float32),
+    ("BasePart", "BottomSurface", VariantType::Enum),
+    ("BasePart", "BottomSurfaceInput", VariantType::Enum),
+    ("BasePart", "BrickColor", VariantType::BrickColor),
+    ("BasePart", "CFrame", VariantType::CFrame),
+    ("BasePart", "CanCollide", VariantType::Bool),
+    ("BasePart", "CanQuery", VariantType::Bool),
+    ("BasePart", "CanTouch", VariantType::Bool),
+    ("BasePart", "CastShadow", VariantType::Bool),
+    ("BasePart", "CenterOfMass", VariantType::Vector3),
+    ("BasePart", "CollisionGroupId", VariantType::Int32),
+    ("BasePart", "CollisionGroups", VariantType::String),
+    ("BasePart", "Color", VariantType::Color3),
+    ("BasePart", "Color3uint8", VariantType::Color3uint8),
+    ("BasePart", "CustomPhysicalProperties", VariantType::PhysicalProperties),
+    ("BasePart", "Elasticity", VariantType::Float32),
+    ("BasePart", "Friction", VariantType::Float32),
+    ("BasePart", "FrontParamA", VariantType::Float32),
+    ("BasePart", "FrontParamB", VariantType::Float32),
+    ("BasePart", "FrontSurface", VariantType::Enum),
+    ("BasePart", "FrontSurfaceInput", VariantType::Enum),
+    ("BasePart", "LeftParamA", VariantType::Float32),
+    ("BasePart", "LeftParamB", VariantType::Float32),
+    ("BasePart", "LeftSurface", VariantType::Enum),
+    ("BasePart", "LeftSurfaceInput", VariantType::Enum),
+    ("BasePart", "LocalTransparencyModifier", VariantType::Float32),
+    ("BasePart", "Locked", VariantType::Bool),
+    ("BasePart", "Mass", VariantType::Float32),
+    ("BasePart", "Massless", VariantType::Bool),
+    ("BasePart", "Material", VariantType::Enum),
+    ("BasePart", "Orientation", VariantType::Vector3),
+    ("BasePart", "PivotOffset", VariantType::CFrame),
+    ("BasePart", "Position", VariantType::Vector3),
+    ("BasePart", "ReceiveAge", VariantType::Float32),
+    ("BasePart", "Reflectance", VariantType::Float32),
+    ("BasePart", "ResizeIncrement", VariantType::Int32),
+    ("BasePart", "ResizeableFaces", VariantType::Faces),
+    ("BasePart", "RightParamA", VariantType::Float32),
+    ("BasePart", "RightParamB", VariantType::Float32),
+    ("BasePart", "RightSurface", VariantType::Enum),
+    ("BasePart", "RightSurfaceInput", VariantType::Enum),
+    ("BasePart", "RootPriority", VariantType::Int32),
+    ("BasePart", "RotVelocity", VariantType::Vector3),
+    ("BasePart", "Rotation", VariantType::Vector3),
+    ("BasePart", "Size", VariantType::Vector3),
+    ("BasePart", "SpecificGravity", VariantType::Float32),
+    ("BasePart", "TopParamA", VariantType::Float32),
+    ("BasePart", "TopParamB", VariantType::Float32),
+    ("BasePart", "TopSurface", VariantType::Enum),
+    ("BasePart", "TopSurfaceInput", VariantType::Enum),
+    ("BasePart", "Transparency", VariantType::Float32),
+    ("BasePart", "Velocity", VariantType::Vector3),
+    ("BasePart", "brickColor", VariantType::BrickColor),
+    ("BasePart", "size", VariantType::Vector3),
+    ("BaseScript", "Disabled", VariantType::Bool),
+    ("BaseScript", "LinkedSource", VariantType::Content),
+    ("BaseWrap", "CageMeshId", VariantType::Content),
+    ("BaseWrap", "CageOrigin", VariantType::CFrame),
+    ("BaseWrap", "CageOriginWorld", VariantType::CFrame),
+    ("BaseWrap", "ImportOrigin", VariantType::CFrame),
+    ("BaseWrap", "ImportOriginWorld", VariantType::CFrame),
+    ("Beam", "Attachment0", VariantType::Ref),
+    ("Beam", "Attachment1", VariantType::Ref),
+    ("Beam", "Color", VariantType::ColorSequence),
+    ("Beam", "CurveSize0", VariantType::Float32),
+    ("Beam", "CurveSize1", VariantType::Float32),
+    ("Beam", "Enabled", VariantType::Bool),
+    ("Beam", "FaceCamera", VariantType::Bool),
+    ("Beam", "LightEmission", VariantType::Float32),
+    ("Beam", "LightInfluence", VariantType::Float32),
+    ("Beam", "Segments", VariantType::Int32),
+    ("Beam", "Texture", VariantType::Content),
+    ("Beam", "TextureLength", VariantType::Float32),
+    ("Beam", "TextureMode", VariantType::Enum),
+    ("Beam", "TextureSpeed", VariantType::Float32),
+    ("Beam", "Transparency", VariantType::NumberSequence),
+    ("Beam", "Width0", VariantType::Float32),
+    ("Beam", "Width1", VariantType::Float32),
+    ("Beam", "ZOffset", VariantType::Float32),
+    ("BillboardGui", "Active", VariantType::Bool),
+    ("BillboardGui", "Adornee", VariantType::Ref),
+    ("BillboardGui", "AlwaysOnTop", VariantType::Bool),
+    ("BillboardGui", "Brightness", VariantType::Float32),
+    ("BillboardGui", "ClipsDescendants", VariantType::Bool),
+    ("BillboardGui", "CurrentDistance", VariantType::Float32),
+    ("BillboardGui", "DistanceLowerLimit", VariantType::Float32),
+    ("BillboardGui", "DistanceStep", VariantType::Float32),
+    ("BillboardGui", "DistanceUpperLimit", VariantType::Float32),
+    ("BillboardGui", "ExtentsOffset", VariantType::Vector3),
+    ("BillboardGui", "ExtentsOffsetWorldSpace", VariantType::Vector3),
+    ("BillboardGui", "LightInfluence", VariantType::Float32),
+    ("BillboardGui", "MaxDistance", VariantType::Float32),
+    ("BillboardGui", "PlayerToHideFrom", VariantType::Ref),
+    ("BillboardGui", "Size", VariantType::UDim2),
+    ("BillboardGui", "SizeOffset", VariantType::Vector2),
+    ("BillboardGui", "StudsOffset", VariantType::Vector3),
+    ("BillboardGui", "StudsOffsetWorldSpace", VariantType::Vector3),
+    ("BloomEffect", "Intensity", VariantType::Float32),
+    ("BloomEffect", "Size", VariantType::Float32),
+    ("BloomEffect", "Threshold", VariantType::Float32),
+    ("BlurEffect", "Size", VariantType::Float32),
+    ("BodyAngularVelocity", "AngularVelocity", VariantType::Vector3),
+    ("BodyAngularVelocity", "MaxTorque", VariantType::Vector3),
+    ("BodyAngularVelocity", "P", VariantType::Float32),
+    ("BodyAngularVelocity", "angularvelocity", VariantType::Vector3),
+    ("BodyAngularVelocity", "maxTorque", VariantType::Vector3),
+    ("BodyColors", "HeadColor", VariantType::BrickColor),
+    ("BodyColors", "HeadColor3", VariantType::Color3),
+    ("BodyColors", "LeftArmColor", VariantType::BrickColor),
+    ("BodyColors", "LeftArmColor3", VariantType::Color3),
+    ("BodyColors", "LeftLegColor", VariantType::BrickColor),
+    ("BodyColors", "LeftLegColor3", VariantType::Color3),
+    ("BodyColors", "RightArmColor", VariantType::BrickColor),
+    ("BodyColors", "RightArmColor3", VariantType::Color3),
+    ("BodyColors", "RightLegColor", VariantType::BrickColor),
+    ("BodyColors", "RightLegColor3", VariantType::Color3),
+    ("BodyColors", "TorsoColor", VariantType::BrickColor),
+    ("BodyColors", "TorsoColor3", VariantType::Color3),
+    ("BodyForce", "Force", VariantType::Vector3),
+    ("BodyForce", "force", VariantType::Vector3),
+    ("BodyGyro", "CFrame", VariantType::CFrame),
+    ("BodyGyro", "D", VariantType::Float32),
+    ("BodyGyro", "MaxTorque", VariantType::Vector3),
+    ("BodyGyro", "P", VariantType::Float32),
+    ("BodyGyro", "cframe", VariantType::CFrame),
+    ("BodyGyro", "maxTorque", VariantType::Vector3),
+    ("BodyPosition", "D", VariantType::Float32),
+    ("BodyPosition", "MaxForce", VariantType::Vector3),
+    ("BodyPosition", "P", VariantType::Float32),
+    ("BodyPosition", "Position", VariantType::Vector3),
+    ("BodyPosition", "maxForce", VariantType::Vector3),
+    ("BodyPosition", "position", VariantType::Vector3),
+    ("BodyThrust", "Force", VariantType::Vector3),
+    ("BodyThrust", "Location", VariantType::Vector3),
+    ("BodyThrust", "force", VariantType::Vector3),
+    ("BodyThrust", "location", VariantType::Vector3),
+    ("BodyVelocity", "MaxForce", VariantType::Vector3),
+    ("BodyVelocity", "P", VariantType::Float32),
+    ("BodyVelocity", "Velocity", VariantType::Vector3),
+    ("BodyVelocity", "maxForce", VariantType::Vector3),
+    ("BodyVelocity", "velocity", VariantType::Vector3),
+    ("Bone", "Transform", VariantType::CFrame),
+    ("Bone", "TransformedCFrame", VariantType::CFrame),
+    ("Bone", "TransformedWorldCFrame", VariantType::CFrame),
+    ("BoolValue", "Value", VariantType::Bool),
+    ("BoxHandleAdornment", "Size", VariantType::Vector3),
+    ("BrickColorValue", "Value", VariantType::BrickColor),
+    ("CFrameValue", "Value", VariantType::CFrame),
+    ("Camera", "CFrame", VariantType::CFrame),
+    ("Camera", "CameraSubject", VariantType::Ref),
+    ("Camera", "CameraType", VariantType::Enum),
+    ("Camera", "CoordinateFrame", VariantType::CFrame),
+    ("Camera", "DiagonalFieldOfView", VariantType::Float32),
+    ("Camera", "FieldOfView", VariantType::Float32),
+    ("Camera", "FieldOfViewMode", VariantType::Enum),
+    ("Camera", "Focus", VariantType::CFrame),
+    ("Camera", "HeadLocked", VariantType::Bool),
+    ("Camera", "HeadScale", VariantType::Float32),
+    ("Camera", "MaxAxisFieldOfView", VariantType::Float32),
+    ("Camera", "NearPlaneZ", VariantType::Float32),
+    ("Camera", "ViewportSize", VariantType::Vector2),
+    ("Camera", "focus", VariantType::CFrame),
+    ("CharacterMesh", "BaseTextureId", VariantType::Int64),
+    ("CharacterMesh", "BodyPart", VariantType::Enum),
+    ("CharacterMesh", "MeshId", VariantType::Int64),
+    ("CharacterMesh", "OverlayTextureId", VariantType::Int64),
+    ("Chat", "BubbleChatEnabled", VariantType::Bool),
+    ("Chat", "LoadDefaultChat", VariantType::Bool),
+    ("ChorusSoundEffect", "Depth", VariantType::Float32),
+    ("ChorusSoundEffect", "Mix", VariantType::Float32),
+    ("ChorusSoundEffect", "Rate", VariantType::Float32),
+    ("ClickDetector", "CursorIcon", VariantType::Content),
+    ("ClickDetector", "MaxActivationDistance", VariantType::Float32),
+    ("Clothing", "Color3", VariantType::Color3),
+    ("Clouds", "Cover", VariantType::Float32),
+    ("Clouds", "Density", VariantType::Float32),
+    ("Color3Value", "Value", VariantType::Color3),
+    ("ColorCorrectionEffect", "Brightness", VariantType::Float32),
+    ("ColorCorrectionEffect", "Contrast", VariantType::Float32),
+    ("ColorCorrectionEffect", "Saturation", VariantType::Float32),
+    ("ColorCorrectionEffect", "TintColor", VariantType::Color3),
+    ("CommandInstance", "AllowGUIAccessPoints", VariantType::Bool),
+    ("CommandInstance", "Checked", VariantType::Bool),
+    ("CommandInstance", "DefaultShortcut", VariantType::String),
+    ("CommandInstance", "Enabled", VariantType::Bool),
+    ("CommandInstance", "Group", VariantType::String),
+    ("CommandInstance", "Icon", VariantType::String),
+    ("CommandInstance", "Name", VariantType::String),
+    ("CommandInstance", "StatusTip", VariantType::String),
+    ("CommandInstance", "Text", VariantType::String),
+    ("CompressorSoundEffect", "Attack", VariantType::Float32),
+    ("CompressorSoundEffect", "GainMakeup", VariantType::Float32),
+    ("CompressorSoundEffect", "Ratio", VariantType::Float32),
+    ("CompressorSoundEffect", "Release", VariantType::Float32),
+    ("CompressorSoundEffect", "SideChain", VariantType::Ref),
+    ("CompressorSoundEffect", "Threshold", VariantType::Float32),
+    ("ConeHandleAdornment", "Height", VariantType::Float32),
+    ("ConeHandleAdornment", "Radius", VariantType::Float32),
+    ("Constraint", "Active", VariantType::Bool),
+    ("Constraint", "Attachment0", VariantType::Ref),
+    ("Constraint", "Attachment1", VariantType::Ref),
+    ("Constraint", "Color", VariantType::BrickColor),
+    ("Constraint", "Enabled", VariantType::Bool),
+    ("Constraint", "Visible", VariantType::Bool),
+    ("ContentProvider", "BaseUrl", VariantType::String),
+    ("ContentProvider", "RequestQueueSize", VariantType::Int32),
+    ("CoreGui", "SelectionImageObject", VariantType::Ref),
+    ("CoreGui", "Version", VariantType::Int32),
+    ("CustomEventReceiver", "Source", VariantType::Ref),
+    ("CylinderHandleAdornment", "Angle", VariantType::Float32),
+    ("CylinderHandleAdornment", "Height", VariantType::Float32),
+    ("CylinderHandleAdornment", "InnerRadius", VariantType::Float32),
+    ("CylinderHandleAdornment", "Radius", VariantType::Float32),
+    ("CylindricalConstraint", "AngularActuatorType", VariantType::Enum),
+    ("CylindricalConstraint", "AngularLimitsEnabled", VariantType::Bool),
+    ("CylindricalConstraint", "AngularResponsiveness", VariantType::Float32),
+    ("CylindricalConstraint", "AngularRestitution", VariantType::Float32),
+    ("CylindricalConstraint", "AngularSpeed", VariantType::Float32),
+    ("CylindricalConstraint", "AngularVelocity", VariantType::Float32),
+    ("CylindricalConstraint", "CurrentAngle", VariantType::Float32),
+    ("CylindricalConstraint", "InclinationAngle", VariantType::Float32),
+    ("CylindricalConstraint", "LowerAngle", VariantType::Float32),
+    ("CylindricalConstraint", "MotorMaxAngularAcceleration", VariantType::Float32),
+    ("CylindricalConstraint", "MotorMaxTorque", VariantType::Float32),
+    ("CylindricalConstraint", "RotationAxisVisible", VariantType::Bool),
+    ("CylindricalConstraint", "ServoMaxTorque", VariantType::Float32),
+    ("CylindricalConstraint", "TargetAngle", VariantType::Float32),
+    ("CylindricalConstraint", "UpperAngle", VariantType::Float32),
+    ("CylindricalConstraint", "WorldRotationAxis", VariantType::Vector3),
+    ("DataModel", "CreatorId", VariantType::Int64),
+    ("DataModel", "CreatorType", VariantType::Enum),
+    ("DataModel", "GameId", VariantType::Int64),
+    ("DataModel", "GearGenreSetting", VariantType::Enum),
+    ("DataModel", "Genre", VariantType::Enum),
+    ("DataModel", "IsSFFlagsLoaded", VariantType::Bool),
+    ("DataModel", "JobId", VariantType::String),
+    ("DataModel", "PlaceId", VariantType::Int64),
+    ("DataModel", "PlaceVersion", VariantType::Int32),
+    ("DataModel", "PrivateServerId", VariantType::String),
+    ("DataModel", "PrivateServerOwnerId", VariantType::Int64),
+    ("DataModel", "VIPServerId", VariantType::String),
+    ("DataModel", "VIPServerOwnerId", VariantType::Int64),
+    ("DataModel", "Workspace", VariantType::Ref),
+    ("DataModel", "lighting", VariantType::Ref),
+    ("DataModel", "workspace", VariantType::Ref),
+    ("DataModelMesh", "Offset", VariantType::Vector3),
+    ("DataModelMesh", "Scale", VariantType::Vector3),
+    ("DataModelMesh", "VertexColor", VariantType::Vector3),
+    ("DataModelSession", "CurrentDataModelType", VariantType::Enum),
+    ("DataModelSession", "SessionId", VariantType::String),
+    ("DataStoreInfo", "CreatedTime", VariantType::Int64),
+    ("DataStoreInfo", "DataStoreName", VariantType::String),
+    ("DataStoreInfo", "UpdatedTime", VariantType::Int64),
+    ("DataStoreKeyInfo", "KeyName", VariantType::String),
+    ("DataStoreKeyVersionInfo", "CreatedTime", VariantType::Int64),
+    ("DataStoreKeyVersionInfo", "UpdatedTime", VariantType::Int64),
+    ("DataStoreKeyVersionInfo", "Version", VariantType::String),
+    ("DataStoreService", "AutomaticRetry", VariantType::Bool),
+    ("DataStoreService", "LegacyNamingScheme", VariantType::Bool),
+    ("Debris", "MaxItems", VariantType::Int32),
+    ("DebugSettings", "DataModel", VariantType::Int32),
+    ("DebugSettings", "InstanceCount", VariantType::Int32),
+    ("DebugSettings", "IsScriptStackTracingEnabled", VariantType::Bool),
+    ("DebugSettings", "JobCount", VariantType::Int32),
+    ("DebugSettings", "PlayerCount", VariantType::Int32),
+    ("DebugSettings", "ReportSoundWarnings", VariantType::Bool),
+    ("DebugSettings", "RobloxVersion", VariantType::String),
+    ("DebugSettings", "TickCountPreciseOverride", VariantType::Enum),
+    ("DebuggerBreakpoint", "Condition", VariantType::String),
+    ("DebuggerBreakpoint", "IsEnabled", VariantType::Bool),
+    ("DebuggerBreakpoint", "Line", VariantType::Int32),
+    ("DebuggerBreakpoint", "isContextDependentBreakpoint", VariantType::Bool),
+    ("DebuggerManager", "DebuggingEnabled", VariantType::Bool),
+    ("DebuggerWatch", "Expression", VariantType::String),
+    ("Decal", "Color3", VariantType::Color3),
+    ("Decal", "LocalTransparencyModifier", VariantType::Float32),
+    ("Decal", "Shiny", VariantType::Float32),
+    ("Decal", "Specular", VariantType::Float32),
+    ("Decal", "Texture", VariantType::Content),
+    ("Decal", "Transparency", VariantType::Float32),
+    ("Decal", "ZIndex", VariantType::Int32),
+    ("DepthOfFieldEffect", "FarIntensity", VariantType::Float32),
+    ("DepthOfFieldEffect", "FocusDistance", VariantType::Float32),
+    ("DepthOfFieldEffect", "InFocusRadius", VariantType::Float32),
+    ("DepthOfFieldEffect", "NearIntensity", VariantType::Float32),
+    ("Dialog", "BehaviorType", VariantType::Enum),
+    ("Dialog", "ConversationDistance", VariantType::Float32),
+    ("Dialog", "GoodbyeChoiceActive", VariantType::Bool),
+    ("Dialog", "GoodbyeDialog", VariantType::String),
+    ("Dialog", "InUse", VariantType::Bool),
+    ("Dialog", "InitialPrompt", VariantType::String),
+    ("Dialog", "Purpose", VariantType::Enum),
+    ("Dialog", "Tone", VariantType::Enum),
+    ("Dialog", "TriggerDistance", VariantType::Float32),
+    ("Dialog", "TriggerOffset", VariantType::Vector3),
+    ("DialogChoice", "GoodbyeChoiceActive", VariantType::Bool),
+    ("DialogChoice", "GoodbyeDialog", VariantType::String),
+    ("DialogChoice", "ResponseDialog", VariantType::String),
+    ("DialogChoice", "UserDialog", VariantType::String),
+    ("DistortionSoundEffect", "Level", VariantType::Float32),
+    ("DockWidgetPluginGui", "HostWidgetWasRestored", VariantType::Bool),
+    ("DoubleConstrainedValue", "ConstrainedValue", VariantType::Float64),
+    ("DoubleConstrainedValue", "MaxValue", VariantType::Float64),
+    ("DoubleConstrainedValue", "MinValue", VariantType::Float64),
+    ("DoubleConstrainedValue", "Value", VariantType::Float64),
+    ("DraggerService", "AlignDraggedObjects", VariantType::Bool),
+    ("DraggerService", "AngleSnapEnabled", VariantType::Bool),
+    ("DraggerService", "AngleSnapIncrement", VariantType::Float32),
+    ("DraggerService", "AnimateHover", VariantType::Bool),
+    ("DraggerService", "CollisionsEnabled", VariantType::Bool),
+    ("DraggerService", "DraggerCoordinateSpace", VariantType::Enum),
+    ("DraggerService", "DraggerMovementMode", VariantType::Enum),
+    ("DraggerService", "GeometrySnapColor", VariantType::Color3),
+    ("DraggerService", "HoverAnimateFrequency", VariantType::Float32),
+    ("DraggerService", "HoverThickness", VariantType::Float32),
+    ("DraggerService", "JointsEnabled", VariantType::Bool),
+    ("DraggerService", "LinearSnapEnabled", VariantType::Bool),
+    ("DraggerService", "LinearSnapIncrement", VariantType::Float32),
+    ("DraggerService", "PivotSnapToGeometry", VariantType::Bool),
+    ("DraggerService", "ShowHover", VariantType::Bool),
+    ("DraggerService", "ShowPivotIndicator", VariantType::Bool),
+    ("DynamicRotate", "BaseAngle", VariantType::Float32),
+    ("EchoSoundEffect", "Delay", VariantType::Float32),
+    ("EchoSoundEffect", "DryLevel", VariantType::Float32),
+    ("EchoSoundEffect", "Feedback", VariantType::Float32),
+    ("EchoSoundEffect", "WetLevel", VariantType::Float32),
+    ("EqualizerSoundEffect", "HighGain", VariantType::Float32),
+    ("EqualizerSoundEffect", "LowGain", VariantType::Float32),
+    ("EqualizerSoundEffect", "MidGain", VariantType::Float32),
+    ("Explosion", "BlastPressure", VariantType::Float32),
+    ("Explosion", "BlastRadius", VariantType::Float32),
+    ("Explosion", "DestroyJointRadiusPercent", VariantType::Float32),
+    ("Explosion", "ExplosionType", VariantType::Enum),
+    ("Explosion", "Position", VariantType::Vector3),
+    ("Explosion", "Visible", VariantType::Bool),
+    ("FaceInstance", "Face", VariantType::Enum),
+    ("Feature", "FaceId", VariantType::Enum),
+    ("Feature", "InOut", VariantType::Enum),
+    ("Feature", "LeftRight", VariantType::Enum),
+    ("Feature", "TopBottom", VariantType::Enum),
+    ("File", "FileName", VariantType::String),
+    ("File", "FileSize", VariantType::Int64),
+    ("File", "Size", VariantType::Int64),
+    ("FileMesh", "MeshId", VariantType::Content),
+    ("FileMesh", "TextureId", VariantType::Content),
+    ("Fire", "Color", VariantType::Color3),
+    ("Fire", "Enabled", VariantType::Bool),
+    ("Fire", "Heat", VariantType::Float32),
+    ("Fire", "SecondaryColor", VariantType::Color3),
+    ("Fire", "Size", VariantType::Float32),
+    ("Fire", "heat_xml", VariantType::Float32),
+    ("Fire", "size", VariantType::Float32),
+    ("Fire", "size_xml", VariantType::Float32),
+    ("Flag", "TeamColor", VariantType::BrickColor),
+    ("FlagStand", "TeamColor", VariantType::BrickColor),
+    ("FlangeSoundEffect", "Depth", VariantType::Float32),
+    ("FlangeSoundEffect", "Mix", VariantType::Float32),
+    ("FlangeSoundEffect", "Rate", VariantType::Float32),
+    ("FloorWire", "CycleOffset", VariantType::Float32),
+    ("FloorWire", "From", VariantType::Ref),
+    ("FloorWire", "StudsBetweenTextures", VariantType::Float32),
+    ("FloorWire", "Texture", VariantType::Content),
+    ("FloorWire", "TextureSize", VariantType::Vector2),
+    ("FloorWire", "To", VariantType::Ref),
+    ("FloorWire", "Velocity", VariantType::Float32),
+    ("FloorWire", "WireRadius", VariantType::Float32),
+    ("ForceField", "Visible", VariantType::Bool),
+    ("FormFactorPart", "FormFactor", VariantType::Enum),
+    ("FormFactorPart", "formFactor", VariantType::Enum),
+    ("FormFactorPart", "formFactorRaw", VariantType::Enum),
+    ("Frame", "Style", VariantType::Enum),
+    ("FunctionalTest", "Description", VariantType::String),
+    ("GameSettings", "AdditionalCoreIncludeDirs", VariantType::String),
+    ("GameSettings", "OverrideStarterScript", VariantType::String),
+    ("GameSettings", "VideoCaptureEnabled", VariantType::Bool),
+    ("GameSettings", "VideoRecording", VariantType::Bool),
+    ("GamepadService", "GamepadCursorEnabled", VariantType::Bool),
+    ("GetDataStoreOptions", "AllScopes", VariantType::Bool),
+    ("Glue", "F0", VariantType::Vector3),
+    ("Glue", "F1", VariantType::Vector3),
+    ("Glue", "F2", VariantType::Vector3),
+    ("Glue", "F3", VariantType::Vector3),
+    ("GuiBase2d", "AbsolutePosition", VariantType::Vector2),
+    ("GuiBase2d", "AbsoluteRotation", VariantType::Float32),
+    ("GuiBase2d", "AbsoluteSize", VariantType::Vector2),
+    ("GuiBase2d", "AutoLocalize", VariantType::Bool),
+    ("GuiBase2d", "ClippedRect", VariantType::Rect),
+    ("GuiBase2d", "IsNotOccluded", VariantType::Bool),
+    ("GuiBase2d", "Localize", VariantType::Bool),
+    ("GuiBase2d", "RawRect2D", VariantType::Rect),
+    ("GuiBase2d", "RootLocalizationTable", VariantType::Ref),
+    ("GuiBase2d", "TotalGroupScale", VariantType::Float32),
+    ("GuiBase3d", "Color", VariantType::BrickColor),
+    ("GuiBase3d", "Color3", VariantType::Color3),
+    ("GuiBase3d", "Transparency", VariantType::Float32),
+    ("GuiBase3d", "Visible", VariantType::Bool),
+    ("GuiButton", "AutoButtonColor", VariantType::Bool),
+    ("GuiButton", "Modal", VariantType::Bool),
+    ("GuiButton", "Selected", VariantType::Bool),
+    ("GuiButton", "Style", VariantType::Enum),
+    ("GuiObject", "Active", VariantType::Bool),
+    ("GuiObject", "AnchorPoint", VariantType::Vector2),
+    ("GuiObject", "AutomaticSize", VariantType::Enum),
+    ("GuiObject", "BackgroundColor", VariantType::BrickColor),
+    ("GuiObject", "BackgroundColor3", VariantType::Color3),
+    ("GuiObject", "BackgroundTransparency", VariantType::Float32),
+    ("GuiObject", "BorderColor", VariantType::BrickColor),
+    ("GuiObject", "BorderColor3", VariantType::Color3),
+    ("GuiObject", "BorderMode", VariantType::Enum),
+    ("GuiObject", "BorderSizePixel", VariantType::Int32),
+    ("GuiObject", "ClipsDescendants", VariantType::Bool),
+    ("GuiObject", "Draggable", VariantType::Bool),
+    ("GuiObject", "LayoutOrder", VariantType::Int32),
+    ("GuiObject", "NextSelectionDown", VariantType::Ref),
+    ("GuiObject", "NextSelectionLeft", VariantType::Ref),
+    ("GuiObject", "NextSelectionRight", VariantType::Ref),
+    ("GuiObject", "NextSelectionUp", VariantType::Ref),
+    ("GuiObject", "Position", VariantType::UDim2),
+    ("GuiObject", "Rotation", VariantType::Float32),
+    ("GuiObject", "Selectable", VariantType::Bool),
+    ("GuiObject", "SelectionImageObject", VariantType::Ref),
+    ("GuiObject", "Size", VariantType::UDim2),
+    ("GuiObject", "SizeConstraint", VariantType::Enum),
+    ("GuiObject", "Transparency", VariantType::Float32),
+    ("GuiObject", "Visible", VariantType::Bool),
+    ("GuiObject", "ZIndex", VariantType::Int32),
+    ("GuiService", "AutoSelectGuiEnabled", VariantType::Bool),
+    ("GuiService", "CoreEffectFolder", VariantType::Ref),
+    ("GuiService", "CoreGuiFolder", VariantType::Ref),
+    ("GuiService", "CoreGuiNavigationEnabled", VariantType::Bool),
+    ("GuiService", "GuiNavigationEnabled", VariantType::Bool),
+    ("GuiService", "IsModalDialog", VariantType::Bool),
+    ("GuiService", "IsWindows", VariantType::Bool),
+    ("GuiService", "MenuIsOpen", VariantType::Bool),
+    ("GuiService", "SelectedCoreObject", VariantType::Ref),
+    ("GuiService", "SelectedObject", VariantType::Ref),
+    ("HandleAdornment", "AdornCullingMode", VariantType::Enum),
+    ("HandleAdornment", "AlwaysOnTop", VariantType::Bool),
+    ("HandleAdornment", "CFrame", VariantType::CFrame),
+    ("HandleAdornment", "SizeRelativeOffset", VariantType::Vector3),
+    ("HandleAdornment", "ZIndex", VariantType::Int32),
+    ("Handles", "Faces", VariantType::Faces),
+    ("Handles", "Style", VariantType::Enum),
+    ("HingeConstraint", "ActuatorType", VariantType::Enum),
+    ("HingeConstraint", "AngularResponsiveness", VariantType::Float32),
+    ("HingeConstraint", "AngularSpeed", VariantType::Float32),
+    ("HingeConstraint", "AngularVelocity", VariantType::Float32),
+    ("HingeConstraint", "CurrentAngle", VariantType::Float32),
+    ("HingeConstraint", "LimitsEnabled", VariantType::Bool),
+    ("HingeConstraint", "LowerAngle", VariantType::Float32),
+    ("HingeConstraint", "MotorMaxAcceleration", VariantType::Float32),
+    ("HingeConstraint", "MotorMaxTorque", VariantType::Float32),
+    ("HingeConstraint", "Radius", VariantType::Float32),
+    ("HingeConstraint", "Restitution", VariantType::Float32),
+    ("HingeConstraint", "ServoMaxTorque", VariantType::Float32),
+    ("HingeConstraint", "TargetAngle", VariantType::Float32),
+    ("HingeConstraint", "UpperAngle", VariantType::Float32),
+    ("HopperBin", "Active", VariantType::Bool),
+    ("HopperBin", "BinType", VariantType::Enum),
+    ("HttpService", "HttpEnabled", VariantType::Bool),
+    ("Humanoid", "AutoJumpEnabled", VariantType::Bool),
+    ("Humanoid", "AutoRotate", VariantType::Bool),
+    ("Humanoid", "AutomaticScalingEnabled", VariantType::Bool),
+    ("Humanoid", "BreakJointsOnDeath", VariantType::Bool),
+    ("Humanoid", "CameraOffset", VariantType::Vector3),
+    ("Humanoid", "CollisionType", VariantType::Enum),
+    ("Humanoid", "DisplayDistanceType", VariantType::Enum),
+    ("Humanoid", "DisplayName", VariantType::String),
+    ("Humanoid", "FloorMaterial", VariantType::Enum),
+    ("Humanoid", "Health", VariantType::Float32),
+    ("Humanoid", "HealthDisplayDistance", VariantType::Float32),
+    ("Humanoid", "HealthDisplayType", VariantType::Enum),
+    ("Humanoid", "HipHeight", VariantType::Float32),
+    ("Humanoid", "Jump", VariantType::Bool),
+    ("Humanoid", "JumpHeight", VariantType::Float32),
+    ("Humanoid", "JumpPower", VariantType::Float32),
+    ("Humanoid", "LeftLeg", VariantType::Ref),
+    ("Humanoid", "MaxHealth", VariantType::Float32),
+    ("Humanoid", "MaxSlopeAngle", VariantType::Float32),
+    ("Humanoid", "MoveDirection", VariantType::Vector3),
+    ("Humanoid", "NameDisplayDistance", VariantType::Float32),
+    ("Humanoid", "NameOcclusion", VariantType::Enum),
+    ("Humanoid", "PlatformStand", VariantType::Bool),
+    ("Humanoid", "RequiresNeck", VariantType::Bool),
+    ("Humanoid", "RigType", VariantType::Enum),
+    ("Humanoid", "RightLeg", VariantType::Ref),
+    ("Humanoid", "RootPart", VariantType::Ref),
+    ("Humanoid", "SeatPart", VariantType::Ref),
+    ("Humanoid", "Sit", VariantType::Bool),
+    ("Humanoid", "TargetPoint", VariantType::Vector3),
+    ("Humanoid", "Torso", VariantType::Ref),
+    ("Humanoid", "UseJumpPower", VariantType::Bool),
+    ("Humanoid", "WalkSpeed", VariantType::Float32),
+    ("Humanoid", "WalkToPart", VariantType::Ref),
+    ("Humanoid", "WalkToPoint", VariantType::Vector3),
+    ("Humanoid", "maxHealth", VariantType::Float32),
+    ("HumanoidDescription", "AccessoryBlob", VariantType::String),
+    ("HumanoidDescription", "BackAccessory", VariantType::String),
+    ("HumanoidDescription", "BodyTypeScale", VariantType::Float32),
+    ("HumanoidDescription", "ClimbAnimation", VariantType::Int64),
+    ("HumanoidDescription", "DepthScale", VariantType::Float32),
+    ("HumanoidDescription", "Face", VariantType::Int64),
+    ("HumanoidDescription", "FaceAccessory", VariantType::String),
+    ("HumanoidDescription", "FallAnimation", VariantType::Int64),
+    ("HumanoidDescription", "FrontAccessory", VariantType::String),
+    ("HumanoidDescription", "GraphicTShirt", VariantType::Int64),
+    ("HumanoidDescription", "HairAccessory", VariantType::String),
+    ("HumanoidDescription", "HatAccessory", VariantType::String),
+    ("HumanoidDescription", "Head", VariantType::Int64),
+    ("HumanoidDescription", "HeadColor", VariantType::Color3),
+    ("HumanoidDescription", "HeadScale", VariantType::Float32),
+    ("HumanoidDescription", "HeightScale", VariantType::Float32),
+    ("HumanoidDescription", "IdleAnimation", VariantType::Int64),
+    ("HumanoidDescription", "JumpAnimation", VariantType::Int64),
+    ("HumanoidDescription", "LeftArm", VariantType::Int64),
+    ("HumanoidDescription", "LeftArmColor", VariantType::Color3),
+    ("HumanoidDescription", "LeftLeg", VariantType::Int64),
+    ("HumanoidDescription", "LeftLegColor", VariantType::Color3),
+    ("HumanoidDescription", "NeckAccessory", VariantType::String),
+    ("HumanoidDescription", "NumberEmotesLoaded", VariantType::Int32),
+    ("HumanoidDescription", "Pants", VariantType::Int64),
+    ("HumanoidDescription", "ProportionScale", VariantType::Float32),
+    ("HumanoidDescription", "RightArm", VariantType::Int64),
+    ("HumanoidDescription", "RightArmColor", VariantType::Color3),
+    ("HumanoidDescription", "RightLeg", VariantType::Int64),
+    ("HumanoidDescription", "RightLegColor", VariantType::Color3),
+    ("HumanoidDescription", "RunAnimation", VariantType::Int64),
+    ("HumanoidDescription", "Shirt", VariantType::Int64),
+    ("HumanoidDescription", "ShouldersAccessory", VariantType::String),
+    ("HumanoidDescription", "SwimAnimation", VariantType::Int64),
+    ("HumanoidDescription", "Torso", VariantType::Int64),
+    ("HumanoidDescription", "TorsoColor", VariantType::Color3),
+    ("HumanoidDescription", "WaistAccessory", VariantType::String),
+    ("HumanoidDescription", "WalkAnimation", VariantType::Int64),
+    ("HumanoidDescription", "WidthScale", VariantType::Float32),
+    ("ImageButton", "HoverImage", VariantType::Content),
+    ("ImageButton", "Image", VariantType::Content),
+    ("ImageButton", "ImageColor3", VariantType::Color3),
+    ("ImageButton", "ImageRectOffset", VariantType::Vector2),
+    ("ImageButton", "ImageRectSize", VariantType::Vector2),
+    ("ImageButton", "ImageTransparency", VariantType::Float32),
+    ("ImageButton", "IsLoaded", VariantType::Bool),
+    ("ImageButton", "PressedImage", VariantType::Content),
+    ("ImageButton", "ScaleType", VariantType::Enum),
+    ("ImageButton", "SliceCenter", VariantType::Rect),
+    ("ImageButton", "SliceScale", VariantType::Float32),
+    ("ImageButton", "TileSize", VariantType::UDim2),
+    ("ImageHandleAdornment", "Image", VariantType::Content),
+    ("ImageHandleAdornment", "Size", VariantType::Vector2),
+    ("ImageLabel", "Image", VariantType::Content),
+    ("ImageLabel", "ImageColor3", VariantType::Color3),
+    ("ImageLabel", "ImageRectOffset", VariantType::Vector2),
+    ("ImageLabel", "ImageRectSize", VariantType::Vector2),
+    ("ImageLabel", "ImageTransparency", VariantType::Float32),
+    ("ImageLabel", "IsLoaded", VariantType::Bool),
+    ("ImageLabel", "ScaleType", VariantType::Enum),
+    ("ImageLabel", "SliceCenter", VariantType::Rect),
+    ("ImageLabel", "SliceScale", VariantType::Float32),
+    ("ImageLabel", "TileSize", VariantType::UDim2),
+    ("InputObject", "Delta", VariantType::Vector3),
+    ("InputObject", "KeyCode", VariantType::Enum),
+    ("InputObject", "Position", VariantType::Vector3),
+    ("InputObject", "UserInputState", VariantType::Enum),
+    ("InputObject", "UserInputType", VariantType::Enum),
+    ("InsertService", "AllowClientInsertModels", VariantType::Bool),
+    ("InsertService", "AllowInsertFreeModels", VariantType::Bool),
+    ("Instance", "Archivable", VariantType::Bool),
+    ("Instance", "Attributes", VariantType::Attributes),
+    ("Instance", "AttributesSerialize", VariantType::BinaryString),
+    ("Instance", "ClassName", VariantType::String),
+    ("Instance", "DataCost", VariantType::Int32),
+    ("Instance", "Name", VariantType::String),
+    ("Instance", "Parent", VariantType::Ref),
+    ("Instance", "RobloxLocked", VariantType::Bool),
+    ("Instance", "SourceAssetId", VariantType::Int64),
+    ("Instance", "Tags", VariantType::Tags),
+    ("Instance", "archivable", VariantType::Bool),
+    ("Instance", "className", VariantType::String),
+    ("InstanceAdornment", "Adornee", VariantType::Ref),
+    ("IntConstrainedValue", "ConstrainedValue", VariantType::Int64),
+    ("IntConstrainedValue", "MaxValue", VariantType::Int64),
+    ("IntConstrainedValue", "MinValue", VariantType::Int64),
+    ("IntConstrainedValue", "Value", VariantType::Int64),
+    ("IntValue", "Value", VariantType::Int64),
+    ("JointInstance", "Active", VariantType::Bool),
+    ("JointInstance", "C0", VariantType::CFrame),
+    ("JointInstance", "C1", VariantType::CFrame),
+    ("JointInstance", "Enabled", VariantType::Bool),
+    ("JointInstance", "Part0", VariantType::Ref),
+    ("JointInstance", "Part1", VariantType::Ref),
+    ("JointInstance", "part1", VariantType::Ref),
+    ("Keyframe", "Time", VariantType::Float32),
+    ("KeyframeMarker", "Value", VariantType::String),
+    ("KeyframeSequence", "AuthoredHipHeight", VariantType::Float32),
+    ("KeyframeSequence", "Loop", VariantType::Bool),
+    ("KeyframeSequence", "Priority", VariantType::Enum),
+    ("LayerCollector", "Enabled", VariantType::Bool),
+    ("LayerCollector", "ResetOnSpawn", VariantType::Bool),
+    ("LayerCollector", "ZIndexBehavior", VariantType::Enum),
+    ("Light", "Brightness", VariantType::Float32),
+    ("Light", "Color", VariantType::Color3),
+    ("Light", "Enabled", VariantType::Bool),
+    ("Light", "Shadows", VariantType::Bool),
+    ("Lighting", "Ambient", VariantType::Color3),
+    ("Lighting", "Brightness", VariantType::Float32),
+    ("Lighting", "ClockTime", VariantType::Float32),
+    ("Lighting", "ColorShift_Bottom", VariantType::Color3),
+    ("Lighting", "ColorShift_Top", VariantType::Color3),
+    ("Lighting", "EnvironmentDiffuseScale", VariantType::Float32),
+    ("Lighting", "EnvironmentSpecularScale", VariantType::Float32),
+    ("Lighting", "ExposureCompensation", VariantType::Float32),
+    ("Lighting", "FogColor", VariantType::Color3),
+    ("Lighting", "FogEnd", VariantType::Float32),
+    ("Lighting", "FogStart", VariantType::Float32),
+    ("Lighting", "GeographicLatitude", VariantType::Float32),
+    ("Lighting", "GlobalShadows", VariantType::Bool),
+    ("Lighting", "OutdoorAmbient", VariantType::Color3),
+    ("Lighting", "Outlines", VariantType::Bool),
+    ("Lighting", "ShadowColor", VariantType::Color3),
+    ("Lighting", "ShadowSoftness", VariantType::Float32),
+    ("Lighting", "Technology", VariantType::Enum),
+    ("Lighting", "TimeOfDay", VariantType::String),
+    ("LineForce", "ApplyAtCenterOfMass", VariantType::Bool),
+    ("LineForce", "InverseSquareLaw", VariantType::Bool),
+    ("LineForce", "Magnitude", VariantType::Float32),
+    ("LineForce", "MaxForce", VariantType::Float32),
+    ("LineForce", "ReactionForceEnabled", VariantType::Bool),
+    ("LineHandleAdornment", "Length", VariantType::Float32),
+    ("LineHandleAdornment", "Thickness", VariantType::Float32),
+    ("LocalizationService", "ForcePlayModeGameLocaleId", VariantType::String),
+    ("LocalizationService", "ForcePlayModeRobloxLocaleId", VariantType::String),
+    ("LocalizationService", "IsTextScraperRunning", VariantType::Bool),
+    ("LocalizationService", "RobloxForcePlayModeGameLocaleId", VariantType::String),
+    ("LocalizationService", "RobloxForcePlayModeRobloxLocaleId", VariantType::String),
+    ("LocalizationService", "RobloxLocaleId", VariantType::String),
+    ("LocalizationService", "SystemLocaleId", VariantType::String),
+    ("LocalizationTable", "Contents", VariantType::String),
+    ("LocalizationTable", "DevelopmentLanguage", VariantType::String),
+    ("LocalizationTable", "Root", VariantType::Ref),
+    ("LocalizationTable", "SourceLocaleId", VariantType::String),
+    ("LuaSourceContainer", "CurrentEditor", VariantType::Ref),
+    ("MeshPart", "DoubleSided", VariantType::Bool),
+    ("MeshPart", "HasJointOffset", VariantType::Bool),
+    ("MeshPart", "HasSkinnedMesh", VariantType::Bool),
+    ("MeshPart", "JointOffset", VariantType::Vector3),
+    ("MeshPart", "MeshID", VariantType::Content),
+    ("MeshPart", "MeshId", VariantType::Content),
+    ("MeshPart", "MeshSize", VariantType::Vector3),
+    ("MeshPart", "RenderFidelity", VariantType::Enum),
+    ("MeshPart", "TextureID", VariantType::Content),
+    ("Message", "Text", VariantType::String),
+    ("Model", "LevelOfDetail", VariantType::Enum),
+    ("Model", "PrimaryPart", VariantType::Ref),
+    ("Model", "World Pivot Orientation", VariantType::Vector3),
+    ("Model", "World Pivot Position", VariantType::Vector3),
+    ("Model", "WorldPivot", VariantType::CFrame),
+    ("ModuleScript", "LinkedSource", VariantType::Content),
+    ("ModuleScript", "Source", VariantType::String),
+    ("Motor", "CurrentAngle", VariantType::Float32),
+    ("Motor", "DesiredAngle", VariantType::Float32),
+    ("Motor", "MaxVelocity", VariantType::Float32),
+    ("Motor6D", "ChildName", VariantType::String),
+    ("Motor6D", "ParentName", VariantType::String),
+    ("Motor6D", "Transform", VariantType::CFrame),
+    ("Mouse", "Hit", VariantType::CFrame),
+    ("Mouse", "Icon", VariantType::Content),
+    ("Mouse", "Origin", VariantType::CFrame),
+    ("Mouse", "Target", VariantType::Ref),
+    ("Mouse", "TargetFilter", VariantType::Ref),
+    ("Mouse", "TargetSurface", VariantType::Enum),
+    ("Mouse", "UnitRay", VariantType::Ray),
+    ("Mouse", "ViewSizeX", VariantType::Int32),
+    ("Mouse", "ViewSizeY", VariantType::Int32),
+    ("Mouse", "X", VariantType::Int32),
+    ("Mouse", "Y", VariantType::Int32),
+    ("Mouse", "hit", VariantType::CFrame),
+    ("Mouse", "target", VariantType::Ref),
+    ("MultipleDocumentInterfaceInstance", "FocusedDataModelSession", VariantType::Ref),
+    ("NetworkSettings", "EmulatedTotalMemoryInMB", VariantType::Int32),
+    ("NetworkSettings", "FreeMemoryMBytes", VariantType::Float32),
+    ("NetworkSettings", "HttpProxyEnabled", VariantType::Bool),
+    ("NetworkSettings", "HttpProxyURL", VariantType::String),
+    ("NetworkSettings", "IncomingReplicationLag", VariantType::Float64),
+    ("NetworkSettings", "PrintJoinSizeBreakdown", VariantType::Bool),
+    ("NetworkSettings", "PrintPhysicsErrors", VariantType::Bool),
+    ("NetworkSettings", "PrintStreamInstanceQuota", VariantType::Bool),
+    ("NetworkSettings", "RandomizeJoinInstanceOrder", VariantType::Bool),
+    ("NetworkSettings", "RenderStreamedRegions", VariantType::Bool),
+    ("NetworkSettings", "ShowActiveAnimationAsset", VariantType::Bool),
+    ("NetworkSettings", "TrackDataTypes", VariantType::Bool),
+    ("NetworkSettings", "TrackPhysicsDetails", VariantType::Bool),
+    ("NoCollisionConstraint", "Enabled", VariantType::Bool),
+    ("NoCollisionConstraint", "Part0", VariantType::Ref),
+    ("NoCollisionConstraint", "Part1", VariantType::Ref),
+    ("NotificationService", "IsLuaChatEnabled", VariantType::Bool),
+    ("NotificationService", "IsLuaGameDetailsEnabled", VariantType::Bool),
+    ("NotificationService", "SelectedTheme", VariantType::String),
+    ("NumberPose", "Value", VariantType::Float64),
+    ("NumberValue", "Value", VariantType::Float64),
+    ("ObjectValue", "Value", VariantType::Ref),
+    ("ObjectVersionInfo", "CreatedTime", VariantType::Int64),
+    ("ObjectVersionInfo", "IsDeleted", VariantType::Bool),
+    ("ObjectVersionInfo", "Version", VariantType::String),
+    ("PVAdornment", "Adornee", VariantType::Ref),
+    ("PVInstance", "Origin Orientation", VariantType::Vector3),
+    ("PVInstance", "Origin Position", VariantType::Vector3),
+    ("PVInstance", "Pivot Offset Orientation", VariantType::Vector3),
+    ("PVInstance", "Pivot Offset Position", VariantType::Vector3),
+    ("PackageLink", "AutoUpdate", VariantType::Bool),
+    ("PackageLink", "Creator", VariantType::String),
+    ("PackageLink", "PackageAssetName", VariantType::String),
+    ("PackageLink", "PackageId", VariantType::Content),
+    ("PackageLink", "PackageIdSerialize", VariantType::Content),
+    ("PackageLink", "PermissionLevel", VariantType::Enum),
+    ("PackageLink", "Status", VariantType::String),
+    ("PackageLink", "VersionNumber", VariantType::Int64),
+    ("Pages", "IsFinished", VariantType::Bool),
+    ("Pants", "PantsTemplate", VariantType::Content),
+    ("ParabolaAdornment", "A", VariantType::Float32),
+    ("ParabolaAdornment", "B", VariantType::Float32),
+    ("ParabolaAdornment", "C", VariantType::Float32),
+    ("ParabolaAdornment", "Range", VariantType::Float32),
+    ("ParabolaAdornment", "Thickness", VariantType::Float32),
+    ("Part", "Shape", VariantType::Enum),
+    ("Part", "shape", VariantType::Enum),
+    ("PartAdornment", "Adornee", VariantType::Ref),
+    ("PartOperation", "AssetId", VariantType::Content),
+    ("PartOperation", "RenderFidelity", VariantType::Enum),
+    ("PartOperation", "SmoothingAngle", VariantType::Float32),
+    ("PartOperation", "TriangleCount", VariantType::Int32),
+    ("PartOperation", "UsePartColor", VariantType::Bool),
+    ("ParticleEmitter", "Acceleration", VariantType::Vector3),
+    ("ParticleEmitter", "Color", VariantType::ColorSequence),
+    ("ParticleEmitter", "Drag", VariantType::Float32),
+    ("ParticleEmitter", "EmissionDirection", VariantType::Enum),
+    ("ParticleEmitter", "Enabled", VariantType::Bool),
+    ("ParticleEmitter", "Lifetime", VariantType::NumberRange),
+    ("ParticleEmitter", "LightEmission", VariantType::Float32),
+    ("ParticleEmitter", "LightInfluence", VariantType::Float32),
+    ("ParticleEmitter", "LockedToPart", VariantType::Bool),
+    ("ParticleEmitter", "Orientation", VariantType::Enum),
+    ("ParticleEmitter", "Rate", VariantType::Float32),
+    ("ParticleEmitter", "RotSpeed", VariantType::NumberRange),
+    ("ParticleEmitter", "Rotation", VariantType::NumberRange),
+    ("ParticleEmitter", "Size", VariantType::NumberSequence),
+    ("ParticleEmitter", "Speed", VariantType::NumberRange),
+    ("ParticleEmitter", "SpreadAngle", VariantType::Vector2),
+    ("ParticleEmitter", "Texture", VariantType::Content),
+    ("ParticleEmitter", "TimeScale", VariantType::Float32),
+    ("ParticleEmitter", "Transparency", VariantType::NumberSequence),
+    ("ParticleEmitter", "VelocityInheritance", VariantType::Float32),
+    ("ParticleEmitter", "VelocitySpread", VariantType::Float32),
+    ("ParticleEmitter", "ZOffset", VariantType::Float32),
+    ("Path", "Status", VariantType::Enum),
+    ("PathfindingService", "EmptyCutoff", VariantType::Float32),
+    ("PhysicsSettings", "AllowSleep", VariantType::Bool),
+    ("PhysicsSettings", "AreAnchorsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreAssembliesShown", VariantType::Bool),
+    ("PhysicsSettings", "AreAwakePartsHighlighted", VariantType::Bool),
+    ("PhysicsSettings", "AreBodyTypesShown", VariantType::Bool),
+    ("PhysicsSettings", "AreContactIslandsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreContactPointsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreJointCoordinatesShown", VariantType::Bool),
+    ("PhysicsSettings", "AreMechanismsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreModelCoordsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreOwnersShown", VariantType::Bool),
+    ("PhysicsSettings", "ArePartCoordsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreRegionsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreTerrainReplicationRegionsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreTimestepsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreUnalignedPartsShown", VariantType::Bool),
+    ("PhysicsSettings", "AreWorldCoordsShown", VariantType::Bool),
+    ("PhysicsSettings", "DisableCSGv2", VariantType::Bool),
+    ("PhysicsSettings", "ForceCSGv2", VariantType::Bool),
+    ("PhysicsSettings", "IsInterpolationThrottleShown", VariantType::Bool),
+    ("PhysicsSettings", "IsReceiveAgeShown", VariantType::Bool),
+    ("PhysicsSettings", "IsTreeShown", VariantType::Bool),
+    ("PhysicsSettings", "PhysicsEnvironmentalThrottle", VariantType::Enum),
+    ("PhysicsSettings", "ShowDecompositionGeometry", VariantType::Bool),
+    ("PhysicsSettings", "ThrottleAdjustTime", VariantType::Float64),
+    ("PhysicsSettings", "UseCSGv2", VariantType::Bool),
+    ("PitchShiftSoundEffect", "Octave", VariantType::Float32),
+    ("Player", "AccountAge", VariantType::Int32),
+    ("Player", "AppearanceDidLoad", VariantType::Bool),
+    ("Player", "AutoJumpEnabled", VariantType::Bool),
+    ("Player", "CameraMaxZoomDistance", VariantType::Float32),
+    ("Player", "CameraMinZoomDistance", VariantType::Float32),
+    ("Player", "CameraMode", VariantType::Enum),
+    ("Player", "CanLoadCharacterAppearance", VariantType::Bool),
+    ("Player", "Character", VariantType::Ref),
+    ("Player", "CharacterAppearance", VariantType::String),
+    ("Player", "CharacterAppearanceId", VariantType::Int64),
+    ("Player", "ChatMode", VariantType::Enum),
+    ("Player", "DataComplexity", VariantType::Int32),
+    ("Player", "DataComplexityLimit", VariantType::Int32),
+    ("Player", "DataReady", VariantType::Bool),
+    ("Player", "DevCameraOcclusionMode", VariantType::Enum),
+    ("Player", "DevComputerCameraMode", VariantType::Enum),
+    ("Player", "DevComputerMovementMode", VariantType::Enum),
+    ("Player", "DevEnableMouseLock", VariantType::Bool),
+    ("Player", "DevTouchCameraMode", VariantType::Enum),
+    ("Player", "DevTouchMovementMode", VariantType::Enum),
+    ("Player", "DisplayName", VariantType::String),
+    ("Player", "FollowUserId", VariantType::Int64),
+    ("Player", "GameplayPaused", VariantType::Bool),
+    ("Player", "Guest", VariantType::Bool),
+    ("Player", "HealthDisplayDistance", VariantType::Float32),
+    ("Player", "LocaleId", VariantType::String),
+    ("Player", "MaximumSimulationRadius", VariantType::Float32),
+    ("Player", "MembershipType", VariantType::Enum),
+    ("Player", "NameDisplayDistance", VariantType::Float32),
+    ("Player", "Neutral", VariantType::Bool),
+    ("Player", "OsPlatform", VariantType::String),
+    ("Player", "PlatformName", VariantType::String),
+    ("Player", "ReplicationFocus", VariantType::Ref),
+    ("Player", "RespawnLocation", VariantType::Ref),
+    ("Player", "SimulationRadius", VariantType::Float32),
+    ("Player", "Team", VariantType::Ref),
+    ("Player", "TeamColor", VariantType::BrickColor),
+    ("Player", "Teleported", VariantType::Bool),
+    ("Player", "TeleportedIn", VariantType::Bool),
+    ("Player", "UserId", VariantType::Int64),
+    ("Player", "VRDevice", VariantType::String),
+    ("Player", "userId", VariantType::Int64),
+    ("PlayerEmulatorService", "DEPRECATED_SerializedEmulatedPolicyInfo", VariantType::String),
+    ("PlayerEmulatorService", "EmulatedCountryCode", VariantType::String),
+    ("PlayerEmulatorService", "EmulatedGameLocale", VariantType::String),
+    ("PlayerEmulatorService", "PlayerEmulationEnabled", VariantType::Bool),
+    ("PlayerEmulatorService", "PlayerEmulationEnabled_deprecated", VariantType::Bool),
+    ("PlayerEmulatorService", "SerializedEmulatedPolicyInfo", VariantType::BinaryString),
+    ("PlayerEmulatorService", "StudioEmulatedCountryRegionCode", VariantType::String),
+    ("PlayerGui", "CurrentScreenOrientation", VariantType::Enum),
+    ("PlayerGui", "ScreenOrientation", VariantType::Enum),
+    ("PlayerGui", "SelectionImageObject", VariantType::Ref),
+    ("Players", "BubbleChat", VariantType::Bool),
+    ("Players", "CharacterAutoLoads", VariantType::Bool),
+    ("Players", "ClassicChat", VariantType::Bool),
+    ("Players", "LocalPlayer", VariantType::Ref),
+    ("Players", "MaxPlayers", VariantType::Int32),
+    ("Players", "MaxPlayersInternal", VariantType::Int32),
+    ("Players", "NumPlayers", VariantType::Int32),
+    ("Players", "PreferredPlayers", VariantType::Int32),
+    ("Players", "PreferredPlayersInternal", VariantType::Int32),
+    ("Players", "RespawnTime", VariantType::Float32),
+    ("Players", "localPlayer", VariantType::Ref),
+    ("Players", "numPlayers", VariantType::Int32),
+    ("Plugin", "CollisionEnabled", VariantType::Bool),
+    ("Plugin", "GridSize", VariantType::Float32),
+    ("Plugin", "HostDataModelType", VariantType::Enum),
+    ("Plugin", "HostDataModelTypeIsCurrent", VariantType::Bool),
+    ("Plugin", "MultipleDocumentInterfaceInstance", VariantType::Ref),
+    ("Plugin", "UsesAssetInsertionDrag", VariantType::Bool),
+    ("PluginAction", "ActionId", VariantType::String),
+    ("PluginAction", "AllowBinding", VariantType::Bool),
+    ("PluginAction", "Checked", VariantType::Bool),
+    ("PluginAction", "DefaultShortcut", VariantType::String),
+    ("PluginAction", "Enabled", VariantType::Bool),
+    ("PluginAction", "StatusTip", VariantType::String),
+    ("PluginAction", "Text", VariantType::String),
+    ("PluginDragEvent", "Data", VariantType::String),
+    ("PluginDragEvent", "MimeType", VariantType::String),
+    ("PluginDragEvent", "Position", VariantType::Vector2),
+    ("PluginDragEvent", "Sender", VariantType::String),
+    ("PluginGui", "Title", VariantType::String),
+    ("PluginMenu", "Icon", VariantType::String),
+    ("PluginMenu", "Title", VariantType::String),
+    ("PluginToolbarButton", "ClickableWhenViewportHidden", VariantType::Bool),
+    ("PluginToolbarButton", "Enabled", VariantType::Bool),
+    ("PluginToolbarButton", "Icon", VariantType::Content),
+    ("PointLight", "Range", VariantType::Float32),
+    ("PolicyService", "IsLuobuServer", VariantType::Enum),
+    ("PolicyService", "LuobuWhitelisted", VariantType::Enum),
+    ("Pose", "CFrame", VariantType::CFrame),
+    ("Pose", "MaskWeight", VariantType::Float32),
+    ("PoseBase", "EasingDirection", VariantType::Enum),
+    ("PoseBase", "EasingStyle", VariantType::Enum),
+    ("PoseBase", "Weight", VariantType::Float32),
+    ("PostEffect", "Enabled", VariantType::Bool),
+    ("ProximityPrompt", "ActionText", VariantType::String),
+    ("ProximityPrompt", "AutoLocalize", VariantType::Bool),
+    ("ProximityPrompt", "ClickablePrompt", VariantType::Bool),
+    ("ProximityPrompt", "Enabled", VariantType::Bool),
+    ("ProximityPrompt", "Exclusivity", VariantType::Enum),
+    ("ProximityPrompt", "GamepadKeyCode", VariantType::Enum),
+    ("ProximityPrompt", "HoldDuration", VariantType::Float32),
+    ("ProximityPrompt", "KeyboardKeyCode", VariantType::Enum),
+    ("ProximityPrompt", "MaxActivationDistance", VariantType::Float32),
+    ("ProximityPrompt", "ObjectText", VariantType::String),
+    ("ProximityPrompt", "RequiresLineOfSight", VariantType::Bool),
+    ("ProximityPrompt", "RootLocalizationTable", VariantType::Ref),
+    ("ProximityPrompt", "Style", VariantType::Enum),
+    ("ProximityPrompt", "UIOffset", VariantType::Vector2),
+    ("ProximityPromptService", "Enabled", VariantType::Bool),
+    ("ProximityPromptService", "MaxPromptsVisible", VariantType::Int32),
+    ("RayValue", "Value", VariantType::Ray),
+    ("ReflectionMetadataClass", "ExplorerImageIndex", VariantType::Int32),
+    ("ReflectionMetadataClass", "ExplorerOrder", VariantType::Int32),
+    ("ReflectionMetadataClass", "Insertable", VariantType::Bool),
+    ("ReflectionMetadataClass", "PreferredParent", VariantType::String),
+    ("ReflectionMetadataItem", "Browsable", VariantType::Bool),
+    ("ReflectionMetadataItem", "ClassCategory", VariantType::String),
+    ("ReflectionMetadataItem", "ClientOnly", VariantType::Bool),
+    ("ReflectionMetadataItem", "Constraint", VariantType::String),
+    ("ReflectionMetadataItem", "Deprecated", VariantType::Bool),
+    ("ReflectionMetadataItem", "EditingDisabled", VariantType::Bool),
+    ("ReflectionMetadataItem", "FFlag", VariantType::String),
+    ("ReflectionMetadataItem", "IsBackend", VariantType::Bool),
+    ("ReflectionMetadataItem", "PropertyOrder", VariantType::Int32),
+    ("ReflectionMetadataItem", "ScriptContext", VariantType::String),
+    ("ReflectionMetadataItem", "ServerOnly", VariantType::Bool),
+    ("ReflectionMetadataItem", "UIMaximum", VariantType::Float64),
+    ("ReflectionMetadataItem", "UIMinimum", VariantType::Float64),
+    ("ReflectionMetadataItem", "UINumTicks", VariantType::Float64),
+    ("ReflectionMetadataItem", "summary", VariantType::String),
+    ("RenderSettings", "AutoFRMLevel", VariantType::Int32),
+    ("RenderSettings", "EagerBulkExecution", VariantType::Bool),
+    ("RenderSettings", "EditQualityLevel", VariantType::Enum),
+    ("RenderSettings", "EnableFRM", VariantType::Bool),
+    ("RenderSettings", "ExportMergeByMaterial", VariantType::Bool),
+    ("RenderSettings", "FrameRateManager", VariantType::Enum),
+    ("RenderSettings", "GraphicsMode", VariantType::Enum),
+    ("RenderSettings", "MeshCacheSize", VariantType::Int32),
+    ("RenderSettings", "MeshPartDetailLevel", VariantType::Enum),
+    ("RenderSettings", "QualityLevel", VariantType::Enum),
+    ("RenderSettings", "ReloadAssets", VariantType::Bool),
+    ("RenderSettings", "RenderCSGTrianglesDebug", VariantType::Bool),
+    ("RenderSettings", "ShowBoundingBoxes", VariantType::Bool),
+    ("RenderingTest", "CFrame", VariantType::CFrame),
+    ("RenderingTest", "ComparisonDiffThreshold", VariantType::Int32),
+    ("RenderingTest", "ComparisonMethod", VariantType::Enum),
+    ("RenderingTest", "ComparisonPsnrThreshold", VariantType::Float32),
+    ("RenderingTest", "Description", VariantType::String),
+    ("RenderingTest", "FieldOfView", VariantType::Float32),
+    ("RenderingTest", "Orientation", VariantType::Vector3),
+    ("RenderingTest", "Position", VariantType::Vector3),
+    ("RenderingTest", "QualityLevel", VariantType::Int32),
+    ("RenderingTest", "ShouldSkip", VariantType::Bool),
+    ("RenderingTest", "Ticket", VariantType::String),
+    ("ReverbSoundEffect", "DecayTime", VariantType::Float32),
+    ("ReverbSoundEffect", "Density", VariantType::Float32),
+    ("ReverbSoundEffect", "Diffusion", VariantType::Float32),
+    ("ReverbSoundEffect", "DryLevel", VariantType::Float32),
+    ("ReverbSoundEffect", "WetLevel", VariantType::Float32),
+    ("RocketPropulsion", "CartoonFactor", VariantType::Float32),
+    ("RocketPropulsion", "MaxSpeed", VariantType::Float32),
+    ("RocketPropulsion", "MaxThrust", VariantType::Float32),
+    ("RocketPropulsion", "MaxTorque", VariantType::Vector3),
+    ("RocketPropulsion", "Target", VariantType::Ref),
+    ("RocketPropulsion", "TargetOffset", VariantType::Vector3),
+    ("RocketPropulsion", "TargetRadius", VariantType::Float32),
+    ("RocketPropulsion", "ThrustD", VariantType::Float32),
+    ("RocketPropulsion", "ThrustP", VariantType::Float32),
+    ("RocketPropulsion", "TurnD", VariantType::Float32),
+    ("RocketPropulsion", "TurnP", VariantType::Float32),
+    ("RodConstraint", "CurrentDistance", VariantType::Float32),
+    ("RodConstraint", "Length", VariantType::Float32),
+    ("RodConstraint", "LimitAngle0", VariantType::Float32),
+    ("RodConstraint", "LimitAngle1", VariantType::Float32),
+    ("RodConstraint", "LimitsEnabled", VariantType::Bool),
+    ("RodConstraint", "Thickness", VariantType::Float32),
+    ("RopeConstraint", "CurrentDistance", VariantType::Float32),
+    ("RopeConstraint", "Length", VariantType::Float32),
+    ("RopeConstraint", "Restitution", VariantType::Float32),
+    ("RopeConstraint", "Thickness", VariantType::Float32),
+    ("RunService", "ClientGitHash", VariantType::String),
+    ("ScreenGui", "DisplayOrder", VariantType::Int32),
+    ("ScreenGui", "IgnoreGuiInset", VariantType::Bool),
+    ("ScreenGui", "OnTopOfCoreBlur", VariantType::Bool),
+    ("Script", "Source", VariantType::String),
+    ("ScriptContext", "ScriptsDisabled", VariantType::Bool),
+    ("ScriptDebugger", "CurrentLine", VariantType::Int32),
+    ("ScriptDebugger", "IsDebugging", VariantType::Bool),
+    ("ScriptDebugger", "IsPaused", VariantType::Bool),
+    ("ScriptDebugger", "Script", VariantType::Ref),
+    ("ScrollingFrame", "AbsoluteCanvasSize", VariantType::Vector2),
+    ("ScrollingFrame", "AbsoluteWindowSize", VariantType::Vector2),
+    ("ScrollingFrame", "AutomaticCanvasSize", VariantType::Enum),
+    ("ScrollingFrame", "BottomImage", VariantType::Content),
+    ("ScrollingFrame", "CanvasPosition", VariantType::Vector2),
+    ("ScrollingFrame", "CanvasSize", VariantType::UDim2),
+    ("ScrollingFrame", "ElasticBehavior", VariantType::Enum),
+    ("ScrollingFrame", "HorizontalBarRect", VariantType::Rect),
+    ("ScrollingFrame", "HorizontalScrollBarInset", VariantType::Enum),
+    ("ScrollingFrame", "MaxCanvasPosition", VariantType::Vector2),
+    ("ScrollingFrame", "MidImage", VariantType::Content),
+    ("ScrollingFrame", "ScrollBarImageColor3", VariantType::Color3),
+    ("ScrollingFrame", "ScrollBarImageTransparency", VariantType::Float32),
+    ("ScrollingFrame", "ScrollBarThickness", VariantType::Int32),
+    ("ScrollingFrame", "ScrollingDirection", VariantType::Enum),
+    ("ScrollingFrame", "ScrollingEnabled", VariantType::Bool),
+    ("ScrollingFrame", "TopImage", VariantType::Content),
+    ("ScrollingFrame", "VerticalBarRect", VariantType::Rect),
+    ("ScrollingFrame", "VerticalScrollBarInset", VariantType::Enum),
+    ("ScrollingFrame", "VerticalScrollBarPosition", VariantType::Enum),
+    ("Seat", "Disabled", VariantType::Bool),
+    ("Seat", "Occupant", VariantType::Ref),
+    ("Selection", "ActiveInstance", VariantType::Ref),
+    ("SelectionBox", "LineThickness", VariantType::Float32),
+    ("SelectionBox", "SurfaceColor", VariantType::BrickColor),
+    ("SelectionBox", "SurfaceColor3", VariantType::Color3),
+    ("SelectionBox", "SurfaceTransparency", VariantType::Float32),
+    ("SelectionLasso", "Humanoid", VariantType::Ref),
+    ("SelectionPartLasso", "Part", VariantType::Ref),
+    ("SelectionPointLasso", "Point", VariantType::Vector3),
+    ("SelectionSphere", "SurfaceColor", VariantType::BrickColor),
+    ("SelectionSphere", "SurfaceColor3", VariantType::Color3),
+    ("SelectionSphere", "SurfaceTransparency", VariantType::Float32),
+    ("ServerScriptService", "LoadStringEnabled", VariantType::Bool),
+    ("Shirt", "ShirtTemplate", VariantType::Content),
+    ("ShirtGraphic", "Color3", VariantType::Color3),
+    ("ShirtGraphic", "Graphic", VariantType::Content),
+    ("SkateboardController", "Steer", VariantType::Float32),
+    ("SkateboardController", "Throttle", VariantType::Float32),
+    ("SkateboardPlatform", "Controller", VariantType::Ref),
+    ("SkateboardPlatform", "ControllingHumanoid", VariantType::Ref),
+    ("SkateboardPlatform", "Steer", VariantType::Int32),
+    ("SkateboardPlatform", "StickyWheels", VariantType::Bool),
+    ("SkateboardPlatform", "Throttle", VariantType::Int32),
+    ("Skin", "SkinColor", VariantType::BrickColor),
+    ("Sky", "CelestialBodiesShown", VariantType::Bool),
+    ("Sky", "MoonAngularSize", VariantType::Float32),
+    ("Sky", "MoonTextureId", VariantType::Content),
+    ("Sky", "SkyboxBk", VariantType::Content),
+    ("Sky", "SkyboxDn", VariantType::Content),
+    ("Sky", "SkyboxFt", VariantType::Content),
+    ("Sky", "SkyboxLf", VariantType::Content),
+    ("Sky", "SkyboxRt", VariantType::Content),
+    ("Sky", "SkyboxUp", VariantType::Content),
+    ("Sky", "StarCount", VariantType::Int32),
+    ("Sky", "SunAngularSize", VariantType::Float32),
+    ("Sky", "SunTextureId", VariantType::Content),
+    ("SlidingBallConstraint", "ActuatorType", VariantType::Enum),
+    ("SlidingBallConstraint", "CurrentPosition", VariantType::Float32),
+    ("SlidingBallConstraint", "LimitsEnabled", VariantType::Bool),
+    ("SlidingBallConstraint", "LinearResponsiveness", VariantType::Float32),
+    ("SlidingBallConstraint", "LowerLimit", VariantType::Float32),
+    ("SlidingBallConstraint", "MotorMaxAcceleration", VariantType::Float32),
+    ("SlidingBallConstraint", "MotorMaxForce", VariantType::Float32),
+    ("SlidingBallConstraint", "Restitution", VariantType::Float32),
+    ("SlidingBallConstraint", "ServoMaxForce", VariantType::Float32),
+    ("SlidingBallConstraint", "Size", VariantType::Float32),
+    ("SlidingBallConstraint", "Speed", VariantType::Float32),
+    ("SlidingBallConstraint", "TargetPosition", VariantType::Float32),
+    ("SlidingBallConstraint", "UpperLimit", VariantType::Float32),
+    ("SlidingBallConstraint", "Velocity", VariantType::Float32),
+    ("Smoke", "Color", VariantType::Color3),
+    ("Smoke", "Enabled", VariantType::Bool),
+    ("Smoke", "Opacity", VariantType::Float32),
+    ("Smoke", "RiseVelocity", VariantType::Float32),
+    ("Smoke", "Size", VariantType::Float32),
+    ("Smoke", "opacity_xml", VariantType::Float32),
+    ("Smoke", "riseVelocity_xml", VariantType::Float32),
+    ("Smoke", "size_xml", VariantType::Float32),
+    ("Sound", "EmitterSize", VariantType::Float32),
+    ("Sound", "IsLoaded", VariantType::Bool),
+    ("Sound", "IsPaused", VariantType::Bool),
+    ("Sound", "IsPlaying", VariantType::Bool),
+    ("Sound", "Looped", VariantType::Bool),
+    ("Sound", "MaxDistance", VariantType::Float32),
+    ("Sound", "MinDistance", VariantType::Float32),
+    ("Sound", "Pitch", VariantType::Float32),
+    ("Sound", "PlayOnRemove", VariantType::Bool),
+    ("Sound", "PlaybackLoudness", VariantType::Float64),
+    ("Sound", "PlaybackSpeed", VariantType::Float32),
+    ("Sound", "Playing", VariantType::Bool),
+    ("Sound", "RollOffMaxDistance", VariantType::Float32),
+    ("Sound", "RollOffMinDistance", VariantType::Float32),
+    ("Sound", "RollOffMode", VariantType::Enum),
+    ("Sound", "SoundGroup", VariantType::Ref),
+    ("Sound", "SoundId", VariantType::Content),
+    ("Sound", "TimeLength", VariantType::Float64),
+    ("Sound", "TimePosition", VariantType::Float64),
+    ("Sound", "Volume", VariantType::Float32),
+    ("Sound", "isPlaying", VariantType::Bool),
+    ("Sound", "xmlRead_MaxDistance_3", VariantType::Float32),
+    ("SoundEffect", "Enabled", VariantType::Bool),
+    ("SoundEffect", "Priority", VariantType::Int32),
+    ("SoundGroup", "Volume", VariantType::Float32),
+    ("SoundService", "AmbientReverb", VariantType::Enum),
+    ("SoundService", "DistanceFactor", VariantType::Float32),
+    ("SoundService", "DopplerScale", VariantType::Float32),
+    ("SoundService", "RespectFilteringEnabled", VariantType::Bool),
+    ("SoundService", "RolloffScale", VariantType::Float32),
+    ("Sparkles", "Color", VariantType::Color3),
+    ("Sparkles", "Enabled", VariantType::Bool),
+    ("Sparkles", "SparkleColor", VariantType::Color3),
+    ("SpawnLocation", "AllowTeamChangeOnTouch", VariantType::Bool),
+    ("SpawnLocation", "Duration", VariantType::Int32),
+    ("SpawnLocation", "Enabled", VariantType::Bool),
+    ("SpawnLocation", "Neutral", VariantType::Bool),
+    ("SpawnLocation", "TeamColor", VariantType::BrickColor),
+    ("Speaker", "PlaybackLoudness", VariantType::Float64),
+    ("Speaker", "RollOffMaxDistance", VariantType::Float32),
+    ("Speaker", "RollOffMinDistance", VariantType::Float32),
+    ("Speaker", "RollOffMode", VariantType::Enum),
+    ("Speaker", "SoundGroup", VariantType::Ref),
+    ("Speaker", "Source", VariantType::Ref),
+    ("Speaker", "Volume", VariantType::Float32),
+    ("SpecialMesh", "MeshType", VariantType::Enum),
+    ("SphereHandleAdornment", "Radius", VariantType::Float32),
+    ("SpotLight", "Angle", VariantType::Float32),
+    ("SpotLight", "Face", VariantType::Enum),
+    ("SpotLight", "Range", VariantType::Float32),
+    ("SpringConstraint", "Coils", VariantType::Float32),
+    ("SpringConstraint", "CurrentLength", VariantType::Float32),
+    ("SpringConstraint", "Damping", VariantType::Float32),
+    ("SpringConstraint", "FreeLength", VariantType::Float32),
+    ("SpringConstraint", "LimitsEnabled", VariantType::Bool),
+    ("SpringConstraint", "MaxForce", VariantType::Float32),
+    ("SpringConstraint", "MaxLength", VariantType::Float32),
+    ("SpringConstraint", "MinLength", VariantType::Float32),
+    ("SpringConstraint", "Radius", VariantType::Float32),
+    ("SpringConstraint", "Stiffness", VariantType::Float32),
+    ("SpringConstraint", "Thickness", VariantType::Float32),
+    ("StarterGui", "ProcessUserInput", VariantType::Bool),
+    ("StarterGui", "ResetPlayerGuiOnSpawn", VariantType::Bool),
+    ("StarterGui", "ScreenOrientation", VariantType::Enum),
+    ("StarterGui", "ShowDevelopmentGui", VariantType::Bool),
+    ("StarterGui", "VirtualCursorMode", VariantType::Enum),
+    ("StarterPlayer", "AllowCustomAnimations", VariantType::Bool),
+    ("StarterPlayer", "AutoJumpEnabled", VariantType::Bool),
+    ("StarterPlayer", "CameraMaxZoomDistance", VariantType::Float32),
+    ("StarterPlayer", "CameraMinZoomDistance", VariantType::Float32),
+    ("StarterPlayer", "CameraMode", VariantType::Enum),
+    ("StarterPlayer", "CharacterJumpHeight", VariantType::Float32),
+    ("StarterPlayer", "CharacterJumpPower", VariantType::Float32),
+    ("StarterPlayer", "CharacterMaxSlopeAngle", VariantType::Float32),
+    ("StarterPlayer", "CharacterUseJumpPower", VariantType::Bool),
+    ("StarterPlayer", "CharacterWalkSpeed", VariantType::Float32),
+    ("StarterPlayer", "DevCameraOcclusionMode", VariantType::Enum),
+    ("StarterPlayer", "DevComputerCameraMovementMode", VariantType::Enum),
+    ("StarterPlayer", "DevComputerMovementMode", VariantType::Enum),
+    ("StarterPlayer", "DevTouchCameraMovementMode", VariantType::Enum),
+    ("StarterPlayer", "DevTouchMovementMode", VariantType::Enum),
+    ("StarterPlayer", "EnableMouseLockOption", VariantType::Bool),
+    ("StarterPlayer", "GameSettingsAssetIDFace", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDHead", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDLeftArm", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDLeftLeg", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDPants", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDRightArm", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDRightLeg", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDShirt", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDTeeShirt", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAssetIDTorso", VariantType::Int64),
+    ("StarterPlayer", "GameSettingsAvatar", VariantType::Enum),
+    ("StarterPlayer", "GameSettingsR15Collision", VariantType::Enum),
+    ("StarterPlayer", "GameSettingsScaleRangeBodyType", VariantType::NumberRange),
+    ("StarterPlayer", "GameSettingsScaleRangeHead", VariantType::NumberRange),
+    ("StarterPlayer", "GameSettingsScaleRangeHeight", VariantType::NumberRange),
+    ("StarterPlayer", "GameSettingsScaleRangeProportion", VariantType::NumberRange),
+    ("StarterPlayer", "GameSettingsScaleRangeWidth", VariantType::NumberRange),
+    ("StarterPlayer", "HealthDisplayDistance", VariantType::Float32),
+    ("StarterPlayer", "LoadCharacterAppearance", VariantType::Bool),
+    ("StarterPlayer", "LoadCharacterLayeredClothing", VariantType::Enum),
+    ("StarterPlayer", "NameDisplayDistance", VariantType::Float32),
+    ("StarterPlayer", "UserEmotesEnabled", VariantType::Bool),
+    ("Stats", "ContactsCount", VariantType::Int32),
+    ("Stats", "DataReceiveKbps", VariantType::Float32),
+    ("Stats", "DataSendKbps", VariantType::Float32),
+    ("Stats", "HeartbeatTimeMs", VariantType::Float32),
+    ("Stats", "InstanceCount", VariantType::Int32),
+    ("Stats", "MovingPrimitivesCount", VariantType::Int32),
+    ("Stats", "PhysicsReceiveKbps", VariantType::Float32),
+    ("Stats", "PhysicsSendKbps", VariantType::Float32),
+    ("Stats", "PhysicsStepTimeMs", VariantType::Float32),
+    ("Stats", "PrimitivesCount", VariantType::Int32),
+    ("StatsItem", "DisplayName", VariantType::String),
+    ("StringValue", "Value", VariantType::String),
+    ("Studio", "\"TODO\" Color", VariantType::Color3),
+    ("Studio", "\"function\" Color", VariantType::Color3),
+    ("Studio", "\"local\" Color", VariantType::Color3),
+    ("Studio", "\"nil\" Color", VariantType::Color3),
+    ("Studio", "\"self\" Color", VariantType::Color3),
+    ("Studio", "Active Color", VariantType::Color3),
+    ("Studio", "Active Hover Over Color", VariantType::Color3),
+    ("Studio", "Always Save Script Changes", VariantType::Bool),
+    ("Studio", "Animate Hover Over", VariantType::Bool),
+    ("Studio", "Auto Clean Empty Line", VariantType::Bool),
+    ("Studio", "Auto Closing Brackets", VariantType::Bool),
+    ("Studio", "Auto Closing Quotes", VariantType::Bool),
+    ("Studio", "Auto Indent Rule", VariantType::Enum),
+    ("Studio", "Auto-Recovery Enabled", VariantType::Bool),
+    ("Studio", "Auto-Recovery Interval (Minutes)", VariantType::Int32),
+    ("Studio", "Background Color", VariantType::Color3),
+    ("Studio", "Basic Objects Display Mode", VariantType::Enum),
+    ("Studio", "Bool Color", VariantType::Color3),
+    ("Studio", "Bracket Color", VariantType::Color3),
+    ("Studio", "Built-in Function Color", VariantType::Color3),
+    ("Studio", "Camera Mouse Wheel Speed", VariantType::Float32),
+    ("Studio", "Camera Shift Speed", VariantType::Float32),
+    ("Studio", "Camera Speed", VariantType::Float32),
+    ("Studio", "Camera Zoom to Mouse Position", VariantType::Bool),
+    ("Studio", "Clear Output On Start", VariantType::Bool),
+    ("Studio", "CommandBarLocalState", VariantType::Bool),
+    ("Studio", "Comment Color", VariantType::Color3),
+    ("Studio", "Current Line Highlight Color", VariantType::Color3),
+    ("Studio", "Debugger Current Line Color", VariantType::Color3),
+    ("Studio", "Debugger Error Line Color", VariantType::Color3),
+    ("Studio", "DeprecatedObjectsShown", VariantType::Bool),
+    ("Studio", "Disable Accurate Play Solo", VariantType::Bool),
+    ("Studio", "DisplayLanguage", VariantType::String),
+    ("Studio", "Drag Multiple Parts As Single Part", VariantType::Bool),
+    ("Studio", "Enable Autocomplete", VariantType::Bool),
+    ("Studio", "Enable CoreScript Debugger", VariantType::Bool),
+    ("Studio", "Enable Http Sandboxing", VariantType::Bool),
+    ("Studio", "Enable Internal Beta Features", VariantType::Bool),
+    ("Studio", "Enable Internal Features", VariantType::Bool),
+    ("Studio", "Enable Linting", VariantType::Bool),
+    ("Studio", "Enable Signature Help", VariantType::Bool),
+    ("Studio", "Enable Temporary Tabs", VariantType::Bool),
+    ("Studio", "Enable Temporary Tabs In Explorer", VariantType::Bool),
+    ("Studio", "Enable Type Hover", VariantType::Bool),
+    ("Studio", "Error Color", VariantType::Color3),
+    ("Studio", "Find Selection Background Color", VariantType::Color3),
+    ("Studio", "Format On Paste", VariantType::Bool),
+    ("Studio", "Format On Type", VariantType::Bool),
+    ("Studio", "Function Name Color", VariantType::Color3),
+    ("Studio", "Highlight Current Line", VariantType::Bool),
+    ("Studio", "Highlight Occurances", VariantType::Bool),
+    ("Studio", "Hover Animate Speed", VariantType::Enum),
+    ("Studio", "Hover Box Thickness", VariantType::Float32),
+    ("Studio", "Hover Over Color", VariantType::Color3),
+    ("Studio", "Indent Using Spaces", VariantType::Bool),
+    ("Studio", "Keyword Color", VariantType::Color3),
+    ("Studio", "Line Thickness", VariantType::Float32),
+    ("Studio", "LuaDebuggerEnabled", VariantType::Bool),
+    ("Studio", "LuaDebuggerEnabledAtStartup", VariantType::Bool),
+    ("Studio", "Luau Keyword Color", VariantType::Color3),
+    ("Studio", "Matching Word Background Color", VariantType::Color3),
+    ("Studio", "Maximum Output Lines", VariantType::Int32),
+    ("Studio", "Menu Item Background Color", VariantType::Color3),
+    ("Studio", "Method Color", VariantType::Color3),
+    ("Studio", "Number Color", VariantType::Color3),
+    ("Studio", "Only Play Audio from Window in Focus", VariantType::Bool),
+    ("Studio", "Operator Color", VariantType::Color3),
+    ("Studio", "Output Layout Mode", VariantType::Enum),
+    ("Studio", "OverrideCoreScripts", VariantType::Bool),
+    ("Studio", "PermissionLevelShown", VariantType::Enum),
+    ("Studio", "Physical Draggers Select Scope By Default", VariantType::Bool),
+    ("Studio", "Pivot Snap To Geometry Color", VariantType::Color3),
+    ("Studio", "PluginDebuggingEnabled", VariantType::Bool),
+    ("Studio", "Primary Text Color", VariantType::Color3),
+    ("Studio", "Property Color", VariantType::Color3),
+    ("Studio", "Render Throttle Percentage", VariantType::Int32),
+    ("Studio", "Respect Studio shortcuts when game has focus", VariantType::Bool),
+    ("Studio", "Ruler Color", VariantType::Color3),
+    ("Studio", "Rulers", VariantType::String),
+    ("Studio", "RuntimeUndoBehavior", VariantType::Enum),
+    ("Studio", "Script Editor Color Preset", VariantType::Enum),
+    ("Studio", "Script Editor Scrollbar Background Color", VariantType::Color3),
+    ("Studio", "Script Editor Scrollbar Handle Color", VariantType::Color3),
+    ("Studio", "ScriptEditorShouldShowPluginMethods", VariantType::Bool),
+    ("Studio", "ScriptTimeoutLength", VariantType::Int32),
+    ("Studio", "Scroll Past Last Line", VariantType::Bool),
+    ("Studio", "Search Content For Core Scripts", VariantType::Bool),
+    ("Studio", "Secondary Text Color", VariantType::Color3),
+    ("Studio", "Select Color", VariantType::Color3),
+    ("Studio", "Select/Hover Color", VariantType::Color3),
+    ("Studio", "Selected Menu Item Background Color", VariantType::Color3),
+    ("Studio", "Selected Text Color", VariantType::Color3),
+    ("Studio", "Selection Background Color", VariantType::Color3),
+    ("Studio", "Selection Color", VariantType::Color3),
+    ("Studio", "Server Audio Behavior", VariantType::Enum),
+    ("Studio", "Show Core GUI in Explorer while Playing", VariantType::Bool),
+    ("Studio", "Show Deployment Warnings", VariantType::Bool),
+    ("Studio", "Show Diagnostics Bar", VariantType::Bool),
+    ("Studio", "Show FileSyncService", VariantType::Bool),
+    ("Studio", "Show Hidden Objects in Explorer", VariantType::Bool),
+    ("Studio", "Show Hover Over", VariantType::Bool),
+    ("Studio", "Show Navigation Mesh", VariantType::Bool),
+    ("Studio", "Show Plugin GUI Service in Explorer", VariantType::Bool),
+    ("Studio", "Show QT warnings in output", VariantType::Bool),
+    ("Studio", "Show Whitespace", VariantType::Bool),
+    ("Studio", "Show plus button on hover in Explorer", VariantType::Bool),
+    ("Studio", "ShowCorePackagesInExplorer", VariantType::Bool),
+    ("Studio", "Skip Closing Brackets and Quotes", VariantType::Bool),
+    ("Studio", "String Color", VariantType::Color3),
+    ("Studio", "Tab Width", VariantType::Int32),
+    ("Studio", "Text Color", VariantType::Color3),
+    ("Studio", "Text Wrapping", VariantType::Bool),
+    ("Studio", "Theme", VariantType::Ref),
+    ("Studio", "UI Theme", VariantType::Enum),
+    ("Studio", "Warning Color", VariantType::Color3),
+    ("Studio", "Whitespace Color", VariantType::Color3),
+    ("StudioData", "EnableScriptCollabByDefaultOnLoad", VariantType::Bool),
+    ("StudioData", "SrcPlaceId", VariantType::Int64),
+    ("StudioData", "SrcUniverseId", VariantType::Int64),
+    ("StudioService", "ActiveScript", VariantType::Ref),
+    ("StudioService", "AlignDraggedObjects", VariantType::Bool),
+    ("StudioService", "DraggerSolveConstraints", VariantType::Bool),
+    ("StudioService", "DrawConstraintsOnTop", VariantType::Bool),
+    ("StudioService", "GridSize", VariantType::Float32),
+    ("StudioService", "HoverInstance", VariantType::Ref),
+    ("StudioService", "InstalledPluginData", VariantType::String),
+    ("StudioService", "PivotSnapToGeometry", VariantType::Bool),
+    ("StudioService", "RotateIncrement", VariantType::Float32),
+    ("StudioService", "ShowActiveInstanceHighlight", VariantType::Bool),
+    ("StudioService", "ShowConstraintDetails", VariantType::Bool),
+    ("StudioService", "StudioLocaleId", VariantType::String),
+    ("StudioService", "UseLocalSpace", VariantType::Bool),
+    ("SunRaysEffect", "Intensity", VariantType::Float32),
+    ("SunRaysEffect", "Spread", VariantType::Float32),
+    ("SurfaceAppearance", "AlphaMode", VariantType::Enum),
+    ("SurfaceAppearance", "ColorMap", VariantType::Content),
+    ("SurfaceAppearance", "MetalnessMap", VariantType::Content),
+    ("SurfaceAppearance", "NormalMap", VariantType::Content),
+    ("SurfaceAppearance", "RoughnessMap", VariantType::Content),
+    ("SurfaceAppearance", "TexturePack", VariantType::Content),
+    ("SurfaceGui", "Active", VariantType::Bool),
+    ("SurfaceGui", "Adornee", VariantType::Ref),
+    ("SurfaceGui", "AlwaysOnTop", VariantType::Bool),
+    ("SurfaceGui", "Brightness", VariantType::Float32),
+    ("SurfaceGui", "CanvasSize", VariantType::Vector2),
+    ("SurfaceGui", "ClipsDescendants", VariantType::Bool),
+    ("SurfaceGui", "Face", VariantType::Enum),
+    ("SurfaceGui", "LightInfluence", VariantType::Float32),
+    ("SurfaceGui", "PixelsPerStud", VariantType::Float32),
+    ("SurfaceGui", "SizingMode", VariantType::Enum),
+    ("SurfaceGui", "ToolPunchThroughDistance", VariantType::Float32),
+    ("SurfaceGui", "ZOffset", VariantType::Float32),
+    ("SurfaceLight", "Angle", VariantType::Float32),
+    ("SurfaceLight", "Face", VariantType::Enum),
+    ("SurfaceLight", "Range", VariantType::Float32),
+    ("SurfaceSelection", "TargetSurface", VariantType::Enum),
+    ("TaskScheduler", "SchedulerDutyCycle", VariantType::Float64),
+    ("TaskScheduler", "SchedulerRate", VariantType::Float64),
+    ("TaskScheduler", "ThreadPoolConfig", VariantType::Enum),
+    ("TaskScheduler", "ThreadPoolSize", VariantType::Int32),
+    ("Team", "AutoAssignable", VariantType::Bool),
+    ("Team", "AutoColorCharacters", VariantType::Bool),
+    ("Team", "ChildOrder", VariantType::Int32),
+    ("Team", "Score", VariantType::Int32),
+    ("Team", "TeamColor", VariantType::BrickColor),
+    ("TeleportAsyncResult", "PrivateServerId", VariantType::String),
+    ("TeleportAsyncResult", "ReservedServerAccessCode", VariantType::String),
+    ("TeleportOptions", "ReservedServerAccessCode", VariantType::String),
+    ("TeleportOptions", "ServerInstanceId", VariantType::String),
+    ("TeleportOptions", "ShouldReserveServer", VariantType::Bool),
+    ("TeleportService", "CustomizedTeleportUI", VariantType::Bool),
+    ("Terrain", "Decoration", VariantType::Bool),
+    ("Terrain", "IsSmooth", VariantType::Bool),
+    ("Terrain", "LastUsedModificationMethod", VariantType::Enum),
+    ("Terrain", "MaterialColors", VariantType::BinaryString),
+    ("Terrain", "MaxExtents", VariantType::Region3int16),
+    ("Terrain", "WaterColor", VariantType::Color3),
+    ("Terrain", "WaterReflectance", VariantType::Float32),
+    ("Terrain", "WaterTransparency", VariantType::Float32),
+    ("Terrain", "WaterWaveSize", VariantType::Float32),
+    ("Terrain", "WaterWaveSpeed", VariantType::Float32),
+    ("TerrainRegion", "IsSmooth", VariantType::Bool),
+    ("TerrainRegion", "SizeInCells", VariantType::Vector3),
+    ("TestService", "AutoRuns", VariantType::Bool),
+    ("TestService", "Description", VariantType::String),
+    ("TestService", "ErrorCount", VariantType::Int32),
+    ("TestService", "ExecuteWithStudioRun", VariantType::Bool),
+    ("TestService", "Is30FpsThrottleEnabled", VariantType::Bool),
+    ("TestService", "IsPhysicsEnvironmentalThrottled", VariantType::Bool),
+    ("TestService", "IsSleepAllowed", VariantType::Bool),
+    ("TestService", "NumberOfPlayers", VariantType::Int32),
+    ("TestService", "SimulateSecondsLag", VariantType::Float64),
+    ("TestService", "TestCount", VariantType::Int32),
+    ("TestService", "Timeout", VariantType::Float64),
+    ("TestService", "WarnCount", VariantType::Int32),
+    ("TextBox", "ClearTextOnFocus", VariantType::Bool),
+    ("TextBox", "CursorPosition", VariantType::Int32),
+    ("TextBox", "EnableRealtimeFilteringHints", VariantType::Bool),
+    ("TextBox", "Font", VariantType::Enum),
+    ("TextBox", "FontSize", VariantType::Enum),
+    ("TextBox", "LineHeight", VariantType::Float32),
+    ("TextBox", "ManualFocusRelease", VariantType::Bool),
+    ("TextBox", "MaxVisibleGraphemes", VariantType::Int32),
+    ("TextBox", "MultiLine", VariantType::Bool),
+    ("TextBox", "OverlayNativeInput", VariantType::Bool),
+    ("TextBox", "PlaceholderColor3", VariantType::Color3),
+    ("TextBox", "PlaceholderText", VariantType::String),
+    ("TextBox", "ReturnKeyType", VariantType::Enum),
+    ("TextBox", "RichText", VariantType::Bool),
+    ("TextBox", "SelectionStart", VariantType::Int32),
+    ("TextBox", "ShowNativeInput", VariantType::Bool),
+    ("TextBox", "Text", VariantType::String),
+    ("TextBox", "TextBounds", VariantType::Vector2),
+    ("TextBox", "TextColor", VariantType::BrickColor),
+    ("TextBox", "TextColor3", VariantType::Color3),
+    ("TextBox", "TextEditable", VariantType::Bool),
+    ("TextBox", "TextFits", VariantType::Bool),
+    ("TextBox", "TextInputType", VariantType::Enum),
+    ("TextBox", "TextScaled", VariantType::Bool),
+    ("TextBox", "TextSize", VariantType::Float32),
+    ("TextBox", "TextStrokeColor3", VariantType::Color3),
+    ("TextBox", "TextStrokeTransparency", VariantType::Float32),
+    ("TextBox", "TextTransparency", VariantType::Float32),
+    ("TextBox", "TextTruncate", VariantType::Enum),
+    ("TextBox", "TextWrap", VariantType::Bool),
+    ("TextBox", "TextWrapped", VariantType::Bool),
+    ("TextBox", "TextXAlignment", VariantType::Enum),
+    ("TextBox", "TextYAlignment", VariantType::Enum),
+    ("TextButton", "Font", VariantType::Enum),
+    ("TextButton", "FontSize", VariantType::Enum),
+    ("TextButton", "LineHeight", VariantType::Float32),
+    ("TextButton", "LocalizedText", VariantType::String),
+    ("TextButton", "MaxVisibleGraphemes", VariantType::Int32),
+    ("TextButton", "RichText", VariantType::Bool),
+    ("TextButton", "Text", VariantType::String),
+    ("TextButton", "TextBounds", VariantType::Vector2),
+    ("TextButton", "TextColor", VariantType::BrickColor),
+    ("TextButton", "TextColor3", VariantType::Color3),
+    ("TextButton", "TextFits", VariantType::Bool),
+    ("TextButton", "TextScaled", VariantType::Bool),
+    ("TextButton", "TextSize", VariantType::Float32),
+    ("TextButton", "TextStrokeColor3", VariantType::Color3),
+    ("TextButton", "TextStrokeTransparency", VariantType::Float32),
+    ("TextButton", "TextTransparency", VariantType::Float32),
+    ("TextButton", "TextTruncate", VariantType::Enum),
+    ("TextButton", "TextWrap", VariantType::Bool),
+    ("TextButton", "TextWrapped", VariantType::Bool),
+    ("TextButton", "TextXAlignment", VariantType::Enum),
+    ("TextButton", "TextYAlignment", VariantType::Enum),
+    ("TextLabel", "Font", VariantType::Enum),
+    ("TextLabel", "FontSize", VariantType::Enum),
+    ("TextLabel", "LineHeight", VariantType::Float32),
+    ("TextLabel", "LocalizedText", VariantType::String),
+    ("TextLabel", "MaxVisibleGraphemes", VariantType::Int32),
+    ("TextLabel", "RichText", VariantType::Bool),
+    ("TextLabel", "Text", VariantType::String),
+    ("TextLabel", "TextBounds", VariantType::Vector2),
+    ("TextLabel", "TextColor", VariantType::BrickColor),
+    ("TextLabel", "TextColor3", VariantType::Color3),
+    ("TextLabel", "TextFits", VariantType::Bool),
+    ("TextLabel", "TextScaled", VariantType::Bool),
+    ("TextLabel", "TextSize", VariantType::Float32),
+    ("TextLabel", "TextStrokeColor3", VariantType::Color3),
+    ("TextLabel", "TextStrokeTransparency", VariantType::Float32),
+    ("TextLabel", "TextTransparency", VariantType::Float32),
+    ("TextLabel", "TextTruncate", VariantType::Enum),
+    ("TextLabel", "TextWrap", VariantType::Bool),
+    ("TextLabel", "TextWrapped", VariantType::Bool),
+    ("TextLabel", "TextXAlignment", VariantType::Enum),
+    ("TextLabel", "TextYAlignment", VariantType::Enum),
+    ("Texture", "OffsetStudsU", VariantType::Float32),
+    ("Texture", "OffsetStudsV", VariantType::Float32),
+    ("Texture", "StudsPerTileU", VariantType::Float32),
+    ("Texture", "StudsPerTileV", VariantType::Float32),
+    ("Tool", "CanBeDropped", VariantType::Bool),
+    ("Tool", "Enabled", VariantType::Bool),
+    ("Tool", "Grip", VariantType::CFrame),
+    ("Tool", "GripForward", VariantType::Vector3),
+    ("Tool", "GripPos", VariantType::Vector3),
+    ("Tool", "GripRight", VariantType::Vector3),
+    ("Tool", "GripUp", VariantType::Vector3),
+    ("Tool", "ManualActivationOnly", VariantType::Bool),
+    ("Tool", "RequiresHandle", VariantType::Bool),
+    ("Tool", "ToolTip", VariantType::String),
+    ("Torque", "RelativeTo", VariantType::Enum),
+    ("Torque", "Torque", VariantType::Vector3),
+    ("TorsionSpringConstraint", "Coils", VariantType::Float32),
+    ("TorsionSpringConstraint", "CurrentAngle", VariantType::Float32),
+    ("TorsionSpringConstraint", "Damping", VariantType::Float32),
+    ("TorsionSpringConstraint", "LimitEnabled", VariantType::Bool),
+    ("TorsionSpringConstraint", "MaxAngle", VariantType::Float32),
+    ("TorsionSpringConstraint", "MaxTorque", VariantType::Float32),
+    ("TorsionSpringConstraint", "Radius", VariantType::Float32),
+    ("TorsionSpringConstraint", "Restitution", VariantType::Float32),
+    ("TorsionSpringConstraint", "Stiffness", VariantType::Float32),
+    ("Trail", "Attachment0", VariantType::Ref),
+    ("Trail", "Attachment1", VariantType::Ref),
+    ("Trail", "Color", VariantType::ColorSequence),
+    ("Trail", "Enabled", VariantType::Bool),
+    ("Trail", "FaceCamera", VariantType::Bool),
+    ("Trail", "Lifetime", VariantType::Float32),
+    ("Trail", "LightEmission", VariantType::Float32),
+    ("Trail", "LightInfluence", VariantType::Float32),
+    ("Trail", "MaxLength", VariantType::Float32),
+    ("Trail", "MinLength", VariantType::Float32),
+    ("Trail", "Texture", VariantType::Content),
+    ("Trail", "TextureLength", VariantType::Float32),
+    ("Trail", "TextureMode", VariantType::Enum),
+    ("Trail", "Transparency", VariantType::NumberSequence),
+    ("Trail", "WidthScale", VariantType::NumberSequence),
+    ("Translator", "LocaleId", VariantType::String),
+    ("TremoloSoundEffect", "Depth", VariantType::Float32),
+    ("TremoloSoundEffect", "Duty", VariantType::Float32),
+    ("TremoloSoundEffect", "Frequency", VariantType::Float32),
+    ("TriangleMeshPart", "CollisionFidelity", VariantType::Enum),
+    ("TrussPart", "Style", VariantType::Enum),
+    ("Tween", "Instance", VariantType::Ref),
+    ("TweenBase", "PlaybackState", VariantType::Enum),
+    ("UIAspectRatioConstraint", "AspectRatio", VariantType::Float32),
+    ("UIAspectRatioConstraint", "AspectType", VariantType::Enum),
+    ("UIAspectRatioConstraint", "DominantAxis", VariantType::Enum),
+    ("UICorner", "CornerRadius", VariantType::UDim),
+    ("UIGradient", "Color", VariantType::ColorSequence),
+    ("UIGradient", "Enabled", VariantType::Bool),
+    ("UIGradient", "Offset", VariantType::Vector2),
+    ("UIGradient", "Rotation", VariantType::Float32),
+    ("UIGradient", "Transparency", VariantType::NumberSequence),
+    ("UIGridLayout", "AbsoluteCellCount", VariantType::Vector2),
+    ("UIGridLayout", "AbsoluteCellSize", VariantType::Vector2),
+    ("UIGridLayout", "CellPadding", VariantType::UDim2),
+    ("UIGridLayout", "CellSize", VariantType::UDim2),
+    ("UIGridLayout", "FillDirectionMaxCells", VariantType::Int32),
+    ("UIGridLayout", "StartCorner", VariantType::Enum),
+    ("UIGridStyleLayout", "AbsoluteContentSize", VariantType::Vector2),
+    ("UIGridStyleLayout", "FillDirection", VariantType::Enum),
+    ("UIGridStyleLayout", "HorizontalAlignment", VariantType::Enum),
+    ("UIGridStyleLayout", "SortOrder", VariantType::Enum),
+    ("UIGridStyleLayout", "VerticalAlignment", VariantType::Enum),
+    ("UIListLayout", "Padding", VariantType::UDim),
+    ("UIPadding", "PaddingBottom", VariantType::UDim),
+    ("UIPadding", "PaddingLeft", VariantType::UDim),
+    ("UIPadding", "PaddingRight", VariantType::UDim),
+    ("UIPadding", "PaddingTop", VariantType::UDim),
+    ("UIPageLayout", "Animated", VariantType::Bool),
+    ("UIPageLayout", "Circular", VariantType::Bool),
+    ("UIPageLayout", "CurrentPage", VariantType::Ref),
+    ("UIPageLayout", "EasingDirection", VariantType::Enum),
+    ("UIPageLayout", "EasingStyle", VariantType::Enum),
+    ("UIPageLayout", "GamepadInputEnabled", VariantType::Bool),
+    ("UIPageLayout", "Padding", VariantType::UDim),
+    ("UIPageLayout", "ScrollWheelInputEnabled", VariantType::Bool),
+    ("UIPageLayout", "TouchInputEnabled", VariantType::Bool),
+    ("UIPageLayout", "TweenTime", VariantType::Float32),
+    ("UIScale", "Scale", VariantType::Float32),
+    ("UISizeConstraint", "MaxSize", VariantType::Vector2),
+    ("UISizeConstraint", "MinSize", VariantType::Vector2),
+    ("UIStroke", "ApplyStrokeMode", VariantType::Enum),
+    ("UIStroke", "Color", VariantType::Color3),
+    ("UIStroke", "Enabled", VariantType::Bool),
+    ("UIStroke", "LineJoinMode", VariantType::Enum),
+    ("UIStroke", "Thickness", VariantType::Float32),
+    ("UIStroke", "Transparency", VariantType::Float32),
+    ("UITableLayout", "FillEmptySpaceColumns", VariantType::Bool),
+    ("UITableLayout", "FillEmptySpaceRows", VariantType::Bool),
+    ("UITableLayout", "MajorAxis", VariantType::Enum),
+    ("UITableLayout", "Padding", VariantType::UDim2),
+    ("UITextSizeConstraint", "MaxTextSize", VariantType::Int32),
+    ("UITextSizeConstraint", "MinTextSize", VariantType::Int32),
+    ("UniversalConstraint", "LimitsEnabled", VariantType::Bool),
+    ("UniversalConstraint", "MaxAngle", VariantType::Float32),
+    ("UniversalConstraint", "Radius", VariantType::Float32),
+    ("UniversalConstraint", "Restitution", VariantType::Float32),
+    ("UserGameSettings", "AllTutorialsDisabled", VariantType::Bool),
+    ("UserGameSettings", "CameraMode", VariantType::Enum),
+    ("UserGameSettings", "CameraYInverted", VariantType::Bool),
+    ("UserGameSettings", "ChatVisible", VariantType::Bool),
+    ("UserGameSettings", "ComputerCameraMovementMode", VariantType::Enum),
+    ("UserGameSettings", "ComputerMovementMode", VariantType::Enum),
+    ("UserGameSettings", "ControlMode", VariantType::Enum),
+    ("UserGameSettings", "Fullscreen", VariantType::Bool),
+    ("UserGameSettings", "GamepadCameraSensitivity", VariantType::Float32),
+    ("UserGameSettings", "GraphicsQualityLevel", VariantType::Int32),
+    ("UserGameSettings", "HasEverUsedVR", VariantType::Bool),
+    ("UserGameSettings", "IsUsingCameraYInverted", VariantType::Bool),
+    ("UserGameSettings", "IsUsingGamepadCameraSensitivity", VariantType::Bool),
+    ("UserGameSettings", "MasterVolume", VariantType::Float32),
+    ("UserGameSettings", "MicroProfilerWebServerEnabled", VariantType::Bool),
+    ("UserGameSettings", "MicroProfilerWebServerIP", VariantType::String),
+    ("UserGameSettings", "MicroProfilerWebServerPort", VariantType::Int32),
+    ("UserGameSettings", "MouseSensitivity", VariantType::Float32),
+    ("UserGameSettings", "MouseSensitivityFirstPerson", VariantType::Vector2),
+    ("UserGameSettings", "MouseSensitivityThirdPerson", VariantType::Vector2),
+    ("UserGameSettings", "OnScreenProfilerEnabled", VariantType::Bool),
+    ("UserGameSettings", "OnboardingsCompleted", VariantType::String),
+    ("UserGameSettings", "PerformanceStatsVisible", VariantType::Bool),
+    ("UserGameSettings", "RCCProfilerRecordFrameRate", VariantType::Int32),
+    ("UserGameSettings", "RCCProfilerRecordTimeFrame", VariantType::Int32),
+    ("UserGameSettings", "RotationType", VariantType::Enum),
+    ("UserGameSettings", "SavedQualityLevel", VariantType::Enum),
+    ("UserGameSettings", "StartMaximized", VariantType::Bool),
+    ("UserGameSettings", "StartScreenPosition", VariantType::Vector2),
+    ("UserGameSettings", "StartScreenSize", VariantType::Vector2),
+    ("UserGameSettings", "TouchCameraMovementMode", VariantType::Enum),
+    ("UserGameSettings", "TouchMovementMode", VariantType::Enum),
+    ("UserGameSettings", "UsedCoreGuiIsVisibleToggle", VariantType::Bool),
+    ("UserGameSettings", "UsedCustomGuiIsVisibleToggle", VariantType::Bool),
+    ("UserGameSettings", "UsedHideHudShortcut", VariantType::Bool),
+    ("UserGameSettings", "VREnabled", VariantType::Bool),
+    ("UserGameSettings", "VRRotationIntensity", VariantType::Int32),
+    ("UserInputService", "AccelerometerEnabled", VariantType::Bool),
+    ("UserInputService", "BottomBarSize", VariantType::Vector2),
+    ("UserInputService", "GamepadEnabled", VariantType::Bool),
+    ("UserInputService", "GazeSelectionEnabled", VariantType::Bool),
+    ("UserInputService", "GyroscopeEnabled", VariantType::Bool),
+    ("UserInputService", "KeyboardEnabled", VariantType::Bool),
+    ("UserInputService", "LegacyInputEventsEnabled", VariantType::Bool),
+    ("UserInputService", "ModalEnabled", VariantType::Bool),
+    ("UserInputService", "MouseBehavior", VariantType::Enum),
+    ("UserInputService", "MouseDeltaSensitivity", VariantType::Float32),
+    ("UserInputService", "MouseEnabled", VariantType::Bool),
+    ("UserInputService", "MouseIconEnabled", VariantType::Bool),
+    ("UserInputService", "NavBarSize", VariantType::Vector2),
+    ("UserInputService", "OnScreenKeyboardAnimationDuration", VariantType::Float64),
+    ("UserInputService", "OnScreenKeyboardPosition", VariantType::Vector2),
+    ("UserInputService", "OnScreenKeyboardSize", VariantType::Vector2),
+    ("UserInputService", "OnScreenKeyboardVisible", VariantType::Bool),
+    ("UserInputService", "OverrideMouseIconBehavior", VariantType::Enum),
+    ("UserInputService", "RightBarSize", VariantType::Vector2),
+    ("UserInputService", "StatusBarSize", VariantType::Vector2),
+    ("UserInputService", "TouchEnabled", VariantType::Bool),
+    ("UserInputService", "UserHeadCFrame", VariantType::CFrame),
+    ("UserInputService", "VREnabled", VariantType::Bool),
+    ("VRService", "GuiInputUserCFrame", VariantType::Enum),
+    ("VRService", "VRDeviceName", VariantType::String),
+    ("VRService", "VREnabled", VariantType::Bool),
+    ("Vector3Value", "Value", VariantType::Vector3),
+    ("VectorForce", "ApplyAtCenterOfMass", VariantType::Bool),
+    ("VectorForce", "Force", VariantType::Vector3),
+    ("VectorForce", "RelativeTo", VariantType::Enum),
+    ("VehicleSeat", "AreHingesDetected", VariantType::Int32),
+    ("VehicleSeat", "Disabled", VariantType::Bool),
+    ("VehicleSeat", "HeadsUpDisplay", VariantType::Bool),
+    ("VehicleSeat", "MaxSpeed", VariantType::Float32),
+    ("VehicleSeat", "Occupant", VariantType::Ref),
+    ("VehicleSeat", "Steer", VariantType::Int32),
+    ("VehicleSeat", "SteerFloat", VariantType::Float32),
+    ("VehicleSeat", "Throttle", VariantType::Int32),
+    ("VehicleSeat", "ThrottleFloat", VariantType::Float32),
+    ("VehicleSeat", "Torque", VariantType::Float32),
+    ("VehicleSeat", "TurnSpeed", VariantType::Float32),
+    ("VelocityMotor", "CurrentAngle", VariantType::Float32),
+    ("VelocityMotor", "DesiredAngle", VariantType::Float32),
+    ("VelocityMotor", "Hole", VariantType::Ref),
+    ("VelocityMotor", "MaxVelocity", VariantType::Float32),
+    ("VideoFrame", "IsLoaded", VariantType::Bool),
+    ("VideoFrame", "Looped", VariantType::Bool),
+    ("VideoFrame", "Playing", VariantType::Bool),
+    ("VideoFrame", "Resolution", VariantType::Vector2),
+    ("VideoFrame", "TimeLength", VariantType::Float64),
+    ("VideoFrame", "TimePosition", VariantType::Float64),
+    ("VideoFrame", "Video", VariantType::Content),
+    ("VideoFrame", "Volume", VariantType::Float32),
+    ("ViewportFrame", "Ambient", VariantType::Color3),
+    ("ViewportFrame", "CurrentCamera", VariantType::Ref),
+    ("ViewportFrame", "ImageColor3", VariantType::Color3),
+    ("ViewportFrame", "ImageTransparency", VariantType::Float32),
+    ("ViewportFrame", "LightColor", VariantType::Color3),
+    ("ViewportFrame", "LightDirection", VariantType::Vector3),
+    ("VirtualInputManager", "AdditionalLuaState", VariantType::String),
+    ("VoiceSource", "UserId", VariantType::Int64),
+    ("WeldConstraint", "Active", VariantType::Bool),
+    ("WeldConstraint", "Enabled", VariantType::Bool),
+    ("WeldConstraint", "Part0", VariantType::Ref),
+    ("WeldConstraint", "Part1", VariantType::Ref),
+    ("Workspace", "AllowThirdPartySales", VariantType::Bool),
+    ("Workspace", "AnimationWeightedBlendFix", VariantType::Enum),
+    ("Workspace", "ClientAnimatorThrottling", VariantType::Enum),
+    ("Workspace", "CollisionGroups", VariantType::String),
+    ("Workspace", "CurrentCamera", VariantType::Ref),
+    ("Workspace", "DistributedGameTime", VariantType::Float64),
+    ("Workspace", "ExplicitAutoJoints", VariantType::Bool),
+    ("Workspace", "FallenPartsDestroyHeight", VariantType::Float32),
+    ("Workspace", "FilteringEnabled", VariantType::Bool),
+    ("Workspace", "Gravity", VariantType::Float32),
+    ("Workspace", "InterpolationThrottling", VariantType::Enum),
+    ("Workspace", "MeshPartHeadsAndAccessories", VariantType::Enum),
+    ("Workspace", "PhysicsSimulationRate", VariantType::Enum),
+    ("Workspace", "PhysicsSteppingMethod", VariantType::Enum),
+    ("Workspace", "SignalBehavior", VariantType::Enum),
+    ("Workspace", "StreamingEnabled", VariantType::Bool),
+    ("Workspace", "StreamingMinRadius", VariantType::Int32),
+    ("Workspace", "StreamingPauseMode", VariantType::Enum),
+    ("Workspace", "StreamingTargetRadius", VariantType::Int32),
+    ("Workspace", "Terrain", VariantType::Ref),
+    ("Workspace", "TouchesUseCollisionGroups", VariantType::Bool),
+    ("WrapLayer", "BindOffset", VariantType::CFrame),
+    ("WrapLayer", "Color", VariantType::Color3),
+    ("WrapLayer", "DebugMode", VariantType::Enum),
+    ("WrapLayer", "Enabled", VariantType::Bool),
+    ("WrapLayer", "Order", VariantType::Int32),
+    ("WrapLayer", "Puffiness", VariantType::Float32),
+    ("WrapLayer", "ReferenceMeshId", VariantType::Content),
+    ("WrapLayer", "ReferenceOrigin", VariantType::CFrame),
+    ("WrapLayer", "ReferenceOriginWorld", VariantType::CFrame),
+    ("WrapLayer", "ShrinkFactor", VariantType::Float32),
+    ("WrapTarget", "Color", VariantType::Color3),
+    ("WrapTarget", "DebugMode", VariantType::Enum),
+    ("WrapTarget", "Stiffness", VariantType::Float32),
+];
+
+/// Tells whether a value of type `ty` is valid for the given property on the
+/// given class, searching superclasses for the property. Unknown classes and
+/// properties are never valid.
+pub fn validate_property_type(class: &str, property: &str, ty: VariantType) -> bool {
+    superclasses(class)
+        .find_map(|class| {
+            PROPERTY_TYPES
+                .binary_search_by(|&(c, p, _)| (c, p).cmp(&(class, property)))
+                .ok()
+        })
+        .map(|index| PROPERTY_TYPES[index].2 == ty)
+        .unwrap_or(false)
+}
+
+/// Returns the given class followed by each of its superclasses.
+fn superclasses(class: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(class), |&class| {
+        SUPERCLASSES
+            .binary_search_by(|&(c, _)| c.cmp(class))
+            .ok()
+            .map(|index| SUPERCLASSES[index].1)
+    })
+}
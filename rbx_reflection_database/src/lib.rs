@@ -20,4 +20,100 @@ mod test {
     fn smoke_test() {
         let _database = get();
     }
+
+    #[test]
+    fn part_default_size() {
+        use rbx_types::{Variant, Vector3};
+
+        let database = get();
+
+        let default_size = Variant::Vector3(Vector3::new(4.0, 1.2, 2.0));
+        assert!(database.is_default("Part", "Size", &default_size));
+
+        let other_size = Variant::Vector3(Vector3::new(1.0, 1.0, 1.0));
+        assert!(!database.is_default("Part", "Size", &other_size));
+
+        // Properties without a known default are never considered default.
+        assert!(!database.is_default("Part", "NotAProperty", &default_size));
+        assert!(!database.is_default("NotAClass", "Size", &default_size));
+    }
+
+    #[test]
+    fn enum_lookup() {
+        let database = get();
+
+        let material = database.get_enum("Material").unwrap();
+        assert_eq!(material.by_name("SmoothPlastic"), Some(272));
+        assert_eq!(material.by_name("Plastic"), Some(256));
+        assert_eq!(material.by_value(256), Some("Plastic"));
+        assert_eq!(material.by_value(272), Some("SmoothPlastic"));
+
+        let normal_id = database.get_enum("NormalId").unwrap();
+        assert_eq!(normal_id.by_name("Right"), Some(0));
+        assert_eq!(normal_id.by_name("Front"), Some(5));
+        assert_eq!(normal_id.by_value(1), Some("Top"));
+        assert_eq!(normal_id.by_value(4), Some("Bottom"));
+
+        let part_type = database.get_enum("PartType").unwrap();
+        assert_eq!(part_type.by_name("Ball"), Some(0));
+        assert_eq!(part_type.by_name("Block"), Some(1));
+        assert_eq!(part_type.by_value(2), Some("Cylinder"));
+
+        assert_eq!(part_type.by_name("NotAnItem"), None);
+        assert_eq!(part_type.by_value(12345), None);
+        assert!(database.get_enum("NotAnEnum").is_none());
+    }
+
+    #[test]
+    fn superclasses() {
+        let database = get();
+        let part = database.classes.get("Part").unwrap();
+
+        let names: Vec<&str> = database
+            .superclasses(part)
+            .map(|class| class.name.as_ref())
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "Part",
+                "FormFactorPart",
+                "BasePart",
+                "PVInstance",
+                "Instance"
+            ]
+        );
+    }
+
+    #[test]
+    fn archivable() {
+        use rbx_types::Variant;
+
+        let database = get();
+
+        assert!(database.is_archivable("Folder", None));
+        assert!(database.is_archivable("Folder", Some(&Variant::Bool(true))));
+        assert!(!database.is_archivable("Folder", Some(&Variant::Bool(false))));
+        assert!(database.is_archivable("NotARealClass", None));
+    }
+
+    #[test]
+    fn deprecated_properties() {
+        let database = get();
+
+        let find_property = |class_name: &str, property_name: &str| {
+            let class = database.classes.get(class_name).unwrap();
+
+            database
+                .superclasses(class)
+                .find_map(|class| class.properties.get(property_name))
+                .unwrap_or_else(|| {
+                    panic!("{}.{} is not in the database", class_name, property_name)
+                })
+        };
+
+        assert!(find_property("Part", "FormFactor").is_deprecated());
+        assert!(!find_property("Part", "Size").is_deprecated());
+    }
 }
@@ -0,0 +1,335 @@
+//! Helpers shared between the tests of the crates in this repository.
+
+#![deny(missing_docs)]
+
+mod tracing_spans;
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use rbx_dom_weak::{
+    types::{Ref, Variant},
+    Instance, WeakDom,
+};
+
+pub use crate::tracing_spans::SpanRecorder;
+
+#[doc(hidden)]
+pub use rbx_binary;
+#[doc(hidden)]
+pub use rbx_dom_weak;
+#[doc(hidden)]
+pub use rbx_xml;
+
+/// Asserts that two DOMs have the same structure and properties, panicking
+/// with a description of the first difference if they don't.
+///
+/// Instances are matched up by their position in the tree, so their
+/// referents don't need to be the same. `Ref` properties are compared by which
+/// instance they point to, and NaN floats are equal to each other.
+#[macro_export]
+macro_rules! assert_dom_eq {
+    ($expected:expr, $actual:expr $(,)?) => {
+        if let Some(difference) = $crate::dom_diff(&$expected, &$actual) {
+            panic!("DOMs are not equal: {}", difference);
+        }
+    };
+}
+
+/// Decodes an XML model or place from a string, panicking if it's invalid.
+#[macro_export]
+macro_rules! dom_from_xml_str {
+    ($source:expr $(,)?) => {
+        $crate::rbx_xml::from_str_default($source).expect("failed to decode XML model")
+    };
+}
+
+/// Decodes a binary model or place from bytes, panicking if it's invalid.
+#[macro_export]
+macro_rules! dom_from_binary {
+    ($bytes:expr $(,)?) => {{
+        let bytes: &[u8] = $bytes.as_ref();
+        $crate::rbx_binary::from_reader(bytes).expect("failed to decode binary model")
+    }};
+}
+
+/// Creates an `InstanceBuilder` with the given class, name, properties, and
+/// optionally children.
+///
+/// ```
+/// use rbx_test_util::with_instance;
+///
+/// let folder = with_instance!("Folder", "Root", {}, [
+///     with_instance!("BoolValue", "Flag", { "Value" => true }),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! with_instance {
+    (
+        $class:expr, $name:expr,
+        { $( $property:expr => $value:expr ),* $(,)? }
+        $(, [ $( $child:expr ),* $(,)? ] )?
+        $(,)?
+    ) => {
+        $crate::rbx_dom_weak::InstanceBuilder::new($class)
+            .with_name($name)
+            $( .with_property($property, $value) )*
+            $( $( .with_child($child) )* )?
+    };
+}
+
+/// Compares two DOMs, returning a description of the first difference between
+/// them, or `None` if they're the same. This is what [`assert_dom_eq!`] uses.
+///
+/// Instances are described by their path from the root of the DOM, like
+/// `Workspace.Model`.
+pub fn dom_diff(expected: &WeakDom, actual: &WeakDom) -> Option<String> {
+    // Match up instances breadth-first so that differences near the top of the
+    // tree are reported first. Every pair is needed before properties can be
+    // compared, since Ref properties can point anywhere in the tree.
+    let mut pairs = Vec::new();
+    let mut ref_map = HashMap::new();
+    let mut to_visit = VecDeque::new();
+    to_visit.push_back((expected.root_ref(), actual.root_ref(), None::<String>));
+
+    while let Some((expected_ref, actual_ref, path)) = to_visit.pop_front() {
+        let expected_instance = expected.get_by_ref(expected_ref).unwrap();
+        let actual_instance = actual.get_by_ref(actual_ref).unwrap();
+        let path = path.unwrap_or_else(|| expected_instance.name.clone());
+
+        if expected_instance.class != actual_instance.class {
+            return Some(format!(
+                "Instance {}: ClassName expected {}, got {}",
+                path, expected_instance.class, actual_instance.class
+            ));
+        }
+
+        if expected_instance.name != actual_instance.name {
+            return Some(format!(
+                "Instance {}: Name expected {:?}, got {:?}",
+                path, expected_instance.name, actual_instance.name
+            ));
+        }
+
+        let expected_children = expected_instance.children();
+        let actual_children = actual_instance.children();
+
+        if expected_children.len() != actual_children.len() {
+            return Some(format!(
+                "Instance {}: child count expected {}, got {}",
+                path,
+                expected_children.len(),
+                actual_children.len()
+            ));
+        }
+
+        for (&expected_child, &actual_child) in expected_children.iter().zip(actual_children) {
+            let name = &expected.get_by_ref(expected_child).unwrap().name;
+            let child_path = if expected_ref == expected.root_ref() {
+                name.clone()
+            } else {
+                format!("{}.{}", path, name)
+            };
+
+            to_visit.push_back((expected_child, actual_child, Some(child_path)));
+        }
+
+        ref_map.insert(expected_ref, actual_ref);
+        pairs.push((expected_instance, actual_instance, path));
+    }
+
+    pairs
+        .into_iter()
+        .find_map(|(expected_instance, actual_instance, path)| {
+            property_diff(expected_instance, actual_instance, &ref_map)
+                .map(|difference| format!("Instance {}: {}", path, difference))
+        })
+}
+
+fn property_diff(
+    expected: &Instance,
+    actual: &Instance,
+    ref_map: &HashMap<Ref, Ref>,
+) -> Option<String> {
+    let names: BTreeSet<&str> = expected
+        .properties
+        .keys()
+        .chain(actual.properties.keys())
+        .map(|name| name.as_ref())
+        .collect();
+
+    for name in names {
+        let expected_value = expected.properties.get(name);
+        let actual_value = actual.properties.get(name);
+
+        let same = match (expected_value, actual_value) {
+            (Some(Variant::Ref(expected_ref)), Some(Variant::Ref(actual_ref))) => {
+                ref_map.get(expected_ref).unwrap_or(expected_ref) == actual_ref
+            }
+            // NaN isn't equal to itself, so values that compare unequal but
+            // print the same way are treated as the same.
+            (expected_value, actual_value) => {
+                expected_value == actual_value
+                    || format!("{:?}", expected_value) == format!("{:?}", actual_value)
+            }
+        };
+
+        if !same {
+            return Some(format!(
+                "{} expected {}, got {}",
+                name,
+                describe(expected_value),
+                describe(actual_value)
+            ));
+        }
+    }
+
+    None
+}
+
+fn describe(value: Option<&Variant>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "nothing".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_dom_weak::types::Vector3;
+
+    fn model(size: Vector3) -> WeakDom {
+        WeakDom::new(with_instance!(
+            "DataModel",
+            "Game",
+            {},
+            [with_instance!(
+                "Workspace",
+                "Workspace",
+                {},
+                [with_instance!("Model", "Model", { "Size" => size }),]
+            ),]
+        ))
+    }
+
+    #[test]
+    fn equal() {
+        let first = model(Vector3::new(4.0, 1.0, 2.0));
+        let second = model(Vector3::new(4.0, 1.0, 2.0));
+
+        assert_eq!(dom_diff(&first, &second), None);
+        assert_dom_eq!(first, second);
+    }
+
+    #[test]
+    fn property_difference() {
+        let expected = model(Vector3::new(4.0, 1.0, 2.0));
+        let actual = model(Vector3::new(4.0, 1.0, 4.0));
+
+        assert_eq!(
+            dom_diff(&expected, &actual).unwrap(),
+            format!(
+                "Instance Workspace.Model: Size expected {}, got {}",
+                Variant::Vector3(Vector3::new(4.0, 1.0, 2.0)),
+                Variant::Vector3(Vector3::new(4.0, 1.0, 4.0))
+            )
+        );
+    }
+
+    #[test]
+    fn nan_equal() {
+        let build =
+            || WeakDom::new(with_instance!("NumberValue", "Value", { "Value" => f64::NAN }));
+
+        assert_dom_eq!(build(), build());
+    }
+
+    #[test]
+    fn structure_difference() {
+        let expected = model(Vector3::new(4.0, 1.0, 2.0));
+        let mut actual = model(Vector3::new(4.0, 1.0, 2.0));
+        let workspace = actual.root().children()[0];
+        actual.insert(workspace, with_instance!("Part", "Extra", {}));
+
+        assert_eq!(
+            dom_diff(&expected, &actual).unwrap(),
+            "Instance Workspace: child count expected 1, got 2"
+        );
+    }
+
+    #[test]
+    fn refs_compared_by_target() {
+        let build = || {
+            let target = with_instance!("Part", "Target", {});
+            let target_ref = target.referent();
+
+            WeakDom::new(with_instance!(
+                "Folder",
+                "Root",
+                {},
+                [
+                    target,
+                    with_instance!("ObjectValue", "Pointer", { "Value" => target_ref }),
+                ]
+            ))
+        };
+
+        assert_dom_eq!(build(), build());
+    }
+
+    #[test]
+    #[should_panic(expected = "Instance Pointer: Value expected")]
+    fn refs_to_different_targets() {
+        let build = |index: usize| {
+            let targets = [
+                with_instance!("Part", "A", {}),
+                with_instance!("Part", "B", {}),
+            ];
+            let target_ref = targets[index].referent();
+            let [a, b] = targets;
+
+            WeakDom::new(with_instance!(
+                "Folder",
+                "Root",
+                {},
+                [
+                    a,
+                    b,
+                    with_instance!("ObjectValue", "Pointer", { "Value" => target_ref }),
+                ]
+            ))
+        };
+
+        assert_dom_eq!(build(0), build(1));
+    }
+
+    #[test]
+    fn decode_helpers() {
+        let dom = dom_from_xml_str!(
+            r#"<roblox version="4">
+                <Item class="BoolValue" referent="RBX1">
+                    <Properties>
+                        <string name="Name">Flag</string>
+                        <bool name="Value">true</bool>
+                    </Properties>
+                </Item>
+            </roblox>"#
+        );
+
+        let mut encoded = Vec::new();
+        rbx_binary::to_writer(&mut encoded, &dom, dom.root().children()).unwrap();
+        let decoded = dom_from_binary!(encoded);
+
+        assert_dom_eq!(dom, decoded);
+        assert_dom_eq!(
+            decoded,
+            WeakDom::new(with_instance!(
+                "DataModel",
+                "DataModel",
+                {},
+                [with_instance!("BoolValue", "Flag", { "Value" => true }),]
+            ))
+        );
+    }
+}
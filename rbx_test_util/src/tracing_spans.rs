@@ -0,0 +1,95 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+/// A span that was opened, along with the fields recorded on it.
+#[derive(Debug, PartialEq)]
+struct RecordedSpan {
+    name: &'static str,
+    fields: Vec<(String, String)>,
+}
+
+/// Records every `tracing` span that's created so that tests can check their
+/// fields.
+#[derive(Clone, Default)]
+pub struct SpanRecorder {
+    spans: Arc<Mutex<Vec<RecordedSpan>>>,
+}
+
+impl SpanRecorder {
+    /// Runs the given function, recording every span created on this thread
+    /// while it runs.
+    pub fn record<F: FnOnce()>(func: F) -> Self {
+        let recorder = Self::default();
+        let subscriber = tracing_subscriber::registry().with(recorder.clone());
+        tracing::subscriber::with_default(subscriber, func);
+
+        recorder
+    }
+
+    /// Tells whether a span with the given name was created with at least the
+    /// given fields. Field values are compared by their `Debug` representation,
+    /// except for strings, which are compared as-is.
+    pub fn has_span(&self, name: &str, fields: &[(&str, &str)]) -> bool {
+        self.spans.lock().unwrap().iter().any(|span| {
+            span.name == name
+                && fields.iter().all(|(key, value)| {
+                    span.fields
+                        .iter()
+                        .any(|(span_key, span_value)| span_key == key && span_value == value)
+                })
+        })
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name().to_owned(), value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_owned(), format!("{:?}", value)));
+    }
+}
+
+impl<S> Layer<S> for SpanRecorder
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, _id: &span::Id, _ctx: Context<'_, S>) {
+        let mut fields = Vec::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+
+        self.spans.lock().unwrap().push(RecordedSpan {
+            name: attrs.metadata().name(),
+            fields,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn records_fields() {
+        let recorder = SpanRecorder::record(|| {
+            let _span = tracing::info_span!("decode_chunk", chunk = "PROP", index = 3).entered();
+        });
+
+        assert!(recorder.has_span("decode_chunk", &[("chunk", "PROP")]));
+        assert!(recorder.has_span("decode_chunk", &[("chunk", "PROP"), ("index", "3")]));
+        assert!(!recorder.has_span("decode_chunk", &[("chunk", "INST")]));
+        assert!(!recorder.has_span("encode_chunk", &[]));
+    }
+}
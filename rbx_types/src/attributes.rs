@@ -0,0 +1,578 @@
+use std::{
+    collections::{btree_map, BTreeMap},
+    convert::TryFrom,
+    fmt,
+    io::{self, Read, Write},
+    iter::FromIterator,
+};
+
+use crate::{
+    BrickColor, Color3, ColorSequence, ColorSequenceKeypoint, NumberRange, NumberSequence,
+    NumberSequenceKeypoint, Rect, UDim, UDim2, Variant, VariantType, Vector2, Vector3,
+};
+
+/// The attributes on an instance, keyed by name.
+///
+/// Roblox stores attributes as a single binary blob in the
+/// `AttributesSerialize` property. `Attributes::decode` and
+/// `Attributes::encode` convert to and from that form. Attributes are kept
+/// sorted by name, so encoding is deterministic.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Attributes {
+    data: BTreeMap<String, Variant>,
+}
+
+impl Attributes {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            data: BTreeMap::new(),
+        }
+    }
+
+    /// Reads attributes from the binary form Roblox uses. Empty input
+    /// produces no attributes.
+    pub fn decode(mut buffer: &[u8]) -> Result<Self, AttributeError> {
+        let mut attributes = Self::new();
+
+        if buffer.is_empty() {
+            return Ok(attributes);
+        }
+
+        let count = read_u32(&mut buffer)?;
+
+        for _ in 0..count {
+            let name = read_string(&mut buffer)?;
+            let value = read_value(&mut buffer, &name)?;
+            attributes.data.insert(name, value);
+        }
+
+        Ok(attributes)
+    }
+
+    /// Writes attributes in the binary form Roblox uses. Fails if any
+    /// attribute holds a type that attributes can't contain.
+    pub fn encode(&self) -> Result<Vec<u8>, AttributeError> {
+        let mut output = Vec::new();
+
+        // Writing to a Vec can't fail, so the only errors here come from the
+        // values themselves.
+        write_u32(&mut output, self.data.len() as u32).unwrap();
+
+        for (name, value) in &self.data {
+            write_string(&mut output, name).unwrap();
+            write_value(&mut output, name, value)?;
+        }
+
+        Ok(output)
+    }
+
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&Variant> {
+        self.data.get(name)
+    }
+
+    /// Sets an attribute, returning its old value if it had one.
+    #[inline]
+    pub fn insert(&mut self, name: String, value: Variant) -> Option<Variant> {
+        self.data.insert(name, value)
+    }
+
+    /// Removes an attribute, returning its value if it had one.
+    #[inline]
+    pub fn remove(&mut self, name: &str) -> Option<Variant> {
+        self.data.remove(name)
+    }
+
+    /// Returns an iterator over every attribute, sorted by name.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Variant)> {
+        self.data.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl IntoIterator for Attributes {
+    type Item = (String, Variant);
+    type IntoIter = btree_map::IntoIter<String, Variant>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<K: Into<String>, V: Into<Variant>> FromIterator<(K, V)> for Attributes {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            data: iter
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+/// The error returned when attributes can't be decoded or encoded.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AttributeError {
+    /// The data ended early or couldn't be read.
+    Io(io::Error),
+
+    /// An attribute name or string value wasn't valid UTF-8.
+    InvalidUtf8,
+
+    /// An attribute had a type ID that isn't known.
+    UnknownType { name: String, type_id: u8 },
+
+    /// An attribute holds a type that attributes can't contain.
+    UnsupportedType { name: String, ty: VariantType },
+}
+
+impl From<io::Error> for AttributeError {
+    fn from(error: io::Error) -> Self {
+        AttributeError::Io(error)
+    }
+}
+
+impl fmt::Display for AttributeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AttributeError::Io(error) => write!(formatter, "{}", error),
+            AttributeError::InvalidUtf8 => {
+                write!(formatter, "attribute contained a string that isn't UTF-8")
+            }
+            AttributeError::UnknownType { name, type_id } => write!(
+                formatter,
+                "attribute {} has unknown type ID {:#04x}",
+                name, type_id
+            ),
+            AttributeError::UnsupportedType { name, ty } => write!(
+                formatter,
+                "attribute {} has type {:?}, which attributes can't contain",
+                name, ty
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AttributeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AttributeError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Type IDs used by the attribute format. These are different from the type IDs
+// used by the binary model format.
+const STRING: u8 = 0x02;
+const BOOL: u8 = 0x03;
+const FLOAT32: u8 = 0x05;
+const FLOAT64: u8 = 0x06;
+const UDIM: u8 = 0x09;
+const UDIM2: u8 = 0x0A;
+const BRICK_COLOR: u8 = 0x0E;
+const COLOR3: u8 = 0x0F;
+const VECTOR2: u8 = 0x10;
+const VECTOR3: u8 = 0x11;
+const NUMBER_SEQUENCE: u8 = 0x17;
+const COLOR_SEQUENCE: u8 = 0x19;
+const NUMBER_RANGE: u8 = 0x1B;
+const RECT: u8 = 0x1C;
+
+fn read_value<R: Read>(reader: &mut R, name: &str) -> Result<Variant, AttributeError> {
+    let type_id = read_u8(reader)?;
+
+    Ok(match type_id {
+        STRING => Variant::String(read_string(reader)?),
+        BOOL => Variant::Bool(read_u8(reader)? != 0),
+        FLOAT32 => Variant::Float32(read_f32(reader)?),
+        FLOAT64 => Variant::Float64(read_f64(reader)?),
+        UDIM => Variant::UDim(read_udim(reader)?),
+        UDIM2 => Variant::UDim2(UDim2::new(read_udim(reader)?, read_udim(reader)?)),
+        BRICK_COLOR => {
+            let number = read_u32(reader)?;
+
+            // Roblox falls back to its default color for unknown numbers.
+            let color = u16::try_from(number)
+                .ok()
+                .and_then(BrickColor::from_number)
+                .unwrap_or(BrickColor::MediumStoneGrey);
+
+            Variant::BrickColor(color)
+        }
+        COLOR3 => Variant::Color3(read_color3(reader)?),
+        VECTOR2 => Variant::Vector2(read_vector2(reader)?),
+        VECTOR3 => Variant::Vector3(Vector3::new(
+            read_f32(reader)?,
+            read_f32(reader)?,
+            read_f32(reader)?,
+        )),
+        NUMBER_SEQUENCE => {
+            let count = read_u32(reader)?;
+            let mut keypoints = Vec::new();
+
+            for _ in 0..count {
+                let envelope = read_f32(reader)?;
+                let time = read_f32(reader)?;
+                let value = read_f32(reader)?;
+                keypoints.push(NumberSequenceKeypoint::new(time, value, envelope));
+            }
+
+            Variant::NumberSequence(NumberSequence { keypoints })
+        }
+        COLOR_SEQUENCE => {
+            let count = read_u32(reader)?;
+            let mut keypoints = Vec::new();
+
+            for _ in 0..count {
+                let envelope = read_f32(reader)?;
+                let time = read_f32(reader)?;
+                let color = read_color3(reader)?;
+                keypoints.push(ColorSequenceKeypoint {
+                    time,
+                    color,
+                    envelope,
+                });
+            }
+
+            Variant::ColorSequence(ColorSequence { keypoints })
+        }
+        NUMBER_RANGE => {
+            Variant::NumberRange(NumberRange::new(read_f32(reader)?, read_f32(reader)?))
+        }
+        RECT => Variant::Rect(Rect::new(read_vector2(reader)?, read_vector2(reader)?)),
+        _ => {
+            return Err(AttributeError::UnknownType {
+                name: name.to_owned(),
+                type_id,
+            })
+        }
+    })
+}
+
+fn write_value<W: Write>(
+    writer: &mut W,
+    name: &str,
+    value: &Variant,
+) -> Result<(), AttributeError> {
+    match value {
+        Variant::String(value) => {
+            writer.write_all(&[STRING])?;
+            write_string(writer, value)?;
+        }
+        Variant::Bool(value) => writer.write_all(&[BOOL, *value as u8])?,
+        Variant::Float32(value) => {
+            writer.write_all(&[FLOAT32])?;
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        Variant::Float64(value) => {
+            writer.write_all(&[FLOAT64])?;
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        Variant::UDim(value) => {
+            writer.write_all(&[UDIM])?;
+            write_udim(writer, value)?;
+        }
+        Variant::UDim2(value) => {
+            writer.write_all(&[UDIM2])?;
+            write_udim(writer, &value.x)?;
+            write_udim(writer, &value.y)?;
+        }
+        Variant::BrickColor(value) => {
+            writer.write_all(&[BRICK_COLOR])?;
+            write_u32(writer, *value as u32)?;
+        }
+        Variant::Color3(value) => {
+            writer.write_all(&[COLOR3])?;
+            write_f32s(writer, &[value.r, value.g, value.b])?;
+        }
+        Variant::Vector2(value) => {
+            writer.write_all(&[VECTOR2])?;
+            write_f32s(writer, &[value.x, value.y])?;
+        }
+        Variant::Vector3(value) => {
+            writer.write_all(&[VECTOR3])?;
+            write_f32s(writer, &[value.x, value.y, value.z])?;
+        }
+        Variant::NumberSequence(value) => {
+            writer.write_all(&[NUMBER_SEQUENCE])?;
+            write_u32(writer, value.keypoints.len() as u32)?;
+
+            for keypoint in &value.keypoints {
+                write_f32s(writer, &[keypoint.envelope, keypoint.time, keypoint.value])?;
+            }
+        }
+        Variant::ColorSequence(value) => {
+            writer.write_all(&[COLOR_SEQUENCE])?;
+            write_u32(writer, value.keypoints.len() as u32)?;
+
+            for keypoint in &value.keypoints {
+                let color = keypoint.color;
+                write_f32s(
+                    writer,
+                    &[keypoint.envelope, keypoint.time, color.r, color.g, color.b],
+                )?;
+            }
+        }
+        Variant::NumberRange(value) => {
+            writer.write_all(&[NUMBER_RANGE])?;
+            write_f32s(writer, &[value.min, value.max])?;
+        }
+        Variant::Rect(value) => {
+            writer.write_all(&[RECT])?;
+            write_f32s(
+                writer,
+                &[value.min.x, value.min.y, value.max.x, value.max.y],
+            )?;
+        }
+        _ => {
+            return Err(AttributeError::UnsupportedType {
+                name: name.to_owned(),
+                ty: value.ty(),
+            })
+        }
+    }
+
+    Ok(())
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut buffer = [0; 1];
+    reader.read_exact(&mut buffer)?;
+    Ok(buffer[0])
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buffer = [0; 4];
+    reader.read_exact(&mut buffer)?;
+    Ok(u32::from_le_bytes(buffer))
+}
+
+fn read_f32<R: Read>(reader: &mut R) -> io::Result<f32> {
+    let mut buffer = [0; 4];
+    reader.read_exact(&mut buffer)?;
+    Ok(f32::from_le_bytes(buffer))
+}
+
+fn read_f64<R: Read>(reader: &mut R) -> io::Result<f64> {
+    let mut buffer = [0; 8];
+    reader.read_exact(&mut buffer)?;
+    Ok(f64::from_le_bytes(buffer))
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<String, AttributeError> {
+    let len = read_u32(reader)?;
+    let mut buffer = Vec::new();
+    reader.take(len as u64).read_to_end(&mut buffer)?;
+
+    if buffer.len() != len as usize {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+
+    String::from_utf8(buffer).map_err(|_| AttributeError::InvalidUtf8)
+}
+
+fn read_udim<R: Read>(reader: &mut R) -> io::Result<UDim> {
+    let scale = read_f32(reader)?;
+
+    let mut buffer = [0; 4];
+    reader.read_exact(&mut buffer)?;
+    let offset = i32::from_le_bytes(buffer);
+
+    Ok(UDim::new(scale, offset))
+}
+
+fn read_color3<R: Read>(reader: &mut R) -> io::Result<Color3> {
+    Ok(Color3::new(
+        read_f32(reader)?,
+        read_f32(reader)?,
+        read_f32(reader)?,
+    ))
+}
+
+fn read_vector2<R: Read>(reader: &mut R) -> io::Result<Vector2> {
+    Ok(Vector2::new(read_f32(reader)?, read_f32(reader)?))
+}
+
+fn write_u32<W: Write>(writer: &mut W, value: u32) -> io::Result<()> {
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_f32s<W: Write>(writer: &mut W, values: &[f32]) -> io::Result<()> {
+    for value in values {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+fn write_string<W: Write>(writer: &mut W, value: &str) -> io::Result<()> {
+    write_u32(writer, value.len() as u32)?;
+    writer.write_all(value.as_bytes())
+}
+
+fn write_udim<W: Write>(writer: &mut W, value: &UDim) -> io::Result<()> {
+    writer.write_all(&value.scale.to_le_bytes())?;
+    writer.write_all(&value.offset.to_le_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::BinaryString;
+
+    fn every_type() -> Attributes {
+        vec![
+            ("String", Variant::String("Hello, world!".to_owned())),
+            ("Bool", Variant::Bool(true)),
+            ("Float32", Variant::Float32(1.5)),
+            ("Float64", Variant::Float64(-100.25)),
+            ("UDim", UDim::new(0.5, 10).into()),
+            (
+                "UDim2",
+                UDim2::new(UDim::new(1.0, 2), UDim::new(3.0, 4)).into(),
+            ),
+            ("BrickColor", BrickColor::ReallyRed.into()),
+            ("Color3", Color3::new(0.0, 0.4, 1.0).into()),
+            ("Vector2", Vector2::new(10.0, 20.0).into()),
+            ("Vector3", Vector3::new(10.0, 20.0, 30.0).into()),
+            (
+                "NumberSequence",
+                NumberSequence {
+                    keypoints: vec![
+                        NumberSequenceKeypoint::new(0.0, 0.0, 0.0),
+                        NumberSequenceKeypoint::new(1.0, 1.0, 0.5),
+                    ],
+                }
+                .into(),
+            ),
+            (
+                "ColorSequence",
+                ColorSequence {
+                    keypoints: vec![
+                        ColorSequenceKeypoint::new(0.0, Color3::new(1.0, 0.0, 0.0)),
+                        ColorSequenceKeypoint::new(1.0, Color3::new(0.0, 0.0, 1.0)),
+                    ],
+                }
+                .into(),
+            ),
+            ("NumberRange", NumberRange::new(10.0, 20.0).into()),
+            (
+                "Rect",
+                Rect::new(Vector2::new(10.0, 20.0), Vector2::new(30.0, 40.0)).into(),
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        let attributes = every_type();
+        let encoded = attributes.encode().unwrap();
+
+        assert_eq!(Attributes::decode(&encoded).unwrap(), attributes);
+    }
+
+    #[test]
+    fn decode() {
+        assert!(Attributes::decode(b"").unwrap().is_empty());
+
+        // One attribute named "Health" holding the Float64 100.
+        let mut encoded = vec![1, 0, 0, 0, 6, 0, 0, 0];
+        encoded.extend_from_slice(b"Health");
+        encoded.push(FLOAT64);
+        encoded.extend_from_slice(&100.0f64.to_le_bytes());
+
+        let attributes = Attributes::decode(&encoded).unwrap();
+        assert_eq!(attributes.len(), 1);
+        assert_eq!(attributes.get("Health"), Some(&Variant::Float64(100.0)));
+        assert_eq!(attributes.encode().unwrap(), encoded);
+    }
+
+    #[test]
+    fn decode_invalid() {
+        let attributes: Attributes = vec![("Name", Variant::Float64(1.0))].into_iter().collect();
+        let encoded = attributes.encode().unwrap();
+
+        assert!(matches!(
+            Attributes::decode(&encoded[..encoded.len() - 1]),
+            Err(AttributeError::Io(_))
+        ));
+
+        let mut unknown = encoded.clone();
+        let type_offset = 4 + 4 + "Name".len();
+        unknown[type_offset] = 0xFF;
+        assert!(matches!(
+            Attributes::decode(&unknown),
+            Err(AttributeError::UnknownType { type_id: 0xFF, .. })
+        ));
+    }
+
+    #[test]
+    fn encode_unsupported() {
+        let attributes: Attributes = vec![("Blob", BinaryString::new())].into_iter().collect();
+
+        assert!(matches!(
+            attributes.encode(),
+            Err(AttributeError::UnsupportedType {
+                ty: VariantType::BinaryString,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut attributes = Attributes::new();
+        assert_eq!(attributes.insert("b".to_owned(), 2.0f64.into()), None);
+        assert_eq!(attributes.insert("a".to_owned(), 1.0f64.into()), None);
+        assert_eq!(
+            attributes.insert("b".to_owned(), 3.0f64.into()),
+            Some(Variant::Float64(2.0))
+        );
+
+        let names: Vec<&str> = attributes.iter().map(|(name, _)| name).collect();
+        assert_eq!(names, ["a", "b"]);
+
+        assert_eq!(attributes.remove("a"), Some(Variant::Float64(1.0)));
+        assert_eq!(attributes.remove("a"), None);
+        assert_eq!(attributes.len(), 1);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_test {
+    use super::*;
+
+    #[test]
+    fn human() {
+        let attributes: Attributes = vec![("Health", Variant::Float64(100.0))]
+            .into_iter()
+            .collect();
+
+        let ser = serde_json::to_string(&attributes).unwrap();
+        assert_eq!(ser, r#"{"Health":{"Float64":100.0}}"#);
+
+        let de: Attributes = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de, attributes);
+    }
+}
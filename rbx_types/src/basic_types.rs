@@ -5,7 +5,7 @@
 ///
 /// A list of all enums and their values are available [on the Roblox Developer
 /// Hub](https://developer.roblox.com/en-us/api-reference/enum).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -50,7 +50,7 @@ impl Vector2 {
 /// * [Vector2int16 on Roblox Developer Hub](https://developer.roblox.com/en-us/api-reference/datatype/Vector2int16)
 ///
 /// [Vector2]: struct.Vector2.html
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vector2int16 {
     pub x: i16,
     pub y: i16,
@@ -128,7 +128,7 @@ impl Vector3 {
 /// * [Vector3int16 on Roblox Developer Hub](https://developer.roblox.com/en-us/api-reference/datatype/Vector3int16)
 ///
 /// [Vector3]: struct.Vector3.html
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vector3int16 {
     pub x: i16,
     pub y: i16,
@@ -225,7 +225,7 @@ impl Color3 {
 ///   colors.
 ///
 /// [BasePart.Color]: https://developer.roblox.com/en-us/api-reference/property/BasePart/Color
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Color3uint8 {
     pub r: u8,
     pub g: u8,
@@ -238,6 +238,16 @@ impl Color3uint8 {
     }
 }
 
+impl From<Color3uint8> for Color3 {
+    fn from(value: Color3uint8) -> Self {
+        Self {
+            r: value.r as f32 / 255.0,
+            g: value.g as f32 / 255.0,
+            b: value.b as f32 / 255.0,
+        }
+    }
+}
+
 /// Represents a ray in 3D space. Direction does not have to be a unit vector,
 /// and is used by APIs like [`Workspace:FindPartOnRay`][FindPartOnRay] to set a
 /// max distance.
@@ -288,7 +298,7 @@ impl Region3 {
 /// * [Region3int16 on Roblox Developer Hub](https://developer.roblox.com/en-us/api-reference/datatype/Region3int16)
 ///
 /// [Region3]: struct.Region3.html
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Region3int16 {
     pub min: Vector3int16,
     pub max: Vector3int16,
@@ -395,11 +405,21 @@ pub struct ColorSequence {
 pub struct ColorSequenceKeypoint {
     pub time: f32,
     pub color: Color3,
+
+    /// Roblox always sets this to zero and doesn't expose it to developers,
+    /// but it's present in both file formats, so we keep it around in case a
+    /// file has something else.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub envelope: f32,
 }
 
 impl ColorSequenceKeypoint {
     pub fn new(time: f32, color: Color3) -> Self {
-        Self { time, color }
+        Self {
+            time,
+            color,
+            envelope: 0.0,
+        }
     }
 }
 
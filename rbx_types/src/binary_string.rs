@@ -85,8 +85,8 @@ mod serde_impl {
     impl<'de> Deserialize<'de> for BinaryString {
         fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
             if deserializer.is_human_readable() {
-                let encoded = <&str>::deserialize(deserializer)?;
-                let buffer = base64::decode(encoded).map_err(D::Error::custom)?;
+                let encoded = String::deserialize(deserializer)?;
+                let buffer = base64::decode(&encoded).map_err(D::Error::custom)?;
 
                 Ok(BinaryString { buffer })
             } else {
@@ -110,6 +110,11 @@ mod serde_test {
 
         let de: BinaryString = serde_json::from_str(&ser).unwrap();
         assert_eq!(de, data);
+
+        // Owned input, like a serde_json::Value, can't be borrowed from.
+        let value = serde_json::to_value(&data).unwrap();
+        let de: BinaryString = serde_json::from_value(value).unwrap();
+        assert_eq!(de, data);
     }
 
     #[test]
@@ -0,0 +1,490 @@
+//! Implements `Display` for `Variant`, formatting values the way they would be
+//! written as Lua source in Roblox.
+
+use std::fmt;
+
+use crate::{
+    Axes, CFrame, Color3, Faces, PhysicalProperties, UDim, Variant, Vector2, Vector3, Vector3int16,
+};
+
+/// Wraps a floating point number so that it's written as a valid Lua
+/// expression, even if it's NaN or infinite.
+struct Number<T>(T);
+
+macro_rules! number_display {
+    ($($ty:ty),*) => {
+        $(
+            impl fmt::Display for Number<$ty> {
+                fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+                    let value = self.0;
+
+                    if value.is_nan() {
+                        write!(out, "0/0")
+                    } else if value.is_infinite() {
+                        if value.is_sign_positive() {
+                            write!(out, "math.huge")
+                        } else {
+                            write!(out, "-math.huge")
+                        }
+                    } else {
+                        write!(out, "{}", value)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+number_display!(f32, f64);
+
+/// Wraps a sequence of bytes so that it's written as a quoted Lua string
+/// literal with special characters escaped.
+struct LuaString<'a>(&'a [u8]);
+
+impl fmt::Display for LuaString<'_> {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        write!(out, "\"")?;
+
+        for &byte in self.0 {
+            match byte {
+                b'"' => write!(out, "\\\"")?,
+                b'\\' => write!(out, "\\\\")?,
+                b'\n' => write!(out, "\\n")?,
+                b'\r' => write!(out, "\\r")?,
+                b'\t' => write!(out, "\\t")?,
+                0x20..=0x7e => write!(out, "{}", byte as char)?,
+                // Decimal escapes are padded so that a following digit isn't
+                // read as part of the escape.
+                _ => write!(out, "\\{:03}", byte)?,
+            }
+        }
+
+        write!(out, "\"")
+    }
+}
+
+fn write_vector2(out: &mut fmt::Formatter, value: &Vector2) -> fmt::Result {
+    write!(out, "Vector2.new({}, {})", Number(value.x), Number(value.y))
+}
+
+fn write_vector3(out: &mut fmt::Formatter, value: &Vector3) -> fmt::Result {
+    write!(
+        out,
+        "Vector3.new({}, {}, {})",
+        Number(value.x),
+        Number(value.y),
+        Number(value.z)
+    )
+}
+
+fn write_vector3int16(out: &mut fmt::Formatter, value: &Vector3int16) -> fmt::Result {
+    write!(
+        out,
+        "Vector3int16.new({}, {}, {})",
+        value.x, value.y, value.z
+    )
+}
+
+fn write_color3(out: &mut fmt::Formatter, value: &Color3) -> fmt::Result {
+    write!(
+        out,
+        "Color3.new({}, {}, {})",
+        Number(value.r),
+        Number(value.g),
+        Number(value.b)
+    )
+}
+
+fn write_udim(out: &mut fmt::Formatter, value: &UDim) -> fmt::Result {
+    write!(out, "UDim.new({}, {})", Number(value.scale), value.offset)
+}
+
+fn write_cframe(out: &mut fmt::Formatter, value: &CFrame) -> fmt::Result {
+    let position = value.position;
+    let orientation = value.orientation;
+
+    write!(out, "CFrame.new(")?;
+    write!(
+        out,
+        "{}, {}, {}",
+        Number(position.x),
+        Number(position.y),
+        Number(position.z)
+    )?;
+
+    for row in &[orientation.x, orientation.y, orientation.z] {
+        write!(
+            out,
+            ", {}, {}, {}",
+            Number(row.x),
+            Number(row.y),
+            Number(row.z)
+        )?;
+    }
+
+    write!(out, ")")
+}
+
+/// Writes a Lua constructor call like `Axes.new(Enum.Axis.X)` for each item
+/// name that's present.
+fn write_enum_list(
+    out: &mut fmt::Formatter,
+    constructor: &str,
+    enum_name: &str,
+    items: &[(&str, bool)],
+) -> fmt::Result {
+    write!(out, "{}.new(", constructor)?;
+
+    let mut first = true;
+    for (name, present) in items {
+        if !present {
+            continue;
+        }
+
+        if !first {
+            write!(out, ", ")?;
+        }
+        first = false;
+
+        write!(out, "Enum.{}.{}", enum_name, name)?;
+    }
+
+    write!(out, ")")
+}
+
+fn write_axes(out: &mut fmt::Formatter, value: Axes) -> fmt::Result {
+    write_enum_list(
+        out,
+        "Axes",
+        "Axis",
+        &[
+            ("X", value.contains(Axes::X)),
+            ("Y", value.contains(Axes::Y)),
+            ("Z", value.contains(Axes::Z)),
+        ],
+    )
+}
+
+fn write_faces(out: &mut fmt::Formatter, value: Faces) -> fmt::Result {
+    write_enum_list(
+        out,
+        "Faces",
+        "NormalId",
+        &[
+            ("Right", value.contains(Faces::RIGHT)),
+            ("Top", value.contains(Faces::TOP)),
+            ("Back", value.contains(Faces::BACK)),
+            ("Left", value.contains(Faces::LEFT)),
+            ("Bottom", value.contains(Faces::BOTTOM)),
+            ("Front", value.contains(Faces::FRONT)),
+        ],
+    )
+}
+
+/// Formats a `Variant` as the Lua expression that would construct the same
+/// value from within Roblox, like `Vector3.new(1, 2, 3)`.
+///
+/// Enums are written as their numeric value, since `Variant` doesn't know
+/// which enum they belong to. `Ref` has no Lua equivalent and is written as
+/// `nil` if it's empty or its hex representation otherwise.
+impl fmt::Display for Variant {
+    fn fmt(&self, out: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Variant::Axes(value) => write_axes(out, *value),
+            Variant::BinaryString(value) => write!(out, "{}", LuaString(value.as_ref())),
+            Variant::Bool(value) => write!(out, "{}", value),
+            Variant::BrickColor(value) => {
+                write!(
+                    out,
+                    "BrickColor.new({})",
+                    LuaString(value.to_string().as_bytes())
+                )
+            }
+            Variant::CFrame(value) => write_cframe(out, value),
+            Variant::Color3(value) => write_color3(out, value),
+            Variant::Color3uint8(value) => {
+                write!(out, "Color3.fromRGB({}, {}, {})", value.r, value.g, value.b)
+            }
+            Variant::ColorSequence(value) => {
+                write!(out, "ColorSequence.new({{")?;
+
+                for (index, keypoint) in value.keypoints.iter().enumerate() {
+                    if index > 0 {
+                        write!(out, ", ")?;
+                    }
+
+                    write!(out, "ColorSequenceKeypoint.new({}, ", Number(keypoint.time))?;
+                    write_color3(out, &keypoint.color)?;
+                    write!(out, ")")?;
+                }
+
+                write!(out, "}})")
+            }
+            Variant::Content(value) => {
+                let content: &str = value.as_ref();
+                write!(out, "{}", LuaString(content.as_bytes()))
+            }
+            Variant::Enum(value) => write!(out, "{}", value.to_u32()),
+            Variant::Faces(value) => write_faces(out, *value),
+            Variant::Float32(value) => write!(out, "{}", Number(*value)),
+            Variant::Float64(value) => write!(out, "{}", Number(*value)),
+            Variant::Int32(value) => write!(out, "{}", value),
+            Variant::Int64(value) => write!(out, "{}", value),
+            Variant::NumberRange(value) => write!(
+                out,
+                "NumberRange.new({}, {})",
+                Number(value.min),
+                Number(value.max)
+            ),
+            Variant::NumberSequence(value) => {
+                write!(out, "NumberSequence.new({{")?;
+
+                for (index, keypoint) in value.keypoints.iter().enumerate() {
+                    if index > 0 {
+                        write!(out, ", ")?;
+                    }
+
+                    write!(
+                        out,
+                        "NumberSequenceKeypoint.new({}, {}, {})",
+                        Number(keypoint.time),
+                        Number(keypoint.value),
+                        Number(keypoint.envelope)
+                    )?;
+                }
+
+                write!(out, "}})")
+            }
+            Variant::PhysicalProperties(value) => match value {
+                PhysicalProperties::Default => write!(out, "nil"),
+                PhysicalProperties::Custom(custom) => write!(
+                    out,
+                    "PhysicalProperties.new({}, {}, {}, {}, {})",
+                    Number(custom.density),
+                    Number(custom.friction),
+                    Number(custom.elasticity),
+                    Number(custom.friction_weight),
+                    Number(custom.elasticity_weight)
+                ),
+            },
+            Variant::Ray(value) => {
+                write!(out, "Ray.new(")?;
+                write_vector3(out, &value.origin)?;
+                write!(out, ", ")?;
+                write_vector3(out, &value.direction)?;
+                write!(out, ")")
+            }
+            Variant::Rect(value) => write!(
+                out,
+                "Rect.new({}, {}, {}, {})",
+                Number(value.min.x),
+                Number(value.min.y),
+                Number(value.max.x),
+                Number(value.max.y)
+            ),
+            Variant::Ref(value) => {
+                if value.is_none() {
+                    write!(out, "nil")
+                } else {
+                    write!(out, "{}", value)
+                }
+            }
+            Variant::Region3(value) => {
+                write!(out, "Region3.new(")?;
+                write_vector3(out, &value.min)?;
+                write!(out, ", ")?;
+                write_vector3(out, &value.max)?;
+                write!(out, ")")
+            }
+            Variant::Region3int16(value) => {
+                write!(out, "Region3int16.new(")?;
+                write_vector3int16(out, &value.min)?;
+                write!(out, ", ")?;
+                write_vector3int16(out, &value.max)?;
+                write!(out, ")")
+            }
+            Variant::SharedString(value) => write!(out, "{}", LuaString(value.data())),
+            Variant::String(value) => write!(out, "{}", LuaString(value.as_bytes())),
+            Variant::UDim(value) => write_udim(out, value),
+            Variant::UDim2(value) => write!(
+                out,
+                "UDim2.new({}, {}, {}, {})",
+                Number(value.x.scale),
+                value.x.offset,
+                Number(value.y.scale),
+                value.y.offset
+            ),
+            Variant::Vector2(value) => write_vector2(out, value),
+            Variant::Vector2int16(value) => {
+                write!(out, "Vector2int16.new({}, {})", value.x, value.y)
+            }
+            Variant::Vector3(value) => write_vector3(out, value),
+            Variant::Vector3int16(value) => write_vector3int16(out, value),
+            Variant::OptionalCFrame(value) => match value {
+                Some(cframe) => write_cframe(out, cframe),
+                None => write!(out, "nil"),
+            },
+            Variant::ProtectedString(value) => {
+                let value: &str = value.as_ref();
+                write!(out, "{}", LuaString(value.as_bytes()))
+            }
+            Variant::Tags(value) => {
+                write!(out, "{{")?;
+
+                for (i, tag) in value.iter().enumerate() {
+                    if i > 0 {
+                        write!(out, ", ")?;
+                    }
+
+                    write!(out, "{}", LuaString(tag.as_bytes()))?;
+                }
+
+                write!(out, "}}")
+            }
+            Variant::Attributes(value) => {
+                write!(out, "{{")?;
+
+                for (i, (name, value)) in value.iter().enumerate() {
+                    if i > 0 {
+                        write!(out, ", ")?;
+                    }
+
+                    write!(out, "[{}] = {}", LuaString(name.as_bytes()), value)?;
+                }
+
+                write!(out, "}}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::{
+        Attributes, BinaryString, BrickColor, Color3uint8, ColorSequence, ColorSequenceKeypoint,
+        Content, CustomPhysicalProperties, Enum, Matrix3, NumberRange, NumberSequence,
+        NumberSequenceKeypoint, ProtectedString, Ray, Rect, Ref, Region3, Region3int16,
+        SharedString, Tags, UDim2, Vector2int16,
+    };
+
+    fn check<V: Into<Variant>>(value: V, expected: &str) {
+        assert_eq!(value.into().to_string(), expected);
+    }
+
+    #[test]
+    fn every_variant() {
+        let cframe = CFrame::new(Vector3::new(1.0, 2.0, 3.0), Matrix3::identity());
+
+        check(
+            Axes::from_bits(0b101).unwrap(),
+            "Axes.new(Enum.Axis.X, Enum.Axis.Z)",
+        );
+        check(BinaryString::from(&b"a\x00b"[..]), r#""a\000b""#);
+        check(true, "true");
+        check(BrickColor::ReallyRed, r#"BrickColor.new("Really red")"#);
+        check(cframe, "CFrame.new(1, 2, 3, 1, 0, 0, 0, 1, 0, 0, 0, 1)");
+        check(Color3::new(1.0, 0.5, 0.0), "Color3.new(1, 0.5, 0)");
+        check(Color3uint8::new(255, 128, 0), "Color3.fromRGB(255, 128, 0)");
+        check(
+            ColorSequence {
+                keypoints: vec![
+                    ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0)),
+                    ColorSequenceKeypoint::new(1.0, Color3::new(1.0, 1.0, 1.0)),
+                ],
+            },
+            "ColorSequence.new({ColorSequenceKeypoint.new(0, Color3.new(0, 0, 0)), \
+             ColorSequenceKeypoint.new(1, Color3.new(1, 1, 1))})",
+        );
+        check(Content::from("rbxassetid://1"), r#""rbxassetid://1""#);
+        check(ProtectedString::from("print(1)"), r#""print(1)""#);
+        check(Tags::new(), "{}");
+        check(Tags::from(&["a", "b"][..]), r#"{"a", "b"}"#);
+        check(Attributes::new(), "{}");
+        check(
+            vec![("Health", Variant::Float64(100.0)), ("Team", "Red".into())]
+                .into_iter()
+                .collect::<Attributes>(),
+            r#"{["Health"] = 100, ["Team"] = "Red"}"#,
+        );
+        check(Enum::from_u32(256), "256");
+        check(
+            Faces::from_bits(0b100010).unwrap(),
+            "Faces.new(Enum.NormalId.Top, Enum.NormalId.Front)",
+        );
+        check(1.5f32, "1.5");
+        check(-2.25f64, "-2.25");
+        check(42i32, "42");
+        check(-42i64, "-42");
+        check(NumberRange::new(1.0, 2.0), "NumberRange.new(1, 2)");
+        check(
+            NumberSequence {
+                keypoints: vec![
+                    NumberSequenceKeypoint::new(0.0, 1.0, 0.0),
+                    NumberSequenceKeypoint::new(1.0, 2.0, 0.5),
+                ],
+            },
+            "NumberSequence.new({NumberSequenceKeypoint.new(0, 1, 0), \
+             NumberSequenceKeypoint.new(1, 2, 0.5)})",
+        );
+        check(PhysicalProperties::Default, "nil");
+        check(
+            PhysicalProperties::Custom(CustomPhysicalProperties {
+                density: 0.5,
+                friction: 1.0,
+                elasticity: 1.5,
+                friction_weight: 2.0,
+                elasticity_weight: 2.5,
+            }),
+            "PhysicalProperties.new(0.5, 1, 1.5, 2, 2.5)",
+        );
+        check(
+            Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)),
+            "Ray.new(Vector3.new(0, 0, 0), Vector3.new(0, 1, 0))",
+        );
+        check(
+            Rect::new(Vector2::new(0.0, 1.0), Vector2::new(2.0, 3.0)),
+            "Rect.new(0, 1, 2, 3)",
+        );
+        check(Ref::none(), "nil");
+        check(
+            Region3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)),
+            "Region3.new(Vector3.new(0, 0, 0), Vector3.new(1, 1, 1))",
+        );
+        check(
+            Region3int16::new(Vector3int16::new(0, 0, 0), Vector3int16::new(1, 1, 1)),
+            "Region3int16.new(Vector3int16.new(0, 0, 0), Vector3int16.new(1, 1, 1))",
+        );
+        check(SharedString::new(b"shared".to_vec()), r#""shared""#);
+        check("Hello", r#""Hello""#);
+        check(UDim::new(0.5, 10), "UDim.new(0.5, 10)");
+        check(
+            UDim2::new(UDim::new(0.5, 10), UDim::new(1.0, -10)),
+            "UDim2.new(0.5, 10, 1, -10)",
+        );
+        check(Vector2::new(1.0, 2.0), "Vector2.new(1, 2)");
+        check(Vector2int16::new(1, -2), "Vector2int16.new(1, -2)");
+        check(Vector3::new(1.0, 2.0, 3.0), "Vector3.new(1, 2, 3)");
+        check(Vector3int16::new(1, 2, -3), "Vector3int16.new(1, 2, -3)");
+        check(
+            Some(cframe),
+            "CFrame.new(1, 2, 3, 1, 0, 0, 0, 1, 0, 0, 0, 1)",
+        );
+        check(None::<CFrame>, "nil");
+    }
+
+    #[test]
+    fn escaped_strings() {
+        check("say \"hi\"\n", r#""say \"hi\"\n""#);
+        check("back\\slash\ttab", r#""back\\slash\ttab""#);
+        check("\u{e9}", r#""\195\169""#);
+    }
+
+    #[test]
+    fn special_floats() {
+        check(f32::NAN, "0/0");
+        check(f32::INFINITY, "math.huge");
+        check(f64::NEG_INFINITY, "-math.huge");
+    }
+}
@@ -2,25 +2,35 @@
 #[macro_use]
 mod serde_util;
 
+mod attributes;
 mod axes;
 mod basic_types;
 mod binary_string;
 mod brick_color;
 mod content;
+mod display;
 mod faces;
 mod lister;
+mod parse;
 mod physical_properties;
+mod protected_string;
 mod referent;
 mod shared_string;
+mod structural;
+mod tags;
 mod variant;
 
+pub use attributes::*;
 pub use axes::*;
 pub use basic_types::*;
 pub use binary_string::*;
 pub use brick_color::*;
 pub use content::*;
 pub use faces::*;
+pub use parse::*;
 pub use physical_properties::*;
+pub use protected_string::*;
 pub use referent::*;
 pub use shared_string::*;
+pub use tags::*;
 pub use variant::*;
@@ -0,0 +1,273 @@
+//! Parses plain text property values, like those found in CSV or TOML files,
+//! into `Variant` values of a known type.
+
+use std::{fmt, str::FromStr};
+
+use crate::{Color3, Enum, UDim, UDim2, Variant, VariantType, Vector2, Vector3};
+
+/// Parses a string into a `Variant` of the given type.
+///
+/// Because a string like `"1"` could be many different types, the type to
+/// parse must be given explicitly. Components of compound values are
+/// separated by commas, like `"0, 1, 0"` for a `Vector3`. `UDim2` values may
+/// optionally group their components with braces, like `"{0.5, 10}, {1, -10}"`.
+///
+/// Only numbers, `Bool`, `String`, `Vector2`, `Vector3`, `Color3`, `UDim`,
+/// `UDim2`, and `Enum` can currently be parsed.
+pub fn parse_variant(source: &str, ty: VariantType) -> Result<Variant, ParseError> {
+    let invalid = || ParseError::InvalidValue {
+        ty,
+        input: source.to_owned(),
+    };
+
+    let value = match ty {
+        VariantType::String => Variant::String(source.to_owned()),
+        VariantType::Bool => match source.trim() {
+            "true" => Variant::Bool(true),
+            "false" => Variant::Bool(false),
+            _ => return Err(invalid()),
+        },
+        VariantType::Float32 => Variant::Float32(parse_number(source).ok_or_else(invalid)?),
+        VariantType::Float64 => Variant::Float64(parse_number(source).ok_or_else(invalid)?),
+        VariantType::Int32 => Variant::Int32(parse_number(source).ok_or_else(invalid)?),
+        VariantType::Int64 => Variant::Int64(parse_number(source).ok_or_else(invalid)?),
+        VariantType::Enum => {
+            Variant::Enum(Enum::from_u32(parse_number(source).ok_or_else(invalid)?))
+        }
+        VariantType::Vector2 => {
+            let [x, y] = parse_components(source, ty)?;
+            Variant::Vector2(Vector2::new(x, y))
+        }
+        VariantType::Vector3 => {
+            let [x, y, z] = parse_components(source, ty)?;
+            Variant::Vector3(Vector3::new(x, y, z))
+        }
+        VariantType::Color3 => {
+            let [r, g, b] = parse_components(source, ty)?;
+            Variant::Color3(Color3::new(r, g, b))
+        }
+        VariantType::UDim => {
+            let [scale, offset]: [&str; 2] = split_components(source, ty)?;
+            Variant::UDim(UDim::new(
+                parse_number(scale).ok_or_else(invalid)?,
+                parse_number(offset).ok_or_else(invalid)?,
+            ))
+        }
+        VariantType::UDim2 => {
+            let without_braces = source.replace(&['{', '}'][..], "");
+            let [x_scale, x_offset, y_scale, y_offset]: [&str; 4] =
+                split_components(&without_braces, ty)?;
+
+            Variant::UDim2(UDim2::new(
+                UDim::new(
+                    parse_number(x_scale).ok_or_else(invalid)?,
+                    parse_number(x_offset).ok_or_else(invalid)?,
+                ),
+                UDim::new(
+                    parse_number(y_scale).ok_or_else(invalid)?,
+                    parse_number(y_offset).ok_or_else(invalid)?,
+                ),
+            ))
+        }
+        _ => return Err(ParseError::UnsupportedType { ty }),
+    };
+
+    Ok(value)
+}
+
+fn parse_number<T: FromStr>(source: &str) -> Option<T> {
+    source.trim().parse().ok()
+}
+
+/// Splits a comma-separated string into exactly `N` components.
+fn split_components<const N: usize>(
+    source: &str,
+    ty: VariantType,
+) -> Result<[&str; N], ParseError> {
+    let mut components = [""; N];
+    let mut count = 0;
+
+    for component in source.split(',') {
+        if count < N {
+            components[count] = component;
+        }
+
+        count += 1;
+    }
+
+    if count == N {
+        Ok(components)
+    } else {
+        Err(ParseError::WrongComponentCount {
+            ty,
+            expected: N,
+            actual: count,
+        })
+    }
+}
+
+/// Splits a comma-separated string into exactly `N` floats.
+fn parse_components<const N: usize>(source: &str, ty: VariantType) -> Result<[f32; N], ParseError> {
+    let components: [&str; N] = split_components(source, ty)?;
+    let mut values = [0.0; N];
+
+    for (value, component) in values.iter_mut().zip(components.iter()) {
+        *value = parse_number(component).ok_or_else(|| ParseError::InvalidValue {
+            ty,
+            input: source.to_owned(),
+        })?;
+    }
+
+    Ok(values)
+}
+
+/// The error returned when `parse_variant` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Values of this type cannot be parsed from a string.
+    UnsupportedType { ty: VariantType },
+
+    /// The input was not a valid value of the requested type.
+    InvalidValue { ty: VariantType, input: String },
+
+    /// The input had the wrong number of comma-separated components for the
+    /// requested type.
+    WrongComponentCount {
+        ty: VariantType,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnsupportedType { ty } => {
+                write!(formatter, "values of type {:?} cannot be parsed", ty)
+            }
+            ParseError::InvalidValue { ty, input } => {
+                write!(formatter, "{:?} is not a valid {:?}", input, ty)
+            }
+            ParseError::WrongComponentCount {
+                ty,
+                expected,
+                actual,
+            } => write!(
+                formatter,
+                "expected {} components for a {:?}, but got {}",
+                expected, ty, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(source: &str, ty: VariantType) -> Variant {
+        parse_variant(source, ty).unwrap()
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse("1.25", VariantType::Float32), Variant::Float32(1.25));
+        assert_eq!(
+            parse(" -2.5 ", VariantType::Float64),
+            Variant::Float64(-2.5)
+        );
+        assert_eq!(parse("42", VariantType::Int32), Variant::Int32(42));
+        assert_eq!(
+            parse("-9000000000", VariantType::Int64),
+            Variant::Int64(-9_000_000_000)
+        );
+        assert_eq!(
+            parse("256", VariantType::Enum),
+            Variant::Enum(Enum::from_u32(256))
+        );
+    }
+
+    #[test]
+    fn bool_and_string() {
+        assert_eq!(parse("true", VariantType::Bool), Variant::Bool(true));
+        assert_eq!(parse("false", VariantType::Bool), Variant::Bool(false));
+        assert_eq!(
+            parse(" hello, world ", VariantType::String),
+            Variant::String(" hello, world ".to_owned())
+        );
+    }
+
+    #[test]
+    fn vectors_and_colors() {
+        assert_eq!(
+            parse("1, 2", VariantType::Vector2),
+            Variant::Vector2(Vector2::new(1.0, 2.0))
+        );
+        assert_eq!(
+            parse("0, 1, 0", VariantType::Vector3),
+            Variant::Vector3(Vector3::new(0.0, 1.0, 0.0))
+        );
+        assert_eq!(
+            parse("1,0.5,0", VariantType::Color3),
+            Variant::Color3(Color3::new(1.0, 0.5, 0.0))
+        );
+    }
+
+    #[test]
+    fn udims() {
+        assert_eq!(
+            parse("0.5, 10", VariantType::UDim),
+            Variant::UDim(UDim::new(0.5, 10))
+        );
+
+        let udim2 = Variant::UDim2(UDim2::new(UDim::new(0.5, 10), UDim::new(1.0, -10)));
+        assert_eq!(parse("0.5, 10, 1, -10", VariantType::UDim2), udim2);
+        assert_eq!(parse("{0.5, 10}, {1, -10}", VariantType::UDim2), udim2);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse_variant("yes", VariantType::Bool),
+            Err(ParseError::InvalidValue {
+                ty: VariantType::Bool,
+                input: "yes".to_owned(),
+            })
+        );
+
+        assert_eq!(
+            parse_variant("1.5", VariantType::Int32),
+            Err(ParseError::InvalidValue {
+                ty: VariantType::Int32,
+                input: "1.5".to_owned(),
+            })
+        );
+
+        assert_eq!(
+            parse_variant("1, 2", VariantType::Vector3),
+            Err(ParseError::WrongComponentCount {
+                ty: VariantType::Vector3,
+                expected: 3,
+                actual: 2,
+            })
+        );
+
+        assert_eq!(
+            parse_variant("1, x, 3", VariantType::Vector3),
+            Err(ParseError::InvalidValue {
+                ty: VariantType::Vector3,
+                input: "1, x, 3".to_owned(),
+            })
+        );
+
+        assert_eq!(
+            parse_variant("0, 0, 0", VariantType::CFrame),
+            Err(ParseError::UnsupportedType {
+                ty: VariantType::CFrame
+            })
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Structural equality and hashing used to implement `Eq` and `Hash` for
+//! `Variant`.
+//!
+//! Floating point values are compared by their bit patterns instead of
+//! numerically. This means that NaN is equal to itself and that positive and
+//! negative zero are different values, which is what we want when treating
+//! values as data instead of as numbers.
+
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    Attributes, Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence,
+    ColorSequenceKeypoint, Content, CustomPhysicalProperties, Enum, Faces, Matrix3, NumberRange,
+    NumberSequence, NumberSequenceKeypoint, PhysicalProperties, ProtectedString, Ray, Rect, Ref,
+    Region3, Region3int16, SharedString, Tags, UDim, UDim2, Vector2, Vector2int16, Vector3,
+    Vector3int16,
+};
+
+pub(crate) trait Structural {
+    fn structural_eq(&self, other: &Self) -> bool;
+    fn structural_hash<H: Hasher>(&self, state: &mut H);
+}
+
+/// Implements `Structural` for types whose `Eq` and `Hash` impls already have
+/// the semantics we want.
+macro_rules! structural_via_hash {
+    ($($ty:ty),*) => {
+        $(
+            impl Structural for $ty {
+                fn structural_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+
+                fn structural_hash<H: Hasher>(&self, state: &mut H) {
+                    Hash::hash(self, state);
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `Structural` for structs by visiting each of their fields.
+macro_rules! structural_via_fields {
+    ($($ty:ident { $($field:ident),* },)*) => {
+        $(
+            impl Structural for $ty {
+                fn structural_eq(&self, other: &Self) -> bool {
+                    $( self.$field.structural_eq(&other.$field) )&&*
+                }
+
+                fn structural_hash<H: Hasher>(&self, state: &mut H) {
+                    $( self.$field.structural_hash(state); )*
+                }
+            }
+        )*
+    };
+}
+
+structural_via_hash!(
+    bool,
+    i32,
+    i64,
+    String,
+    Axes,
+    BinaryString,
+    BrickColor,
+    Color3uint8,
+    Content,
+    Enum,
+    Faces,
+    ProtectedString,
+    Ref,
+    Region3int16,
+    SharedString,
+    Tags,
+    Attributes,
+    Vector2int16,
+    Vector3int16
+);
+
+impl Structural for f32 {
+    fn structural_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
+impl Structural for f64 {
+    fn structural_eq(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+}
+
+impl<T: Structural> Structural for Option<T> {
+    fn structural_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.structural_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Some(value) => {
+                true.hash(state);
+                value.structural_hash(state);
+            }
+            None => false.hash(state),
+        }
+    }
+}
+
+impl<T: Structural> Structural for Vec<T> {
+    fn structural_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.structural_eq(b))
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+
+        for value in self {
+            value.structural_hash(state);
+        }
+    }
+}
+
+impl Structural for PhysicalProperties {
+    fn structural_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PhysicalProperties::Default, PhysicalProperties::Default) => true,
+            (PhysicalProperties::Custom(a), PhysicalProperties::Custom(b)) => a.structural_eq(b),
+            _ => false,
+        }
+    }
+
+    fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            PhysicalProperties::Default => false.hash(state),
+            PhysicalProperties::Custom(custom) => {
+                true.hash(state);
+                custom.structural_hash(state);
+            }
+        }
+    }
+}
+
+structural_via_fields! {
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Matrix3 { x, y, z },
+    CFrame { position, orientation },
+    Color3 { r, g, b },
+    Ray { origin, direction },
+    Region3 { min, max },
+    Rect { min, max },
+    UDim { scale, offset },
+    UDim2 { x, y },
+    NumberRange { min, max },
+    ColorSequence { keypoints },
+    ColorSequenceKeypoint { time, color, envelope },
+    NumberSequence { keypoints },
+    NumberSequenceKeypoint { time, value, envelope },
+    CustomPhysicalProperties { density, friction, elasticity, friction_weight, elasticity_weight },
+}
@@ -0,0 +1,150 @@
+/// The list of tags on an instance, as used by `CollectionService`.
+///
+/// Roblox stores tags as a single binary string with each tag separated by a
+/// null byte. `Tags::decode` and `Tags::encode` convert to and from that form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct Tags {
+    members: Vec<String>,
+}
+
+impl Tags {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    /// Reads tags from the null-delimited form Roblox uses. Empty input
+    /// produces no tags, and invalid UTF-8 is replaced.
+    pub fn decode(buffer: &[u8]) -> Self {
+        if buffer.is_empty() {
+            return Self::new();
+        }
+
+        let members = buffer
+            .split(|&byte| byte == 0)
+            .map(|tag| String::from_utf8_lossy(tag).into_owned())
+            .collect();
+
+        Self { members }
+    }
+
+    /// Writes tags in the null-delimited form Roblox uses.
+    pub fn encode(&self) -> Vec<u8> {
+        self.members.join("\0").into_bytes()
+    }
+
+    /// Adds a tag to the end of the list if it isn't already present.
+    pub fn push(&mut self, tag: &str) {
+        if !self.contains(tag) {
+            self.members.push(tag.to_owned());
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, tag: &str) -> bool {
+        self.members.iter().any(|member| member == tag)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(String::as_str)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<String> {
+        self.members
+    }
+}
+
+impl From<Vec<String>> for Tags {
+    fn from(members: Vec<String>) -> Self {
+        Self { members }
+    }
+}
+
+impl From<&'_ [&'_ str]> for Tags {
+    fn from(members: &[&str]) -> Self {
+        Self {
+            members: members.iter().map(|&tag| tag.to_owned()).collect(),
+        }
+    }
+}
+
+impl From<Tags> for Vec<String> {
+    fn from(value: Tags) -> Self {
+        value.members
+    }
+}
+
+impl AsRef<[String]> for Tags {
+    fn as_ref(&self) -> &[String] {
+        &self.members
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        assert!(Tags::decode(b"").is_empty());
+        assert_eq!(Tags::decode(b"foo"), Tags::from(&["foo"][..]));
+        assert_eq!(
+            Tags::decode(b"foo\0bar\0baz"),
+            Tags::from(&["foo", "bar", "baz"][..])
+        );
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(Tags::new().encode(), b"");
+        assert_eq!(Tags::from(&["foo"][..]).encode(), b"foo");
+        assert_eq!(Tags::from(&["foo", "bar"][..]).encode(), b"foo\0bar");
+    }
+
+    #[test]
+    fn push() {
+        let mut tags = Tags::new();
+        tags.push("foo");
+        tags.push("bar");
+        tags.push("foo");
+
+        assert_eq!(tags.iter().collect::<Vec<_>>(), vec!["foo", "bar"]);
+        assert!(tags.contains("bar"));
+        assert!(!tags.contains("baz"));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_test {
+    use super::*;
+
+    #[test]
+    fn human() {
+        let tags = Tags::from(&["foo", "bar"][..]);
+
+        let ser = serde_json::to_string(&tags).unwrap();
+        assert_eq!(ser, r#"["foo","bar"]"#);
+
+        let de: Tags = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de, tags);
+    }
+}
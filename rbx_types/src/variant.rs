@@ -1,7 +1,15 @@
+use std::{
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
 use crate::{
-    Axes, BinaryString, BrickColor, CFrame, Color3, Color3uint8, ColorSequence, Content, Enum,
-    Faces, NumberRange, NumberSequence, PhysicalProperties, Ray, Rect, Ref, Region3, Region3int16,
-    SharedString, UDim, UDim2, Vector2, Vector2int16, Vector3, Vector3int16,
+    structural::Structural, Attributes, Axes, BinaryString, BrickColor, CFrame, Color3,
+    Color3uint8, ColorSequence, ColorSequenceKeypoint, Content, Enum, Faces, Matrix3, NumberRange,
+    NumberSequence, NumberSequenceKeypoint, PhysicalProperties, ProtectedString, Ray, Rect, Ref,
+    Region3, Region3int16, SharedString, Tags, UDim, UDim2, Vector2, Vector2int16, Vector3,
+    Vector3int16,
 };
 
 /// Reduces boilerplate from listing different values of Variant by wrapping
@@ -20,7 +28,14 @@ macro_rules! make_variant {
         ///
         /// New variants may be added to `Variant` in minor releases. As
         /// such, it is marked `#[non_exhaustive]`.
-        #[derive(Debug, Clone, PartialEq)]
+        ///
+        /// ## Equality and Hashing
+        ///
+        /// `Variant` compares values structurally: floating point values are
+        /// equal only if their bits are equal. This means that NaN is equal to
+        /// NaN, and that `0.0` and `-0.0` are not equal. This makes `Variant`
+        /// usable as a key in a `HashMap` or `HashSet`.
+        #[derive(Debug, Clone)]
         #[non_exhaustive]
         #[cfg_attr(
             feature = "serde",
@@ -45,16 +60,71 @@ macro_rules! make_variant {
             }
         }
 
+        impl PartialEq for Variant {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    $(
+                        (Variant::$variant_name(a), Variant::$variant_name(b)) => {
+                            a.structural_eq(b)
+                        }
+                    )*
+                    _ => false,
+                }
+            }
+        }
+
+        impl Eq for Variant {}
+
+        impl Hash for Variant {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.ty().hash(state);
+
+                match self {
+                    $(
+                        Variant::$variant_name(value) => value.structural_hash(state),
+                    )*
+                }
+            }
+        }
+
         $(
             impl From<$inner_type> for Variant {
                 fn from(value: $inner_type) -> Self {
                     Self::$variant_name(value)
                 }
             }
+
+            impl TryFrom<Variant> for $inner_type {
+                type Error = VariantTypeMismatch;
+
+                fn try_from(value: Variant) -> Result<Self, Self::Error> {
+                    match value {
+                        Variant::$variant_name(inner) => Ok(inner),
+                        other => Err(VariantTypeMismatch {
+                            expected: VariantType::$variant_name,
+                            actual: other.ty(),
+                        }),
+                    }
+                }
+            }
+
+            impl TryFrom<&Variant> for $inner_type {
+                type Error = VariantTypeMismatch;
+
+                fn try_from(value: &Variant) -> Result<Self, Self::Error> {
+                    match value {
+                        Variant::$variant_name(inner) => Ok(inner.clone()),
+                        other => Err(VariantTypeMismatch {
+                            expected: VariantType::$variant_name,
+                            actual: other.ty(),
+                        }),
+                    }
+                }
+            }
         )*
 
         /// Represents any type that can be held in a `Variant`.
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         #[non_exhaustive]
         #[cfg_attr(
             feature = "serde",
@@ -77,7 +147,11 @@ macro_rules! make_variant {
             /// this test will start failing.
             #[allow(dead_code)]
             fn conversions_are_exhaustive() {
-                fn trait_test<T: Into<Variant>>() {}
+                fn trait_test<T>()
+                where
+                    T: Into<Variant> + TryFrom<Variant> + for<'a> TryFrom<&'a Variant>,
+                {
+                }
 
                 $( trait_test::<$inner_type>(); )*
                 trait_test::<SharedString>();
@@ -126,6 +200,9 @@ make_variant! {
     Vector3(Vector3),
     Vector3int16(Vector3int16),
     OptionalCFrame(Option<CFrame>),
+    ProtectedString(ProtectedString),
+    Tags(Tags),
+    Attributes(Attributes),
 }
 
 impl From<&'_ str> for Variant {
@@ -134,6 +211,541 @@ impl From<&'_ str> for Variant {
     }
 }
 
+/// Generates methods on `Variant` that borrow or copy out the inner value if
+/// the `Variant` holds the expected type.
+macro_rules! variant_accessors {
+    (
+        $(
+            $( #[$attr:meta] )*
+            $method:ident -> $output:ty => |$binding:ident| $variant_name:ident => $value:expr,
+        )*
+    ) => {
+        impl Variant {
+            $(
+                $( #[$attr] )*
+                pub fn $method(&self) -> Option<$output> {
+                    match self {
+                        Variant::$variant_name($binding) => Some($value),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+variant_accessors! {
+    /// Returns the inner value if this is a `Variant::Axes`.
+    as_axes -> Axes => |value| Axes => *value,
+    /// Returns a reference to the inner value if this is a
+    /// `Variant::BinaryString`.
+    as_binary_string -> &BinaryString => |value| BinaryString => value,
+    /// Returns the inner value if this is a `Variant::Bool`.
+    as_bool -> bool => |value| Bool => *value,
+    /// Returns the inner value if this is a `Variant::BrickColor`.
+    as_brick_color -> BrickColor => |value| BrickColor => *value,
+    /// Returns the inner value if this is a `Variant::CFrame`.
+    as_cframe -> CFrame => |value| CFrame => *value,
+    /// Returns the inner value if this is a `Variant::Color3`.
+    as_color3 -> Color3 => |value| Color3 => *value,
+    /// Returns the inner value if this is a `Variant::Color3uint8`.
+    as_color3uint8 -> Color3uint8 => |value| Color3uint8 => *value,
+    /// Returns a reference to the inner value if this is a
+    /// `Variant::ColorSequence`.
+    as_color_sequence -> &ColorSequence => |value| ColorSequence => value,
+    /// Returns a reference to the inner value if this is a `Variant::Content`.
+    as_content -> &Content => |value| Content => value,
+    /// Returns the inner value if this is a `Variant::Enum`.
+    as_enum -> Enum => |value| Enum => *value,
+    /// Returns the inner value if this is a `Variant::Faces`.
+    as_faces -> Faces => |value| Faces => *value,
+    /// Returns the inner value if this is a `Variant::Float32`.
+    as_f32 -> f32 => |value| Float32 => *value,
+    /// Returns the inner value if this is a `Variant::Float64`.
+    as_f64 -> f64 => |value| Float64 => *value,
+    /// Returns the inner value if this is a `Variant::Int32`.
+    as_i32 -> i32 => |value| Int32 => *value,
+    /// Returns the inner value if this is a `Variant::Int64`.
+    as_i64 -> i64 => |value| Int64 => *value,
+    /// Returns the inner value if this is a `Variant::NumberRange`.
+    as_number_range -> NumberRange => |value| NumberRange => *value,
+    /// Returns a reference to the inner value if this is a
+    /// `Variant::NumberSequence`.
+    as_number_sequence -> &NumberSequence => |value| NumberSequence => value,
+    /// Returns the inner value if this is a `Variant::PhysicalProperties`.
+    as_physical_properties -> PhysicalProperties => |value| PhysicalProperties => *value,
+    /// Returns the inner value if this is a `Variant::Ray`.
+    as_ray -> Ray => |value| Ray => *value,
+    /// Returns the inner value if this is a `Variant::Rect`.
+    as_rect -> Rect => |value| Rect => *value,
+    /// Returns the inner value if this is a `Variant::Ref`.
+    ///
+    /// This is not named `as_ref` to avoid confusion with `AsRef::as_ref`.
+    as_referent -> Ref => |value| Ref => *value,
+    /// Returns the inner value if this is a `Variant::Region3`.
+    as_region3 -> Region3 => |value| Region3 => *value,
+    /// Returns the inner value if this is a `Variant::Region3int16`.
+    as_region3int16 -> Region3int16 => |value| Region3int16 => *value,
+    /// Returns a reference to the inner value if this is a
+    /// `Variant::SharedString`.
+    as_shared_string -> &SharedString => |value| SharedString => value,
+    /// Returns the contained string if this is a `Variant::String`.
+    as_str -> &str => |value| String => value.as_str(),
+    /// Returns the inner value if this is a `Variant::UDim`.
+    as_udim -> UDim => |value| UDim => *value,
+    /// Returns the inner value if this is a `Variant::UDim2`.
+    as_udim2 -> UDim2 => |value| UDim2 => *value,
+    /// Returns the inner value if this is a `Variant::Vector2`.
+    as_vector2 -> Vector2 => |value| Vector2 => *value,
+    /// Returns the inner value if this is a `Variant::Vector2int16`.
+    as_vector2int16 -> Vector2int16 => |value| Vector2int16 => *value,
+    /// Returns the inner value if this is a `Variant::Vector3`.
+    as_vector3 -> Vector3 => |value| Vector3 => *value,
+    /// Returns the inner value if this is a `Variant::Vector3int16`.
+    as_vector3int16 -> Vector3int16 => |value| Vector3int16 => *value,
+    /// Returns the inner value if this is a `Variant::OptionalCFrame`.
+    as_optional_cframe -> Option<CFrame> => |value| OptionalCFrame => *value,
+    /// Returns a reference to the inner value if this is a
+    /// `Variant::ProtectedString`.
+    as_protected_string -> &ProtectedString => |value| ProtectedString => value,
+    /// Returns a reference to the inner value if this is a `Variant::Tags`.
+    as_tags -> &Tags => |value| Tags => value,
+    /// Returns a reference to the inner value if this is a
+    /// `Variant::Attributes`.
+    as_attributes -> &Attributes => |value| Attributes => value,
+}
+
+impl Variant {
+    /// Returns a zero-like value of the given type, like an empty string, `0`,
+    /// or a CFrame at the origin with no rotation.
+    ///
+    /// Types without a natural zero return `None`. These are `Ref`, since
+    /// whether an empty referent makes sense depends on the property,
+    /// `SharedString`, since every shared string is tied to its contents, and
+    /// `Enum`, since the valid values depend on which enum it is.
+    pub fn default_for_type(ty: VariantType) -> Option<Variant> {
+        let zero3 = Vector3::new(0.0, 0.0, 0.0);
+
+        Some(match ty {
+            VariantType::Axes => Variant::Axes(Axes::empty()),
+            VariantType::BinaryString => Variant::BinaryString(BinaryString::new()),
+            VariantType::Bool => Variant::Bool(false),
+            VariantType::BrickColor => Variant::BrickColor(BrickColor::MediumStoneGrey),
+            VariantType::CFrame => Variant::CFrame(CFrame::new(zero3, Matrix3::identity())),
+            VariantType::Color3 => Variant::Color3(Color3::new(0.0, 0.0, 0.0)),
+            VariantType::Color3uint8 => Variant::Color3uint8(Color3uint8::new(0, 0, 0)),
+            VariantType::ColorSequence => Variant::ColorSequence(ColorSequence {
+                keypoints: vec![
+                    ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0)),
+                    ColorSequenceKeypoint::new(1.0, Color3::new(0.0, 0.0, 0.0)),
+                ],
+            }),
+            VariantType::Content => Variant::Content(Content::new()),
+            VariantType::Faces => Variant::Faces(Faces::empty()),
+            VariantType::Float32 => Variant::Float32(0.0),
+            VariantType::Float64 => Variant::Float64(0.0),
+            VariantType::Int32 => Variant::Int32(0),
+            VariantType::Int64 => Variant::Int64(0),
+            VariantType::NumberRange => Variant::NumberRange(NumberRange::new(0.0, 0.0)),
+            VariantType::NumberSequence => Variant::NumberSequence(NumberSequence {
+                keypoints: vec![
+                    NumberSequenceKeypoint::new(0.0, 0.0, 0.0),
+                    NumberSequenceKeypoint::new(1.0, 0.0, 0.0),
+                ],
+            }),
+            VariantType::PhysicalProperties => {
+                Variant::PhysicalProperties(PhysicalProperties::Default)
+            }
+            VariantType::Ray => Variant::Ray(Ray::new(zero3, zero3)),
+            VariantType::Rect => {
+                Variant::Rect(Rect::new(Vector2::new(0.0, 0.0), Vector2::new(0.0, 0.0)))
+            }
+            VariantType::Region3 => Variant::Region3(Region3::new(zero3, zero3)),
+            VariantType::Region3int16 => Variant::Region3int16(Region3int16::new(
+                Vector3int16::new(0, 0, 0),
+                Vector3int16::new(0, 0, 0),
+            )),
+            VariantType::String => Variant::String(String::new()),
+            VariantType::UDim => Variant::UDim(UDim::new(0.0, 0)),
+            VariantType::UDim2 => Variant::UDim2(UDim2::new(UDim::new(0.0, 0), UDim::new(0.0, 0))),
+            VariantType::Vector2 => Variant::Vector2(Vector2::new(0.0, 0.0)),
+            VariantType::Vector2int16 => Variant::Vector2int16(Vector2int16::new(0, 0)),
+            VariantType::Vector3 => Variant::Vector3(zero3),
+            VariantType::Vector3int16 => Variant::Vector3int16(Vector3int16::new(0, 0, 0)),
+            VariantType::OptionalCFrame => Variant::OptionalCFrame(None),
+            VariantType::ProtectedString => Variant::ProtectedString(ProtectedString::new()),
+            VariantType::Tags => Variant::Tags(Tags::new()),
+            VariantType::Attributes => Variant::Attributes(Attributes::new()),
+            VariantType::Enum | VariantType::Ref | VariantType::SharedString => return None,
+        })
+    }
+}
+
+/// The error returned when converting a `Variant` into a concrete type fails
+/// because the `Variant` holds a different type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariantTypeMismatch {
+    /// The type that the conversion expected.
+    pub expected: VariantType,
+
+    /// The type that the `Variant` actually contained.
+    pub actual: VariantType,
+}
+
+impl fmt::Display for VariantTypeMismatch {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "expected a value of type {:?}, but got {:?}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for VariantTypeMismatch {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::{hash_map::DefaultHasher, HashSet};
+
+    use crate::CustomPhysicalProperties;
+
+    fn hash_of(value: &Variant) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn one_of_each() -> Vec<Variant> {
+        let cframe = CFrame::new(Vector3::new(1.0, 2.0, 3.0), Matrix3::identity());
+
+        vec![
+            Axes::all().into(),
+            BinaryString::from(b"hello".to_vec()).into(),
+            true.into(),
+            BrickColor::ReallyRed.into(),
+            cframe.into(),
+            Color3::new(1.0, 0.5, 0.0).into(),
+            Color3uint8::new(255, 128, 0).into(),
+            ColorSequence {
+                keypoints: vec![
+                    ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0)),
+                    ColorSequenceKeypoint::new(1.0, Color3::new(1.0, 1.0, 1.0)),
+                ],
+            }
+            .into(),
+            Content::from("rbxassetid://12345").into(),
+            Enum::from_u32(256).into(),
+            Faces::all().into(),
+            1.5f32.into(),
+            2.5f64.into(),
+            42i32.into(),
+            42i64.into(),
+            NumberRange::new(1.0, 2.0).into(),
+            NumberSequence {
+                keypoints: vec![
+                    NumberSequenceKeypoint::new(0.0, 1.0, 0.0),
+                    NumberSequenceKeypoint::new(1.0, 2.0, 0.5),
+                ],
+            }
+            .into(),
+            PhysicalProperties::Custom(CustomPhysicalProperties {
+                density: 0.5,
+                friction: 1.0,
+                elasticity: 1.5,
+                friction_weight: 2.0,
+                elasticity_weight: 2.5,
+            })
+            .into(),
+            Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)).into(),
+            Rect::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)).into(),
+            Ref::new().into(),
+            Region3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)).into(),
+            Region3int16::new(Vector3int16::new(0, 0, 0), Vector3int16::new(1, 1, 1)).into(),
+            SharedString::new(b"shared".to_vec()).into(),
+            "hello".into(),
+            UDim::new(0.5, 10).into(),
+            UDim2::new(UDim::new(0.5, 10), UDim::new(1.0, -10)).into(),
+            Vector2::new(1.0, 2.0).into(),
+            Vector2int16::new(1, 2).into(),
+            Vector3::new(1.0, 2.0, 3.0).into(),
+            Vector3int16::new(1, 2, 3).into(),
+            Some(cframe).into(),
+            ProtectedString::from("print('hello')").into(),
+            Tags::from(&["foo", "bar"][..]).into(),
+            vec![("Health", Variant::Float64(100.0))]
+                .into_iter()
+                .collect::<Attributes>()
+                .into(),
+        ]
+    }
+
+    #[test]
+    fn eq_and_hash_every_variant() {
+        let values = one_of_each();
+
+        for value in &values {
+            let copy = value.clone();
+
+            assert_eq!(value, &copy);
+            assert_eq!(hash_of(value), hash_of(&copy));
+        }
+
+        let set: HashSet<Variant> = values.iter().cloned().collect();
+        assert_eq!(set.len(), values.len());
+
+        for value in &values {
+            assert!(set.contains(value));
+        }
+    }
+
+    #[test]
+    fn default_for_type() {
+        for value in one_of_each() {
+            let ty = value.ty();
+
+            match Variant::default_for_type(ty) {
+                Some(default) => {
+                    assert_eq!(default.ty(), ty);
+                    assert_ne!(default, value, "{:?} default is not zero-like", ty);
+                }
+                None => assert!(
+                    matches!(
+                        ty,
+                        VariantType::Enum | VariantType::Ref | VariantType::SharedString
+                    ),
+                    "{:?} should have a default",
+                    ty
+                ),
+            }
+        }
+
+        assert_eq!(
+            Variant::default_for_type(VariantType::String),
+            Some(Variant::String(String::new()))
+        );
+        assert_eq!(
+            Variant::default_for_type(VariantType::Vector3),
+            Some(Variant::Vector3(Vector3::new(0.0, 0.0, 0.0)))
+        );
+        assert_eq!(
+            Variant::default_for_type(VariantType::OptionalCFrame),
+            Some(Variant::OptionalCFrame(None))
+        );
+        assert_eq!(Variant::default_for_type(VariantType::Ref), None);
+        assert_eq!(Variant::default_for_type(VariantType::SharedString), None);
+        assert_eq!(Variant::default_for_type(VariantType::Enum), None);
+    }
+
+    #[test]
+    fn mismatched_types() {
+        assert_ne!(Variant::Int32(1), Variant::Int64(1));
+        assert_ne!(Variant::Float32(1.0), Variant::Float64(1.0));
+        assert_ne!(
+            Variant::OptionalCFrame(None),
+            Variant::OptionalCFrame(Some(CFrame::new(
+                Vector3::new(0.0, 0.0, 0.0),
+                Matrix3::identity()
+            )))
+        );
+    }
+
+    #[test]
+    fn nan_floats() {
+        let nan32 = Variant::Float32(f32::NAN);
+        let nan64 = Variant::Float64(f64::NAN);
+        let nan_vector = Variant::Vector3(Vector3::new(f32::NAN, 0.0, 0.0));
+
+        assert_eq!(nan32, nan32.clone());
+        assert_eq!(nan64, nan64.clone());
+        assert_eq!(nan_vector, nan_vector.clone());
+        assert_eq!(hash_of(&nan32), hash_of(&nan32.clone()));
+        assert_eq!(hash_of(&nan_vector), hash_of(&nan_vector.clone()));
+
+        let mut set = HashSet::new();
+        set.insert(nan32.clone());
+        set.insert(nan32);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn try_from_matching() {
+        assert_eq!(String::try_from(Variant::from("hi")), Ok("hi".to_owned()));
+        assert_eq!(bool::try_from(&Variant::Bool(true)), Ok(true));
+        assert_eq!(i32::try_from(Variant::Int32(5)), Ok(5));
+        assert_eq!(i64::try_from(&Variant::Int64(-5)), Ok(-5));
+        assert_eq!(f32::try_from(Variant::Float32(1.5)), Ok(1.5));
+        assert_eq!(f64::try_from(&Variant::Float64(2.5)), Ok(2.5));
+
+        let vector = Vector3::new(1.0, 2.0, 3.0);
+        assert_eq!(Vector3::try_from(&Variant::Vector3(vector)), Ok(vector));
+
+        let cframe = CFrame::new(vector, Matrix3::identity());
+        assert_eq!(CFrame::try_from(Variant::CFrame(cframe)), Ok(cframe));
+        assert_eq!(
+            Option::<CFrame>::try_from(Variant::OptionalCFrame(Some(cframe))),
+            Ok(Some(cframe))
+        );
+    }
+
+    #[test]
+    fn try_from_mismatched() {
+        assert_eq!(
+            String::try_from(Variant::Bool(true)),
+            Err(VariantTypeMismatch {
+                expected: VariantType::String,
+                actual: VariantType::Bool,
+            })
+        );
+
+        assert_eq!(
+            i32::try_from(&Variant::Int64(5)),
+            Err(VariantTypeMismatch {
+                expected: VariantType::Int32,
+                actual: VariantType::Int64,
+            })
+        );
+
+        assert_eq!(
+            Vector3::try_from(&Variant::Vector2(Vector2::new(1.0, 2.0))),
+            Err(VariantTypeMismatch {
+                expected: VariantType::Vector3,
+                actual: VariantType::Vector2,
+            })
+        );
+    }
+
+    #[test]
+    fn signed_zero() {
+        assert_ne!(Variant::Float32(0.0), Variant::Float32(-0.0));
+        assert_ne!(Variant::Float64(0.0), Variant::Float64(-0.0));
+        assert_ne!(
+            Variant::Color3(Color3::new(0.0, 0.0, 0.0)),
+            Variant::Color3(Color3::new(-0.0, 0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        /// Checks that `$method` returns `$expected` for a `Variant` built
+        /// from `$value`, and returns `None` for a `Variant` of another type.
+        macro_rules! check {
+            ($method:ident, $value:expr, $expected:expr) => {
+                let value = Variant::from($value);
+                assert_eq!(value.$method(), Some($expected), stringify!($method));
+
+                let other = if value.ty() == VariantType::Bool {
+                    Variant::Int32(1)
+                } else {
+                    Variant::Bool(true)
+                };
+                assert_eq!(other.$method(), None, stringify!($method));
+            };
+        }
+
+        let cframe = CFrame::new(Vector3::new(1.0, 2.0, 3.0), Matrix3::identity());
+        let binary_string = BinaryString::from(b"hello".to_vec());
+        let color_sequence = ColorSequence {
+            keypoints: vec![ColorSequenceKeypoint::new(0.0, Color3::new(0.0, 0.0, 0.0))],
+        };
+        let content = Content::from("rbxassetid://12345");
+        let number_sequence = NumberSequence {
+            keypoints: vec![NumberSequenceKeypoint::new(0.0, 1.0, 0.0)],
+        };
+        let physical_properties = PhysicalProperties::Custom(CustomPhysicalProperties {
+            density: 0.5,
+            friction: 1.0,
+            elasticity: 1.5,
+            friction_weight: 2.0,
+            elasticity_weight: 2.5,
+        });
+        let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let rect = Rect::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0));
+        let referent = Ref::new();
+        let region3 = Region3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0));
+        let region3int16 =
+            Region3int16::new(Vector3int16::new(0, 0, 0), Vector3int16::new(1, 1, 1));
+        let shared_string = SharedString::new(b"shared".to_vec());
+        let udim2 = UDim2::new(UDim::new(0.5, 10), UDim::new(1.0, -10));
+        let protected_string = ProtectedString::from("print('hello')");
+        let tags = Tags::from(&["foo", "bar"][..]);
+
+        check!(as_axes, Axes::all(), Axes::all());
+        check!(as_binary_string, binary_string.clone(), &binary_string);
+        check!(as_bool, true, true);
+        check!(as_brick_color, BrickColor::ReallyRed, BrickColor::ReallyRed);
+        check!(as_cframe, cframe, cframe);
+        check!(
+            as_color3,
+            Color3::new(1.0, 0.5, 0.0),
+            Color3::new(1.0, 0.5, 0.0)
+        );
+        check!(
+            as_color3uint8,
+            Color3uint8::new(255, 128, 0),
+            Color3uint8::new(255, 128, 0)
+        );
+        check!(as_color_sequence, color_sequence.clone(), &color_sequence);
+        check!(as_content, content.clone(), &content);
+        check!(as_enum, Enum::from_u32(256), Enum::from_u32(256));
+        check!(as_faces, Faces::all(), Faces::all());
+        check!(as_f32, 1.5f32, 1.5);
+        check!(as_f64, 2.5f64, 2.5);
+        check!(as_i32, 42i32, 42);
+        check!(as_i64, 42i64, 42);
+        check!(
+            as_number_range,
+            NumberRange::new(1.0, 2.0),
+            NumberRange::new(1.0, 2.0)
+        );
+        check!(
+            as_number_sequence,
+            number_sequence.clone(),
+            &number_sequence
+        );
+        check!(
+            as_physical_properties,
+            physical_properties,
+            physical_properties
+        );
+        check!(as_ray, ray, ray);
+        check!(as_rect, rect, rect);
+        check!(as_referent, referent, referent);
+        check!(as_region3, region3, region3);
+        check!(as_region3int16, region3int16, region3int16);
+        check!(as_shared_string, shared_string.clone(), &shared_string);
+        check!(as_str, "hello", "hello");
+        check!(as_udim, UDim::new(0.5, 10), UDim::new(0.5, 10));
+        check!(as_udim2, udim2, udim2);
+        check!(as_vector2, Vector2::new(1.0, 2.0), Vector2::new(1.0, 2.0));
+        check!(
+            as_vector2int16,
+            Vector2int16::new(1, 2),
+            Vector2int16::new(1, 2)
+        );
+        check!(
+            as_vector3,
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(1.0, 2.0, 3.0)
+        );
+        check!(
+            as_vector3int16,
+            Vector3int16::new(1, 2, 3),
+            Vector3int16::new(1, 2, 3)
+        );
+        check!(as_optional_cframe, Some(cframe), Some(cframe));
+        check!(as_optional_cframe, Option::<CFrame>::None, None);
+        check!(
+            as_protected_string,
+            protected_string.clone(),
+            &protected_string
+        );
+        check!(as_tags, tags.clone(), &tags);
+    }
+}
+
 #[cfg(all(test, feature = "serde"))]
 mod serde_test {
     use super::*;
@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use rbx_dom_weak::{
+    types::{Color3, Vector3},
+    InstanceBuilder, WeakDom,
+};
+
+pub fn de_parts_10000(c: &mut Criterion) {
+    let mut tree = WeakDom::new(InstanceBuilder::new("Folder"));
+    let root_ref = tree.root_ref();
+
+    for i in 0..10000 {
+        tree.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_name(format!("Part{}", i))
+                .with_property("Anchored", i % 2 == 0)
+                .with_property("Color", Color3::new(0.5, 0.25, 1.0))
+                .with_property("Size", Vector3::new(4.0, 1.0, 2.0))
+                .with_property("Transparency", 0.5f32),
+        );
+    }
+
+    let mut buffer = Vec::new();
+    rbx_xml::to_writer_default(&mut buffer, &tree, &[root_ref]).unwrap();
+
+    c.bench_function("Deserialize 10,000 Parts", |b| {
+        b.iter(|| {
+            deserialize_bench(&buffer);
+        });
+    });
+}
+
+#[inline(always)]
+fn deserialize_bench(buffer: &[u8]) {
+    rbx_xml::from_reader_default(buffer).unwrap();
+}
+
+criterion_group!(deserializer, de_parts_10000);
+criterion_main!(deserializer);
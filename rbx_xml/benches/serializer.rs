@@ -0,0 +1,39 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use rbx_dom_weak::{
+    types::{Color3, Vector3},
+    InstanceBuilder, WeakDom,
+};
+
+pub fn ser_parts_10000(c: &mut Criterion) {
+    let mut tree = WeakDom::new(InstanceBuilder::new("Folder"));
+    let root_ref = tree.root_ref();
+
+    for i in 0..10000 {
+        tree.insert(
+            root_ref,
+            InstanceBuilder::new("Part")
+                .with_name(format!("Part{}", i))
+                .with_property("Anchored", i % 2 == 0)
+                .with_property("Color", Color3::new(0.5, 0.25, 1.0))
+                .with_property("Size", Vector3::new(4.0, 1.0, 2.0))
+                .with_property("Transparency", 0.5f32),
+        );
+    }
+
+    let mut buffer = Vec::new();
+
+    // Encode once into the buffer to pre-size it.
+    rbx_xml::to_writer_default(&mut buffer, &tree, &[root_ref]).unwrap();
+    buffer.clear();
+
+    c.bench_function("Serialize 10,000 Parts", |b| {
+        b.iter(|| {
+            rbx_xml::to_writer_default(&mut buffer, &tree, &[root_ref]).unwrap();
+            buffer.clear();
+        });
+    });
+}
+
+criterion_group!(serializer, ser_parts_10000);
+criterion_main!(serializer);
@@ -4,7 +4,9 @@
 use std::borrow::{Borrow, Cow};
 use std::convert::TryInto;
 
-use rbx_dom_weak::types::{BrickColor, Variant, VariantType};
+use rbx_dom_weak::types::{Attributes, BinaryString, BrickColor, Tags, Variant, VariantType};
+
+use crate::logging;
 
 pub trait ConvertVariant: Clone + Sized {
     fn try_convert(self, target_type: VariantType) -> Result<Self, String> {
@@ -37,6 +39,27 @@ impl ConvertVariant for Variant {
                     .map(Into::into)
                     .map(Cow::Owned)
             }
+            (Variant::BinaryString(value), VariantType::Tags) => {
+                Ok(Cow::Owned(Tags::decode(value.as_ref()).into()))
+            }
+            (Variant::Tags(value), VariantType::BinaryString) => {
+                Ok(Cow::Owned(BinaryString::from(value.encode()).into()))
+            }
+            // Attributes of types we don't understand shouldn't stop the file
+            // from loading, so the raw BinaryString is kept instead.
+            (Variant::BinaryString(buffer), VariantType::Attributes) => {
+                match Attributes::decode(buffer.as_ref()) {
+                    Ok(attributes) => Ok(Cow::Owned(attributes.into())),
+                    Err(err) => {
+                        logging::warn!("Could not decode attributes: {}", err);
+                        Ok(value)
+                    }
+                }
+            }
+            (Variant::Attributes(value), VariantType::BinaryString) => value
+                .encode()
+                .map(|buffer| Cow::Owned(BinaryString::from(buffer).into()))
+                .map_err(|err| err.to_string()),
             (_, _) => Ok(value),
         }
     }
@@ -1,6 +1,10 @@
-use std::io::{Read, Write};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    io::{Read, Write},
+};
 
-use rbx_reflection::{PropertyDescriptor, PropertyKind, PropertySerialization};
+use rbx_reflection::{ClassDescriptor, PropertyDescriptor, PropertyKind, PropertySerialization};
 
 use crate::{
     deserializer_core::XmlEventReader,
@@ -53,94 +57,94 @@ pub fn find_serialized_property_descriptor(
     find_property_descriptors(class_name, property_name).map(|(_canonical, serialized)| serialized)
 }
 
-/// Find both the canonical and serialized property descriptors for a given
-/// class and property name pair. These might be the same descriptor!
-fn find_property_descriptors(
-    class_name: &str,
-    property_name: &str,
-) -> Option<(
+type PropertyDescriptors = (
     &'static PropertyDescriptor<'static>,
     &'static PropertyDescriptor<'static>,
-)> {
-    let class_descriptor = rbx_reflection_database::get().classes.get(class_name)?;
-
-    let mut current_class_descriptor = class_descriptor;
-
-    // We need to find the canonical property descriptor associated with
-    // the property we're trying to deserialize.
-    //
-    // At each step of the loop, we're checking a new class descriptor
-    // to see if it has an entry for the property name we're looking for.
-    loop {
-        // If this class descriptor knows about this property name,
-        // we're pretty much done!
-        if let Some(property_descriptor) = current_class_descriptor.properties.get(property_name) {
-            match &property_descriptor.kind {
-                PropertyKind::Canonical { serialization } => match serialization {
-                    PropertySerialization::Serializes => {
-                        return Some((property_descriptor, property_descriptor))
-                    }
-                    PropertySerialization::DoesNotSerialize => {
-                        // FIXME: Is this the correct solution?
-                        return None;
-                    }
-                    PropertySerialization::SerializesAs(serialized_name) => {
-                        let serialized_descriptor = current_class_descriptor
-                            .properties
-                            .get(serialized_name.as_ref())
-                            .unwrap();
-
-                        return Some((property_descriptor, serialized_descriptor));
-                    }
-                    _ => unimplemented!(),
-                },
-                PropertyKind::Alias { alias_for } => {
-                    let canonical_descriptor = current_class_descriptor
-                        .properties
-                        .get(alias_for.as_ref())
-                        .unwrap();
-
-                    // FIXME: This code is duplicated with above.
-                    match &canonical_descriptor.kind {
-                        PropertyKind::Canonical { serialization } => match serialization {
-                            PropertySerialization::Serializes => {
-                                return Some((canonical_descriptor, canonical_descriptor))
-                            }
-                            PropertySerialization::DoesNotSerialize => {
-                                // FIXME: Is this the correct solution?
-                                return None;
-                            }
-                            PropertySerialization::SerializesAs(serialized_name) => {
-                                let serialized_descriptor = current_class_descriptor
-                                    .properties
-                                    .get(serialized_name.as_ref())
-                                    .unwrap();
-
-                                return Some((canonical_descriptor, serialized_descriptor));
-                            }
-                            _ => unimplemented!(),
-                        },
-                        _ => return None,
-                    }
-                }
-                // FIXME
-                _ => unimplemented!(),
-            }
-        }
+);
+
+thread_local! {
+    /// Results of `find_property_descriptors`, keyed by class name and then
+    /// property name. Only names that are in the reflection database are ever
+    /// used as keys, so the cache can't grow past the size of the database no
+    /// matter what names show up in a file.
+    static DESCRIPTOR_CACHE: RefCell<HashMap<&'static str, HashMap<&'static str, Option<PropertyDescriptors>>>> =
+        RefCell::new(HashMap::new());
+}
 
-        if let Some(superclass_name) = &current_class_descriptor.superclass {
-            // If a property descriptor isn't found in our class, check
-            // our superclass.
+/// Find both the canonical and serialized property descriptors for a given
+/// class and property name pair. These might be the same descriptor!
+///
+/// Finding descriptors walks the superclass chain, which adds up when a file
+/// has thousands of instances, so results for known properties are cached per
+/// thread.
+fn find_property_descriptors(class_name: &str, property_name: &str) -> Option<PropertyDescriptors> {
+    let database = rbx_reflection_database::get();
+    let (class_name, class_descriptor) = database.classes.get_key_value(class_name)?;
+
+    DESCRIPTOR_CACHE.with(|cache| {
+        let cached = cache
+            .borrow()
+            .get(class_name.as_ref())
+            .and_then(|class_cache| class_cache.get(property_name).copied());
+
+        if let Some(descriptors) = cached {
+            return descriptors;
+        }
 
-            current_class_descriptor = rbx_reflection_database::get()
-                .classes
-                .get(superclass_name)
-                .expect("Superclass in reflection database didn't exist");
-        } else {
-            // This property isn't known by any class in the reflection
-            // database.
+        // Unknown properties aren't cached, since their names come from the
+        // file being read.
+        let (owner, property_name, property_descriptor) =
+            database.superclasses(class_descriptor).find_map(|class| {
+                class
+                    .properties
+                    .get_key_value(property_name)
+                    .map(|(name, descriptor)| (class, name, descriptor))
+            })?;
+
+        let descriptors = resolve_property_descriptors(owner, property_descriptor);
+
+        cache
+            .borrow_mut()
+            .entry(class_name.as_ref())
+            .or_insert_with(HashMap::new)
+            .insert(property_name.as_ref(), descriptors);
+
+        descriptors
+    })
+}
 
-            return None;
+/// Given a property descriptor and the class that defines it, finds the
+/// canonical and serialized descriptors of the logical property it belongs to.
+fn resolve_property_descriptors(
+    class_descriptor: &'static ClassDescriptor<'static>,
+    property_descriptor: &'static PropertyDescriptor<'static>,
+) -> Option<PropertyDescriptors> {
+    let canonical_descriptor = match &property_descriptor.kind {
+        PropertyKind::Canonical { .. } => property_descriptor,
+        PropertyKind::Alias { alias_for } => {
+            class_descriptor.properties.get(alias_for.as_ref()).unwrap()
         }
+        // FIXME
+        _ => unimplemented!(),
+    };
+
+    match &canonical_descriptor.kind {
+        PropertyKind::Canonical { serialization } => match serialization {
+            PropertySerialization::Serializes => Some((canonical_descriptor, canonical_descriptor)),
+            PropertySerialization::DoesNotSerialize => {
+                // FIXME: Is this the correct solution?
+                None
+            }
+            PropertySerialization::SerializesAs(serialized_name) => {
+                let serialized_descriptor = class_descriptor
+                    .properties
+                    .get(serialized_name.as_ref())
+                    .unwrap();
+
+                Some((canonical_descriptor, serialized_descriptor))
+            }
+            _ => unimplemented!(),
+        },
+        _ => None,
     }
 }
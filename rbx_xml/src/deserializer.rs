@@ -1,9 +1,10 @@
 use std::{
     collections::{HashMap, HashSet},
-    io::Read,
+    fmt,
+    io::{self, Read},
+    sync::{Arc, PoisonError, RwLock},
 };
 
-use log::trace;
 use rbx_dom_weak::{
     types::{Ref, SharedString, Variant, VariantType},
     InstanceBuilder, WeakDom,
@@ -14,16 +15,27 @@ use crate::{
     conversion::ConvertVariant,
     core::find_canonical_property_descriptor,
     error::{DecodeError, DecodeErrorKind},
+    logging,
     types::read_value_xml,
 };
 
 use crate::deserializer_core::{XmlEventReader, XmlReadEvent};
 
 pub fn decode_internal<R: Read>(source: R, options: DecodeOptions) -> Result<WeakDom, DecodeError> {
+    decode_internal_full(source, options).map(|output| output.dom)
+}
+
+pub fn decode_internal_full<R: Read>(
+    source: R,
+    options: DecodeOptions,
+) -> Result<DecodeOutput, DecodeError> {
     let mut tree = WeakDom::new(InstanceBuilder::new("DataModel"));
 
     let root_id = tree.root_ref();
 
+    let source = skip_bom(source)
+        .map_err(|error| DecodeError::new_without_position(DecodeErrorKind::from(error)))?;
+
     let mut iterator = XmlEventReader::from_source(source);
     let mut state = ParseState::new(&mut tree, options);
 
@@ -31,7 +43,87 @@ pub fn decode_internal<R: Read>(source: R, options: DecodeOptions) -> Result<Wea
     apply_referent_rewrites(&mut state);
     apply_shared_string_rewrites(&mut state);
 
-    Ok(tree)
+    let unknown_properties = state.unknown_properties;
+    let stats = state.stats;
+
+    Ok(DecodeOutput {
+        dom: tree,
+        unknown_properties,
+        stats,
+    })
+}
+
+/// The result of deserializing a file with
+/// [`from_reader_with_output`][crate::from_reader_with_output].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct DecodeOutput {
+    /// The deserialized DOM, which has a DataModel at the top level.
+    pub dom: WeakDom,
+
+    /// Every distinct property that was skipped because it isn't in the
+    /// reflection database, in the order they were first found. Always empty
+    /// unless the property behavior is
+    /// [`DecodePropertyBehavior::WarnOnUnknown`].
+    pub unknown_properties: Vec<UnknownProperty>,
+
+    /// Counts of things that were lost or dropped while deserializing.
+    pub stats: DecodeStats,
+}
+
+/// Counts of things rbx_xml had to drop while deserializing a file, returned
+/// as part of [`DecodeOutput`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DecodeStats {
+    /// The number of Ref properties that pointed to a referent that wasn't
+    /// defined in the file, like a reference to an instance outside of a
+    /// model. These properties are left as `Ref::none()`.
+    pub nullified_refs: usize,
+
+    /// The number of properties that were skipped because they aren't in the
+    /// reflection database. Unlike
+    /// [`DecodeOutput::unknown_properties`], this counts every occurrence.
+    pub unknown_properties: usize,
+}
+
+/// A property that rbx_xml found in a file but didn't know about.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct UnknownProperty {
+    /// The ClassName of the instance the property was found on.
+    pub class_name: String,
+
+    /// The name of the property as it appears in the file.
+    pub property_name: String,
+}
+
+/// The UTF-8 byte-order mark, which some text editors write at the start of
+/// every file they save.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Skips over a UTF-8 byte-order mark at the start of `source`, if there is
+/// one. Older versions of xml-rs reject documents that start with one.
+fn skip_bom<R: Read>(mut source: R) -> io::Result<impl Read> {
+    let mut start = [0; 3];
+    let mut len = 0;
+
+    while len < start.len() {
+        match source.read(&mut start[len..]) {
+            Ok(0) => break,
+            Ok(read) => len += read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    let prefix = if &start[..len] == UTF8_BOM {
+        Vec::new()
+    } else {
+        start[..len].to_vec()
+    };
+
+    Ok(io::Cursor::new(prefix).chain(source))
 }
 
 /// Describes the strategy that rbx_xml should use when deserializing
@@ -58,6 +150,15 @@ pub enum DecodePropertyBehavior {
     /// rbx_xml.
     ErrorOnUnknown,
 
+    /// Ignores properties that aren't known by rbx_xml, like
+    /// [`IgnoreUnknown`][DecodePropertyBehavior::IgnoreUnknown], but logs a
+    /// warning and records each one in
+    /// [`DecodeOutput::unknown_properties`].
+    ///
+    /// This is useful for detecting files that use properties newer than
+    /// rbx_xml's reflection database.
+    WarnOnUnknown,
+
     /// Completely turns off rbx_xml's reflection database. Property names and
     /// types will appear exactly as they are in XML.
     ///
@@ -67,10 +168,56 @@ pub enum DecodePropertyBehavior {
     NoReflection,
 }
 
+/// What rbx_xml should do with a property that isn't in its reflection
+/// database. Returned by the callback given to
+/// [`DecodeOptions::on_unknown_property`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UnknownPropertyAction {
+    /// Skip the property, like [`DecodePropertyBehavior::IgnoreUnknown`].
+    Skip,
+
+    /// Read the property as-is, like [`DecodePropertyBehavior::ReadUnknown`].
+    Read,
+
+    /// Stop deserializing with an error, like
+    /// [`DecodePropertyBehavior::ErrorOnUnknown`].
+    Error,
+}
+
+type UnknownPropertyCallback = dyn Fn(&str, &str) -> UnknownPropertyAction + Send + Sync;
+
+/// A callback given to [`DecodeOptions::on_unknown_property`].
+#[derive(Clone)]
+struct UnknownPropertyHandler(Arc<UnknownPropertyCallback>);
+
+impl fmt::Debug for UnknownPropertyHandler {
+    fn fmt(&self, output: &mut fmt::Formatter) -> fmt::Result {
+        write!(output, "UnknownPropertyHandler")
+    }
+}
+
+/// Describes how rbx_xml should pick the `Ref` for each instance it
+/// deserializes based on the instance's referent in the file.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ReferentMappingStrategy {
+    /// Gives every instance a brand new `Ref`. This is the default.
+    Fresh,
+
+    /// Reuses the `Ref` from the given map for any referent that's already in
+    /// it, and adds any referents that aren't. Sharing the same map between
+    /// multiple decodes gives instances with the same referent the same `Ref`
+    /// every time, which is useful when reloading a file that has changed.
+    Stable(Arc<RwLock<HashMap<String, Ref>>>),
+}
+
 /// Options available for deserializing an XML-format model or place.
 #[derive(Debug, Clone)]
 pub struct DecodeOptions {
     property_behavior: DecodePropertyBehavior,
+    referent_mapping_strategy: ReferentMappingStrategy,
+    unknown_property_handler: Option<UnknownPropertyHandler>,
 }
 
 impl DecodeOptions {
@@ -79,6 +226,8 @@ impl DecodeOptions {
     pub fn new() -> Self {
         DecodeOptions {
             property_behavior: DecodePropertyBehavior::IgnoreUnknown,
+            referent_mapping_strategy: ReferentMappingStrategy::Fresh,
+            unknown_property_handler: None,
         }
     }
 
@@ -86,7 +235,40 @@ impl DecodeOptions {
     /// ones.
     #[inline]
     pub fn property_behavior(self, property_behavior: DecodePropertyBehavior) -> Self {
-        DecodeOptions { property_behavior }
+        DecodeOptions {
+            property_behavior,
+            ..self
+        }
+    }
+
+    /// Determines how rbx_xml will pick the `Ref` of each instance it
+    /// deserializes.
+    #[inline]
+    pub fn referent_mapping_strategy(
+        self,
+        referent_mapping_strategy: ReferentMappingStrategy,
+    ) -> Self {
+        DecodeOptions {
+            referent_mapping_strategy,
+            ..self
+        }
+    }
+
+    /// Sets a callback that decides what to do with each property that isn't
+    /// in the reflection database. It's given the ClassName of the instance
+    /// and the name of the property as it appears in the file.
+    ///
+    /// When set, this takes the place of the property behavior for unknown
+    /// properties. It isn't called when the property behavior is
+    /// [`DecodePropertyBehavior::NoReflection`].
+    pub fn on_unknown_property<F>(self, handler: F) -> Self
+    where
+        F: Fn(&str, &str) -> UnknownPropertyAction + Send + Sync + 'static,
+    {
+        DecodeOptions {
+            unknown_property_handler: Some(UnknownPropertyHandler(Arc::new(handler))),
+            ..self
+        }
     }
 
     /// A utility function to determine whether or not we should reference the
@@ -136,6 +318,14 @@ pub struct ParseState<'a> {
     /// Contains all of the unknown types that have been found so far. Tracking
     /// them here helps ensure that we only output a warning once per type.
     unknown_type_names: HashSet<String>,
+
+    /// Every distinct unknown property that has been skipped so far when the
+    /// property behavior is `WarnOnUnknown`.
+    unknown_properties: Vec<UnknownProperty>,
+    unknown_property_set: HashSet<UnknownProperty>,
+
+    /// Counts of dropped values, reported in `DecodeOutput::stats`.
+    stats: DecodeStats,
 }
 
 struct ReferentRewrite {
@@ -161,9 +351,34 @@ impl<'a> ParseState<'a> {
             known_shared_strings: HashMap::new(),
             shared_string_rewrites: Vec::new(),
             unknown_type_names: HashSet::new(),
+            unknown_properties: Vec::new(),
+            unknown_property_set: HashSet::new(),
+            stats: DecodeStats::default(),
         }
     }
 
+    /// Called when an unknown property is skipped because of
+    /// `DecodePropertyBehavior::WarnOnUnknown`.
+    fn unknown_property_skipped(&mut self, class_name: &str, property_name: &str) {
+        let property = UnknownProperty {
+            class_name: class_name.to_owned(),
+            property_name: property_name.to_owned(),
+        };
+
+        if self.unknown_property_set.contains(&property) {
+            return;
+        }
+
+        logging::warn!(
+            "Skipped unknown property {class}.{prop} in Roblox XML model file.",
+            class = class_name,
+            prop = property_name,
+        );
+
+        self.unknown_property_set.insert(property.clone());
+        self.unknown_properties.push(property);
+    }
+
     /// Called when the deserializer encounters an unknown property type.
     pub fn unknown_type_visited(&mut self, id: Ref, property_name: &str, type_name: &str) {
         if self.unknown_type_names.contains(type_name) {
@@ -173,7 +388,7 @@ impl<'a> ParseState<'a> {
         self.unknown_type_names.insert(type_name.to_owned());
         let instance = self.tree.get_by_ref(id).unwrap();
 
-        log::warn!(
+        logging::warn!(
             "Unknown value type name \"{name}\" in Roblox XML model file. \
              Found in property {class}.{prop}.",
             name = type_name,
@@ -216,9 +431,13 @@ fn apply_referent_rewrites(state: &mut ParseState) {
     for rewrite in &state.referent_rewrites {
         let new_value = match state.referents_to_ids.get(&rewrite.referent_value) {
             Some(id) => *id,
-            None => continue,
+            None => {
+                state.stats.nullified_refs += 1;
+                continue;
+            }
         };
 
+        let property_name = state.tree.intern_property_name(&rewrite.property_name);
         let instance = state
             .tree
             .get_by_ref_mut(rewrite.id)
@@ -226,7 +445,15 @@ fn apply_referent_rewrites(state: &mut ParseState) {
 
         instance
             .properties
-            .insert(rewrite.property_name.clone(), Variant::Ref(new_value));
+            .insert(property_name, Variant::Ref(new_value));
+    }
+
+    if state.stats.nullified_refs > 0 {
+        logging::warn!(
+            "{count} Ref properties in Roblox XML model file pointed to referents that weren't \
+             defined and were set to nil.",
+            count = state.stats.nullified_refs,
+        );
     }
 }
 
@@ -237,14 +464,14 @@ fn apply_shared_string_rewrites(state: &mut ParseState) {
             None => continue,
         };
 
+        let property_name = state.tree.intern_property_name(&rewrite.property_name);
         let instance = state.tree.get_by_ref_mut(rewrite.id).expect(
             "rbx_xml bug: had ID in SharedString rewrite list that didn't end up in the tree",
         );
 
-        instance.properties.insert(
-            rewrite.property_name.clone(),
-            Variant::SharedString(new_value),
-        );
+        instance
+            .properties
+            .insert(property_name, Variant::SharedString(new_value));
     }
 }
 
@@ -431,9 +658,28 @@ fn deserialize_instance<R: Read>(
         (class, referent)
     };
 
-    trace!("Class {} with referent {:?}", class_name, referent);
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("decode_instance", type_name = %class_name).entered();
+
+    logging::trace!("Class {} with referent {:?}", class_name, referent);
+
+    let mut builder = InstanceBuilder::new(class_name);
+
+    if let (Some(referent), ReferentMappingStrategy::Stable(mapping)) =
+        (&referent, &state.options.referent_mapping_strategy)
+    {
+        let mut mapping = mapping.write().unwrap_or_else(PoisonError::into_inner);
+        let stable_ref = *mapping
+            .entry(referent.clone())
+            .or_insert_with(|| builder.referent());
+
+        // If a file uses the same referent more than once, only the first
+        // instance can use the stable Ref.
+        if state.tree.get_by_ref(stable_ref).is_none() {
+            builder = builder.with_referent(stable_ref);
+        }
+    }
 
-    let builder = InstanceBuilder::new(class_name);
     let instance_id = state.tree.insert(parent_id, builder);
 
     if let Some(referent) = referent {
@@ -473,9 +719,15 @@ fn deserialize_instance<R: Read>(
         }
     }
 
+    let name = properties.remove("Name");
+    let properties = properties
+        .into_iter()
+        .map(|(key, value)| (state.tree.intern_property_name(&key), value))
+        .collect();
+
     let instance = state.tree.get_by_ref_mut(instance_id).unwrap();
 
-    instance.name = match properties.remove("Name") {
+    instance.name = match name {
         Some(value) => match value {
             Variant::String(value) => value,
             _ => return Err(reader.error(DecodeErrorKind::NameMustBeString(value.ty()))),
@@ -507,7 +759,7 @@ fn deserialize_properties<R: Read>(
         .class
         .clone();
 
-    log::trace!(
+    logging::trace!(
         "Deserializing properties for instance {:?}, whose ClassName is {}",
         instance_id,
         class_name
@@ -551,13 +803,21 @@ fn deserialize_properties<R: Read>(
             }
         };
 
-        log::trace!(
+        logging::trace!(
             "Deserializing property {}.{}, of type {}",
             class_name,
             xml_property_name,
             xml_type_name
         );
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "decode_property",
+            type_name = %class_name,
+            prop_name = %xml_property_name,
+        )
+        .entered();
+
         let maybe_descriptor = if state.options.use_reflection() {
             find_canonical_property_descriptor(&class_name, &xml_property_name)
         } else {
@@ -609,10 +869,28 @@ fn deserialize_properties<R: Read>(
 
             props.insert(descriptor.name.to_string(), value);
         } else {
-            match state.options.property_behavior {
-                DecodePropertyBehavior::IgnoreUnknown => {
+            let action = match &state.options.unknown_property_handler {
+                Some(handler) if state.options.use_reflection() => {
+                    (handler.0)(&class_name, &xml_property_name)
+                }
+                _ => match state.options.property_behavior {
+                    DecodePropertyBehavior::IgnoreUnknown => UnknownPropertyAction::Skip,
+                    DecodePropertyBehavior::WarnOnUnknown => {
+                        state.unknown_property_skipped(&class_name, &xml_property_name);
+                        UnknownPropertyAction::Skip
+                    }
+                    DecodePropertyBehavior::ReadUnknown | DecodePropertyBehavior::NoReflection => {
+                        UnknownPropertyAction::Read
+                    }
+                    DecodePropertyBehavior::ErrorOnUnknown => UnknownPropertyAction::Error,
+                },
+            };
+
+            match action {
+                UnknownPropertyAction::Skip => {
                     // We don't care about this property, so we can read it and
                     // throw it into the void.
+                    state.stats.unknown_properties += 1;
 
                     read_value_xml(
                         reader,
@@ -622,7 +900,7 @@ fn deserialize_properties<R: Read>(
                         &xml_property_name,
                     )?;
                 }
-                DecodePropertyBehavior::ReadUnknown | DecodePropertyBehavior::NoReflection => {
+                UnknownPropertyAction::Read => {
                     // We'll take this value as-is with no conversions on either
                     // the name or value.
 
@@ -638,7 +916,7 @@ fn deserialize_properties<R: Read>(
                     };
                     props.insert(xml_property_name, value);
                 }
-                DecodePropertyBehavior::ErrorOnUnknown => {
+                UnknownPropertyAction::Error => {
                     return Err(reader.error(DecodeErrorKind::UnknownProperty {
                         class_name,
                         property_name: xml_property_name,
@@ -648,3 +926,331 @@ fn deserialize_properties<R: Read>(
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_dom_weak::types::Tags;
+
+    static DOCUMENT: &str = r#"
+        <roblox version="4">
+            <Item class="Folder" referent="RBX1">
+                <Properties>
+                    <string name="Name">First</string>
+                </Properties>
+                <Item class="ObjectValue" referent="RBX2">
+                    <Properties>
+                        <Ref name="Value">RBX1</Ref>
+                    </Properties>
+                </Item>
+            </Item>
+        </roblox>
+    "#;
+
+    #[test]
+    fn decode_with_bom() {
+        let mut with_bom = UTF8_BOM.to_vec();
+        with_bom.extend_from_slice(DOCUMENT.trim().as_bytes());
+
+        let dom = decode_internal(with_bom.as_slice(), DecodeOptions::new()).unwrap();
+        let folder = dom.get_by_ref(dom.root().children()[0]).unwrap();
+
+        assert_eq!(folder.name, "First");
+        assert_eq!(folder.children().len(), 1);
+    }
+
+    #[test]
+    fn short_documents_without_bom() {
+        let mut output = Vec::new();
+        skip_bom(&b"<a"[..])
+            .unwrap()
+            .read_to_end(&mut output)
+            .unwrap();
+
+        assert_eq!(output, b"<a");
+    }
+
+    fn decode_refs(document: &str, options: DecodeOptions) -> (Ref, Ref, Variant) {
+        let dom = decode_internal(document.as_bytes(), options).unwrap();
+        let folder = dom.get_by_ref(dom.root().children()[0]).unwrap();
+        let value = dom.get_by_ref(folder.children()[0]).unwrap();
+
+        (
+            folder.referent(),
+            value.referent(),
+            value.properties.get("Value").unwrap().clone(),
+        )
+    }
+
+    #[test]
+    fn fresh_referents() {
+        let first = decode_refs(DOCUMENT, DecodeOptions::new());
+        let second = decode_refs(DOCUMENT, DecodeOptions::new());
+
+        assert_ne!(first.0, second.0);
+        assert_ne!(first.1, second.1);
+    }
+
+    #[test]
+    fn stable_referents() {
+        let mapping = Arc::new(RwLock::new(HashMap::new()));
+        let options = DecodeOptions::new()
+            .referent_mapping_strategy(ReferentMappingStrategy::Stable(Arc::clone(&mapping)));
+
+        let first = decode_refs(DOCUMENT, options.clone());
+        let second = decode_refs(DOCUMENT, options.clone());
+
+        assert_eq!(first, second);
+        assert_eq!(first.2, Variant::Ref(first.0));
+        assert_eq!(mapping.read().unwrap().get("RBX1"), Some(&first.0));
+
+        // Instances that weren't in the earlier version of the file get new
+        // Refs, while the rest keep theirs.
+        let changed = DOCUMENT.replace("RBX2", "RBX3");
+        let third = decode_refs(&changed, options);
+
+        assert_eq!(third.0, first.0);
+        assert_ne!(third.1, first.1);
+    }
+
+    #[test]
+    fn stable_duplicate_referents() {
+        let document = r#"
+            <roblox version="4">
+                <Item class="Folder" referent="RBX1"></Item>
+                <Item class="Folder" referent="RBX1"></Item>
+            </roblox>
+        "#;
+
+        let options = DecodeOptions::new().referent_mapping_strategy(
+            ReferentMappingStrategy::Stable(Arc::new(RwLock::new(HashMap::new()))),
+        );
+
+        let dom = decode_internal(document.as_bytes(), options).unwrap();
+        let children = dom.root().children();
+
+        assert_eq!(children.len(), 2);
+        assert_ne!(children[0], children[1]);
+    }
+
+    #[test]
+    fn canonicalize_no_reflection() {
+        let document = r#"
+            <roblox version="4">
+                <Item class="Part" referent="RBX1">
+                    <Properties>
+                        <token name="formFactorRaw">1</token>
+                        <Color3uint8 name="Color3uint8">4294901811</Color3uint8>
+                    </Properties>
+                </Item>
+            </roblox>
+        "#;
+
+        let options = DecodeOptions::new().property_behavior(DecodePropertyBehavior::NoReflection);
+        let mut dom = decode_internal(document.as_bytes(), options).unwrap();
+
+        let part_ref = dom.root().children()[0];
+        let part = dom.get_by_ref(part_ref).unwrap();
+        assert!(part.properties.contains_key("formFactorRaw"));
+        assert!(part.properties.contains_key("Color3uint8"));
+
+        rbx_dom_weak::canonicalize_properties(&mut dom);
+
+        let part = dom.get_by_ref(part_ref).unwrap();
+        let mut names: Vec<&str> = part.properties.keys().map(|key| key.as_ref()).collect();
+        names.sort_unstable();
+
+        assert_eq!(names, ["Color", "FormFactor"]);
+        assert_eq!(
+            part.properties.get("Color"),
+            Some(&Variant::Color3(rbx_dom_weak::types::Color3::new(
+                1.0, 0.0, 0.2
+            )))
+        );
+    }
+
+    #[test]
+    fn error_position() {
+        let document = "<roblox version=\"4\">\n\t<Item class=\"Folder\" referent=\"RBX1\">\n\t\t<Properties>\n\t\t\t<string name=\"Name\">Oops</bool>\n";
+
+        let error = decode_internal(document.as_bytes(), DecodeOptions::new()).unwrap_err();
+
+        assert_eq!(error.line(), 4);
+        assert!(error.to_string().starts_with("line 4, column "));
+    }
+
+    #[test]
+    fn warn_on_unknown() {
+        let document = r#"
+            <roblox version="4">
+                <Item class="Folder" referent="RBX1">
+                    <Properties>
+                        <string name="Name">Folder</string>
+                        <string name="FutureProperty">Folder</string>
+                    </Properties>
+                    <Item class="Part" referent="RBX2">
+                        <Properties>
+                            <string name="FutureProperty">Part</string>
+                            <bool name="OtherFutureProperty">true</bool>
+                        </Properties>
+                    </Item>
+                    <Item class="Part" referent="RBX3">
+                        <Properties>
+                            <string name="FutureProperty">Part</string>
+                        </Properties>
+                    </Item>
+                </Item>
+            </roblox>
+        "#;
+
+        let options = DecodeOptions::new().property_behavior(DecodePropertyBehavior::WarnOnUnknown);
+        let output = decode_internal_full(document.as_bytes(), options).unwrap();
+
+        let unknown = |class_name: &str, property_name: &str| UnknownProperty {
+            class_name: class_name.to_owned(),
+            property_name: property_name.to_owned(),
+        };
+
+        assert_eq!(
+            output.unknown_properties,
+            vec![
+                unknown("Folder", "FutureProperty"),
+                unknown("Part", "FutureProperty"),
+                unknown("Part", "OtherFutureProperty"),
+            ]
+        );
+
+        let folder = output
+            .dom
+            .get_by_ref(output.dom.root().children()[0])
+            .unwrap();
+        assert_eq!(folder.name, "Folder");
+        assert!(!folder.properties.contains_key("FutureProperty"));
+
+        let ignored = decode_internal_full(document.as_bytes(), DecodeOptions::new()).unwrap();
+        assert!(ignored.unknown_properties.is_empty());
+    }
+
+    #[test]
+    fn nullified_refs() {
+        let document = r#"
+            <roblox version="4">
+                <Item class="Folder" referent="RBX1">
+                    <Properties>
+                        <string name="FutureProperty">Folder</string>
+                    </Properties>
+                    <Item class="ObjectValue" referent="RBX2">
+                        <Properties>
+                            <Ref name="Value">RBX1</Ref>
+                        </Properties>
+                    </Item>
+                    <Item class="ObjectValue" referent="RBX3">
+                        <Properties>
+                            <Ref name="Value">RBXOUTSIDE</Ref>
+                        </Properties>
+                    </Item>
+                </Item>
+            </roblox>
+        "#;
+
+        let output = decode_internal_full(document.as_bytes(), DecodeOptions::new()).unwrap();
+        assert_eq!(output.stats.nullified_refs, 1);
+        assert_eq!(output.stats.unknown_properties, 1);
+
+        let folder_ref = output.dom.root().children()[0];
+        let children = output.dom.get_by_ref(folder_ref).unwrap().children();
+
+        let inside = output.dom.get_by_ref(children[0]).unwrap();
+        assert_eq!(
+            inside.properties.get("Value"),
+            Some(&Variant::Ref(folder_ref))
+        );
+
+        let outside = output.dom.get_by_ref(children[1]).unwrap();
+        assert_eq!(
+            outside.properties.get("Value"),
+            Some(&Variant::Ref(Ref::none()))
+        );
+    }
+
+    #[test]
+    fn tags() {
+        let document = r#"
+            <roblox version="4">
+                <Item class="Folder" referent="RBX1">
+                </Item>
+                <Item class="Folder" referent="RBX2">
+                    <Properties>
+                        <BinaryString name="Tags">RW5lbXk=</BinaryString>
+                    </Properties>
+                </Item>
+                <Item class="Folder" referent="RBX3">
+                    <Properties>
+                        <BinaryString name="Tags">RW5lbXkAQm9zcwBGbHlpbmc=</BinaryString>
+                    </Properties>
+                </Item>
+            </roblox>
+        "#;
+
+        let dom = decode_internal(document.as_bytes(), DecodeOptions::new()).unwrap();
+        let tags: Vec<_> = dom
+            .root()
+            .children()
+            .iter()
+            .map(|&id| dom.get_by_ref(id).unwrap().properties.get("Tags"))
+            .collect();
+
+        assert_eq!(tags[0], None);
+        assert_eq!(tags[1], Some(&Variant::Tags(Tags::from(&["Enemy"][..]))));
+        assert_eq!(
+            tags[2],
+            Some(&Variant::Tags(Tags::from(&["Enemy", "Boss", "Flying"][..])))
+        );
+    }
+
+    #[test]
+    fn on_unknown_property() {
+        let document = r#"
+            <roblox version="4">
+                <Item class="Folder" referent="RBX1">
+                    <Properties>
+                        <string name="FutureProperty">Folder</string>
+                    </Properties>
+                    <Item class="Part" referent="RBX2">
+                        <Properties>
+                            <string name="FutureProperty">Part</string>
+                        </Properties>
+                    </Item>
+                </Item>
+            </roblox>
+        "#;
+
+        let options = DecodeOptions::new()
+            .property_behavior(DecodePropertyBehavior::ErrorOnUnknown)
+            .on_unknown_property(|class_name, _property_name| {
+                if class_name == "Part" {
+                    UnknownPropertyAction::Read
+                } else {
+                    UnknownPropertyAction::Skip
+                }
+            });
+
+        let dom = decode_internal(document.as_bytes(), options).unwrap();
+        let folder = dom.get_by_ref(dom.root().children()[0]).unwrap();
+        let part = dom.get_by_ref(folder.children()[0]).unwrap();
+
+        assert!(!folder.properties.contains_key("FutureProperty"));
+        assert_eq!(
+            part.properties.get("FutureProperty"),
+            Some(&Variant::String("Part".to_owned()))
+        );
+
+        let options = DecodeOptions::new().on_unknown_property(|_, property_name| {
+            assert_eq!(property_name, "FutureProperty");
+            UnknownPropertyAction::Error
+        });
+
+        assert!(decode_internal(document.as_bytes(), options).is_err());
+    }
+}
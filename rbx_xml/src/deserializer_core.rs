@@ -1,11 +1,11 @@
 use std::io::Read;
 
-use log::trace;
 use xml::{attribute::OwnedAttribute, reader::ParserConfig};
 
 use crate::{
     core::XmlType,
     error::{DecodeError as NewDecodeError, DecodeErrorKind},
+    logging,
 };
 
 pub use xml::reader::Error as XmlReadError;
@@ -276,25 +276,25 @@ impl<R: Read> XmlEventReader<R> {
     pub fn eat_unknown_tag(&mut self) -> Result<(), NewDecodeError> {
         let mut depth = 0;
 
-        trace!("Starting unknown block");
+        logging::trace!("Starting unknown block");
 
         loop {
             match self.expect_next()? {
                 XmlReadEvent::StartElement { name, .. } => {
-                    trace!("Eat unknown start: {:?}", name);
+                    logging::trace!("Eat unknown start: {:?}", name);
                     depth += 1;
                 }
                 XmlReadEvent::EndElement { name } => {
-                    trace!("Eat unknown end: {:?}", name);
+                    logging::trace!("Eat unknown end: {:?}", name);
                     depth -= 1;
 
                     if depth == 0 {
-                        trace!("Reached end of unknown block");
+                        logging::trace!("Reached end of unknown block");
                         break;
                     }
                 }
                 other => {
-                    trace!("Eat unknown: {:?}", other);
+                    logging::trace!("Eat unknown: {:?}", other);
                 }
             }
         }
@@ -1,9 +1,10 @@
 use std::{
     fmt,
     io::{self, Read, Write},
+    string::FromUtf8Error,
 };
 
-use rbx_dom_weak::types::VariantType;
+use rbx_dom_weak::types::{AttributeError, VariantType};
 
 /// An error that can occur when deserializing an XML-format model or place.
 #[derive(Debug)]
@@ -32,12 +33,26 @@ impl DecodeError {
         }
     }
 
-    /// 1-based line number in the document where the error occured.
+    /// Creates an error that didn't happen at any particular place in the
+    /// document, like failing to open the file it's in.
+    pub(crate) fn new_without_position(kind: DecodeErrorKind) -> DecodeError {
+        DecodeError {
+            inner: Box::new(DecodeErrorImpl {
+                kind,
+                line: 0,
+                column: 0,
+            }),
+        }
+    }
+
+    /// 1-based line number in the document where the error occured, or 0 if
+    /// the error didn't happen while reading the document.
     pub fn line(&self) -> usize {
         self.inner.line
     }
 
-    /// 1-based column number in the document where the error occured.
+    /// 1-based column number in the document where the error occured, or 0 if
+    /// the error didn't happen while reading the document.
     pub fn column(&self) -> usize {
         self.inner.column
     }
@@ -45,6 +60,10 @@ impl DecodeError {
 
 impl fmt::Display for DecodeError {
     fn fmt(&self, output: &mut fmt::Formatter) -> fmt::Result {
+        if self.inner.line == 0 {
+            return write!(output, "{}", self.inner.kind);
+        }
+
         write!(
             output,
             "line {}, column {}: {}",
@@ -69,6 +88,7 @@ struct DecodeErrorImpl {
 #[derive(Debug)]
 pub(crate) enum DecodeErrorKind {
     // Errors from other crates
+    Io(io::Error),
     Xml(xml::reader::Error),
     ParseFloat(std::num::ParseFloatError),
     ParseInt(std::num::ParseIntError),
@@ -99,6 +119,7 @@ impl fmt::Display for DecodeErrorKind {
         use self::DecodeErrorKind::*;
 
         match self {
+            Io(err) => write!(output, "{}", err),
             Xml(err) => write!(output, "{}", err),
             ParseFloat(err) => write!(output, "{}", err),
             ParseInt(err) => write!(output, "{}", err),
@@ -147,6 +168,7 @@ impl std::error::Error for DecodeErrorKind {
         use self::DecodeErrorKind::*;
 
         match self {
+            Io(err) => Some(err),
             Xml(err) => Some(err),
             ParseFloat(err) => Some(err),
             ParseInt(err) => Some(err),
@@ -164,6 +186,12 @@ impl std::error::Error for DecodeErrorKind {
     }
 }
 
+impl From<io::Error> for DecodeErrorKind {
+    fn from(error: io::Error) -> DecodeErrorKind {
+        DecodeErrorKind::Io(error)
+    }
+}
+
 impl From<xml::reader::Error> for DecodeErrorKind {
     fn from(error: xml::reader::Error) -> DecodeErrorKind {
         DecodeErrorKind::Xml(error)
@@ -200,6 +228,10 @@ impl EncodeError {
         kind: EncodeErrorKind,
         _writer: &xml::EventWriter<W>,
     ) -> EncodeError {
+        EncodeError::new(kind)
+    }
+
+    pub(crate) fn new(kind: EncodeErrorKind) -> EncodeError {
         EncodeError {
             kind: Box::new(kind),
         }
@@ -222,6 +254,7 @@ impl std::error::Error for EncodeError {
 pub(crate) enum EncodeErrorKind {
     Io(io::Error),
     Xml(xml::writer::Error),
+    InvalidUtf8(FromUtf8Error),
 
     UnknownProperty {
         class_name: String,
@@ -235,6 +268,7 @@ pub(crate) enum EncodeErrorKind {
         actual_type: VariantType,
         message: String,
     },
+    InvalidAttributes(AttributeError),
 }
 
 impl fmt::Display for EncodeErrorKind {
@@ -244,6 +278,7 @@ impl fmt::Display for EncodeErrorKind {
         match self {
             Io(err) => write!(output, "{}", err),
             Xml(err) => write!(output, "{}", err),
+            InvalidUtf8(err) => write!(output, "The encoded output is not valid UTF-8: {}", err),
 
             UnknownProperty {
                 class_name,
@@ -268,6 +303,7 @@ impl fmt::Display for EncodeErrorKind {
                  When trying to convert the value, this error occured: {}",
                 class_name, property_name, expected_type, actual_type, message
             ),
+            InvalidAttributes(err) => write!(output, "Could not encode attributes: {}", err),
         }
     }
 }
@@ -279,6 +315,8 @@ impl std::error::Error for EncodeErrorKind {
         match self {
             Io(err) => Some(err),
             Xml(err) => Some(err),
+            InvalidUtf8(err) => Some(err),
+            InvalidAttributes(err) => Some(err),
 
             UnknownProperty { .. }
             | UnsupportedPropertyType(_)
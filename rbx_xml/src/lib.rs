@@ -88,6 +88,9 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! To get the XML as a `String` instead, use [`to_string_default`] or
+//! [`to_string`].
+//!
 //! ## Configuration
 //! rbx_xml exposes no useful configuration yet, but there are methods that
 //! accept [`DecodeOptions`][DecodeOptions] and
@@ -110,21 +113,36 @@ mod core;
 mod deserializer;
 mod deserializer_core;
 mod error;
+mod logging;
 mod serializer;
 mod serializer_core;
 mod types;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 #[cfg(test)]
 mod test_util;
 
-use std::io::{Read, Write};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
 
-use rbx_dom_weak::{types::Ref, WeakDom};
+use rbx_dom_weak::{types::Ref, Instance, WeakDom};
 
-use crate::{deserializer::decode_internal, serializer::encode_internal};
+use crate::{
+    deserializer::{decode_internal, decode_internal_full},
+    error::{DecodeErrorKind, EncodeErrorKind},
+    serializer::{encode_internal, encode_subset_internal},
+};
 
 pub use crate::{
-    deserializer::{DecodeOptions, DecodePropertyBehavior},
+    deserializer::{
+        DecodeOptions, DecodeOutput, DecodePropertyBehavior, DecodeStats, ReferentMappingStrategy,
+        UnknownProperty, UnknownPropertyAction,
+    },
     error::{DecodeError, EncodeError},
     serializer::{EncodeOptions, EncodePropertyBehavior},
 };
@@ -135,6 +153,16 @@ pub fn from_reader<R: Read>(reader: R, options: DecodeOptions) -> Result<WeakDom
     decode_internal(reader, options)
 }
 
+/// Decodes an XML-format model or place from something that implements the
+/// `std::io::Read` trait, returning extra information about the file along
+/// with the DOM.
+pub fn from_reader_with_output<R: Read>(
+    reader: R,
+    options: DecodeOptions,
+) -> Result<DecodeOutput, DecodeError> {
+    decode_internal_full(reader, options)
+}
+
 /// Decodes an XML-format model or place from something that implements the
 /// `std::io::Read` trait using the default decoder options.
 pub fn from_reader_default<R: Read>(reader: R) -> Result<WeakDom, DecodeError> {
@@ -152,6 +180,34 @@ pub fn from_str_default<S: AsRef<str>>(reader: S) -> Result<WeakDom, DecodeError
     decode_internal(reader.as_ref().as_bytes(), DecodeOptions::default())
 }
 
+/// Decodes an XML-format model or place from a slice of bytes.
+pub fn decode_from_slice_with_options(
+    slice: &[u8],
+    options: DecodeOptions,
+) -> Result<WeakDom, DecodeError> {
+    decode_internal(slice, options)
+}
+
+/// Decodes an XML-format model or place from a slice of bytes using the default
+/// decoder options.
+pub fn decode_from_slice(slice: &[u8]) -> Result<WeakDom, DecodeError> {
+    decode_internal(slice, DecodeOptions::default())
+}
+
+/// Decodes an XML-format model or place from the file at the given path.
+pub fn from_file<P: AsRef<Path>>(path: P, options: DecodeOptions) -> Result<WeakDom, DecodeError> {
+    let file = File::open(path)
+        .map_err(|err| DecodeError::new_without_position(DecodeErrorKind::Io(err)))?;
+
+    decode_internal(BufReader::new(file), options)
+}
+
+/// Decodes an XML-format model or place from the file at the given path using
+/// the default decoder options.
+pub fn from_file_default<P: AsRef<Path>>(path: P) -> Result<WeakDom, DecodeError> {
+    from_file(path, DecodeOptions::default())
+}
+
 /// Serializes a subset of the given tree to an XML format model or place,
 /// writing to something that implements the `std::io::Write` trait.
 pub fn to_writer<W: Write>(
@@ -173,3 +229,89 @@ pub fn to_writer_default<W: Write>(
 ) -> Result<(), EncodeError> {
     encode_internal(writer, tree, ids, EncodeOptions::default())
 }
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// leaving out every instance that `predicate` returns `false` for, along with
+/// all of its descendants.
+///
+/// Ref properties that point to instances that were left out are written as
+/// null.
+pub fn encode_subset<W, F>(
+    writer: W,
+    tree: &WeakDom,
+    ids: &[Ref],
+    predicate: F,
+    options: EncodeOptions,
+) -> Result<(), EncodeError>
+where
+    W: Write,
+    F: Fn(&Instance) -> bool,
+{
+    encode_subset_internal(writer, tree, ids, predicate, options)
+}
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// returning the encoded bytes.
+pub fn encode_to_vec_with_options(
+    tree: &WeakDom,
+    ids: &[Ref],
+    options: EncodeOptions,
+) -> Result<Vec<u8>, EncodeError> {
+    let mut buffer = Vec::new();
+    encode_internal(&mut buffer, tree, ids, options)?;
+
+    Ok(buffer)
+}
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// returning the encoded bytes using the default encoder options.
+pub fn encode_to_vec(tree: &WeakDom, ids: &[Ref]) -> Result<Vec<u8>, EncodeError> {
+    encode_to_vec_with_options(tree, ids, EncodeOptions::default())
+}
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// returning the XML as a string.
+///
+/// rbx_xml always writes UTF-8, but if the output is somehow not valid UTF-8,
+/// an error is returned instead of a lossy string.
+pub fn to_string(
+    tree: &WeakDom,
+    ids: &[Ref],
+    options: EncodeOptions,
+) -> Result<String, EncodeError> {
+    let buffer = encode_to_vec_with_options(tree, ids, options)?;
+
+    String::from_utf8(buffer).map_err(|err| EncodeError::new(EncodeErrorKind::InvalidUtf8(err)))
+}
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// returning the XML as a string using the default encoder options.
+pub fn to_string_default(tree: &WeakDom, ids: &[Ref]) -> Result<String, EncodeError> {
+    to_string(tree, ids, EncodeOptions::default())
+}
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// creating or replacing the file at the given path.
+pub fn to_file<P: AsRef<Path>>(
+    path: P,
+    tree: &WeakDom,
+    ids: &[Ref],
+    options: EncodeOptions,
+) -> Result<(), EncodeError> {
+    let io_error = |err| EncodeError::new(EncodeErrorKind::Io(err));
+
+    let mut writer = BufWriter::new(File::create(path).map_err(io_error)?);
+    encode_internal(&mut writer, tree, ids, options)?;
+    writer.flush().map_err(io_error)
+}
+
+/// Serializes a subset of the given tree to an XML format model or place,
+/// creating or replacing the file at the given path using the default encoder
+/// options.
+pub fn to_file_default<P: AsRef<Path>>(
+    path: P,
+    tree: &WeakDom,
+    ids: &[Ref],
+) -> Result<(), EncodeError> {
+    to_file(path, tree, ids, EncodeOptions::default())
+}
@@ -0,0 +1,10 @@
+//! Chooses where diagnostics are sent. By default, rbx_xml logs through the
+//! `log` crate. With the `tracing` feature enabled, the same messages are
+//! emitted as `tracing` events instead, and decoding and encoding are wrapped
+//! in spans with structured fields like `type_name` and `prop_name`.
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use log::{debug, trace, warn};
+
+#[cfg(feature = "tracing")]
+pub(crate) use tracing::{debug, trace, warn};
@@ -1,11 +1,12 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
     io::Write,
 };
 
 use rbx_dom_weak::{
-    types::{Ref, SharedString, SharedStringHash, Variant, VariantType},
-    WeakDom,
+    types::{Enum, Ref, SharedString, SharedStringHash, Variant, VariantType},
+    Instance, WeakDom,
 };
 use rbx_reflection::DataType;
 
@@ -13,7 +14,8 @@ use crate::{
     conversion::ConvertVariant,
     core::find_serialized_property_descriptor,
     error::{EncodeError as NewEncodeError, EncodeErrorKind},
-    types::write_value_xml,
+    logging,
+    types::{write_enum_with_name, write_value_xml},
 };
 
 use crate::serializer_core::{XmlEventWriter, XmlWriteEvent};
@@ -24,8 +26,62 @@ pub fn encode_internal<W: Write>(
     ids: &[Ref],
     options: EncodeOptions,
 ) -> Result<(), NewEncodeError> {
-    let mut writer = XmlEventWriter::from_output(output);
+    let ids = root_ids(tree, ids, &options);
+    encode_with_state(output, tree, &ids, EmitState::new(options))
+}
+
+pub fn encode_subset_internal<W, F>(
+    output: W,
+    tree: &WeakDom,
+    ids: &[Ref],
+    predicate: F,
+    options: EncodeOptions,
+) -> Result<(), NewEncodeError>
+where
+    W: Write,
+    F: Fn(&Instance) -> bool,
+{
+    let ids = root_ids(tree, ids, &options);
+
+    // We need to know every instance that will be written before writing any
+    // of them so that Ref properties pointing to excluded instances can be
+    // written as null.
+    let mut included = HashSet::new();
+    let mut to_visit: Vec<Ref> = ids.to_vec();
+
+    while let Some(id) = to_visit.pop() {
+        let instance = tree.get_by_ref(id).unwrap();
+
+        if predicate(instance) {
+            included.insert(id);
+            to_visit.extend(instance.children());
+        }
+    }
+
     let mut state = EmitState::new(options);
+    state.included = Some(included);
+
+    encode_with_state(output, tree, &ids, state)
+}
+
+/// Returns the instances that should be written at the top level of the file.
+/// If the format should be detected automatically, every `DataModel` in `ids`
+/// is replaced by its children.
+fn root_ids<'a>(tree: &WeakDom, ids: &'a [Ref], options: &EncodeOptions) -> Cow<'a, [Ref]> {
+    if options.auto_detect_format {
+        Cow::Owned(tree.expand_data_models(ids))
+    } else {
+        Cow::Borrowed(ids)
+    }
+}
+
+fn encode_with_state<W: Write>(
+    output: W,
+    tree: &WeakDom,
+    ids: &[Ref],
+    mut state: EmitState,
+) -> Result<(), NewEncodeError> {
+    let mut writer = XmlEventWriter::from_output(output);
 
     writer.write(XmlWriteEvent::start_element("roblox").attr("version", "4"))?;
 
@@ -75,6 +131,11 @@ pub enum EncodePropertyBehavior {
 #[derive(Debug, Clone)]
 pub struct EncodeOptions {
     property_behavior: EncodePropertyBehavior,
+    sort_children_by_name: bool,
+    skip_non_archivable: bool,
+    include_default_properties: bool,
+    include_enum_names: bool,
+    auto_detect_format: bool,
 }
 
 impl EncodeOptions {
@@ -83,6 +144,11 @@ impl EncodeOptions {
     pub fn new() -> Self {
         EncodeOptions {
             property_behavior: EncodePropertyBehavior::IgnoreUnknown,
+            sort_children_by_name: false,
+            skip_non_archivable: false,
+            include_default_properties: false,
+            include_enum_names: false,
+            auto_detect_format: false,
         }
     }
 
@@ -90,7 +156,84 @@ impl EncodeOptions {
     /// ones.
     #[inline]
     pub fn property_behavior(self, property_behavior: EncodePropertyBehavior) -> Self {
-        EncodeOptions { property_behavior }
+        EncodeOptions {
+            property_behavior,
+            ..self
+        }
+    }
+
+    /// Determines whether the children of each instance will be sorted by
+    /// name, instead of keeping the order they have in the tree. Children with
+    /// the same name are ordered by class name, then by their order in the
+    /// tree.
+    ///
+    /// This makes the output the same no matter what order instances were
+    /// inserted in, which keeps diffs of files in version control small.
+    #[inline]
+    pub fn sort_children_by_name(self, sort_children_by_name: bool) -> Self {
+        EncodeOptions {
+            sort_children_by_name,
+            ..self
+        }
+    }
+
+    /// Determines whether instances that Roblox wouldn't save will be left out
+    /// of the output, along with all of their descendants. This matches what
+    /// Roblox does when saving a file.
+    ///
+    /// An instance isn't saved if its `Archivable` property is false. See
+    /// `ReflectionDatabase::is_archivable`.
+    #[inline]
+    pub fn skip_non_archivable(self, skip_non_archivable: bool) -> Self {
+        EncodeOptions {
+            skip_non_archivable,
+            ..self
+        }
+    }
+
+    /// Determines whether properties equal to their default value from the
+    /// reflection database will be written. Defaults to `false`, which leaves
+    /// them out of the output like Roblox Studio does. Writing every property
+    /// is useful for debugging.
+    ///
+    /// Properties without a known default value are always written.
+    #[inline]
+    pub fn include_default_properties(self, include_default_properties: bool) -> Self {
+        EncodeOptions {
+            include_default_properties,
+            ..self
+        }
+    }
+
+    /// Determines whether enum properties will be written with the name of
+    /// their item alongside their value, like
+    /// `<token name="Material" value="256">SmoothPlastic</token>`. Defaults to
+    /// `false`, which writes only the value.
+    ///
+    /// Names only appear in the output to make it easier to read. They're
+    /// ignored when decoding, and enums that aren't described by the reflection
+    /// database are always written without one.
+    #[inline]
+    pub fn include_enum_names(self, include_enum_names: bool) -> Self {
+        EncodeOptions {
+            include_enum_names,
+            ..self
+        }
+    }
+
+    /// Determines whether rbx_xml will decide between writing a model and
+    /// writing a place based on the instances it's given.
+    ///
+    /// Places don't contain their `DataModel`, only its children. With this
+    /// set, any `DataModel` that's passed in to be serialized is replaced by
+    /// its children, so `&[tree.root_ref()]` writes a place if the root is a
+    /// `DataModel` and a model otherwise.
+    #[inline]
+    pub fn auto_detect_format(self, auto_detect_format: bool) -> Self {
+        EncodeOptions {
+            auto_detect_format,
+            ..self
+        }
     }
 
     pub(crate) fn use_reflection(&self) -> bool {
@@ -117,6 +260,10 @@ pub struct EmitState {
     /// A map of all shared strings referenced so far while generating XML. This
     /// map will be written as the file's SharedString dictionary.
     shared_strings_to_emit: BTreeMap<SharedStringHash, SharedString>,
+
+    /// If set, only these instances will be written. Ref properties that point
+    /// to other instances are written as null.
+    included: Option<HashSet<Ref>>,
 }
 
 impl EmitState {
@@ -126,6 +273,15 @@ impl EmitState {
             referent_map: HashMap::new(),
             next_referent: 0,
             shared_strings_to_emit: BTreeMap::new(),
+            included: None,
+        }
+    }
+
+    /// Tells whether the instance with the given ID will be written.
+    pub fn is_included(&self, id: Ref) -> bool {
+        match &self.included {
+            Some(included) => included.contains(&id),
+            None => true,
         }
     }
 
@@ -155,9 +311,29 @@ fn serialize_instance<'a, W: Write>(
     state: &mut EmitState,
     tree: &'a WeakDom,
     id: Ref,
-    property_buffer: &mut Vec<(&'a String, &'a Variant)>,
+    property_buffer: &mut Vec<(&'a str, &'a Variant)>,
 ) -> Result<(), NewEncodeError> {
     let instance = tree.get_by_ref(id).unwrap();
+
+    if !state.is_included(id) {
+        return Ok(());
+    }
+
+    if state.options.skip_non_archivable
+        && !rbx_reflection_database::get()
+            .is_archivable(&instance.class, instance.properties.get("Archivable"))
+    {
+        logging::debug!(
+            "Skipping non-archivable instance {} ({})",
+            instance.name,
+            instance.class
+        );
+        return Ok(());
+    }
+
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("encode_instance", type_name = %instance.class).entered();
+
     let mapped_id = state.map_id(id);
 
     writer.write(
@@ -177,10 +353,23 @@ fn serialize_instance<'a, W: Write>(
 
     // Move references to our properties into property_buffer so we can sort
     // them and iterate them in order.
-    property_buffer.extend(&instance.properties);
+    property_buffer.extend(
+        instance
+            .properties
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value)),
+    );
     property_buffer.sort_unstable_by_key(|(key, _)| *key);
 
     for (property_name, value) in property_buffer.drain(..) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!(
+            "encode_property",
+            type_name = %instance.class,
+            prop_name = property_name,
+        )
+        .entered();
+
         let maybe_serialized_descriptor = if state.options.use_reflection() {
             find_serialized_property_descriptor(&instance.class, property_name)
         } else {
@@ -188,6 +377,12 @@ fn serialize_instance<'a, W: Write>(
         };
 
         if let Some(serialized_descriptor) = maybe_serialized_descriptor {
+            if !state.options.include_default_properties
+                && is_default_value(&instance.class, property_name, value)
+            {
+                continue;
+            }
+
             let data_type = match &serialized_descriptor.data_type {
                 DataType::Value(data_type) => *data_type,
                 DataType::Enum(_enum_name) => VariantType::Enum,
@@ -209,7 +404,23 @@ fn serialize_instance<'a, W: Write>(
                 }
             };
 
-            write_value_xml(writer, state, &serialized_descriptor.name, &converted_value)?;
+            let named_enum = match (&serialized_descriptor.data_type, converted_value.as_ref()) {
+                (DataType::Enum(enum_name), Variant::Enum(value))
+                    if state.options.include_enum_names =>
+                {
+                    find_enum_item_name(enum_name, *value).map(|item_name| (*value, item_name))
+                }
+                _ => None,
+            };
+
+            match named_enum {
+                Some((value, item_name)) => {
+                    write_enum_with_name(writer, &serialized_descriptor.name, value, item_name)?
+                }
+                None => {
+                    write_value_xml(writer, state, &serialized_descriptor.name, &converted_value)?
+                }
+            }
         } else {
             match state.options.property_behavior {
                 EncodePropertyBehavior::IgnoreUnknown => {}
@@ -222,7 +433,7 @@ fn serialize_instance<'a, W: Write>(
                 EncodePropertyBehavior::ErrorOnUnknown => {
                     return Err(writer.error(EncodeErrorKind::UnknownProperty {
                         class_name: instance.class.clone(),
-                        property_name: property_name.clone(),
+                        property_name: property_name.to_owned(),
                     }));
                 }
             }
@@ -231,8 +442,22 @@ fn serialize_instance<'a, W: Write>(
 
     writer.write(XmlWriteEvent::end_element())?;
 
-    for child_id in instance.children() {
-        serialize_instance(writer, state, tree, *child_id, property_buffer)?;
+    if state.options.sort_children_by_name {
+        let mut children: Vec<&Instance> = instance
+            .children()
+            .iter()
+            .map(|child_id| tree.get_by_ref(*child_id).unwrap())
+            .collect();
+
+        children.sort_by(|a, b| (&a.name, &a.class).cmp(&(&b.name, &b.class)));
+
+        for child in children {
+            serialize_instance(writer, state, tree, child.referent(), property_buffer)?;
+        }
+    } else {
+        for child_id in instance.children() {
+            serialize_instance(writer, state, tree, *child_id, property_buffer)?;
+        }
     }
 
     writer.write(XmlWriteEvent::end_element())?;
@@ -240,6 +465,17 @@ fn serialize_instance<'a, W: Write>(
     Ok(())
 }
 
+/// Finds the name of the item in the given enum that has the given value.
+fn find_enum_item_name(enum_name: &str, value: Enum) -> Option<&'static str> {
+    let descriptor = rbx_reflection_database::get().enums.get(enum_name)?;
+
+    descriptor
+        .items
+        .iter()
+        .find(|(_, &item_value)| item_value == value.to_u32())
+        .map(|(item_name, _)| item_name.as_ref())
+}
+
 fn serialize_shared_strings<W: Write>(
     writer: &mut XmlEventWriter<W>,
     state: &mut EmitState,
@@ -268,3 +504,388 @@ fn serialize_shared_strings<W: Write>(
     writer.end_element()?;
     Ok(())
 }
+
+/// Tells whether `value` is the default value of the given property. Scripts
+/// hold their source as a `ProtectedString`, while the reflection database's
+/// defaults are plain `String`s, so those are compared by their contents.
+fn is_default_value(class_name: &str, property_name: &str, value: &Variant) -> bool {
+    let database = rbx_reflection_database::get();
+
+    match (
+        value,
+        database.find_default_property(class_name, property_name),
+    ) {
+        (Variant::ProtectedString(value), Some(Variant::String(default))) => {
+            AsRef::<str>::as_ref(value) == default
+        }
+        (_, Some(default)) => value == default,
+        (_, None) => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rbx_dom_weak::{
+        types::{Attributes, Ray, Tags, Vector3},
+        InstanceBuilder,
+    };
+
+    use crate::{DecodeOptions, DecodePropertyBehavior};
+
+    #[test]
+    fn sort_children_by_name() {
+        fn build(names: &[&str]) -> WeakDom {
+            WeakDom::new(
+                InstanceBuilder::new("Folder").with_children(names.iter().map(|name| {
+                    InstanceBuilder::new("StringValue")
+                        .with_name(*name)
+                        .with_property("Value", *name)
+                })),
+            )
+        }
+
+        let encode = |tree: &WeakDom| {
+            let mut buffer = Vec::new();
+            let options = EncodeOptions::new().sort_children_by_name(true);
+            encode_internal(&mut buffer, tree, &[tree.root_ref()], options)
+                .expect("failed to encode model");
+            String::from_utf8(buffer).unwrap()
+        };
+
+        let first = encode(&build(&["Charlie", "Alpha", "Bravo"]));
+        let second = encode(&build(&["Bravo", "Charlie", "Alpha"]));
+
+        assert_eq!(first, second);
+
+        let alpha = first.find("Alpha").unwrap();
+        let bravo = first.find("Bravo").unwrap();
+        let charlie = first.find("Charlie").unwrap();
+        assert!(alpha < bravo && bravo < charlie);
+    }
+
+    #[test]
+    fn skip_non_archivable() {
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder").with_name("Kept"))
+                .with_child(
+                    InstanceBuilder::new("Folder")
+                        .with_name("Skipped")
+                        .with_property("Archivable", false)
+                        .with_child(InstanceBuilder::new("Folder").with_name("Skipped Child")),
+                ),
+        );
+
+        let mut buffer = Vec::new();
+        let options = EncodeOptions::new().skip_non_archivable(true);
+        encode_internal(&mut buffer, &tree, &[tree.root_ref()], options)
+            .expect("failed to encode model");
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("Kept"));
+        assert!(!output.contains("Skipped"));
+    }
+
+    #[test]
+    fn auto_detect_format() {
+        let place = WeakDom::new(
+            InstanceBuilder::new("DataModel")
+                .with_child(
+                    InstanceBuilder::new("Workspace")
+                        .with_child(InstanceBuilder::new("Part").with_name("Part")),
+                )
+                .with_child(InstanceBuilder::new("Lighting")),
+        );
+        let model =
+            WeakDom::new(InstanceBuilder::new("Model").with_child(InstanceBuilder::new("Part")));
+
+        let top_level = |tree: &WeakDom, auto_detect_format| {
+            let mut buffer = Vec::new();
+            let options = EncodeOptions::new().auto_detect_format(auto_detect_format);
+            encode_internal(&mut buffer, tree, &[tree.root_ref()], options)
+                .expect("failed to encode");
+
+            let decoded = crate::from_reader_default(buffer.as_slice()).unwrap();
+            decoded
+                .root()
+                .children()
+                .iter()
+                .map(|&id| decoded.get_by_ref(id).unwrap().class.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(top_level(&place, true), ["Workspace", "Lighting"]);
+        assert_eq!(top_level(&place, false), ["DataModel"]);
+        assert_eq!(top_level(&model, true), ["Model"]);
+    }
+
+    #[test]
+    fn tags_round_trip() {
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("Folder"))
+                .with_child(
+                    InstanceBuilder::new("Folder")
+                        .with_property("Tags", Tags::from(&["Enemy"][..])),
+                )
+                .with_child(
+                    InstanceBuilder::new("Folder")
+                        .with_property("Tags", Tags::from(&["Enemy", "Boss", "Flying"][..])),
+                ),
+        );
+
+        let mut buffer = Vec::new();
+        encode_internal(
+            &mut buffer,
+            &tree,
+            tree.root().children(),
+            EncodeOptions::new(),
+        )
+        .expect("failed to encode");
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains(r#"<BinaryString name="Tags"><![CDATA[RW5lbXk=]]></BinaryString>"#));
+
+        let decoded = crate::from_str_default(&output).unwrap();
+        let tags: Vec<_> = decoded
+            .root()
+            .children()
+            .iter()
+            .map(|&id| decoded.get_by_ref(id).unwrap().properties.get("Tags"))
+            .collect();
+
+        assert_eq!(tags[0], None);
+        assert_eq!(tags[1], Some(&Variant::Tags(Tags::from(&["Enemy"][..]))));
+        assert_eq!(
+            tags[2],
+            Some(&Variant::Tags(Tags::from(&["Enemy", "Boss", "Flying"][..])))
+        );
+    }
+
+    #[test]
+    fn attributes_round_trip() {
+        let attributes: Attributes = vec![
+            ("Health", Variant::Float64(100.0)),
+            ("Team", Variant::String("Red".to_owned())),
+        ]
+        .into_iter()
+        .collect();
+
+        let tree = WeakDom::new(InstanceBuilder::new("Folder").with_child(
+            InstanceBuilder::new("Folder").with_property("Attributes", attributes.clone()),
+        ));
+
+        let mut buffer = Vec::new();
+        encode_internal(
+            &mut buffer,
+            &tree,
+            tree.root().children(),
+            EncodeOptions::new(),
+        )
+        .expect("failed to encode");
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains(r#"<BinaryString name="AttributesSerialize">"#));
+
+        let decoded = crate::from_str_default(&output).unwrap();
+        let child = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+
+        assert_eq!(
+            child.properties.get("Attributes"),
+            Some(&Variant::Attributes(attributes))
+        );
+    }
+
+    #[test]
+    fn include_enum_names() {
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Part").with_property("Material", Enum::from_u32(272)),
+        );
+
+        let encode = |options: EncodeOptions| {
+            let mut buffer = Vec::new();
+            encode_internal(&mut buffer, &tree, &[tree.root_ref()], options)
+                .expect("failed to encode model");
+            String::from_utf8(buffer).unwrap()
+        };
+
+        let numeric = encode(EncodeOptions::new());
+        assert!(numeric.contains(r#"<token name="Material">272</token>"#));
+
+        let named = encode(EncodeOptions::new().include_enum_names(true));
+        assert!(named.contains(r#"<token name="Material" value="272">SmoothPlastic</token>"#));
+
+        for output in &[numeric, named] {
+            let decoded = crate::from_str_default(output).unwrap();
+            let part = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+
+            assert_eq!(
+                part.properties.get("Material"),
+                Some(&Variant::Enum(Enum::from_u32(272)))
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip_protected_string() {
+        let source = r#"
+            <roblox version="4">
+                <Item class="Script" referent="RBX0">
+                    <Properties>
+                        <string name="Name">Script</string>
+                        <ProtectedString name="Source"><![CDATA[print("Hello, world!")
+]]></ProtectedString>
+                    </Properties>
+                </Item>
+            </roblox>
+        "#;
+
+        let tree = crate::from_str_default(source).unwrap();
+        let script = tree.get_by_ref(tree.root().children()[0]).unwrap();
+
+        assert_eq!(
+            script.properties.get("Source"),
+            Some(&Variant::ProtectedString(
+                "print(\"Hello, world!\")\n".into()
+            ))
+        );
+
+        let mut buffer = Vec::new();
+        encode_internal(
+            &mut buffer,
+            &tree,
+            tree.root().children(),
+            EncodeOptions::new(),
+        )
+        .expect("failed to encode model");
+        let output = String::from_utf8(buffer).unwrap();
+
+        assert!(output.contains("<ProtectedString name=\"Source\">"));
+
+        let decoded = crate::from_str_default(&output).unwrap();
+        let script = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+
+        assert_eq!(
+            script.properties.get("Source"),
+            Some(&Variant::ProtectedString(
+                "print(\"Hello, world!\")\n".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn round_trip_ray() {
+        let ray = Ray::new(Vector3::new(1.0, -2.5, 3.0), Vector3::new(0.0, -50.0, 0.0));
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Folder")
+                .with_child(InstanceBuilder::new("RayValue").with_property("Value", ray))
+                .with_child(InstanceBuilder::new("Folder").with_property("HypotheticalRay", ray)),
+        );
+
+        let round_trip = |encode_options: EncodeOptions, decode_options: DecodeOptions| {
+            let mut buffer = Vec::new();
+            encode_internal(&mut buffer, &tree, tree.root().children(), encode_options)
+                .expect("failed to encode model");
+            let output = String::from_utf8(buffer).unwrap();
+            let decoded = crate::from_str(&output, decode_options).unwrap();
+
+            (output, decoded)
+        };
+
+        let (output, decoded) = round_trip(EncodeOptions::new(), DecodeOptions::new());
+        assert!(output.contains("<Ray name=\"Value\">"));
+        assert!(!output.contains("HypotheticalRay"));
+
+        let children = decoded.root().children();
+        let value = decoded.get_by_ref(children[0]).unwrap();
+        assert_eq!(value.properties.get("Value"), Some(&Variant::Ray(ray)));
+
+        let (output, decoded) = round_trip(
+            EncodeOptions::new().property_behavior(EncodePropertyBehavior::NoReflection),
+            DecodeOptions::new().property_behavior(DecodePropertyBehavior::NoReflection),
+        );
+        assert!(output.contains("<Ray name=\"Value\">"));
+        assert!(output.contains("<Ray name=\"HypotheticalRay\">"));
+
+        let children = decoded.root().children();
+        let value = decoded.get_by_ref(children[0]).unwrap();
+        assert_eq!(value.properties.get("Value"), Some(&Variant::Ray(ray)));
+
+        let folder = decoded.get_by_ref(children[1]).unwrap();
+        assert_eq!(
+            folder.properties.get("HypotheticalRay"),
+            Some(&Variant::Ray(ray))
+        );
+    }
+
+    #[test]
+    fn include_default_properties() {
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Part")
+                .with_property("Anchored", false)
+                .with_property("Transparency", 0.0f32)
+                .with_property("Locked", true),
+        );
+
+        let encode = |options: EncodeOptions| {
+            let mut buffer = Vec::new();
+            encode_internal(&mut buffer, &tree, &[tree.root_ref()], options)
+                .expect("failed to encode model");
+            String::from_utf8(buffer).unwrap()
+        };
+
+        let minimal = encode(EncodeOptions::new());
+        assert!(minimal.contains("<string name=\"Name\">Part</string>"));
+        assert!(minimal.contains("<bool name=\"Locked\">true</bool>"));
+        assert!(!minimal.contains("Anchored"));
+        assert!(!minimal.contains("Transparency"));
+
+        let full = encode(EncodeOptions::new().include_default_properties(true));
+        assert!(full.contains("<bool name=\"Anchored\">false</bool>"));
+        assert!(full.contains("Transparency"));
+        assert!(full.contains("<bool name=\"Locked\">true</bool>"));
+    }
+
+    #[test]
+    fn default_properties_only() {
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Part")
+                .with_property("Anchored", false)
+                .with_property("CanCollide", true),
+        );
+
+        let mut buffer = Vec::new();
+        encode_internal(&mut buffer, &tree, &[tree.root_ref()], EncodeOptions::new())
+            .expect("failed to encode model");
+
+        let output = String::from_utf8(buffer).unwrap();
+        let properties = &output[output.find("<Properties>").unwrap()..];
+        let properties = &properties[..properties.find("</Properties>").unwrap()];
+
+        assert_eq!(
+            properties
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>(),
+            ["<Properties>", "<string name=\"Name\">Part</string>"]
+        );
+    }
+
+    #[test]
+    fn default_protected_string() {
+        let tree = WeakDom::new(
+            InstanceBuilder::new("Script")
+                .with_property("Source", Variant::ProtectedString("".into())),
+        );
+
+        let mut buffer = Vec::new();
+        encode_internal(&mut buffer, &tree, &[tree.root_ref()], EncodeOptions::new())
+            .expect("failed to encode model");
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(!output.contains("Source"));
+    }
+}
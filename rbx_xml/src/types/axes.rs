@@ -53,5 +53,15 @@ mod test {
     #[test]
     fn round_trip_axes() {
         test_util::test_xml_round_trip(&Axes::all());
+        test_util::test_xml_round_trip(&Axes::empty());
+    }
+
+    #[test]
+    fn deserialize_axes_out_of_range() {
+        let source = "<Axes name=\"foo\"><axes>8</axes></Axes>";
+        let mut reader = XmlEventReader::from_source(source.as_bytes());
+        reader.next().unwrap().unwrap(); // Eat StartDocument event
+
+        assert!(Axes::read_outer_xml(&mut reader).is_err());
     }
 }
@@ -23,9 +23,7 @@ impl XmlType for ColorSequence {
             writer.write_characters(keypoint.color.b)?;
             writer.write(XmlWriteEvent::characters(" "))?;
 
-            // Envelope is always 0 for ColorSequenceKeypoint. This value isn't
-            // exposed to developers but serializes in the XML format.
-            writer.write_characters(0)?;
+            writer.write_characters(keypoint.envelope)?;
             writer.write(XmlWriteEvent::characters(" "))?;
         }
 
@@ -58,14 +56,12 @@ impl XmlType for ColorSequence {
             let r = pieces.next().ok_or_else(wrong_length)??;
             let g = pieces.next().ok_or_else(wrong_length)??;
             let b = pieces.next().ok_or_else(wrong_length)??;
-
-            // This value is always zero, isn't developer-exposed, and doesn't
-            // have a corresponding field in rbx_dom_weak's type.
-            let _envelope = pieces.next().ok_or_else(wrong_length)??;
+            let envelope = pieces.next().ok_or_else(wrong_length)??;
 
             keypoints.push(ColorSequenceKeypoint {
                 time,
                 color: Color3::new(r, g, b),
+                envelope,
             });
         }
 
@@ -92,10 +88,12 @@ mod test {
                 ColorSequenceKeypoint {
                     time: 0.0,
                     color: Color3::new(0.0, 0.5, 1.0),
+                    envelope: 0.0,
                 },
                 ColorSequenceKeypoint {
                     time: 1.0,
                     color: Color3::new(1.0, 0.5, 0.0),
+                    envelope: 0.0,
                 },
             ],
         });
@@ -112,10 +110,12 @@ mod test {
                     ColorSequenceKeypoint {
                         time: 0.0,
                         color: Color3::new(0.0, 0.5, 1.0),
+                        envelope: 0.0,
                     },
                     ColorSequenceKeypoint {
                         time: 1.0,
                         color: Color3::new(1.0, 0.5, 0.0),
+                        envelope: 0.0,
                     },
                 ],
             },
@@ -133,13 +133,33 @@ mod test {
                     ColorSequenceKeypoint {
                         time: 0.0,
                         color: Color3::new(0.0, 0.5, 1.0),
+                        envelope: 0.0,
                     },
                     ColorSequenceKeypoint {
                         time: 1.0,
                         color: Color3::new(1.0, 0.5, 0.0),
+                        envelope: 0.0,
                     },
                 ],
             },
         );
     }
+
+    #[test]
+    fn round_trip_envelope() {
+        test_util::test_xml_round_trip(&ColorSequence {
+            keypoints: vec![
+                ColorSequenceKeypoint {
+                    time: 0.0,
+                    color: Color3::new(0.0, 0.5, 1.0),
+                    envelope: 0.25,
+                },
+                ColorSequenceKeypoint {
+                    time: 1.0,
+                    color: Color3::new(1.0, 0.5, 0.0),
+                    envelope: 0.0,
+                },
+            ],
+        });
+    }
 }
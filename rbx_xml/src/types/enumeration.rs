@@ -6,7 +6,7 @@ use crate::{
     core::XmlType,
     deserializer_core::XmlEventReader,
     error::{DecodeError, EncodeError},
-    serializer_core::XmlEventWriter,
+    serializer_core::{XmlEventWriter, XmlWriteEvent},
 };
 
 impl XmlType for Enum {
@@ -24,6 +24,49 @@ impl XmlType for Enum {
 
         Ok(Enum::from_u32(value))
     }
+
+    /// Enums are usually written as just their value, but they can also be
+    /// written with their value in a `value` attribute and the name of their
+    /// item as their contents, like
+    /// `<token name="Material" value="256">SmoothPlastic</token>`. In that
+    /// case, the name is ignored.
+    fn read_outer_xml<R: Read>(reader: &mut XmlEventReader<R>) -> Result<Self, DecodeError> {
+        let attributes = reader.expect_start_with_name(Self::XML_TAG_NAME)?;
+        let value_attribute = attributes
+            .into_iter()
+            .find(|attribute| attribute.name.local_name == "value");
+
+        let value = match value_attribute {
+            Some(attribute) => {
+                reader.read_characters()?;
+
+                let value: u32 = attribute.value.parse().map_err(|e| reader.error(e))?;
+                Enum::from_u32(value)
+            }
+            None => Self::read_xml(reader)?,
+        };
+
+        reader.expect_end_with_name(Self::XML_TAG_NAME)?;
+
+        Ok(value)
+    }
+}
+
+/// Writes an enum with its value in a `value` attribute and the name of its
+/// item as its contents, which `Enum::read_outer_xml` understands.
+pub fn write_enum_with_name<W: Write>(
+    writer: &mut XmlEventWriter<W>,
+    xml_property_name: &str,
+    value: Enum,
+    item_name: &str,
+) -> Result<(), EncodeError> {
+    writer.write(
+        XmlWriteEvent::start_element(Enum::XML_TAG_NAME)
+            .attr("name", xml_property_name)
+            .attr("value", &value.to_u32().to_string()),
+    )?;
+    writer.write_string(item_name)?;
+    writer.write(XmlWriteEvent::end_element())
 }
 
 #[cfg(test)]
@@ -36,4 +79,32 @@ mod test {
     fn round_trip() {
         test_util::test_xml_round_trip(&Enum::from_u32(4654321));
     }
+
+    #[test]
+    fn deserialize_with_name() {
+        test_util::test_xml_deserialize(
+            r#"<token name="foo" value="256">SmoothPlastic</token>"#,
+            &Enum::from_u32(256),
+        );
+    }
+
+    #[test]
+    fn round_trip_with_name() {
+        let mut buffer = Vec::new();
+        let mut writer = XmlEventWriter::from_output(&mut buffer);
+        write_enum_with_name(&mut writer, "foo", Enum::from_u32(256), "SmoothPlastic").unwrap();
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<token name="foo" value="256">SmoothPlastic</token>"#
+        );
+
+        let mut reader = XmlEventReader::from_source(buffer.as_slice());
+        reader.next().unwrap().unwrap(); // Eat StartDocument event
+
+        assert_eq!(
+            Enum::read_outer_xml(&mut reader).unwrap(),
+            Enum::from_u32(256)
+        );
+    }
 }
@@ -56,4 +56,13 @@ mod test {
         test_util::test_xml_round_trip(&Faces::all());
         test_util::test_xml_round_trip(&Faces::empty());
     }
+
+    #[test]
+    fn deserialize_faces_out_of_range() {
+        let source = "<Faces name=\"foo\"><faces>64</faces></Faces>";
+        let mut reader = XmlEventReader::from_source(source.as_bytes());
+        reader.next().unwrap().unwrap(); // Eat StartDocument event
+
+        assert!(Faces::read_outer_xml(&mut reader).is_err());
+    }
 }
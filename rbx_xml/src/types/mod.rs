@@ -33,8 +33,8 @@ use std::io::{Read, Write};
 
 use rbx_dom_weak::types::{
     Axes, BinaryString, CFrame, Color3, Color3uint8, ColorSequence, Content, Enum, Faces,
-    NumberRange, NumberSequence, PhysicalProperties, Ray, Rect, Ref, UDim, UDim2, Variant, Vector2,
-    Vector2int16, Vector3, Vector3int16,
+    NumberRange, NumberSequence, PhysicalProperties, ProtectedString, Ray, Rect, Ref, UDim, UDim2,
+    Variant, Vector2, Vector2int16, Vector3, Vector3int16,
 };
 
 use crate::{
@@ -46,6 +46,8 @@ use crate::{
     serializer_core::XmlEventWriter,
 };
 
+pub use self::enumeration::write_enum_with_name;
+
 use self::{
     referent::{read_ref, write_ref},
     shared_string::{read_shared_string, write_shared_string},
@@ -69,12 +71,6 @@ macro_rules! declare_rbx_types {
             match xml_type_name {
                 $(<$inner_type>::XML_TAG_NAME => Ok(Some(Variant::$variant_name(<$inner_type>::read_outer_xml(reader)?))),)*
 
-                // Protected strings are only read, never written
-                self::strings::ProtectedStringDummy::XML_TAG_NAME => {
-                    let value = self::strings::ProtectedStringDummy::read_outer_xml(reader)?;
-                    Ok(Some(Variant::String(value.0)))
-                },
-
                 self::referent::XML_TAG_NAME => Ok(Some(Variant::Ref(read_ref(reader, instance_id, property_name, state)?))),
                 self::shared_string::XML_TAG_NAME => read_shared_string(reader, instance_id, property_name, state).map(Some),
 
@@ -103,6 +99,21 @@ macro_rules! declare_rbx_types {
                 Variant::BrickColor(value) =>
                     (*value as i32).write_outer_xml(xml_property_name, writer),
 
+                // Tags have no XML type of their own and are stored as a
+                // null-delimited BinaryString, like in the binary format.
+                Variant::Tags(value) =>
+                    BinaryString::from(value.encode()).write_outer_xml(xml_property_name, writer),
+
+                // Attributes are stored the same way as in the binary format,
+                // as a BinaryString.
+                Variant::Attributes(value) => {
+                    let buffer = value.encode().map_err(|err| {
+                        writer.error(EncodeErrorKind::InvalidAttributes(err))
+                    })?;
+
+                    BinaryString::from(buffer).write_outer_xml(xml_property_name, writer)
+                }
+
                 Variant::Ref(value) => write_ref(writer, xml_property_name, *value, state),
                 Variant::SharedString(value) => write_shared_string(writer, xml_property_name, value, state),
 
@@ -133,6 +144,7 @@ declare_rbx_types! {
     NumberSequence: NumberSequence,
     OptionalCFrame: Option<CFrame>,
     PhysicalProperties: PhysicalProperties,
+    ProtectedString: ProtectedString,
     Ray: Ray,
     Rect: Rect,
     String: String,
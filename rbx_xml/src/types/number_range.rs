@@ -9,13 +9,15 @@ use crate::{
     serializer_core::{XmlEventWriter, XmlWriteEvent},
 };
 
+use super::numbers::XmlFloat;
+
 impl XmlType for NumberRange {
     const XML_TAG_NAME: &'static str = "NumberRange";
 
     fn write_xml<W: Write>(&self, writer: &mut XmlEventWriter<W>) -> Result<(), EncodeError> {
-        writer.write_characters(self.min)?;
+        self.min.write_xml(writer)?;
         writer.write(XmlWriteEvent::characters(" "))?;
-        writer.write_characters(self.max)?;
+        self.max.write_xml(writer)?;
         writer.write(XmlWriteEvent::characters(" "))?;
 
         Ok(())
@@ -26,7 +28,7 @@ impl XmlType for NumberRange {
         let mut pieces = contents
             .split(' ')
             .filter(|slice| !slice.is_empty())
-            .map(|piece| piece.parse::<f32>().map_err(|e| reader.error(e)));
+            .map(|piece| f32::parse_xml(piece).map_err(|e| reader.error(e)));
 
         let min = pieces
             .next()
@@ -72,6 +74,73 @@ mod test {
         );
     }
 
+    #[test]
+    fn round_trip_negative_number_range() {
+        test_util::test_xml_round_trip(&NumberRange {
+            min: -10.25,
+            max: -0.5,
+        });
+    }
+
+    #[test]
+    fn deserialize_number_range_non_finite() {
+        test_util::test_xml_deserialize(
+            r#"
+                <NumberRange name="foo">-INF INF </NumberRange>
+            "#,
+            &NumberRange {
+                min: f32::NEG_INFINITY,
+                max: f32::INFINITY,
+            },
+        );
+
+        let mut reader = XmlEventReader::from_source(
+            r#"<NumberRange name="foo">NAN 5 </NumberRange>"#.as_bytes(),
+        );
+        reader.next().unwrap().unwrap(); // Eat StartDocument event
+        let value = NumberRange::read_outer_xml(&mut reader).unwrap();
+        assert!(value.min.is_nan());
+        assert_eq!(value.max, 5.0);
+    }
+
+    #[test]
+    fn serialize_number_range_non_finite() {
+        test_util::test_xml_serialize(
+            r#"
+                <NumberRange name="foo">-INF INF </NumberRange>
+            "#,
+            &NumberRange {
+                min: f32::NEG_INFINITY,
+                max: f32::INFINITY,
+            },
+        );
+
+        test_util::test_xml_serialize(
+            r#"
+                <NumberRange name="foo">NAN 0 </NumberRange>
+            "#,
+            &NumberRange {
+                min: f32::NAN,
+                max: 0.0,
+            },
+        );
+    }
+
+    #[test]
+    fn deserialize_number_range_malformed() {
+        for contents in &["", "1", "1 2 3", "1 x"] {
+            let document = format!(r#"<NumberRange name="foo">{}</NumberRange>"#, contents);
+            let mut reader = XmlEventReader::from_source(document.as_bytes());
+            reader.next().unwrap().unwrap(); // Eat StartDocument event
+
+            assert!(
+                NumberRange::read_outer_xml(&mut reader).is_err(),
+                "{:?} should not parse",
+                contents
+            );
+        }
+    }
+
     #[test]
     fn serialize_number_range() {
         test_util::test_xml_serialize(
@@ -1,4 +1,7 @@
-use std::io::{Read, Write};
+use std::{
+    io::{Read, Write},
+    num::ParseFloatError,
+};
 
 use crate::{
     core::XmlType,
@@ -7,8 +10,26 @@ use crate::{
     serializer_core::XmlEventWriter,
 };
 
+/// Parses floats the way Roblox writes them, which spells out infinity and NaN.
+/// Types that pack several floats into one string, like `NumberRange`, use this
+/// to read each piece.
+pub(crate) trait XmlFloat: Sized {
+    fn parse_xml(contents: &str) -> Result<Self, ParseFloatError>;
+}
+
 macro_rules! float_type {
     ($rust_type: ident, $xml_name: expr) => {
+        impl XmlFloat for $rust_type {
+            fn parse_xml(contents: &str) -> Result<Self, ParseFloatError> {
+                match contents {
+                    "INF" => Ok(std::$rust_type::INFINITY),
+                    "-INF" => Ok(std::$rust_type::NEG_INFINITY),
+                    "NAN" => Ok(std::$rust_type::NAN),
+                    number => number.parse(),
+                }
+            }
+        }
+
         impl XmlType for $rust_type {
             const XML_TAG_NAME: &'static str = $xml_name;
 
@@ -30,12 +51,7 @@ macro_rules! float_type {
             fn read_xml<R: Read>(reader: &mut XmlEventReader<R>) -> Result<Self, DecodeError> {
                 let contents = reader.read_characters()?;
 
-                Ok(match contents.as_str() {
-                    "INF" => std::$rust_type::INFINITY,
-                    "-INF" => std::$rust_type::NEG_INFINITY,
-                    "NAN" => std::$rust_type::NAN,
-                    number => number.parse().map_err(|e| reader.error(e))?,
-                })
+                Self::parse_xml(&contents).map_err(|e| reader.error(e))
             }
         }
     };
@@ -124,4 +140,27 @@ mod test {
 
         test_util::test_xml_serialize(r#"<float name="foo">NAN</float>"#, &std::f32::NAN);
     }
+
+    #[test]
+    fn test_inf_and_nan_deserialize_f64() {
+        test_util::test_xml_deserialize(r#"<double name="foo">INF</double>"#, &f64::INFINITY);
+
+        test_util::test_xml_deserialize(r#"<double name="foo">-INF</double>"#, &f64::NEG_INFINITY);
+
+        #[allow(clippy::string_lit_as_bytes)]
+        let mut reader =
+            XmlEventReader::from_source(r#"<double name="foo">NAN</double>"#.as_bytes());
+        reader.next().unwrap().unwrap(); // Eat StartDocument event
+        let value = f64::read_outer_xml(&mut reader).unwrap();
+        assert!(value.is_nan());
+    }
+
+    #[test]
+    fn test_inf_and_nan_serialize_f64() {
+        test_util::test_xml_serialize(r#"<double name="foo">INF</double>"#, &f64::INFINITY);
+
+        test_util::test_xml_serialize(r#"<double name="foo">-INF</double>"#, &f64::NEG_INFINITY);
+
+        test_util::test_xml_serialize(r#"<double name="foo">NAN</double>"#, &f64::NAN);
+    }
 }
@@ -44,7 +44,7 @@ mod test {
     }
 
     #[test]
-    fn deserialize_rect() {
+    fn deserialize_ray() {
         test_util::test_xml_deserialize(
             r#"
                 <Ray name="Value">
@@ -67,6 +67,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn deserialize_ray_without_whitespace() {
+        test_util::test_xml_deserialize(
+            concat!(
+                r#"<Ray name="Value">"#,
+                "<origin><X>-1</X><Y>0</Y><Z>1.5</Z></origin>",
+                "<direction><X>0</X><Y>-50</Y><Z>0</Z></direction>",
+                "</Ray>",
+            ),
+            &Ray {
+                origin: Vector3::new(-1.0, 0.0, 1.5),
+                direction: Vector3::new(0.0, -50.0, 0.0),
+            },
+        );
+    }
+
     #[test]
     fn serialize_ray() {
         test_util::test_xml_serialize(
@@ -31,7 +31,7 @@ pub fn write_ref<W: Write>(
 ) -> Result<(), EncodeError> {
     writer.write(XmlWriteEvent::start_element(XML_TAG_NAME).attr("name", xml_property_name))?;
 
-    if value.is_none() {
+    if value.is_none() || !state.is_included(value) {
         writer.write(XmlWriteEvent::characters("null"))?;
     } else {
         writer.write_characters(state.map_id(value))?;
@@ -1,5 +1,7 @@
 use std::io::{Read, Write};
 
+use rbx_dom_weak::types::ProtectedString;
+
 use crate::{
     core::XmlType,
     deserializer_core::XmlEventReader,
@@ -19,24 +21,21 @@ impl XmlType for String {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct ProtectedStringDummy(pub String);
-
-impl XmlType for ProtectedStringDummy {
+impl XmlType for ProtectedString {
     const XML_TAG_NAME: &'static str = "ProtectedString";
 
-    fn write_xml<W: Write>(&self, _writer: &mut XmlEventWriter<W>) -> Result<(), EncodeError> {
-        panic!("ProtectedString values are only read, never written.");
+    fn write_xml<W: Write>(&self, writer: &mut XmlEventWriter<W>) -> Result<(), EncodeError> {
+        writer.write_string(self.as_ref())
     }
 
     fn read_xml<R: Read>(reader: &mut XmlEventReader<R>) -> Result<Self, DecodeError> {
-        Ok(ProtectedStringDummy(reader.read_characters()?))
+        Ok(ProtectedString::from(reader.read_characters()?))
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::ProtectedStringDummy;
+    use super::*;
 
     use crate::test_util;
 
@@ -83,6 +82,11 @@ mod test {
             test_value
         );
 
-        test_util::test_xml_deserialize(&test_source, &ProtectedStringDummy(test_value.to_owned()));
+        test_util::test_xml_deserialize(&test_source, &ProtectedString::from(test_value));
+    }
+
+    #[test]
+    fn round_trip_protected_string() {
+        test_util::test_xml_round_trip(&ProtectedString::from("print(\"Hello, world!\")\n"));
     }
 }
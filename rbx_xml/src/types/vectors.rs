@@ -66,4 +66,62 @@ mod test {
     fn round_trip_vector3int16() {
         test_util::test_xml_round_trip(&Vector3int16::new(1234, 4567, 8913));
     }
+
+    #[test]
+    fn round_trip_int16_limits() {
+        test_util::test_xml_round_trip(&Vector2int16::new(i16::MIN, i16::MAX));
+        test_util::test_xml_round_trip(&Vector3int16::new(-1, i16::MIN, i16::MAX));
+    }
+
+    #[test]
+    fn serialize_vector2int16() {
+        test_util::test_xml_serialize(
+            r#"
+                <Vector2int16 name="foo">
+                    <X>-12</X>
+                    <Y>345</Y>
+                </Vector2int16>
+            "#,
+            &Vector2int16::new(-12, 345),
+        );
+    }
+
+    #[test]
+    fn deserialize_vector3int16() {
+        test_util::test_xml_deserialize(
+            r#"
+                <Vector3int16 name="foo">
+                    <X>-12</X>
+                    <Y>345</Y>
+                    <Z>0</Z>
+                </Vector3int16>
+            "#,
+            &Vector3int16::new(-12, 345, 0),
+        );
+    }
+
+    /// Missing or out-of-range components are errors rather than being
+    /// replaced with 0, so that bad files aren't silently changed.
+    #[test]
+    fn deserialize_invalid_int16_vectors() {
+        let documents = [
+            r#"<Vector2int16 name="foo"><X>1</X></Vector2int16>"#,
+            r#"<Vector2int16 name="foo"><X>1</X><Y>32768</Y></Vector2int16>"#,
+            r#"<Vector3int16 name="foo"><X>1</X><Y>2</Y></Vector3int16>"#,
+            r#"<Vector3int16 name="foo"><X>1.5</X><Y>2</Y><Z>3</Z></Vector3int16>"#,
+        ];
+
+        for document in documents {
+            let mut reader = XmlEventReader::from_source(document.as_bytes());
+            reader.next().unwrap().unwrap(); // Eat StartDocument event
+
+            let result = if document.starts_with("<Vector2int16") {
+                Vector2int16::read_outer_xml(&mut reader).map(|_| ())
+            } else {
+                Vector3int16::read_outer_xml(&mut reader).map(|_| ())
+            };
+
+            assert!(result.is_err(), "{} should not parse", document);
+        }
+    }
 }
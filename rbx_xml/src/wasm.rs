@@ -0,0 +1,35 @@
+//! Bindings that expose [`encode_to_vec`][crate::encode_to_vec] and
+//! [`decode_from_slice`][crate::decode_from_slice] to JavaScript through
+//! wasm-bindgen, enabled by the `wasm` feature.
+//!
+//! JavaScript can't hold a `WeakDom` directly, so decoded files are returned as
+//! an opaque [`Dom`] handle that can be passed back to `encodeToVec`. The
+//! handle comes from [`rbx_dom_weak::wasm`], whose docs describe how to run
+//! these bindings' tests, since CI doesn't.
+
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+pub use rbx_dom_weak::wasm::Dom;
+
+/// Decodes an XML model or place from the given bytes.
+#[wasm_bindgen(js_name = decodeFromSlice)]
+pub fn decode_from_slice(bytes: &[u8]) -> Result<Dom, JsValue> {
+    crate::decode_from_slice(bytes)
+        .map(Dom::from)
+        .map_err(to_js_error)
+}
+
+/// Encodes every child of the DOM's root as an XML model, returning the
+/// encoded bytes.
+#[wasm_bindgen(js_name = encodeToVec)]
+pub fn encode_to_vec(dom: &Dom) -> Result<Uint8Array, JsValue> {
+    let dom = dom.inner();
+    let buffer = crate::encode_to_vec(dom, dom.root().children()).map_err(to_js_error)?;
+
+    Ok(Uint8Array::from(buffer.as_slice()))
+}
+
+fn to_js_error<E: ToString>(err: E) -> JsValue {
+    js_sys::Error::new(&err.to_string()).into()
+}
@@ -0,0 +1,39 @@
+use std::{env, fs, process};
+
+use rbx_dom_weak::{types::Variant, InstanceBuilder, WeakDom};
+use rbx_test_util::assert_dom_eq;
+
+#[test]
+fn file_round_trip() {
+    let _ = env_logger::try_init();
+
+    let path = env::temp_dir().join(format!("rbx_xml-file-round-trip-{}.rbxmx", process::id()));
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+
+    rbx_xml::to_file_default(&path, &dom, dom.root().children()).unwrap();
+    let decoded = rbx_xml::from_file_default(&path);
+    fs::remove_file(&path).unwrap();
+
+    let decoded = decoded.unwrap();
+    let value = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    assert_eq!(value.properties.get("Value"), Some(&Variant::Bool(true)));
+
+    let expected = WeakDom::new(
+        InstanceBuilder::new("DataModel")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+    assert_dom_eq!(expected, decoded);
+}
+
+#[test]
+fn missing_file() {
+    let path = env::temp_dir().join("rbx_xml-this-file-does-not-exist.rbxmx");
+
+    let error = rbx_xml::from_file_default(&path).unwrap_err();
+    assert_eq!(error.line(), 0);
+    assert!(!error.to_string().starts_with("line"));
+}
@@ -0,0 +1,64 @@
+use rbx_dom_weak::{types::Variant, InstanceBuilder, WeakDom};
+use rbx_test_util::assert_dom_eq;
+use rbx_xml::{DecodeOptions, DecodePropertyBehavior, EncodeOptions};
+
+#[test]
+fn string_round_trip() {
+    let _ = env_logger::try_init();
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder").with_child(
+            InstanceBuilder::new("StringValue")
+                .with_name("Grüße")
+                .with_property("Value", "héllo, wörld"),
+        ),
+    );
+
+    let encoded = rbx_xml::to_string_default(&dom, dom.root().children()).unwrap();
+
+    let expected = rbx_xml::encode_to_vec(&dom, dom.root().children()).unwrap();
+    assert_eq!(encoded.as_bytes(), expected.as_slice());
+
+    let decoded = rbx_xml::from_str_default(&encoded).unwrap();
+    let value = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    assert_eq!(value.name, "Grüße");
+    assert_eq!(
+        value.properties.get("Value"),
+        Some(&Variant::String("héllo, wörld".to_owned()))
+    );
+
+    let expected = WeakDom::new(
+        InstanceBuilder::new("DataModel").with_child(
+            InstanceBuilder::new("StringValue")
+                .with_name("Grüße")
+                .with_property("Value", "héllo, wörld"),
+        ),
+    );
+    assert_dom_eq!(expected, decoded);
+}
+
+#[test]
+fn string_round_trip_with_options() {
+    let _ = env_logger::try_init();
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder").with_property("NotAProperty", 5i32)),
+    );
+
+    let encode_options =
+        EncodeOptions::new().property_behavior(rbx_xml::EncodePropertyBehavior::NoReflection);
+    let encoded = rbx_xml::to_string(&dom, dom.root().children(), encode_options).unwrap();
+    assert!(encoded.contains(r#"<int name="NotAProperty">5</int>"#));
+
+    let decode_options =
+        DecodeOptions::new().property_behavior(DecodePropertyBehavior::NoReflection);
+    let decoded = rbx_xml::from_str(&encoded, decode_options).unwrap();
+    let folder = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    assert_eq!(
+        folder.properties.get("NotAProperty"),
+        Some(&Variant::Int32(5))
+    );
+
+    assert!(rbx_xml::from_str("<roblox", DecodeOptions::new()).is_err());
+}
@@ -0,0 +1,104 @@
+use rbx_dom_weak::{
+    types::{Ref, Variant},
+    Instance, InstanceBuilder, WeakDom,
+};
+use rbx_xml::EncodeOptions;
+
+fn is_not_script(instance: &Instance) -> bool {
+    !instance.is_a("LuaSourceContainer")
+}
+
+fn encode_without_scripts(dom: &WeakDom) -> WeakDom {
+    let mut buffer = Vec::new();
+    rbx_xml::encode_subset(
+        &mut buffer,
+        dom,
+        dom.root().children(),
+        is_not_script,
+        EncodeOptions::new(),
+    )
+    .unwrap();
+
+    rbx_xml::decode_from_slice(&buffer).unwrap()
+}
+
+fn child_names(dom: &WeakDom, parent: Ref) -> Vec<&str> {
+    dom.get_by_ref(parent)
+        .unwrap()
+        .children()
+        .iter()
+        .map(|child| dom.get_by_ref(*child).unwrap().name.as_str())
+        .collect()
+}
+
+#[test]
+fn exclude_scripts() {
+    let _ = env_logger::try_init();
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder").with_name("Kept"))
+            .with_child(InstanceBuilder::new("Script").with_name("Script"))
+            .with_child(InstanceBuilder::new("LocalScript").with_name("LocalScript"))
+            .with_child(
+                InstanceBuilder::new("Model").with_name("Model").with_child(
+                    InstanceBuilder::new("ModuleScript")
+                        .with_name("ModuleScript")
+                        .with_child(InstanceBuilder::new("Folder").with_name("Inside Script")),
+                ),
+            ),
+    );
+
+    let decoded = encode_without_scripts(&dom);
+
+    assert_eq!(child_names(&decoded, decoded.root_ref()), ["Kept", "Model"]);
+
+    let model = decoded
+        .find_first_child(decoded.root_ref(), "Model", false)
+        .unwrap();
+    assert!(model.children().is_empty());
+}
+
+#[test]
+fn refs_to_excluded_instances_are_null() {
+    let _ = env_logger::try_init();
+
+    let script = InstanceBuilder::new("Script").with_name("Script");
+    let script_ref = script.referent();
+    let part = InstanceBuilder::new("Part").with_name("Part");
+    let part_ref = part.referent();
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(script)
+            .with_child(part)
+            .with_child(
+                InstanceBuilder::new("ObjectValue")
+                    .with_name("ToScript")
+                    .with_property("Value", script_ref),
+            )
+            .with_child(
+                InstanceBuilder::new("ObjectValue")
+                    .with_name("ToPart")
+                    .with_property("Value", part_ref),
+            ),
+    );
+
+    let decoded = encode_without_scripts(&dom);
+    let root = decoded.root_ref();
+
+    assert_eq!(child_names(&decoded, root), ["Part", "ToScript", "ToPart"]);
+
+    let to_script = decoded.find_first_child(root, "ToScript", false).unwrap();
+    assert_eq!(
+        to_script.properties.get("Value"),
+        Some(&Variant::Ref(Ref::none()))
+    );
+
+    let decoded_part = decoded.find_first_child(root, "Part", false).unwrap();
+    let to_part = decoded.find_first_child(root, "ToPart", false).unwrap();
+    assert_eq!(
+        to_part.properties.get("Value"),
+        Some(&Variant::Ref(decoded_part.referent()))
+    );
+}
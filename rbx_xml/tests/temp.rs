@@ -1,6 +1,7 @@
 //! Temporary tests while re-bootstrapping rbx_xml
 
 use rbx_dom_weak::types::Variant;
+use rbx_test_util::dom_from_xml_str;
 
 #[test]
 fn with_bool() {
@@ -16,7 +17,7 @@ fn with_bool() {
         </roblox>
     "#;
 
-    let tree = rbx_xml::from_str_default(document).unwrap();
+    let tree = dom_from_xml_str!(document);
 
     let root = tree.root();
     let child = tree.get_by_ref(root.children()[0]).unwrap();
@@ -1,6 +1,7 @@
 use std::{fs, path::Path};
 
 use rbx_dom_weak::DomViewer;
+use rbx_test_util::dom_from_xml_str;
 
 macro_rules! test_models {
     ( $( $test_name: ident : $file_name: expr,)* ) => {
@@ -13,7 +14,7 @@ macro_rules! test_models {
                 path.push("xml.rbxmx");
 
                 let contents = fs::read_to_string(path).unwrap();
-                let dom = rbx_xml::from_str_default(&contents).unwrap();
+                let dom = dom_from_xml_str!(&contents);
 
                 let mut viewer = DomViewer::new();
 
@@ -0,0 +1,29 @@
+#![cfg(feature = "tracing")]
+
+use rbx_dom_weak::{InstanceBuilder, WeakDom};
+use rbx_test_util::SpanRecorder;
+
+#[test]
+fn spans_have_fields() {
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Part").with_property("Transparency", 0.5f32)),
+    );
+
+    let recorder = SpanRecorder::record(|| {
+        let mut buffer = Vec::new();
+        rbx_xml::to_writer_default(&mut buffer, &dom, dom.root().children()).unwrap();
+        rbx_xml::from_reader_default(buffer.as_slice()).unwrap();
+    });
+
+    assert!(recorder.has_span("encode_instance", &[("type_name", "Part")]));
+    assert!(recorder.has_span(
+        "encode_property",
+        &[("type_name", "Part"), ("prop_name", "Transparency")]
+    ));
+    assert!(recorder.has_span("decode_instance", &[("type_name", "Part")]));
+    assert!(recorder.has_span(
+        "decode_property",
+        &[("type_name", "Part"), ("prop_name", "Transparency")]
+    ));
+}
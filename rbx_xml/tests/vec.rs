@@ -0,0 +1,55 @@
+use rbx_dom_weak::{types::Variant, InstanceBuilder, WeakDom};
+use rbx_test_util::assert_dom_eq;
+use rbx_xml::{DecodeOptions, DecodePropertyBehavior, EncodeOptions};
+
+#[test]
+fn vec_round_trip() {
+    let _ = env_logger::try_init();
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+
+    let encoded = rbx_xml::encode_to_vec(&dom, dom.root().children()).unwrap();
+
+    let mut expected = Vec::new();
+    rbx_xml::to_writer_default(&mut expected, &dom, dom.root().children()).unwrap();
+    assert_eq!(encoded, expected);
+
+    let decoded = rbx_xml::decode_from_slice(&encoded).unwrap();
+    let value = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    assert_eq!(value.properties.get("Value"), Some(&Variant::Bool(true)));
+
+    let expected = WeakDom::new(
+        InstanceBuilder::new("DataModel")
+            .with_child(InstanceBuilder::new("BoolValue").with_property("Value", true)),
+    );
+    assert_dom_eq!(expected, decoded);
+}
+
+#[test]
+fn vec_round_trip_with_options() {
+    let _ = env_logger::try_init();
+
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder")
+            .with_child(InstanceBuilder::new("Folder").with_property("NotAProperty", 5i32)),
+    );
+
+    let encode_options =
+        EncodeOptions::new().property_behavior(rbx_xml::EncodePropertyBehavior::NoReflection);
+    let encoded =
+        rbx_xml::encode_to_vec_with_options(&dom, dom.root().children(), encode_options).unwrap();
+
+    let decode_options =
+        DecodeOptions::new().property_behavior(DecodePropertyBehavior::NoReflection);
+    let decoded = rbx_xml::decode_from_slice_with_options(&encoded, decode_options).unwrap();
+    let folder = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    assert_eq!(
+        folder.properties.get("NotAProperty"),
+        Some(&Variant::Int32(5))
+    );
+
+    assert!(rbx_xml::decode_from_slice(b"<roblox").is_err());
+}
@@ -0,0 +1,53 @@
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+// These tests only run in a WebAssembly host, which CI doesn't test. Run them
+// locally with `wasm-pack test --headless --firefox -- --features wasm`.
+
+use js_sys::{Array, Reflect};
+use rbx_dom_weak::{types::Variant, InstanceBuilder, WeakDom};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+use rbx_xml::wasm::{decode_from_slice, encode_to_vec, Dom};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn field(object: &JsValue, key: &str) -> JsValue {
+    Reflect::get(object, &key.into()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn round_trip() {
+    let dom = WeakDom::new(
+        InstanceBuilder::new("Folder").with_child(
+            InstanceBuilder::new("StringValue")
+                .with_name("Greeting")
+                .with_property("Value", "Hello, browser!"),
+        ),
+    );
+
+    let encoded = encode_to_vec(&Dom::from(dom)).unwrap();
+    let decoded = decode_from_slice(&encoded.to_vec()).unwrap();
+
+    let root = JsValue::from(decoded.to_object());
+    assert_eq!(field(&root, "className"), "DataModel");
+
+    let children = Array::from(&field(&root, "children"));
+    assert_eq!(children.length(), 1);
+
+    let child = children.get(0);
+    assert_eq!(field(&child, "name"), "Greeting");
+    assert_eq!(field(&child, "className"), "StringValue");
+
+    let decoded = decoded.into_inner();
+    let value = decoded.get_by_ref(decoded.root().children()[0]).unwrap();
+    assert_eq!(
+        value.properties.get("Value"),
+        Some(&Variant::String("Hello, browser!".to_owned()))
+    );
+}
+
+#[wasm_bindgen_test]
+fn invalid_input() {
+    assert!(decode_from_slice(b"<roblox version=\"4\"><Item").is_err());
+}